@@ -85,6 +85,7 @@ impl State {
             .animation(Motion {
                 response: Duration::from_millis(500),
                 damping: 0.6,
+                ..Motion::SMOOTH
             })
             .animates_layout(true),
         )
@@ -1,9 +1,14 @@
 use iced::{
     theme::palette::{Extended, Pair},
-    widget::{column, container, pick_list, row, text, tooltip, Row, Space},
+    widget::{column, container, pick_list, row, text, Row, Space},
     Border, Element, Length, Theme,
 };
-use iced_anim::{Animated, Animation, Event};
+use iced_anim::{
+    transition::Easing,
+    widget::tooltip::{self, tooltip},
+    Animated, Animation, Event,
+};
+use std::time::Duration;
 
 #[derive(Debug, Clone)]
 enum Message {
@@ -113,21 +118,22 @@ fn pair_square<'a>(name: String, pair: Pair) -> Element<'a, Message> {
                 background: Some(pair.color.into()),
                 ..Default::default()
             }),
-        container(text(name).style(|theme: &iced::Theme| text::Style {
-            color: Some(theme.palette().text),
-        }))
-        .style(|theme: &iced::Theme| container::Style {
-            background: Some(theme.extended_palette().background.weak.color.into()),
-            border: Border {
-                color: theme.extended_palette().background.base.color,
-                width: 1.0,
-                radius: 6.0.into(),
-            },
-            ..Default::default()
-        })
-        .padding(8),
+        text(name),
         tooltip::Position::Top,
     )
+    .padding(8)
+    .show_delay(Duration::from_millis(300))
+    .animation(Easing::EASE_OUT.with_duration(Duration::from_millis(150)))
+    .style(|theme: &iced::Theme| container::Style {
+        text_color: Some(theme.palette().text),
+        background: Some(theme.extended_palette().background.weak.color.into()),
+        border: Border {
+            color: theme.extended_palette().background.base.color,
+            width: 1.0,
+            radius: 6.0.into(),
+        },
+        ..Default::default()
+    })
     .into()
 }
 
@@ -1,6 +1,6 @@
 use iced::{
     gradient::{ColorStop, Linear},
-    widget::{checkbox, column, container, row, text},
+    widget::{column, container, row, text},
     Alignment::Center,
     Background, Border, Color, Element, Gradient,
     Length::Fill,
@@ -9,7 +9,11 @@ use iced::{
 use iced_anim::{
     spring::Motion,
     transition::Easing,
-    widget::button::{button, danger, primary, Status},
+    widget::{
+        animated_number,
+        button::{button, danger, primary, Status},
+        checkbox, text_input,
+    },
 };
 use std::{f32::consts::PI, sync::LazyLock, time::Duration};
 
@@ -17,6 +21,7 @@ use std::{f32::consts::PI, sync::LazyLock, time::Duration};
 enum Message {
     Adjust(i32),
     DisableButtons(bool),
+    EditLabel(String),
 }
 
 #[derive(Debug, Default)]
@@ -25,6 +30,8 @@ struct State {
     counter: i32,
     /// Whether the buttons in this example are disabled
     is_disabled: bool,
+    /// The label shown in the text input.
+    label: String,
 }
 
 impl State {
@@ -32,6 +39,7 @@ impl State {
         match message {
             Message::Adjust(amount) => self.counter += amount,
             Message::DisableButtons(disabled) => self.is_disabled = disabled,
+            Message::EditLabel(label) => self.label = label,
         };
     }
 
@@ -48,7 +56,12 @@ impl State {
                         .on_press_maybe(is_enabled.then_some(Message::Adjust(-10)))
                         .animation(Motion::default())
                         .style(danger_gradient),
-                    text(self.counter.to_string()).size(24).width(60.0).center(),
+                    animated_number(f64::from(self.counter))
+                        .size(24)
+                        .width(60.0)
+                        .center()
+                        .monospace()
+                        .animation(Motion::SMOOTH),
                     button(text("+10"))
                         .on_press_maybe(is_enabled.then_some(Message::Adjust(10)))
                         .animation(Motion::default())
@@ -58,15 +71,24 @@ impl State {
                 button(text("-"))
                     .on_press_maybe(is_enabled.then_some(Message::Adjust(-1)))
                     .animation(Easing::EASE)
-                    .style(danger),
+                    .style(danger)
+                    .ripple(true),
                 button(text("Reset").size(20))
                     .on_press_maybe(is_enabled.then_some(Message::Adjust(-self.counter)))
                     .animation(Motion {
                         response: Duration::from_millis(1000),
                         damping: Motion::SMOOTH.damping(),
+                        ..Motion::SMOOTH
                     })
                     .style(rainbow_style),
-                checkbox("Disable Buttons", self.is_disabled).on_toggle(Message::DisableButtons)
+                text_input("Counter label", &self.label)
+                    .on_input_maybe(is_enabled.then_some(Message::EditLabel))
+                    .animation(Easing::EASE_OUT)
+                    .style(focus_ring)
+                    .width(240),
+                checkbox("Disable Buttons", self.is_disabled)
+                    .on_toggle(Message::DisableButtons)
+                    .animation(Motion::BOUNCY)
             ]
             .align_x(Center)
             .spacing(8)
@@ -183,6 +205,31 @@ fn success_gradient(theme: &Theme, status: Status) -> iced::widget::button::Styl
     }
 }
 
+/// A text input whose border fades in to a thick focus ring when it's focused.
+fn focus_ring(theme: &Theme, status: text_input::Status) -> text_input::Style {
+    let palette = theme.extended_palette();
+    let default = text_input::default(theme, status);
+    let border = match status {
+        text_input::Status::Focused => Border::default()
+            .width(3.0)
+            .color(palette.primary.strong.color),
+        text_input::Status::Hovered => Border::default()
+            .width(1.0)
+            .color(palette.background.base.text),
+        text_input::Status::Active => Border::default()
+            .width(1.0)
+            .color(palette.background.strong.color),
+        text_input::Status::Disabled => Border::default()
+            .width(1.0)
+            .color(palette.background.weak.color),
+    };
+
+    text_input::Style {
+        border: border.rounded(8),
+        ..default
+    }
+}
+
 fn rainbow_style(_theme: &Theme, status: Status) -> iced::widget::button::Style {
     const RED: Color = Color::from_rgb(1.0, 0.0, 0.0);
     const ORANGE: Color = Color::from_rgb(1.0, 0.5, 0.0);
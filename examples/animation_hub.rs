@@ -0,0 +1,115 @@
+//! A benchmark of 50 animated values that are ticked by a single `AnimationHub`.
+//!
+//! Without the hub, each `Animation` publishes its own message every frame. With the hub,
+//! every value is ticked by one message per frame, which the counter at the top shows.
+use std::time::Instant;
+
+use iced::{
+    widget::{button, column, container, row, scrollable, text, Column},
+    Border, Element, Length,
+};
+use iced_anim::{
+    animation_hub::Tickable, spring::Motion, Animated, Animation, AnimationHub, Hub, HubHandle,
+};
+
+/// The number of animated values to show.
+const COUNT: usize = 50;
+
+#[derive(Debug, Clone)]
+enum Message {
+    Toggle,
+    Tick(Instant),
+}
+
+struct State {
+    hub: Hub,
+    bars: Vec<(Animated<f32>, HubHandle)>,
+    is_expanded: bool,
+    /// The number of tick messages received since the last toggle.
+    ticks: usize,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        let hub = Hub::new();
+        let bars = (0..COUNT)
+            .map(|_| (Animated::spring(20.0, Motion::SMOOTH), hub.register()))
+            .collect();
+
+        Self {
+            hub,
+            bars,
+            is_expanded: false,
+            ticks: 0,
+        }
+    }
+}
+
+impl State {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Toggle => {
+                self.is_expanded = !self.is_expanded;
+                self.ticks = 0;
+                for (index, (bar, _)) in self.bars.iter_mut().enumerate() {
+                    let target = if self.is_expanded {
+                        40.0 + 8.0 * (index % 20) as f32
+                    } else {
+                        20.0
+                    };
+                    bar.set_target(target);
+                }
+            }
+            Message::Tick(now) => {
+                self.ticks += 1;
+                let bars = self
+                    .bars
+                    .iter_mut()
+                    .map(|(bar, _)| bar as &mut dyn Tickable);
+                self.hub.apply_tick(now, bars);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let bars = Column::with_children(self.bars.iter().map(|(bar, handle)| {
+            Animation::new(
+                bar,
+                container("")
+                    .style(|theme: &iced::Theme| container::Style {
+                        border: Border {
+                            radius: 2.0.into(),
+                            ..Default::default()
+                        },
+                        background: Some(theme.extended_palette().primary.base.color.into()),
+                        ..Default::default()
+                    })
+                    .width(*bar.value())
+                    .height(6),
+            )
+            .hub(handle)
+            .into()
+        }))
+        .spacing(2);
+
+        let content = column![
+            row![
+                button(text("Toggle")).on_press(Message::Toggle),
+                text(format!("{} values, {} tick messages", COUNT, self.ticks)),
+            ]
+            .spacing(8)
+            .align_y(iced::Alignment::Center),
+            scrollable(bars).height(Length::Fill),
+        ]
+        .spacing(8)
+        .padding(8);
+
+        AnimationHub::new(&self.hub, content)
+            .on_tick(Message::Tick)
+            .into()
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::run("Animation hub", State::update, State::view)
+}
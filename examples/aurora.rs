@@ -0,0 +1,288 @@
+//! An ambient aurora background of soft blobs that drift, breathe, and change color forever.
+//!
+//! Every property oscillates between two values with its own period, so the scene never settles
+//! and rarely repeats. It doubles as a soak test for long-running animations:
+//!
+//! - The backdrop is a linear gradient using all 8 of its stops, which animate as one value.
+//! - Oscillations flip their target as soon as they finish, so they never come to rest.
+//! - Frames are only requested while the window is focused, so an unfocused window doesn't keep
+//!   the CPU busy. The animations resume where they left off when focus returns instead of
+//!   jumping ahead by the time they were paused.
+//! - Ticking transitions doesn't allocate, so memory stays flat when left running for hours.
+use std::time::{Duration, Instant};
+
+use iced::{
+    event,
+    gradient::Linear,
+    mouse::Cursor,
+    widget::{
+        canvas::{self, Frame, Geometry, Path},
+        container, Canvas,
+    },
+    window, Background, Color, Element, Event,
+    Length::Fill,
+    Point, Radians, Rectangle, Renderer, Subscription, Theme,
+};
+use iced_anim::{transition::Easing, Animate, Animated};
+
+/// The number of concentric circles used to fake the blur of each blob.
+const RINGS: usize = 12;
+
+#[derive(Debug, Clone)]
+enum Message {
+    Tick(Instant),
+    Focused(bool),
+}
+
+/// A value that eases back and forth between two values forever.
+struct Oscillator<T> {
+    animated: Animated<T>,
+    from: T,
+    to: T,
+}
+
+impl<T: Animate> Oscillator<T> {
+    fn new(from: T, to: T, period: Duration) -> Self {
+        Self {
+            animated: Animated::transition(from.clone(), Easing::EASE_IN_OUT.with_duration(period))
+                .to(to.clone()),
+            from,
+            to,
+        }
+    }
+
+    fn value(&self) -> &T {
+        self.animated.value()
+    }
+
+    fn resume_at(&mut self, now: Instant) {
+        self.animated.resume_at(now);
+    }
+
+    fn tick(&mut self, now: Instant) {
+        self.animated.tick(now);
+        if !self.animated.is_animating() {
+            // Head back the other way, keeping the frame's clock so the loop doesn't drift.
+            let next = if self.animated.target() == &self.to {
+                self.from.clone()
+            } else {
+                self.to.clone()
+            };
+            self.animated.set_target(next);
+            self.animated.resume_at(now);
+        }
+    }
+}
+
+/// A soft, glowing blob that wanders around the window.
+struct Blob {
+    /// The center of the blob, relative to the size of the window.
+    center: Oscillator<Point>,
+    /// The radius of the blob, relative to the smaller side of the window.
+    radius: Oscillator<f32>,
+    color: Oscillator<Color>,
+}
+
+impl Blob {
+    fn new(seed: u64, from: Color, to: Color) -> Self {
+        // Offset each period so the blobs drift in and out of sync with each other.
+        let period = |base: u64| Duration::from_millis(base + seed * 1_300);
+        let x = 0.15 + 0.7 * ((seed * 37) % 10) as f32 / 10.0;
+        let y = 0.15 + 0.7 * ((seed * 53) % 10) as f32 / 10.0;
+
+        Self {
+            center: Oscillator::new(
+                Point::new(x, y),
+                Point::new(1.0 - y, 1.0 - x),
+                period(9_000),
+            ),
+            radius: Oscillator::new(0.2, 0.4, period(5_000)),
+            color: Oscillator::new(from, to, period(7_000)),
+        }
+    }
+
+    fn oscillators(&mut self) -> [&mut dyn Tick; 3] {
+        [&mut self.center, &mut self.radius, &mut self.color]
+    }
+}
+
+/// Lets every oscillator be ticked and resumed together regardless of its value type.
+trait Tick {
+    fn tick(&mut self, now: Instant);
+    fn resume_at(&mut self, now: Instant);
+}
+
+impl<T: Animate> Tick for Oscillator<T> {
+    fn tick(&mut self, now: Instant) {
+        Oscillator::tick(self, now);
+    }
+
+    fn resume_at(&mut self, now: Instant) {
+        Oscillator::resume_at(self, now);
+    }
+}
+
+/// A diagonal gradient through the given `colors`, using all 8 stops.
+fn backdrop(angle: f32, colors: [Color; 8]) -> Linear {
+    colors
+        .into_iter()
+        .enumerate()
+        .fold(Linear::new(Radians(angle)), |gradient, (index, color)| {
+            gradient.add_stop(index as f32 / 7.0, color)
+        })
+}
+
+struct State {
+    backdrop: Oscillator<Linear>,
+    blobs: Vec<Blob>,
+    is_focused: bool,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        let night = [
+            Color::from_rgb8(0x05, 0x06, 0x1A),
+            Color::from_rgb8(0x0B, 0x10, 0x33),
+            Color::from_rgb8(0x10, 0x1E, 0x4A),
+            Color::from_rgb8(0x0E, 0x2F, 0x52),
+            Color::from_rgb8(0x0C, 0x3B, 0x4F),
+            Color::from_rgb8(0x0A, 0x2A, 0x3A),
+            Color::from_rgb8(0x07, 0x17, 0x26),
+            Color::from_rgb8(0x03, 0x08, 0x12),
+        ];
+        let dusk = [
+            Color::from_rgb8(0x14, 0x05, 0x24),
+            Color::from_rgb8(0x26, 0x0A, 0x3D),
+            Color::from_rgb8(0x3A, 0x10, 0x4F),
+            Color::from_rgb8(0x2C, 0x1B, 0x5C),
+            Color::from_rgb8(0x16, 0x26, 0x5E),
+            Color::from_rgb8(0x0B, 0x20, 0x45),
+            Color::from_rgb8(0x06, 0x12, 0x2B),
+            Color::from_rgb8(0x02, 0x05, 0x10),
+        ];
+
+        Self {
+            backdrop: Oscillator::new(
+                backdrop(0.6, night),
+                backdrop(1.2, dusk),
+                Duration::from_secs(17),
+            ),
+            blobs: vec![
+                Blob::new(
+                    0,
+                    Color::from_rgb8(0x3C, 0xF2, 0xA0),
+                    Color::from_rgb8(0x2E, 0x9C, 0xF2),
+                ),
+                Blob::new(
+                    1,
+                    Color::from_rgb8(0x8E, 0x5C, 0xF7),
+                    Color::from_rgb8(0xF2, 0x5C, 0xB6),
+                ),
+                Blob::new(
+                    2,
+                    Color::from_rgb8(0x2E, 0xD8, 0xE6),
+                    Color::from_rgb8(0x5C, 0xF2, 0x6E),
+                ),
+                Blob::new(
+                    3,
+                    Color::from_rgb8(0xF2, 0x9E, 0x4C),
+                    Color::from_rgb8(0xA0, 0x3C, 0xF2),
+                ),
+            ],
+            is_focused: true,
+        }
+    }
+}
+
+impl State {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Tick(now) => {
+                self.backdrop.tick(now);
+                for blob in &mut self.blobs {
+                    for oscillator in blob.oscillators() {
+                        oscillator.tick(now);
+                    }
+                }
+            }
+            Message::Focused(is_focused) => {
+                self.is_focused = is_focused;
+                if is_focused {
+                    // Skip the time spent unfocused instead of jumping ahead.
+                    let now = Instant::now();
+                    self.backdrop.resume_at(now);
+                    for blob in &mut self.blobs {
+                        for oscillator in blob.oscillators() {
+                            oscillator.resume_at(now);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        let focus = event::listen_with(|event, _status, _window| match event {
+            Event::Window(window::Event::Focused) => Some(Message::Focused(true)),
+            Event::Window(window::Event::Unfocused) => Some(Message::Focused(false)),
+            _ => None,
+        });
+
+        // Only request frames while the window is focused.
+        if self.is_focused {
+            Subscription::batch([focus, window::frames().map(Message::Tick)])
+        } else {
+            focus
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let backdrop = *self.backdrop.value();
+        container(Canvas::new(self).width(Fill).height(Fill))
+            .style(move |_| container::Style {
+                background: Some(Background::Gradient(backdrop.into())),
+                ..Default::default()
+            })
+            .into()
+    }
+}
+
+impl canvas::Program<Message> for State {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry<Renderer>> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let scale = bounds.width.min(bounds.height);
+
+        for blob in &self.blobs {
+            let center = blob.center.value();
+            let center = Point::new(center.x * bounds.width, center.y * bounds.height);
+            let radius = blob.radius.value() * scale;
+            let color = *blob.color.value();
+
+            // Overlapping translucent rings are densest in the middle and fade out at the edge.
+            for ring in 0..RINGS {
+                let ring_radius = radius * (RINGS - ring) as f32 / RINGS as f32;
+                frame.fill(
+                    &Path::circle(center, ring_radius),
+                    color.scale_alpha(0.6 / RINGS as f32),
+                );
+            }
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::application("Aurora", State::update, State::view)
+        .subscription(State::subscription)
+        .run()
+}
@@ -0,0 +1,82 @@
+//! Animates the progress of a background task purely from a subscription, without putting an
+//! `Animation` widget in the view, so the progress keeps animating while it's hidden.
+use std::time::{Duration, Instant};
+
+use iced::{
+    widget::{button, checkbox, column, progress_bar, row, text},
+    Element, Subscription,
+};
+use iced_anim::{transition::Easing, Animated};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Advance,
+    ShowProgress(bool),
+    Tick(Instant),
+}
+
+struct State {
+    progress: Animated<f32>,
+    is_visible: bool,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            progress: Animated::transition(0.0, Easing::EASE_IN_OUT)
+                .with_duration(Duration::from_millis(800)),
+            is_visible: true,
+        }
+    }
+}
+
+impl State {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Advance => {
+                let target = (self.progress.target() + 0.25).min(1.0);
+                self.progress.set_target(target);
+            }
+            Message::ShowProgress(is_visible) => self.is_visible = is_visible,
+            Message::Tick(now) => {
+                self.progress.tick(now);
+                println!("Progress: {:.1}%", self.progress.value() * 100.0);
+            }
+        }
+    }
+
+    /// Only request ticks while the progress is animating.
+    fn subscription(&self) -> Subscription<Message> {
+        iced_anim::subscription::ticks(self.progress.is_animating()).map(Message::Tick)
+    }
+
+    fn view(&self) -> Element<Message> {
+        let progress = if self.is_visible {
+            column![
+                progress_bar(0.0..=1.0, *self.progress.value()),
+                text(format!("{:.0}%", self.progress.value() * 100.0)),
+            ]
+            .spacing(8)
+        } else {
+            column![text("Hidden, but still animating. Check the logs!")]
+        };
+
+        column![
+            row![
+                button("Advance").on_press(Message::Advance),
+                checkbox("Show progress", self.is_visible).on_toggle(Message::ShowProgress),
+            ]
+            .spacing(16),
+            progress,
+        ]
+        .spacing(16)
+        .padding(16)
+        .into()
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::application("Background progress", State::update, State::view)
+        .subscription(State::subscription)
+        .run()
+}
@@ -0,0 +1,96 @@
+//! Cycles a hero banner through a sequence of colors with a `Carousel`.
+//!
+//! The carousel advances on its own every few seconds, and the buttons navigate manually.
+//! Going back from the first color animates straight to the last one instead of through
+//! every color in between.
+use std::time::{Duration, Instant};
+
+use iced::{
+    widget::{button, column, container, row, text},
+    Color, Element, Length,
+};
+use iced_anim::{transition::Easing, AnimationHub, Carousel, Hub, HubHandle};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Prev,
+    Next,
+    Tick(Instant),
+}
+
+struct State {
+    hub: Hub,
+    hero: (Carousel<Color>, HubHandle),
+}
+
+impl Default for State {
+    fn default() -> Self {
+        let hub = Hub::new();
+        let colors = [
+            Color::from_rgb8(0xE6, 0x39, 0x46),
+            Color::from_rgb8(0xF4, 0xA2, 0x61),
+            Color::from_rgb8(0x2A, 0x9D, 0x8F),
+            Color::from_rgb8(0x26, 0x46, 0x53),
+        ];
+        let easing = Easing::EASE_IN_OUT.with_duration(Duration::from_millis(600));
+        let carousel = Carousel::new(colors, easing).with_auto_advance(Duration::from_secs(3));
+        let handle = hub.register();
+
+        Self {
+            hub,
+            hero: (carousel, handle),
+        }
+    }
+}
+
+impl State {
+    fn update(&mut self, message: Message) {
+        let (carousel, _) = &mut self.hero;
+        match message {
+            Message::Prev => carousel.prev(),
+            Message::Next => carousel.next(),
+            Message::Tick(now) => carousel.tick(now),
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let (carousel, handle) = &self.hero;
+        handle.track(carousel);
+
+        let color = *carousel.value();
+        let hero = container(
+            text(format!(
+                "Slide {} of {}",
+                carousel.index() + 1,
+                carousel.entries().len()
+            ))
+            .size(32)
+            .color(Color::WHITE),
+        )
+        .center(Length::Fill)
+        .style(move |_| container::Style {
+            background: Some(color.into()),
+            ..Default::default()
+        });
+
+        let content = column![
+            hero,
+            row![
+                button(text("Previous")).on_press(Message::Prev),
+                button(text("Next")).on_press(Message::Next),
+            ]
+            .spacing(8),
+        ]
+        .spacing(8)
+        .padding(8)
+        .align_x(iced::Alignment::Center);
+
+        AnimationHub::new(&self.hub, content)
+            .on_tick(Message::Tick)
+            .into()
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::run("Carousel", State::update, State::view)
+}
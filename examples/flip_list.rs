@@ -0,0 +1,101 @@
+//! An example of a list whose rows slide to their new positions when it's re-sorted.
+use iced::{
+    widget::{button, column, container, row, text},
+    Element, Length,
+};
+use iced_anim::{spring::Motion, widget::flip_group};
+
+#[derive(Debug, Clone)]
+enum Message {
+    SortByName,
+    SortByScore,
+    Rotate,
+    Add,
+    Remove,
+}
+
+struct Player {
+    id: u32,
+    name: String,
+    score: u32,
+}
+
+struct State {
+    players: Vec<Player>,
+    next_id: u32,
+}
+
+const NAMES: [&str; 12] = [
+    "Ada", "Grace", "Linus", "Barbara", "Ken", "Margaret", "Dennis", "Frances", "Alan", "Radia",
+    "Edsger", "Hedy",
+];
+
+impl Default for State {
+    fn default() -> Self {
+        let mut state = Self {
+            players: Vec::new(),
+            next_id: 0,
+        };
+        for _ in 0..10 {
+            state.add();
+        }
+        state
+    }
+}
+
+impl State {
+    fn add(&mut self) {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.players.push(Player {
+            id,
+            name: NAMES[id as usize % NAMES.len()].to_string(),
+            score: (id * 37 + 11) % 100,
+        });
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::SortByName => self.players.sort_by(|a, b| a.name.cmp(&b.name)),
+            Message::SortByScore => self
+                .players
+                .sort_by_key(|player| std::cmp::Reverse(player.score)),
+            Message::Rotate => self.players.rotate_left(1),
+            Message::Add => self.add(),
+            Message::Remove => {
+                self.players.pop();
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let controls = row![
+            button("Sort by name").on_press(Message::SortByName),
+            button("Sort by score").on_press(Message::SortByScore),
+            button("Rotate").on_press(Message::Rotate),
+            button("Add").on_press(Message::Add),
+            button("Remove").on_press(Message::Remove),
+        ]
+        .spacing(8);
+
+        let players = flip_group(self.players.iter().map(|player| {
+            let row = row![
+                text(&player.name).width(Length::Fill),
+                text(player.score.to_string()),
+            ];
+            let row = container(row)
+                .padding(8)
+                .width(240)
+                .style(container::rounded_box);
+            (player.id, row.into())
+        }))
+        .spacing(4)
+        .animation(Motion::SNAPPY);
+
+        column![controls, players].spacing(16).padding(16).into()
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::run("FLIP list", State::update, State::view)
+}
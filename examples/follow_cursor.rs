@@ -0,0 +1,64 @@
+//! An example of cards that spring towards the cursor while they're hovered.
+use iced::{
+    widget::{checkbox, column, container, row, text},
+    Element, Length, Vector,
+};
+use iced_anim::{spring::Motion, widget::follow_cursor};
+
+#[derive(Debug, Clone)]
+enum Message {
+    ToggleEnabled(bool),
+}
+
+struct State {
+    is_enabled: bool,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self { is_enabled: true }
+    }
+}
+
+impl State {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::ToggleEnabled(is_enabled) => self.is_enabled = is_enabled,
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let card = |label: &'static str, motion: Motion| {
+            let card = container(text(label))
+                .padding(24)
+                .style(container::rounded_box);
+
+            // Each card sits in a larger hover area so it has room to move around.
+            let follower = follow_cursor(card)
+                .animation(motion)
+                .offset(Vector::new(0.0, -12.0))
+                .enabled(self.is_enabled);
+            container(follower)
+                .center(160)
+                .style(container::bordered_box)
+        };
+
+        column![
+            checkbox("Follow the cursor", self.is_enabled).on_toggle(Message::ToggleEnabled),
+            row![
+                card("Smooth", Motion::SMOOTH),
+                card("Snappy", Motion::SNAPPY),
+                card("Bouncy", Motion::BOUNCY),
+            ]
+            .spacing(16),
+        ]
+        .spacing(16)
+        .padding(16)
+        .width(Length::Fill)
+        .into()
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::run("Follow cursor", State::update, State::view)
+}
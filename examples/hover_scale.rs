@@ -0,0 +1,54 @@
+//! Springs a button up to a slightly larger scale while it's hovered, without moving the widgets
+//! around it.
+use iced::{
+    widget::{button, center, column, mouse_area, text},
+    Element,
+};
+use iced_anim::{
+    spring::Motion,
+    widget::transform::{transform, Transform},
+};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Hover(bool),
+    Pressed,
+}
+
+#[derive(Default)]
+struct State {
+    is_hovered: bool,
+    presses: usize,
+}
+
+impl State {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Hover(is_hovered) => self.is_hovered = is_hovered,
+            Message::Pressed => self.presses += 1,
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let scale = if self.is_hovered { 1.1 } else { 1.0 };
+        let button = transform(button(text("Hover me").size(24)).on_press(Message::Pressed))
+            .transform(Transform::scale(scale))
+            .animation(Motion::BOUNCY);
+
+        center(
+            column![
+                mouse_area(button)
+                    .on_enter(Message::Hover(true))
+                    .on_exit(Message::Hover(false)),
+                text(format!("Pressed {} times", self.presses)),
+            ]
+            .spacing(16)
+            .align_x(iced::Alignment::Center),
+        )
+        .into()
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::application("Hover scale", State::update, State::view).run()
+}
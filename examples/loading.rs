@@ -0,0 +1,68 @@
+//! An example of the self-driven loading indicators next to a determinate progress bar.
+use iced::{
+    widget::{button, checkbox, column, progress_bar, row, text},
+    Element, Length,
+};
+use iced_anim::{
+    widget::{indeterminate_bar, spinner},
+    AnimationBuilder,
+};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Advance,
+    ToggleReducedMotion(bool),
+}
+
+#[derive(Default)]
+struct State {
+    /// The progress of the determinate bar, from 0 to 100.
+    progress: f32,
+    /// Whether the indicators should stay still.
+    reduced_motion: bool,
+}
+
+impl State {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Advance => {
+                self.progress = if self.progress >= 100.0 {
+                    0.0
+                } else {
+                    (self.progress + 20.0).min(100.0)
+                };
+            }
+            Message::ToggleReducedMotion(reduced_motion) => self.reduced_motion = reduced_motion,
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let determinate = AnimationBuilder::new(self.progress, |progress| {
+            progress_bar(0.0..=100.0, progress).height(4).into()
+        })
+        .disabled(self.reduced_motion);
+
+        column![
+            row![spinner().disabled(self.reduced_motion), text("Loading..."),]
+                .spacing(12)
+                .align_y(iced::Alignment::Center),
+            indeterminate_bar().disabled(self.reduced_motion),
+            determinate,
+            row![
+                button(text("Advance")).on_press(Message::Advance),
+                checkbox("Reduce motion", self.reduced_motion)
+                    .on_toggle(Message::ToggleReducedMotion),
+            ]
+            .spacing(12)
+            .align_y(iced::Alignment::Center),
+        ]
+        .spacing(16)
+        .padding(16)
+        .width(Length::Fixed(320.0))
+        .into()
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::run("Loading", State::update, State::view)
+}
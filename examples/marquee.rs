@@ -0,0 +1,62 @@
+//! An example of labels that automatically scroll when they're too long to fit.
+use std::time::Duration;
+
+use iced::{
+    widget::{column, container, text},
+    Border, Element, Length,
+};
+use iced_anim::widget::{marquee, marquee::Rewind};
+
+#[derive(Debug, Clone)]
+enum Message {}
+
+#[derive(Default)]
+struct State;
+
+impl State {
+    /// Some track titles, a few of which are too long to fit.
+    const TITLES: [&'static str; 4] = [
+        "Short song",
+        "A song with a title that is far too long to fit in the player",
+        "Another fits",
+        "Extended mix (featuring several artists whose names keep going on and on)",
+    ];
+
+    fn update(&mut self, message: Message) {
+        match message {}
+    }
+
+    fn view(&self) -> Element<Message> {
+        let labels = State::TITLES.iter().enumerate().map(|(index, title)| {
+            // Alternate between the two rewind styles to show them both off.
+            let rewind = if index % 2 == 0 {
+                Rewind::Scroll
+            } else {
+                Rewind::Snap
+            };
+
+            container(
+                marquee(text(*title))
+                    .hold(Duration::from_secs(1))
+                    .rewind(rewind),
+            )
+            .style(|theme: &iced::Theme| container::Style {
+                border: Border {
+                    color: theme.extended_palette().secondary.strong.color,
+                    width: 1.0,
+                    radius: 6.0.into(),
+                },
+                ..Default::default()
+            })
+            .padding(8)
+            .width(Length::Fixed(240.0))
+            .into()
+        });
+
+        column(labels).spacing(8).padding(8).into()
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::run("Marquee", State::update, State::view)
+}
@@ -1,7 +1,7 @@
-//! Note: this example doesn't yet fully work as expected. The core issue is needing to rebuild
-//! the `cached_element` in `AnimationBuilder::on_event`, but trying to propagate events to the
-//! updated widget causes a panic in the `preview_motion` example. This is related to the
-//! `push_maybe` calls in that example, although I haven't figured out a workaround yet.
+//! Animates a size and a color together, either separately or at the same time.
+//!
+//! Both values are driven by a single `Animation` widget that takes a tuple of them, rather than
+//! nesting an `Animation` per value.
 use iced::{
     widget::{button, column, container, row, text},
     Border, Color, Element, Length,
@@ -13,6 +13,7 @@ enum Message {
     AdjustAll,
     ChangeSize(Event<f32>),
     ChangeColor(Event<Color>),
+    Tick(Event<(f32, Color)>),
 }
 
 struct State {
@@ -55,6 +56,10 @@ impl State {
             }
             Message::ChangeSize(event) => self.size.update(event),
             Message::ChangeColor(event) => self.color.update(event),
+            Message::Tick(event) => {
+                self.size.update(event.map(|(size, _)| size));
+                self.color.update(event.map(|(_, color)| color));
+            }
         }
     }
 
@@ -81,24 +86,21 @@ impl State {
         .spacing(8);
 
         let animated_box = Animation::new(
-            &self.size,
-            Animation::new(
-                &self.color,
-                container(text((*self.size.value() as isize).to_string()))
-                    .style(move |_: &iced::Theme| container::Style {
-                        border: Border {
-                            color: *self.color.value(),
-                            width: 1.0,
-                            radius: 6.0.into(),
-                        },
-                        background: Some((*self.color.value()).into()),
-                        ..Default::default()
-                    })
-                    .center(*self.size.value()),
-            )
-            .on_update(Message::ChangeColor),
+            (&self.size, &self.color),
+            container(text((*self.size.value() as isize).to_string()))
+                .style(move |_: &iced::Theme| container::Style {
+                    border: Border {
+                        color: *self.color.value(),
+                        width: 1.0,
+                        radius: 6.0.into(),
+                    },
+                    background: Some((*self.color.value()).into()),
+                    ..Default::default()
+                })
+                .center(*self.size.value()),
         )
-        .on_update(Message::ChangeSize);
+        .animates_layout(true)
+        .on_update(Message::Tick);
 
         column![buttons, animated_box]
             .spacing(8)
@@ -1,10 +1,11 @@
 use iced::{
-    widget::{button, column, container, pick_list, radio, row, stack, text, Column, Row, Space},
+    widget::{button, column, container, pick_list, row, stack, text, Column, Row, Space},
     Alignment::Center,
     Border, Element, Length,
 };
 use iced_anim::{
     animated::Mode, animation_builder::AnimationBuilder, spring::Motion, transition::Easing,
+    widget::radio,
 };
 
 const CIRCLE_DIAMETER: f32 = 50.0;
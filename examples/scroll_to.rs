@@ -0,0 +1,61 @@
+//! An example of a scrollable that glides to the top or bottom instead of jumping there.
+use iced::{
+    widget::{button, column, row, scrollable::AbsoluteOffset, text},
+    Element, Length, Task,
+};
+use iced_anim::{
+    spring::Motion,
+    widget::scrollable::{self, scrollable, Id},
+};
+
+#[derive(Debug, Clone)]
+enum Message {
+    JumpToTop,
+    JumpToBottom,
+}
+
+fn log_id() -> Id {
+    Id::new("log")
+}
+
+#[derive(Default)]
+struct State;
+
+impl State {
+    fn update(&mut self, message: Message) -> Task<Message> {
+        let y = match message {
+            Message::JumpToTop => 0.0,
+            // Offsets past the end are clamped, so this always lands on the last entry.
+            Message::JumpToBottom => f32::INFINITY,
+        };
+
+        scrollable::scroll_to(log_id(), AbsoluteOffset { x: 0.0, y })
+    }
+
+    fn view(&self) -> Element<Message> {
+        let entries = column((1..=200).map(|line| text(format!("Log entry #{line}")).into()))
+            .spacing(4)
+            .width(Length::Fill);
+
+        column![
+            row![
+                button("Jump to top").on_press(Message::JumpToTop),
+                button("Jump to bottom").on_press(Message::JumpToBottom),
+            ]
+            .spacing(8),
+            scrollable(
+                log_id(),
+                iced::widget::scrollable(entries).height(Length::Fill)
+            )
+            .animation(Motion::SMOOTH),
+            text("Scroll with the mouse wheel mid-animation to take over."),
+        ]
+        .spacing(12)
+        .padding(16)
+        .into()
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::application("Scroll to", State::update, State::view).run()
+}
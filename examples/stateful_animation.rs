@@ -2,6 +2,9 @@
 //! the `AnimationBuilder` widget. Stateful animations store the animation
 //! within the app state whereas the `AnimationBuilder` widget stores the
 //! animation within the widget tree.
+//!
+//! Pressing and dragging makes the bubble track the cursor closely with an
+//! interactive spring, and releasing hands its momentum to a bouncier spring.
 use std::time::Duration;
 
 use iced::{
@@ -16,6 +19,7 @@ use iced_anim::{spring::Motion, Animated, Animation, Event};
 enum Message {
     UpdatePosition(Event<Point>),
     Resized(Size),
+    Drag(bool),
 }
 
 struct State {
@@ -25,16 +29,17 @@ struct State {
     size: Size,
 }
 
+/// The motion of the bubble while it isn't being dragged.
+const RELEASED: Motion = Motion {
+    response: Duration::from_millis(500),
+    damping: 0.6,
+    ..Motion::SMOOTH
+};
+
 impl Default for State {
     fn default() -> Self {
         Self {
-            position: Animated::spring(
-                Point::new(50.0, 50.0),
-                Motion {
-                    response: Duration::from_millis(500),
-                    damping: 0.6,
-                },
-            ),
+            position: Animated::spring(Point::new(50.0, 50.0), RELEASED),
             size: Size::new(1024.0, 768.0),
         }
     }
@@ -45,6 +50,9 @@ impl State {
         match message {
             Message::UpdatePosition(event) => self.position.update(event),
             Message::Resized(size) => self.size = size,
+            // Switching motions keeps the velocity, so releasing mid-drag doesn't hitch.
+            Message::Drag(true) => self.position.set_mode(Motion::INTERACTIVE),
+            Message::Drag(false) => self.position.set_mode(RELEASED),
         }
     }
 
@@ -77,7 +85,9 @@ impl State {
                 )
                 .on_update(Message::UpdatePosition),
             )
-            .on_move(|point| Message::UpdatePosition(point.into())),
+            .on_move(|point| Message::UpdatePosition(point.into()))
+            .on_press(Message::Drag(true))
+            .on_release(Message::Drag(false)),
         )
         .width(Fill)
         .height(Fill)
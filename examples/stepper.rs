@@ -0,0 +1,63 @@
+//! An example of numeric steppers that animate their values and repeat while held.
+use iced::{
+    widget::{column, row, text},
+    Alignment, Element,
+};
+use iced_anim::{spring::Motion, transition::Easing, widget::stepper};
+
+#[derive(Debug, Clone)]
+enum Message {
+    ChangeQuantity(f32),
+    ChangeVolume(f32),
+}
+
+struct State {
+    quantity: f32,
+    volume: f32,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            quantity: 1.0,
+            volume: 5.0,
+        }
+    }
+}
+
+impl State {
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::ChangeQuantity(quantity) => self.quantity = quantity,
+            Message::ChangeVolume(volume) => self.volume = volume,
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let quantity = stepper(self.quantity)
+            .range(1.0..=99.0)
+            .on_change(Message::ChangeQuantity)
+            .animation(Motion::SNAPPY);
+
+        let volume = stepper(self.volume)
+            .range(0.0..=10.0)
+            .step(0.5)
+            .format(|volume| format!("{volume:.1} dB"))
+            .display_width(96.0)
+            .on_change(Message::ChangeVolume)
+            .animation(Easing::EASE_OUT);
+
+        column![
+            row![text("Quantity").width(80), quantity].align_y(Alignment::Center),
+            row![text("Volume").width(80), volume].align_y(Alignment::Center),
+            text("Hold a button to repeat, or step past the bounds to see them push back."),
+        ]
+        .spacing(12)
+        .padding(16)
+        .into()
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::run("Stepper", State::update, State::view)
+}
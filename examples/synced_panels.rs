@@ -0,0 +1,75 @@
+//! An example of several panels resizing in exact lockstep by sharing a single
+//! animated progress with a `SharedMotion`.
+use iced::{
+    widget::{button, column, container, row, text},
+    Border, Element, Length,
+};
+use iced_anim::{transition::Easing, AnimationBuilder, SharedMotion};
+
+#[derive(Debug, Clone)]
+enum Message {
+    Toggle,
+}
+
+struct State {
+    /// The shared progress that every panel is driven by.
+    motion: SharedMotion,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            motion: SharedMotion::new(0.0, Easing::EASE_IN_OUT),
+        }
+    }
+}
+
+impl State {
+    /// The minimum and maximum heights of each panel.
+    const RANGES: [(f32, f32); 3] = [(40.0, 240.0), (120.0, 60.0), (80.0, 160.0)];
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Toggle => {
+                let target = if self.motion.target() > 0.5 { 0.0 } else { 1.0 };
+                self.motion.set_target(target);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let panels = row(State::RANGES.iter().map(|&(from, to)| {
+            AnimationBuilder::new(from, |height| {
+                container(text(height as isize))
+                    .style(|theme: &iced::Theme| container::Style {
+                        border: Border {
+                            color: theme.extended_palette().primary.strong.color,
+                            width: 1.0,
+                            radius: 6.0.into(),
+                        },
+                        background: Some(theme.extended_palette().primary.weak.color.into()),
+                        ..Default::default()
+                    })
+                    .center_x(80)
+                    .center_y(height)
+                    .into()
+            })
+            // Each panel maps the shared progress to its own range.
+            .driven_by(&self.motion, move |progress| from + (to - from) * progress)
+            .animates_layout(true)
+            .into()
+        }))
+        .spacing(8)
+        .align_y(iced::Alignment::End)
+        .height(Length::Fixed(240.0));
+
+        column![button(text("Toggle")).on_press(Message::Toggle), panels]
+            .spacing(8)
+            .padding(8)
+            .into()
+    }
+}
+
+pub fn main() -> iced::Result {
+    iced::run("Synced panels", State::update, State::view)
+}
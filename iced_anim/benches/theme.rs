@@ -0,0 +1,49 @@
+//! Compares ticking a theme animation where only a few colors change with one where every color
+//! changes, since springs only step the components that are moving.
+//!
+//! Run it with `cargo bench -p iced_anim --bench theme`.
+use iced::{theme::Palette, Color, Theme};
+use iced_anim::Spring;
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// How many times each animation is run from start to finish.
+const RUNS: u32 = 200;
+
+/// How many frames each animation is ticked for.
+const FRAMES: u32 = 60;
+
+/// The time between frames.
+const FRAME: Duration = Duration::from_millis(16);
+
+/// Animates a spring from `from` to `to` repeatedly and prints the average time per tick.
+fn bench(name: &str, from: &Theme, to: &Theme) {
+    let started = Instant::now();
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        let mut spring = Spring::new_at(from.clone(), start);
+        spring.set_target_at(to.clone(), start);
+        for frame in 1..=FRAMES {
+            spring.tick(start + FRAME * frame);
+        }
+        black_box(spring.value());
+    }
+    let per_tick = started.elapsed() / (RUNS * FRAMES);
+    println!("{name:<16} {per_tick:?} per tick");
+}
+
+fn main() {
+    // Only the primary color and the extended colors derived from it change.
+    let accent = Theme::custom(
+        String::from("Accent"),
+        Palette {
+            primary: Color::from_rgb(0.8, 0.2, 0.5),
+            ..Palette::LIGHT
+        },
+    );
+
+    bench("light -> accent", &Theme::Light, &accent);
+    bench("light -> dark", &Theme::Light, &Theme::Dark);
+}
@@ -4,15 +4,52 @@
 //! Spring/velocity-based animations to make animations interruptible without any jarring effects.
 //!
 //! You can implement this trait for custom types using the "derive" feature.
-use std::sync::Arc;
+//!
+//! # Supported geometry types
+//!
+//! Continuous types interpolate each component, while snapping types jump between values since
+//! there is no meaningful in-between state. Snapping types switch halfway through transitions and
+//! immediately for springs.
+//!
+//! | Type | Components | Behavior |
+//! |------|------------|----------|
+//! | `iced::Point<T>` | 2 × `T` | continuous |
+//! | `iced::Vector<T>` | 2 × `T` | continuous |
+//! | `iced::Size<T>` | 2 × `T` | continuous |
+//! | `iced::Rectangle<T>` | 4 × `T` | continuous |
+//! | `iced::Padding` | 4 | continuous |
+//! | `iced::Radians` | 1 | continuous |
+//! | `iced::Degrees` | 1 | continuous |
+//! | `iced::Rotation` | 1 | continuous angle, snapping variant |
+//! | `iced::Pixels` | 1 | continuous |
+//! | `iced::widget::text::LineHeight` | 1 | continuous, snapping variant |
+//! | `iced::Length` | 1 | continuous `Fixed`, snapping variant |
+//! | `f64` | 1 | continuous |
+//! | `u8` | 1 | continuous, rounded |
+//! | `u16` | 1 | continuous, rounded |
+//! | `u32` | 1 | continuous, rounded |
+//! | [`AnimatedInt`] | 1 | continuous, rounded, keeps fractions |
+//! | [`WrappingAngle`] | 1 | shortest path around the circle |
+//! | `iced::ContentFit` | 0 | snap |
+//! | `iced::Alignment` | 0 | snap |
+//! | `iced::alignment::Horizontal` | 0 | snap |
+//! | `iced::alignment::Vertical` | 0 | snap |
+//! | `bool` | 0 | snap |
+use std::{
+    cell::Cell,
+    f32::consts::{PI, TAU},
+    marker::PhantomData,
+    rc::Rc,
+    sync::Arc,
+};
 
 use iced::{theme::palette, Theme};
 
 /// A trait for types that can be animated on a per-property basis.
 ///
 /// You can derive this trait with `#[derive(Animate)]` with the `derive` feature enabled.
-/// Otherwise, you can manually implement it while ensuring that `Animate::components` returns
-/// the same length vector as `Animate::distance_to` returns.
+/// Otherwise, you can manually implement it while ensuring that `Animate::instance_components`
+/// returns the same length as the vector that `Animate::distance_to` returns.
 ///
 /// Also, ensure that `Animate::update` and `Animate::distance_to` are consistent with each other
 /// in both the number of components consumed and the order of the components. Keeping these in
@@ -24,6 +61,15 @@ pub trait Animate: Clone + PartialEq {
     /// This is used so the animation knows how many properties may be animated.
     fn components() -> usize;
 
+    /// The number of animatable components in this value.
+    ///
+    /// This is `Animate::components` for types whose values always have the same number of
+    /// components. Collections like `Vec<T>` count the components of each of their elements
+    /// instead, and types containing them add up the components of each of their parts.
+    fn instance_components(&self) -> usize {
+        Self::components()
+    }
+
     /// Update the type with the next set of components.
     ///
     /// The `components` is an iterator of new fractional values that should be added to the
@@ -34,7 +80,7 @@ pub trait Animate: Clone + PartialEq {
     ///
     /// The `end` value is the target value that the current value should be animated towards.
     /// This distance can be positive or negative and returns a vector that should be consistent
-    /// with `Animate::components` and the update order in `Animate::update`.
+    /// with `Animate::instance_components` and the update order in `Animate::update`.
     fn distance_to(&self, end: &Self) -> Vec<f32>;
 
     /// Linearly interpolate between two values based on a progress ratio.
@@ -42,6 +88,145 @@ pub trait Animate: Clone + PartialEq {
     /// The `start` value is the initial value, the `end` value is the target value, and `progress`
     /// is a value between 0.0 and 1.0 representing the interpolation progress.
     fn lerp(&mut self, start: &Self, end: &Self, progress: f32);
+
+    /// Matches the shape of the value to the `target` before animating towards it.
+    ///
+    /// Most types always have the same number of components, so this does nothing by default.
+    /// Collections like `Vec<T>` add or remove elements here so that `Animate::distance_to` and
+    /// `Animate::update` agree on the number of components while animating.
+    fn reshape(&mut self, _target: &Self) {}
+
+    /// The value that stands in for `None` when an `Option<Self>` animates to or from `present`.
+    ///
+    /// Returning `None`, which is the default, makes options snap between `None` and `Some`.
+    /// Types with a natural empty state return it instead, like a transparent version of a color,
+    /// so that optional values fade in and out.
+    fn absent(_present: &Self) -> Option<Self> {
+        None
+    }
+}
+
+/// A way in which the methods of an [`Animate`] implementation disagree with each other,
+/// as reported by [`verify_animate_consistency`].
+#[cfg(debug_assertions)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConsistencyError {
+    /// `Animate::distance_to` returned a different number of components than
+    /// `Animate::instance_components`.
+    DistanceLength {
+        /// The number of components reported by `Animate::instance_components`.
+        components: usize,
+        /// The number of components returned by `Animate::distance_to`.
+        distance: usize,
+    },
+    /// `Animate::update` consumed a different number of components than
+    /// `Animate::instance_components`.
+    UpdateConsumed {
+        /// The number of components reported by `Animate::instance_components`.
+        expected: usize,
+        /// The number of components taken by `Animate::update`, which is one more than expected
+        /// when it tried to take too many.
+        consumed: usize,
+    },
+    /// Lerping from the start to the end with a progress of 1 didn't produce the end value.
+    LerpEnd,
+}
+
+#[cfg(debug_assertions)]
+impl std::fmt::Display for ConsistencyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DistanceLength {
+                components,
+                distance,
+            } => write!(
+                f,
+                "`distance_to` returned {distance} components, but `components` is {components}"
+            ),
+            Self::UpdateConsumed { expected, consumed } => write!(
+                f,
+                "`update` consumed {consumed} components, but `components` is {expected}"
+            ),
+            Self::LerpEnd => write!(
+                f,
+                "`lerp` with a progress of 1.0 didn't reach the end value"
+            ),
+        }
+    }
+}
+
+#[cfg(debug_assertions)]
+impl std::error::Error for ConsistencyError {}
+
+/// Checks that the methods of an [`Animate`] implementation agree with each other for the
+/// sample values `a` and `b`, which is useful when implementing the trait by hand.
+///
+/// This checks that:
+///
+/// - `distance_to` returns exactly `instance_components()` entries in both directions.
+/// - `update` consumes exactly `instance_components()` components.
+/// - `lerp(a, b, 1.0)` produces `b`.
+///
+/// Samples that aren't equal to themselves, like `f32::NAN`, skip the `lerp` check.
+///
+/// ```rust
+/// # use iced_anim::animate::verify_animate_consistency;
+/// assert!(verify_animate_consistency(&iced::Color::BLACK, &iced::Color::WHITE).is_ok());
+/// ```
+///
+/// > Note: this is only available in debug builds, where [`Spring`](crate::Spring) and
+/// > [`Transition`](crate::Transition) also check the values they're created with.
+#[cfg(debug_assertions)]
+pub fn verify_animate_consistency<T: Animate>(a: &T, b: &T) -> Result<(), ConsistencyError> {
+    // Animations reshape values before measuring their distance, so the samples are too.
+    let (mut start, mut end) = (a.clone(), b.clone());
+    start.reshape(b);
+    end.reshape(a);
+    for (value, other) in [(&start, b), (&end, a)] {
+        let (components, distance) = (value.instance_components(), value.distance_to(other).len());
+        if distance != components {
+            return Err(ConsistencyError::DistanceLength {
+                components,
+                distance,
+            });
+        }
+    }
+
+    // Offer one more component than expected so implementations that take too many are caught
+    // without ever running out.
+    let expected = start.instance_components();
+    let mut consumed = 0;
+    start.update(
+        &mut std::iter::repeat(0.0)
+            .take(expected + 1)
+            .inspect(|_| consumed += 1),
+    );
+    if consumed != expected {
+        return Err(ConsistencyError::UpdateConsumed { expected, consumed });
+    }
+
+    let mut value = a.clone();
+    value.lerp(a, b, 1.0);
+    #[allow(clippy::eq_op)]
+    let is_comparable = b == b;
+    if is_comparable && value != *b {
+        return Err(ConsistencyError::LerpEnd);
+    }
+
+    Ok(())
+}
+
+/// Panics in debug builds if the [`Animate`] implementation of `T` is inconsistent for `value`,
+/// so broken implementations are caught when animations are created.
+#[inline]
+pub(crate) fn debug_verify<T: Animate>(_value: &T) {
+    #[cfg(debug_assertions)]
+    if let Err(error) = verify_animate_consistency(_value, _value) {
+        panic!(
+            "inconsistent `Animate` implementation for `{}`: {error}",
+            std::any::type_name::<T>()
+        );
+    }
 }
 
 impl Animate for f32 {
@@ -60,16 +245,159 @@ impl Animate for f32 {
     fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
         *self = start + (end - start) * progress
     }
+
+    fn absent(_present: &Self) -> Option<Self> {
+        Some(0.0)
+    }
+}
+
+/// Doubles animate with `f32` components, so only the distance to the target loses precision
+/// while animating. Springs still settle exactly on their target.
+impl Animate for f64 {
+    fn components() -> usize {
+        1
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        *self += f64::from(components.next().unwrap());
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        vec![(self - end) as f32]
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        *self = start + (end - start) * f64::from(progress)
+    }
+
+    fn absent(_present: &Self) -> Option<Self> {
+        Some(0.0)
+    }
+}
+
+/// Implements [`Animate`] for unsigned integers and for [`AnimatedInt`]s that wrap them.
+///
+/// Whole numbers animate as floats and are rounded to the nearest integer after each update,
+/// e.g. so `iced::Size<u32>` can animate a window size in physical pixels. Updates smaller than
+/// half a step are lost to rounding, which [`AnimatedInt`] avoids by keeping the fraction.
+macro_rules! impl_unsigned_animate {
+    ($($int:ty),* $(,)?) => {
+        $(
+            impl Animate for $int {
+                fn components() -> usize {
+                    1
+                }
+
+                fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+                    let value = *self as f32 + components.next().unwrap();
+                    *self = value.round().max(0.0) as $int;
+                }
+
+                fn distance_to(&self, end: &Self) -> Vec<f32> {
+                    vec![*self as f32 - *end as f32]
+                }
+
+                fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+                    let (start, end) = (*start as f32, *end as f32);
+                    *self = (start + (end - start) * progress).round().max(0.0) as $int;
+                }
+            }
+
+            impl AnimatedInt<$int> {
+                /// The integer along with the fraction that hasn't been rounded into it yet.
+                fn exact(&self) -> f32 {
+                    self.value as f32 + self.fraction
+                }
+
+                /// Rounds the `exact` value into the integer, keeping the rest as the fraction.
+                fn set_exact(&mut self, exact: f32) {
+                    // Anything past the range of the integer is dropped rather than kept around.
+                    let exact = exact.clamp(0.0, <$int>::MAX as f32);
+                    let rounded = exact.round();
+                    self.value = rounded as $int;
+                    self.fraction = exact - rounded;
+                }
+            }
+
+            impl Animate for AnimatedInt<$int> {
+                fn components() -> usize {
+                    1
+                }
+
+                fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+                    self.set_exact(self.exact() + components.next().unwrap());
+                }
+
+                fn distance_to(&self, end: &Self) -> Vec<f32> {
+                    vec![self.exact() - end.exact()]
+                }
+
+                fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+                    let (start, end) = (start.exact(), end.exact());
+                    self.set_exact(start + (end - start) * progress);
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned_animate!(u8, u16, u32);
+
+/// An unsigned integer that keeps the fraction of its updates, so many small updates add up
+/// instead of each being rounded away.
+///
+/// Plain integers are rounded after every update, so a spring taking tiny steps towards a nearby
+/// target can stall a step short of it. An animated integer only rounds what it displays:
+///
+/// ```rust
+/// # use iced_anim::{Animate, AnimatedInt};
+/// let mut height = AnimatedInt::new(0_u16);
+/// for _ in 0..10 {
+///     height.update(&mut [0.3].into_iter());
+/// }
+/// assert_eq!(height.get(), 3);
+/// ```
+///
+/// This is implemented for `u8`, `u16`, and `u32`, e.g. for the channels of an RGBA8 color.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AnimatedInt<T> {
+    value: T,
+    /// The part of the updates that hasn't been rounded into the value, within `[-0.5, 0.5]`.
+    fraction: f32,
+}
+
+impl<T: Copy> AnimatedInt<T> {
+    /// Creates a new animated integer with the given `value` and no fraction.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            fraction: 0.0,
+        }
+    }
+
+    /// Returns the integer, rounded to the nearest whole number.
+    pub fn get(self) -> T {
+        self.value
+    }
+}
+
+impl<T: Copy> From<T> for AnimatedInt<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
 }
 
-impl Animate for iced::Point<f32> {
+impl<T> Animate for iced::Point<T>
+where
+    T: Animate,
+{
     fn components() -> usize {
-        2
+        2 * T::components()
     }
 
     fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
-        self.x += components.next().unwrap();
-        self.y += components.next().unwrap();
+        self.x.update(components);
+        self.y.update(components);
     }
 
     fn distance_to(&self, end: &Self) -> Vec<f32> {
@@ -110,6 +438,138 @@ impl Animate for iced::Color {
         self.b.lerp(&start.b, &end.b, progress);
         self.a.lerp(&start.a, &end.a, progress);
     }
+
+    /// Missing colors are a transparent version of the present one, so they fade in and out
+    /// without passing through another color.
+    fn absent(present: &Self) -> Option<Self> {
+        Some(Self { a: 0.0, ..*present })
+    }
+}
+
+/// A color that animates in the perceptual Oklab color space instead of sRGB.
+///
+/// Animating an [`iced::Color`] directly interpolates its sRGB channels, which makes midpoints
+/// look muddy, e.g. blue to yellow passes through gray. Oklab is designed so that equal steps look
+/// like equal changes, keeping the lightness and saturation of in-between colors sensible.
+///
+/// ```rust
+/// # use iced_anim::{Animate, OklabColor};
+/// let start = OklabColor::new(iced::Color::from_rgb(1.0, 0.0, 0.0));
+/// let end = OklabColor::new(iced::Color::from_rgb(0.0, 1.0, 0.0));
+/// let mut color = start;
+/// color.lerp(&start, &end, 0.5);
+/// let midpoint: iced::Color = color.into();
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct OklabColor {
+    /// The perceived lightness, from 0 to 1.
+    l: f32,
+    /// How green or red the color is.
+    a: f32,
+    /// How blue or yellow the color is.
+    b: f32,
+    /// The opacity of the color, from 0 to 1.
+    alpha: f32,
+}
+
+impl OklabColor {
+    /// Creates a new Oklab color from an sRGB `color`.
+    pub fn new(color: iced::Color) -> Self {
+        let [r, g, b] = [color.r, color.g, color.b].map(srgb_to_linear);
+
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+        Self {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+            alpha: color.a,
+        }
+    }
+
+    /// Returns the perceived lightness of the color, from 0 to 1.
+    pub fn lightness(&self) -> f32 {
+        self.l
+    }
+
+    /// Converts the color back to sRGB, clamping it to the displayable range.
+    pub fn color(&self) -> iced::Color {
+        let l = (self.l + 0.396_337_78 * self.a + 0.215_803_76 * self.b).powi(3);
+        let m = (self.l - 0.105_561_346 * self.a - 0.063_854_17 * self.b).powi(3);
+        let s = (self.l - 0.089_484_18 * self.a - 1.291_485_5 * self.b).powi(3);
+
+        let [r, g, b] = [
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        ]
+        .map(linear_to_srgb);
+
+        iced::Color::from_rgba(r, g, b, self.alpha)
+    }
+}
+
+impl From<iced::Color> for OklabColor {
+    fn from(color: iced::Color) -> Self {
+        Self::new(color)
+    }
+}
+
+impl From<OklabColor> for iced::Color {
+    fn from(color: OklabColor) -> Self {
+        color.color()
+    }
+}
+
+/// Converts an sRGB channel to linear light.
+fn srgb_to_linear(channel: f32) -> f32 {
+    if channel <= 0.04045 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a linear light channel to sRGB, clamping it to `[0, 1]`.
+fn linear_to_srgb(channel: f32) -> f32 {
+    let channel = channel.clamp(0.0, 1.0);
+    if channel <= 0.003_130_8 {
+        channel * 12.92
+    } else {
+        1.055 * channel.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+impl Animate for OklabColor {
+    fn components() -> usize {
+        4
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.l = (self.l + components.next().unwrap()).clamp(0.0, 1.0);
+        self.a += components.next().unwrap();
+        self.b += components.next().unwrap();
+        self.alpha = (self.alpha + components.next().unwrap()).clamp(0.0, 1.0);
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.l.distance_to(&end.l),
+            self.a.distance_to(&end.a),
+            self.b.distance_to(&end.b),
+            self.alpha.distance_to(&end.alpha),
+        ]
+        .concat()
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.l.lerp(&start.l, &end.l, progress);
+        self.a.lerp(&start.a, &end.a, progress);
+        self.b.lerp(&start.b, &end.b, progress);
+        self.alpha.lerp(&start.alpha, &end.alpha, progress);
+    }
 }
 
 impl Animate for iced::theme::Palette {
@@ -158,11 +618,7 @@ impl Animate for Theme {
         let mut extended = *self.extended_palette();
         extended.update(components);
 
-        *self = Theme::Custom(Arc::new(iced::theme::Custom::with_fn(
-            "Animating Theme".to_owned(),
-            palette,
-            move |_| extended,
-        )))
+        set_animating_theme(self, palette, extended);
     }
 
     fn distance_to(&self, end: &Self) -> Vec<f32> {
@@ -180,11 +636,40 @@ impl Animate for Theme {
         let mut extended = *start.extended_palette();
         extended.lerp(start.extended_palette(), end.extended_palette(), progress);
 
-        *self = Theme::Custom(Arc::new(iced::theme::Custom::with_fn(
-            "Animating Theme".to_owned(),
-            palette,
-            move |_| extended,
-        )));
+        set_animating_theme(self, palette, extended);
+    }
+}
+
+thread_local! {
+    /// The theme replaced by the last step of a theme animation, which the next step reuses once
+    /// nothing else holds it, e.g. after the runtime drops the theme it drew the last frame with.
+    static SPARE_THEME: Cell<Option<Arc<iced::theme::Custom>>> = const { Cell::new(None) };
+}
+
+/// Replaces the `theme` with an in-between theme of the given palettes.
+///
+/// Animating a theme alternates between two allocations instead of allocating a new theme every
+/// frame. In-between themes don't have a name, since `Custom` themes own their name and naming
+/// each of them would allocate a new `String` every frame.
+fn set_animating_theme(
+    theme: &mut Theme,
+    palette: iced::theme::Palette,
+    extended: palette::Extended,
+) {
+    let custom = iced::theme::Custom::with_fn(String::new(), palette, move |_| extended);
+    let custom = match SPARE_THEME.take() {
+        Some(mut spare) => match Arc::get_mut(&mut spare) {
+            Some(reused) => {
+                *reused = custom;
+                spare
+            }
+            None => Arc::new(custom),
+        },
+        None => Arc::new(custom),
+    };
+
+    if let Theme::Custom(previous) = std::mem::replace(theme, Theme::Custom(custom)) {
+        SPARE_THEME.set(Some(previous));
     }
 }
 
@@ -399,27 +884,65 @@ where
         T::components()
     }
 
+    fn instance_components(&self) -> usize {
+        self.as_ref()
+            .map_or(T::components(), Animate::instance_components)
+    }
+
     fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
         if let Some(inner) = self {
             inner.update(components);
         } else {
-            components.nth(T::components() - 1);
+            // Skip the components one at a time, since `nth` would underflow for values
+            // without any components and consume the rest of the iterator.
+            for _ in 0..T::components() {
+                components.next();
+            }
         }
     }
 
+    /// The distance between `None` and `Some` is measured from [`Animate::absent`], so a value
+    /// that's fading out keeps its distance until it's fully gone.
     fn distance_to(&self, end: &Self) -> Vec<f32> {
-        match (self, end) {
-            (Some(current), Some(end)) => current.distance_to(end),
-            _ => vec![0.0; T::components()],
-        }
+        let distance = match (self, end) {
+            (Some(current), Some(end)) => Some(current.distance_to(end)),
+            (Some(current), None) => T::absent(current).map(|end| current.distance_to(&end)),
+            (None, Some(end)) => T::absent(end).map(|start| start.distance_to(end)),
+            (None, None) => None,
+        };
+        distance.unwrap_or_else(|| vec![0.0; self.instance_components()])
     }
 
+    /// Interpolates from or to [`Animate::absent`] when only one side is `Some`.
+    ///
+    /// Fading out keeps the value `Some` until the animation completes and settles at `None`.
     fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
-        // TODO: Maybe figure out something better here. Potentially only use Option with Default impls if missing.
-        if let (Some(start), Some(end)) = (start, end) {
-            if let Some(value) = self.as_mut() {
-                value.lerp(start, end, progress);
+        let (start, end) = match (start, end) {
+            (Some(start), Some(end)) => {
+                self.get_or_insert_with(|| start.clone())
+                    .lerp(start, end, progress);
+                return;
             }
+            (Some(start), None) => match T::absent(start) {
+                Some(end) => (start.clone(), end),
+                None => return,
+            },
+            (None, Some(end)) => match T::absent(end) {
+                Some(start) => (start, end.clone()),
+                None => return,
+            },
+            (None, None) => return,
+        };
+        self.get_or_insert_with(|| start.clone())
+            .lerp(&start, &end, progress);
+    }
+
+    /// Starts a `None` value that's animating to `Some` from [`Animate::absent`].
+    fn reshape(&mut self, target: &Self) {
+        match (self.as_mut(), target) {
+            (Some(value), Some(target)) => value.reshape(target),
+            (None, Some(target)) => *self = T::absent(target),
+            _ => {}
         }
     }
 }
@@ -613,426 +1136,1940 @@ impl Animate for iced::Radians {
     }
 }
 
-impl Animate for iced::gradient::ColorStop {
+impl Animate for iced::Degrees {
     fn components() -> usize {
-        f32::components() + iced::Color::components()
+        f32::components()
     }
 
     fn distance_to(&self, end: &Self) -> Vec<f32> {
-        [
-            self.offset.distance_to(&end.offset),
-            self.color.distance_to(&end.color),
-        ]
-        .concat()
+        self.0.distance_to(&end.0)
     }
 
     fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
-        self.offset.update(components);
-        self.color.update(components);
+        self.0.update(components);
     }
 
     fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
-        self.offset.lerp(&start.offset, &end.offset, progress);
-        self.color.lerp(&start.color, &end.color, progress);
+        self.0.lerp(&start.0, &end.0, progress);
     }
 }
 
-impl<T, const N: usize> Animate for [T; N]
-where
-    T: Animate,
-{
+impl Animate for iced::Pixels {
     fn components() -> usize {
-        N * T::components()
+        f32::components()
     }
 
     fn distance_to(&self, end: &Self) -> Vec<f32> {
-        self.iter()
-            .zip(end.iter())
-            .flat_map(|(start, end)| start.distance_to(end))
-            .collect()
+        self.0.distance_to(&end.0)
     }
 
     fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
-        for item in self.iter_mut() {
-            item.update(components);
-        }
+        self.0.update(components);
     }
 
     fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
-        for (item, (start, end)) in self.iter_mut().zip(start.iter().zip(end.iter())) {
-            item.lerp(start, end, progress);
-        }
+        self.0.lerp(&start.0, &end.0, progress);
     }
 }
 
-impl Animate for iced::gradient::Linear {
+impl Animate for iced::widget::text::LineHeight {
     fn components() -> usize {
-        iced::Radians::components() + 8 * iced::gradient::ColorStop::components()
+        f32::components()
     }
 
     fn distance_to(&self, end: &Self) -> Vec<f32> {
-        [
-            self.angle.distance_to(&end.angle),
-            self.stops.distance_to(&end.stops),
-        ]
-        .concat()
+        use iced::widget::text::LineHeight;
+        match (self, end) {
+            (LineHeight::Relative(start), LineHeight::Relative(end)) => start.distance_to(end),
+            (LineHeight::Absolute(start), LineHeight::Absolute(end)) => start.distance_to(end),
+            _ => vec![0.0; Self::components()],
+        }
     }
 
     fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
-        self.angle.update(components);
-
-        for stop in &mut self.stops {
-            stop.update(components);
+        use iced::widget::text::LineHeight;
+        match self {
+            LineHeight::Relative(factor) => factor.update(components),
+            LineHeight::Absolute(pixels) => pixels.update(components),
         }
     }
 
     fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
-        self.angle.lerp(&start.angle, &end.angle, progress);
-
-        for (stop, (start, end)) in self
-            .stops
-            .iter_mut()
-            .zip(start.stops.iter().zip(end.stops.iter()))
-        {
-            stop.lerp(start, end, progress);
+        use iced::widget::text::LineHeight;
+        match (self, start, end) {
+            (
+                LineHeight::Relative(value),
+                LineHeight::Relative(start),
+                LineHeight::Relative(end),
+            ) => value.lerp(start, end, progress),
+            (
+                LineHeight::Absolute(value),
+                LineHeight::Absolute(start),
+                LineHeight::Absolute(end),
+            ) => value.lerp(start, end, progress),
+            // Relative and absolute heights can't be interpolated, so they snap halfway.
+            (value, start, end) => *value = if progress >= 0.5 { *end } else { *start },
         }
     }
 }
 
-impl Animate for iced::Gradient {
+/// Only fixed lengths animate, since `Fill`, `Shrink`, and `FillPortion` depend on the space
+/// available during layout. Any other change snaps between the lengths instead.
+impl Animate for iced::Length {
     fn components() -> usize {
-        iced::gradient::Linear::components()
+        f32::components()
     }
 
     fn distance_to(&self, end: &Self) -> Vec<f32> {
+        use iced::Length;
         match (self, end) {
-            (iced::Gradient::Linear(start), iced::Gradient::Linear(end)) => start.distance_to(end),
+            (Length::Fixed(start), Length::Fixed(end)) => start.distance_to(end),
+            _ => vec![0.0; Self::components()],
         }
     }
 
     fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        use iced::Length;
         match self {
-            iced::Gradient::Linear(start) => start.update(components),
+            Length::Fixed(size) => size.update(components),
+            _ => {
+                components.next();
+            }
         }
     }
 
     fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        use iced::Length;
         match (self, start, end) {
-            (
-                iced::Gradient::Linear(value),
-                iced::Gradient::Linear(start),
-                iced::Gradient::Linear(end),
-            ) => {
+            (Length::Fixed(value), Length::Fixed(start), Length::Fixed(end)) => {
                 value.lerp(start, end, progress);
             }
+            // Flexible lengths can't be interpolated, so they snap halfway.
+            (value, start, end) => *value = if progress >= 0.5 { *end } else { *start },
         }
     }
 }
 
-impl Animate for iced::Background {
+impl Animate for iced::Rotation {
     fn components() -> usize {
-        iced::gradient::Gradient::components().max(iced::Color::components())
+        iced::Radians::components()
     }
 
     fn distance_to(&self, end: &Self) -> Vec<f32> {
-        match (self, end) {
-            (iced::Background::Color(start), iced::Background::Color(end)) => {
-                let mut distance = start.distance_to(end);
-                distance.resize(Self::components(), 0.0);
-                distance
-            }
-            (iced::Background::Color(_), iced::Background::Gradient(_)) => {
-                vec![0.0; Self::components()]
-            }
-            (iced::Background::Gradient(start), iced::Background::Gradient(end)) => {
-                let mut distance = start.distance_to(end);
-                distance.resize(Self::components(), 0.0);
-                distance
-            }
-            (iced::Background::Gradient(_), iced::Background::Color(_)) => {
-                vec![0.0; Self::components()]
-            }
-        }
+        self.radians().distance_to(&end.radians())
     }
 
     fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
         match self {
-            iced::Background::Color(color) => {
-                color.update(components);
-                let extra = Self::components() - iced::Color::components() - 1;
-                components.nth(extra);
+            iced::Rotation::Floating(radians) | iced::Rotation::Solid(radians) => {
+                radians.update(components)
             }
-            iced::Background::Gradient(gradient) => gradient.update(components),
         }
     }
 
     fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
-        match (self, start, end) {
-            (
-                iced::Background::Color(value),
-                iced::Background::Color(start),
-                iced::Background::Color(end),
-            ) => {
-                value.lerp(start, end, progress);
-            }
-            (
-                iced::Background::Gradient(value),
-                iced::Background::Gradient(start),
-                iced::Background::Gradient(end),
-            ) => {
-                value.lerp(start, end, progress);
-            }
-            _ => {}
-        }
+        // The rotation variant can't be interpolated, so it snaps halfway like other discrete
+        // values.
+        let mut radians = start.radians();
+        radians.lerp(&start.radians(), &end.radians(), progress);
+        *self = match if progress >= 0.5 { end } else { start } {
+            iced::Rotation::Floating(_) => iced::Rotation::Floating(radians),
+            iced::Rotation::Solid(_) => iced::Rotation::Solid(radians),
+        };
     }
 }
 
-impl Animate for iced::widget::button::Style {
-    fn components() -> usize {
-        Option::<iced::Background>::components()
-            + iced::Color::components()
-            + iced::Border::components()
-            + iced::Shadow::components()
+/// An angle that animates along the shortest path around the circle.
+///
+/// Plain [`iced::Radians`] animate like any other number, so going from 350° to 10° spins back
+/// 340° instead of crossing 0°. A wrapping angle is always kept within `[0, 2π)` and moves
+/// by at most half a turn in either direction, which suits things like compass needles.
+///
+/// ```rust
+/// # use iced_anim::{Animate, WrappingAngle};
+/// let start = WrappingAngle::new(iced::Degrees(350.0));
+/// let end = WrappingAngle::new(iced::Degrees(10.0));
+/// let mut angle = start;
+/// angle.lerp(&start, &end, 0.75);
+/// assert!((angle.radians().0.to_degrees() - 5.0).abs() < 1e-3);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct WrappingAngle(f32);
+
+impl WrappingAngle {
+    /// Creates a new wrapping angle, normalizing it into `[0, 2π)`.
+    pub fn new(angle: impl Into<iced::Radians>) -> Self {
+        Self(wrap(angle.into().0))
+    }
+
+    /// Returns the angle in radians, within `[0, 2π)`.
+    pub fn radians(self) -> iced::Radians {
+        iced::Radians(self.0)
     }
+}
 
-    fn distance_to(&self, end: &Self) -> Vec<f32> {
-        [
-            self.background.distance_to(&end.background),
-            self.text_color.distance_to(&end.text_color),
-            self.border.distance_to(&end.border),
-            self.shadow.distance_to(&end.shadow),
-        ]
-        .concat()
+impl From<iced::Radians> for WrappingAngle {
+    fn from(radians: iced::Radians) -> Self {
+        Self::new(radians)
+    }
+}
+
+impl From<iced::Degrees> for WrappingAngle {
+    fn from(degrees: iced::Degrees) -> Self {
+        Self::new(degrees)
+    }
+}
+
+impl From<WrappingAngle> for iced::Radians {
+    fn from(angle: WrappingAngle) -> Self {
+        angle.radians()
+    }
+}
+
+/// Normalizes an angle in radians into `[0, 2π)`.
+fn wrap(radians: f32) -> f32 {
+    let wrapped = radians.rem_euclid(TAU);
+    // Tiny negative angles can round up to a full turn.
+    if wrapped >= TAU {
+        0.0
+    } else {
+        wrapped
+    }
+}
+
+/// The signed difference `to - from` along the shortest path around the circle, in `(-π, π]`.
+fn shortest_turn(from: f32, to: f32) -> f32 {
+    let turn = (to - from).rem_euclid(TAU);
+    if turn > PI {
+        turn - TAU
+    } else {
+        turn
+    }
+}
+
+impl Animate for WrappingAngle {
+    fn components() -> usize {
+        1
     }
 
     fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
-        self.background.update(components);
-        self.text_color.update(components);
-        self.border.update(components);
-        self.shadow.update(components);
+        self.0 = wrap(self.0 + components.next().unwrap());
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        vec![shortest_turn(end.0, self.0)]
     }
 
     fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
-        self.background
-            .lerp(&start.background, &end.background, progress);
-        self.text_color
-            .lerp(&start.text_color, &end.text_color, progress);
-        self.border.lerp(&start.border, &end.border, progress);
-        self.shadow.lerp(&start.shadow, &end.shadow, progress);
+        self.0 = wrap(start.0 + shortest_turn(start.0, end.0) * progress);
     }
 }
 
-impl Animate for iced::widget::svg::Style {
+impl Animate for iced::Padding {
     fn components() -> usize {
-        Option::<iced::Color>::components()
+        4 * f32::components()
     }
 
     fn distance_to(&self, end: &Self) -> Vec<f32> {
-        self.color.distance_to(&end.color)
+        [
+            self.top.distance_to(&end.top),
+            self.right.distance_to(&end.right),
+            self.bottom.distance_to(&end.bottom),
+            self.left.distance_to(&end.left),
+        ]
+        .concat()
     }
 
     fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
-        self.color.update(components);
+        self.top.update(components);
+        self.right.update(components);
+        self.bottom.update(components);
+        self.left.update(components);
     }
 
     fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
-        self.color.lerp(&start.color, &end.color, progress);
+        self.top.lerp(&start.top, &end.top, progress);
+        self.right.lerp(&start.right, &end.right, progress);
+        self.bottom.lerp(&start.bottom, &end.bottom, progress);
+        self.left.lerp(&start.left, &end.left, progress);
     }
 }
 
-impl<T1, T2> Animate for (T1, T2)
-where
-    T1: Animate,
-    T2: Animate,
-{
+impl Animate for iced::widget::scrollable::AbsoluteOffset {
     fn components() -> usize {
-        T1::components() + T2::components()
+        2 * f32::components()
     }
 
-    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
-        self.0.update(components);
-        self.1.update(components);
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [self.x.distance_to(&end.x), self.y.distance_to(&end.y)].concat()
     }
 
-    fn distance_to(&self, end: &Self) -> Vec<f32> {
-        [self.0.distance_to(&end.0), self.1.distance_to(&end.1)].concat()
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.x.update(components);
+        self.y.update(components);
     }
 
     fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
-        self.0.lerp(&start.0, &end.0, progress);
-        self.1.lerp(&start.1, &end.1, progress);
+        self.x.lerp(&start.x, &end.x, progress);
+        self.y.lerp(&start.y, &end.y, progress);
     }
 }
 
-impl<T1, T2, T3> Animate for (T1, T2, T3)
+/// Markers don't hold any data, so they have nothing to animate.
+impl<T> Animate for PhantomData<T>
 where
-    T1: Animate,
-    T2: Animate,
-    T3: Animate,
+    T: ?Sized,
 {
     fn components() -> usize {
-        T1::components() + T2::components() + T3::components()
+        0
     }
 
-    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
-        self.0.update(components);
-        self.1.update(components);
-        self.2.update(components);
+    fn distance_to(&self, _end: &Self) -> Vec<f32> {
+        Vec::new()
+    }
+
+    fn update(&mut self, _components: &mut impl Iterator<Item = f32>) {}
+
+    fn lerp(&mut self, _start: &Self, _end: &Self, _progress: f32) {}
+}
+
+/// Implements [`Animate`] for discrete types that snap between values instead of interpolating.
+///
+/// These types have no animatable components, so springs will jump to the target immediately
+/// while transitions will switch to the target halfway through.
+macro_rules! impl_snap_animate {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Animate for $ty {
+                fn components() -> usize {
+                    0
+                }
+
+                fn distance_to(&self, _end: &Self) -> Vec<f32> {
+                    Vec::new()
+                }
+
+                fn update(&mut self, _components: &mut impl Iterator<Item = f32>) {}
+
+                fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+                    *self = if progress >= 0.5 {
+                        end.clone()
+                    } else {
+                        start.clone()
+                    };
+                }
+            }
+        )*
+    };
+}
+
+impl_snap_animate!(
+    bool,
+    iced::ContentFit,
+    iced::Alignment,
+    iced::alignment::Horizontal,
+    iced::alignment::Vertical,
+);
+
+impl Animate for iced::gradient::ColorStop {
+    fn components() -> usize {
+        f32::components() + iced::Color::components()
     }
 
     fn distance_to(&self, end: &Self) -> Vec<f32> {
         [
-            self.0.distance_to(&end.0),
-            self.1.distance_to(&end.1),
-            self.2.distance_to(&end.2),
+            self.offset.distance_to(&end.offset),
+            self.color.distance_to(&end.color),
         ]
         .concat()
     }
 
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.offset.update(components);
+        self.color.update(components);
+    }
+
     fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
-        self.0.lerp(&start.0, &end.0, progress);
-        self.1.lerp(&start.1, &end.1, progress);
-        self.2.lerp(&start.2, &end.2, progress);
+        self.offset.lerp(&start.offset, &end.offset, progress);
+        self.color.lerp(&start.color, &end.color, progress);
     }
 }
 
-impl<T1, T2, T3, T4> Animate for (T1, T2, T3, T4)
+impl<T, const N: usize> Animate for [T; N]
 where
-    T1: Animate,
-    T2: Animate,
-    T3: Animate,
-    T4: Animate,
+    T: Animate,
 {
     fn components() -> usize {
-        T1::components() + T2::components() + T3::components() + T4::components()
+        N * T::components()
     }
 
-    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
-        self.0.update(components);
-        self.1.update(components);
-        self.2.update(components);
-        self.3.update(components);
+    fn instance_components(&self) -> usize {
+        self.iter().map(Animate::instance_components).sum()
     }
 
     fn distance_to(&self, end: &Self) -> Vec<f32> {
-        [
-            self.0.distance_to(&end.0),
-            self.1.distance_to(&end.1),
-            self.2.distance_to(&end.2),
-            self.3.distance_to(&end.3),
-        ]
-        .concat()
+        self.iter()
+            .zip(end.iter())
+            .flat_map(|(start, end)| start.distance_to(end))
+            .collect()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        for item in self.iter_mut() {
+            item.update(components);
+        }
     }
 
     fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
-        self.0.lerp(&start.0, &end.0, progress);
-        self.1.lerp(&start.1, &end.1, progress);
-        self.2.lerp(&start.2, &end.2, progress);
-        self.3.lerp(&start.3, &end.3, progress);
+        for (item, (start, end)) in self.iter_mut().zip(start.iter().zip(end.iter())) {
+            item.lerp(start, end, progress);
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn reshape(&mut self, target: &Self) {
+        for (item, target) in self.iter_mut().zip(target.iter()) {
+            item.reshape(target);
+        }
+    }
+}
 
-    #[test]
-    fn f32_components() {
-        assert_eq!(f32::components(), 1);
+/// Vectors animate each element that they share with the other value, so the number of
+/// components depends on their length. `Animate::components` is `0` for an empty vector, and
+/// `Animate::instance_components` counts the components of each element.
+///
+/// When the lengths differ, elements past the end of the target are removed immediately and new
+/// elements start from a copy of the last element, e.g. new bars in a chart grow from the height
+/// of the last bar.
+impl<T> Animate for Vec<T>
+where
+    T: Animate,
+{
+    fn components() -> usize {
+        0
     }
 
-    #[test]
-    fn f32_point_components() {
-        assert_eq!(iced::Point::<f32>::components(), 2);
+    fn instance_components(&self) -> usize {
+        self.iter().map(Animate::instance_components).sum()
     }
 
-    #[test]
-    fn f32_color_components() {
-        assert_eq!(iced::Color::components(), 4);
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        self.iter()
+            .enumerate()
+            .flat_map(|(index, item)| item.distance_to(end.get(index).unwrap_or(item)))
+            .collect()
     }
 
-    #[test]
-    fn color_pair_components() {
-        assert_eq!(
-            iced::theme::palette::Pair::components(),
-            2 * iced::Color::components()
-        );
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        for item in self.iter_mut() {
+            item.update(components);
+        }
     }
 
-    #[test]
-    fn primary_components() {
-        assert_eq!(
-            iced::theme::palette::Primary::components(),
-            3 * iced::theme::palette::Pair::components()
-        );
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.truncate(end.len());
+        for (index, end) in end.iter().enumerate() {
+            let start = start.get(index).or(start.last()).unwrap_or(end);
+            if index >= self.len() {
+                self.push(start.clone());
+            }
+            self[index].lerp(start, end, progress);
+        }
     }
 
-    #[test]
-    fn secondary_components() {
-        assert_eq!(
-            iced::theme::palette::Secondary::components(),
-            3 * iced::theme::palette::Pair::components()
-        );
+    fn reshape(&mut self, target: &Self) {
+        self.truncate(target.len());
+        for (index, target) in target.iter().enumerate() {
+            if index >= self.len() {
+                let item = self.last().unwrap_or(target).clone();
+                self.push(item);
+            }
+            self[index].reshape(target);
+        }
     }
+}
 
-    #[test]
-    fn success_components() {
-        assert_eq!(
-            iced::theme::palette::Success::components(),
-            3 * iced::theme::palette::Pair::components()
-        );
+/// Boxed values animate the value they hold, e.g. a large part of a derived struct that's boxed
+/// to keep the struct itself small.
+impl<T> Animate for Box<T>
+where
+    T: Animate,
+{
+    fn components() -> usize {
+        T::components()
     }
 
-    #[test]
-    fn danger_components() {
-        assert_eq!(
-            iced::theme::palette::Danger::components(),
-            3 * iced::theme::palette::Pair::components()
-        );
+    fn instance_components(&self) -> usize {
+        (**self).instance_components()
     }
 
-    #[test]
-    fn background_components() {
-        assert_eq!(
-            iced::theme::palette::Background::components(),
-            3 * iced::theme::palette::Pair::components()
-        );
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        (**self).distance_to(end)
     }
 
-    #[test]
-    fn extended_palette_components() {
-        assert_eq!(
-            iced::theme::palette::Extended::components(),
-            iced::theme::palette::Background::components()
-                + iced::theme::palette::Primary::components()
-                + iced::theme::palette::Secondary::components()
-                + iced::theme::palette::Success::components()
-                + iced::theme::palette::Danger::components()
-        );
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        (**self).update(components);
     }
 
-    #[test]
-    fn theme_components() {
-        assert_eq!(
-            Theme::components(),
-            iced::theme::Palette::components() + iced::theme::palette::Extended::components()
-        );
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        (**self).lerp(start, end, progress);
     }
 
-    #[test]
-    fn option_components() {
-        assert_eq!(Option::<f32>::components(), 1);
+    fn reshape(&mut self, target: &Self) {
+        (**self).reshape(target);
     }
 
-    /// `Some` value should update the value with the next component.
-    #[test]
-    fn option_update_some() {
-        let mut option = Some(1.0);
-        option.update(&mut [2.0].iter().copied());
-        assert_eq!(option, Some(3.0));
+    fn absent(present: &Self) -> Option<Self> {
+        T::absent(present).map(Box::new)
     }
+}
 
-    /// Trying to update `None` should update the iterator, but not change the value.
+/// Implements [`Animate`] for shared pointers that copy their value on write, so animating a
+/// value never changes it for anything else that shares it.
+macro_rules! impl_shared_animate {
+    ($($pointer:ident),+) => {
+        $(
+            impl<T> Animate for $pointer<T>
+            where
+                T: Animate,
+            {
+                fn components() -> usize {
+                    T::components()
+                }
+
+                fn instance_components(&self) -> usize {
+                    (**self).instance_components()
+                }
+
+                fn distance_to(&self, end: &Self) -> Vec<f32> {
+                    (**self).distance_to(end)
+                }
+
+                fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+                    $pointer::make_mut(self).update(components);
+                }
+
+                fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+                    $pointer::make_mut(self).lerp(start, end, progress);
+                }
+
+                fn reshape(&mut self, target: &Self) {
+                    $pointer::make_mut(self).reshape(target);
+                }
+
+                fn absent(present: &Self) -> Option<Self> {
+                    T::absent(present).map($pointer::new)
+                }
+            }
+        )+
+    };
+}
+
+impl_shared_animate!(Rc, Arc);
+
+/// Gradients with different numbers of stops animate every stop either of them has.
+///
+/// A stop that's missing on one side stands in as a copy of its nearest defined neighbor, so new
+/// stops grow out of an existing one and removed stops merge into their neighbor before the
+/// gradient settles without them.
+impl Animate for iced::gradient::Linear {
+    fn components() -> usize {
+        iced::Radians::components() + 8 * iced::gradient::ColorStop::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.angle.distance_to(&end.angle),
+            fill_stops(&self.stops, &end.stops).distance_to(&fill_stops(&end.stops, &self.stops)),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.angle.update(components);
+
+        for stop in &mut self.stops {
+            stop.update(components);
+        }
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        if progress >= 1.0 {
+            *self = *end;
+            return;
+        }
+
+        self.angle.lerp(&start.angle, &end.angle, progress);
+        let (start, end) = (
+            fill_stops(&start.stops, &end.stops),
+            fill_stops(&end.stops, &start.stops),
+        );
+        self.stops = start;
+        self.stops.lerp(&start, &end, progress);
+    }
+
+    /// Adds the stops that only the `target` has before animating towards it.
+    fn reshape(&mut self, target: &Self) {
+        self.stops = fill_stops(&self.stops, &target.stops);
+    }
+}
+
+/// Fills the slots of `stops` that are only defined in `other` with a copy of the nearest defined
+/// stop, preferring the one before it.
+///
+/// Gradients without any stops use transparent versions of the `other` stops instead.
+fn fill_stops(
+    stops: &[Option<iced::gradient::ColorStop>; 8],
+    other: &[Option<iced::gradient::ColorStop>; 8],
+) -> [Option<iced::gradient::ColorStop>; 8] {
+    let mut filled = *stops;
+    for (index, slot) in filled.iter_mut().enumerate() {
+        let Some(other) = other[index].filter(|_| slot.is_none()) else {
+            continue;
+        };
+
+        let before = stops[..index].iter().rev().flatten().next();
+        let after = stops[index..].iter().flatten().next();
+        let transparent = iced::gradient::ColorStop {
+            color: iced::Color {
+                a: 0.0,
+                ..other.color
+            },
+            ..other
+        };
+        *slot = Some(before.or(after).copied().unwrap_or(transparent));
+    }
+    filled
+}
+
+impl Animate for iced::Gradient {
+    fn components() -> usize {
+        iced::gradient::Linear::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        match (self, end) {
+            (iced::Gradient::Linear(start), iced::Gradient::Linear(end)) => start.distance_to(end),
+        }
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        match self {
+            iced::Gradient::Linear(start) => start.update(components),
+        }
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        match (self, start, end) {
+            (
+                iced::Gradient::Linear(value),
+                iced::Gradient::Linear(start),
+                iced::Gradient::Linear(end),
+            ) => {
+                value.lerp(start, end, progress);
+            }
+        }
+    }
+}
+
+/// Backgrounds cross-fade between colors and gradients by treating the color as a uniform
+/// gradient with the same stops as the other background.
+///
+/// A color that's animating to a gradient becomes a uniform gradient right away, which looks the
+/// same, while a gradient that's animating to a color stays a gradient until the animation
+/// completes and it settles at the color.
+impl Animate for iced::Background {
+    fn components() -> usize {
+        iced::gradient::Gradient::components().max(iced::Color::components())
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        let mut distance = match (self, end) {
+            (iced::Background::Color(start), iced::Background::Color(end)) => {
+                start.distance_to(end)
+            }
+            (iced::Background::Color(start), iced::Background::Gradient(end)) => {
+                uniform(*start, end).distance_to(end)
+            }
+            (iced::Background::Gradient(start), iced::Background::Gradient(end)) => {
+                start.distance_to(end)
+            }
+            (iced::Background::Gradient(start), iced::Background::Color(end)) => {
+                start.distance_to(&uniform(*end, start))
+            }
+        };
+        distance.resize(Self::components(), 0.0);
+        distance
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        match self {
+            iced::Background::Color(color) => {
+                color.update(components);
+                let extra = Self::components() - iced::Color::components() - 1;
+                components.nth(extra);
+            }
+            iced::Background::Gradient(gradient) => gradient.update(components),
+        }
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        let (start, end) = match (start, end) {
+            (iced::Background::Color(start), iced::Background::Color(end)) => {
+                let mut value = *start;
+                value.lerp(start, end, progress);
+                *self = iced::Background::Color(value);
+                return;
+            }
+            (iced::Background::Color(start), iced::Background::Gradient(end)) => {
+                (uniform(*start, end), *end)
+            }
+            (iced::Background::Gradient(start), iced::Background::Gradient(end)) => (*start, *end),
+            (iced::Background::Gradient(start), iced::Background::Color(end)) => {
+                if progress >= 1.0 {
+                    *self = iced::Background::Color(*end);
+                    return;
+                }
+                (*start, uniform(*end, start))
+            }
+        };
+        let mut value = start;
+        value.lerp(&start, &end, progress);
+        *self = iced::Background::Gradient(value);
+    }
+
+    /// Turns a color into a uniform gradient before it animates to a gradient.
+    fn reshape(&mut self, target: &Self) {
+        if let (iced::Background::Color(color), iced::Background::Gradient(target)) =
+            (&*self, target)
+        {
+            *self = iced::Background::Gradient(uniform(*color, target));
+        }
+    }
+
+    /// Missing backgrounds are a transparent version of the present one.
+    fn absent(present: &Self) -> Option<Self> {
+        Some(match present {
+            iced::Background::Color(color) => iced::Background::Color(iced::Color::absent(color)?),
+            iced::Background::Gradient(iced::Gradient::Linear(linear)) => {
+                let mut linear = *linear;
+                for stop in linear.stops.iter_mut().flatten() {
+                    stop.color.a = 0.0;
+                }
+                iced::Background::Gradient(iced::Gradient::Linear(linear))
+            }
+        })
+    }
+}
+
+/// A gradient of a single `color` with the same angle and stops as the `template`.
+fn uniform(color: iced::Color, template: &iced::Gradient) -> iced::Gradient {
+    match template {
+        iced::Gradient::Linear(linear) => {
+            let mut linear = *linear;
+            for stop in linear.stops.iter_mut().flatten() {
+                stop.color = color;
+            }
+            iced::Gradient::Linear(linear)
+        }
+    }
+}
+
+impl Animate for iced::widget::button::Style {
+    fn components() -> usize {
+        Option::<iced::Background>::components()
+            + iced::Color::components()
+            + iced::Border::components()
+            + iced::Shadow::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.background.distance_to(&end.background),
+            self.text_color.distance_to(&end.text_color),
+            self.border.distance_to(&end.border),
+            self.shadow.distance_to(&end.shadow),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.background.update(components);
+        self.text_color.update(components);
+        self.border.update(components);
+        self.shadow.update(components);
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.background
+            .lerp(&start.background, &end.background, progress);
+        self.text_color
+            .lerp(&start.text_color, &end.text_color, progress);
+        self.border.lerp(&start.border, &end.border, progress);
+        self.shadow.lerp(&start.shadow, &end.shadow, progress);
+    }
+
+    fn reshape(&mut self, target: &Self) {
+        self.background.reshape(&target.background);
+    }
+}
+
+/// Declares a wrapper around one of Iced's widget styles so it can be animated.
+///
+/// Most widget styles don't implement `PartialEq`, which [`Animate`] requires, so the wrapper
+/// compares the listed fields instead. It dereferences to the wrapped style and converts to and
+/// from it, so widgets can animate the wrapper and draw with the style inside.
+#[cfg(feature = "widgets")]
+macro_rules! animated_style {
+    ($(#[$meta:meta])* $name:ident($style:ty) { $($field:ident),+ $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name(pub $style);
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                $(self.0.$field == other.0.$field)&&+
+            }
+        }
+
+        impl From<$style> for $name {
+            fn from(style: $style) -> Self {
+                Self(style)
+            }
+        }
+
+        impl From<$name> for $style {
+            fn from(style: $name) -> Self {
+                style.0
+            }
+        }
+
+        impl std::ops::Deref for $name {
+            type Target = $style;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl std::ops::DerefMut for $name {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+    };
+}
+
+#[cfg(feature = "widgets")]
+animated_style!(
+    /// A [`container::Style`](iced::widget::container::Style) that can be animated.
+    AnimatedContainerStyle(iced::widget::container::Style) {
+        text_color,
+        background,
+        border,
+        shadow,
+    }
+);
+
+#[cfg(feature = "widgets")]
+impl Animate for AnimatedContainerStyle {
+    fn components() -> usize {
+        Option::<iced::Color>::components()
+            + Option::<iced::Background>::components()
+            + iced::Border::components()
+            + iced::Shadow::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.text_color.distance_to(&end.text_color),
+            self.background.distance_to(&end.background),
+            self.border.distance_to(&end.border),
+            self.shadow.distance_to(&end.shadow),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.text_color.update(components);
+        self.background.update(components);
+        self.border.update(components);
+        self.shadow.update(components);
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.text_color
+            .lerp(&start.text_color, &end.text_color, progress);
+        self.background
+            .lerp(&start.background, &end.background, progress);
+        self.border.lerp(&start.border, &end.border, progress);
+        self.shadow.lerp(&start.shadow, &end.shadow, progress);
+    }
+
+    fn reshape(&mut self, target: &Self) {
+        self.text_color.reshape(&target.text_color);
+        self.background.reshape(&target.background);
+    }
+}
+
+impl Animate for iced::widget::svg::Style {
+    fn components() -> usize {
+        Option::<iced::Color>::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        self.color.distance_to(&end.color)
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.color.update(components);
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.color.lerp(&start.color, &end.color, progress);
+    }
+
+    fn reshape(&mut self, target: &Self) {
+        self.color.reshape(&target.color);
+    }
+}
+
+#[cfg(feature = "widgets")]
+animated_style!(
+    /// A [`text::Style`](iced::widget::text::Style) that can be animated.
+    AnimatedTextStyle(iced::widget::text::Style) { color }
+);
+
+#[cfg(feature = "widgets")]
+impl Animate for AnimatedTextStyle {
+    fn components() -> usize {
+        Option::<iced::Color>::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        self.color.distance_to(&end.color)
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.color.update(components);
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.color.lerp(&start.color, &end.color, progress);
+    }
+
+    fn reshape(&mut self, target: &Self) {
+        self.color.reshape(&target.color);
+    }
+}
+
+#[cfg(feature = "widgets")]
+animated_style!(
+    /// A [`text_input::Style`](iced::widget::text_input::Style) that can be animated.
+    AnimatedTextInputStyle(iced::widget::text_input::Style) {
+        background,
+        border,
+        icon,
+        placeholder,
+        value,
+        selection,
+    }
+);
+
+#[cfg(feature = "widgets")]
+impl Animate for AnimatedTextInputStyle {
+    fn components() -> usize {
+        iced::Background::components() + iced::Border::components() + iced::Color::components() * 4
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.background.distance_to(&end.background),
+            self.border.distance_to(&end.border),
+            self.icon.distance_to(&end.icon),
+            self.placeholder.distance_to(&end.placeholder),
+            self.value.distance_to(&end.value),
+            self.selection.distance_to(&end.selection),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.background.update(components);
+        self.border.update(components);
+        self.icon.update(components);
+        self.placeholder.update(components);
+        self.value.update(components);
+        self.selection.update(components);
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.background
+            .lerp(&start.background, &end.background, progress);
+        self.border.lerp(&start.border, &end.border, progress);
+        self.icon.lerp(&start.icon, &end.icon, progress);
+        self.placeholder
+            .lerp(&start.placeholder, &end.placeholder, progress);
+        self.value.lerp(&start.value, &end.value, progress);
+        self.selection
+            .lerp(&start.selection, &end.selection, progress);
+    }
+
+    fn reshape(&mut self, target: &Self) {
+        self.background.reshape(&target.background);
+    }
+}
+
+#[cfg(feature = "widgets")]
+animated_style!(
+    /// A [`checkbox::Style`](iced::widget::checkbox::Style) that can be animated.
+    AnimatedCheckboxStyle(iced::widget::checkbox::Style) {
+        background,
+        icon_color,
+        border,
+        text_color,
+    }
+);
+
+#[cfg(feature = "widgets")]
+impl Animate for AnimatedCheckboxStyle {
+    fn components() -> usize {
+        iced::Background::components()
+            + iced::Color::components()
+            + iced::Border::components()
+            + Option::<iced::Color>::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.background.distance_to(&end.background),
+            self.icon_color.distance_to(&end.icon_color),
+            self.border.distance_to(&end.border),
+            self.text_color.distance_to(&end.text_color),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.background.update(components);
+        self.icon_color.update(components);
+        self.border.update(components);
+        self.text_color.update(components);
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.background
+            .lerp(&start.background, &end.background, progress);
+        self.icon_color
+            .lerp(&start.icon_color, &end.icon_color, progress);
+        self.border.lerp(&start.border, &end.border, progress);
+        self.text_color
+            .lerp(&start.text_color, &end.text_color, progress);
+    }
+
+    fn reshape(&mut self, target: &Self) {
+        self.background.reshape(&target.background);
+    }
+}
+
+#[cfg(feature = "widgets")]
+animated_style!(
+    /// A [`toggler::Style`](iced::widget::toggler::Style) that can be animated.
+    AnimatedTogglerStyle(iced::widget::toggler::Style) {
+        background,
+        background_border_width,
+        background_border_color,
+        foreground,
+        foreground_border_width,
+        foreground_border_color,
+    }
+);
+
+#[cfg(feature = "widgets")]
+impl Animate for AnimatedTogglerStyle {
+    fn components() -> usize {
+        iced::Color::components() * 4 + f32::components() * 2
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.background.distance_to(&end.background),
+            self.background_border_width
+                .distance_to(&end.background_border_width),
+            self.background_border_color
+                .distance_to(&end.background_border_color),
+            self.foreground.distance_to(&end.foreground),
+            self.foreground_border_width
+                .distance_to(&end.foreground_border_width),
+            self.foreground_border_color
+                .distance_to(&end.foreground_border_color),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.background.update(components);
+        self.background_border_width.update(components);
+        self.background_border_color.update(components);
+        self.foreground.update(components);
+        self.foreground_border_width.update(components);
+        self.foreground_border_color.update(components);
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.background
+            .lerp(&start.background, &end.background, progress);
+        self.background_border_width.lerp(
+            &start.background_border_width,
+            &end.background_border_width,
+            progress,
+        );
+        self.background_border_color.lerp(
+            &start.background_border_color,
+            &end.background_border_color,
+            progress,
+        );
+        self.foreground
+            .lerp(&start.foreground, &end.foreground, progress);
+        self.foreground_border_width.lerp(
+            &start.foreground_border_width,
+            &end.foreground_border_width,
+            progress,
+        );
+        self.foreground_border_color.lerp(
+            &start.foreground_border_color,
+            &end.foreground_border_color,
+            progress,
+        );
+    }
+}
+
+#[cfg(feature = "widgets")]
+animated_style!(
+    /// A [`radio::Style`](iced::widget::radio::Style) that can be animated.
+    AnimatedRadioStyle(iced::widget::radio::Style) {
+        background,
+        dot_color,
+        border_width,
+        border_color,
+        text_color,
+    }
+);
+
+#[cfg(feature = "widgets")]
+impl Animate for AnimatedRadioStyle {
+    fn components() -> usize {
+        iced::Background::components()
+            + iced::Color::components() * 2
+            + f32::components()
+            + Option::<iced::Color>::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.background.distance_to(&end.background),
+            self.dot_color.distance_to(&end.dot_color),
+            self.border_width.distance_to(&end.border_width),
+            self.border_color.distance_to(&end.border_color),
+            self.text_color.distance_to(&end.text_color),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.background.update(components);
+        self.dot_color.update(components);
+        self.border_width.update(components);
+        self.border_color.update(components);
+        self.text_color.update(components);
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.background
+            .lerp(&start.background, &end.background, progress);
+        self.dot_color
+            .lerp(&start.dot_color, &end.dot_color, progress);
+        self.border_width
+            .lerp(&start.border_width, &end.border_width, progress);
+        self.border_color
+            .lerp(&start.border_color, &end.border_color, progress);
+        self.text_color
+            .lerp(&start.text_color, &end.text_color, progress);
+    }
+
+    fn reshape(&mut self, target: &Self) {
+        self.background.reshape(&target.background);
+    }
+}
+
+/// A [`slider::Style`](iced::widget::slider::Style) that can be animated.
+///
+/// Iced's slider style and its parts don't implement `PartialEq`, so this mirrors them with
+/// types that do and converts to and from the style.
+#[cfg(feature = "widgets")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnimatedSliderStyle {
+    rail: SliderRail,
+    handle: SliderHandle,
+}
+
+/// Mirrors a slider's [`Rail`](iced::widget::slider::Rail).
+#[cfg(feature = "widgets")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SliderRail {
+    backgrounds: (iced::Background, iced::Background),
+    width: f32,
+    border: iced::Border,
+}
+
+/// Mirrors a slider's [`Handle`](iced::widget::slider::Handle).
+#[cfg(feature = "widgets")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SliderHandle {
+    shape: SliderHandleShape,
+    background: iced::Background,
+    border_width: f32,
+    border_color: iced::Color,
+}
+
+/// Mirrors a slider's [`HandleShape`](iced::widget::slider::HandleShape).
+#[cfg(feature = "widgets")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SliderHandleShape {
+    Circle {
+        radius: f32,
+    },
+    Rectangle {
+        width: u16,
+        border_radius: iced::border::Radius,
+    },
+}
+
+#[cfg(feature = "widgets")]
+impl From<iced::widget::slider::Style> for AnimatedSliderStyle {
+    fn from(style: iced::widget::slider::Style) -> Self {
+        use iced::widget::slider::HandleShape;
+        let iced::widget::slider::Style { rail, handle } = style;
+        Self {
+            rail: SliderRail {
+                backgrounds: rail.backgrounds,
+                width: rail.width,
+                border: rail.border,
+            },
+            handle: SliderHandle {
+                shape: match handle.shape {
+                    HandleShape::Circle { radius } => SliderHandleShape::Circle { radius },
+                    HandleShape::Rectangle {
+                        width,
+                        border_radius,
+                    } => SliderHandleShape::Rectangle {
+                        width,
+                        border_radius,
+                    },
+                },
+                background: handle.background,
+                border_width: handle.border_width,
+                border_color: handle.border_color,
+            },
+        }
+    }
+}
+
+#[cfg(feature = "widgets")]
+impl From<AnimatedSliderStyle> for iced::widget::slider::Style {
+    fn from(style: AnimatedSliderStyle) -> Self {
+        use iced::widget::slider::{Handle, HandleShape, Rail};
+        let AnimatedSliderStyle { rail, handle } = style;
+        Self {
+            rail: Rail {
+                backgrounds: rail.backgrounds,
+                width: rail.width,
+                border: rail.border,
+            },
+            handle: Handle {
+                shape: match handle.shape {
+                    SliderHandleShape::Circle { radius } => HandleShape::Circle { radius },
+                    SliderHandleShape::Rectangle {
+                        width,
+                        border_radius,
+                    } => HandleShape::Rectangle {
+                        width,
+                        border_radius,
+                    },
+                },
+                background: handle.background,
+                border_width: handle.border_width,
+                border_color: handle.border_color,
+            },
+        }
+    }
+}
+
+#[cfg(feature = "widgets")]
+impl Animate for SliderHandleShape {
+    fn components() -> usize {
+        f32::components() + iced::border::Radius::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        match (self, end) {
+            (Self::Circle { radius: start }, Self::Circle { radius: end }) => {
+                let mut distance = start.distance_to(end);
+                distance.resize(Self::components(), 0.0);
+                distance
+            }
+            (
+                Self::Rectangle {
+                    border_radius: start,
+                    ..
+                },
+                Self::Rectangle {
+                    border_radius: end, ..
+                },
+            ) => [vec![0.0], start.distance_to(end)].concat(),
+            _ => vec![0.0; Self::components()],
+        }
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        match self {
+            Self::Circle { radius } => {
+                radius.update(components);
+                for _ in 0..iced::border::Radius::components() {
+                    components.next();
+                }
+            }
+            // The width is a whole number of pixels, so it changes without animating.
+            Self::Rectangle { border_radius, .. } => {
+                components.next();
+                border_radius.update(components);
+            }
+        }
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        match (self, start, end) {
+            (
+                Self::Circle { radius: value },
+                Self::Circle { radius: start },
+                Self::Circle { radius: end },
+            ) => value.lerp(start, end, progress),
+            (
+                Self::Rectangle {
+                    border_radius: value,
+                    ..
+                },
+                Self::Rectangle {
+                    border_radius: start,
+                    ..
+                },
+                Self::Rectangle {
+                    border_radius: end, ..
+                },
+            ) => value.lerp(start, end, progress),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(feature = "widgets")]
+impl Animate for SliderRail {
+    fn components() -> usize {
+        iced::Background::components() * 2 + f32::components() + iced::Border::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.backgrounds.distance_to(&end.backgrounds),
+            self.width.distance_to(&end.width),
+            self.border.distance_to(&end.border),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.backgrounds.update(components);
+        self.width.update(components);
+        self.border.update(components);
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.backgrounds
+            .lerp(&start.backgrounds, &end.backgrounds, progress);
+        self.width.lerp(&start.width, &end.width, progress);
+        self.border.lerp(&start.border, &end.border, progress);
+    }
+
+    fn reshape(&mut self, target: &Self) {
+        self.backgrounds.reshape(&target.backgrounds);
+    }
+}
+
+#[cfg(feature = "widgets")]
+impl Animate for SliderHandle {
+    fn components() -> usize {
+        SliderHandleShape::components()
+            + iced::Background::components()
+            + f32::components()
+            + iced::Color::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.shape.distance_to(&end.shape),
+            self.background.distance_to(&end.background),
+            self.border_width.distance_to(&end.border_width),
+            self.border_color.distance_to(&end.border_color),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.shape.update(components);
+        self.background.update(components);
+        self.border_width.update(components);
+        self.border_color.update(components);
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.shape.lerp(&start.shape, &end.shape, progress);
+        self.background
+            .lerp(&start.background, &end.background, progress);
+        self.border_width
+            .lerp(&start.border_width, &end.border_width, progress);
+        self.border_color
+            .lerp(&start.border_color, &end.border_color, progress);
+    }
+
+    fn reshape(&mut self, target: &Self) {
+        self.background.reshape(&target.background);
+    }
+}
+
+#[cfg(feature = "widgets")]
+impl Animate for AnimatedSliderStyle {
+    fn components() -> usize {
+        SliderRail::components() + SliderHandle::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.rail.distance_to(&end.rail),
+            self.handle.distance_to(&end.handle),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.rail.update(components);
+        self.handle.update(components);
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.rail.lerp(&start.rail, &end.rail, progress);
+        self.handle.lerp(&start.handle, &end.handle, progress);
+    }
+
+    fn reshape(&mut self, target: &Self) {
+        self.rail.reshape(&target.rail);
+        self.handle.reshape(&target.handle);
+    }
+}
+
+#[cfg(feature = "widgets")]
+animated_style!(
+    /// A [`pick_list::Style`](iced::widget::pick_list::Style) that can be animated.
+    AnimatedPickListStyle(iced::widget::pick_list::Style) {
+        text_color,
+        placeholder_color,
+        handle_color,
+        background,
+        border,
+    }
+);
+
+#[cfg(feature = "widgets")]
+impl Animate for AnimatedPickListStyle {
+    fn components() -> usize {
+        iced::Color::components() * 3 + iced::Background::components() + iced::Border::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.text_color.distance_to(&end.text_color),
+            self.placeholder_color.distance_to(&end.placeholder_color),
+            self.handle_color.distance_to(&end.handle_color),
+            self.background.distance_to(&end.background),
+            self.border.distance_to(&end.border),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.text_color.update(components);
+        self.placeholder_color.update(components);
+        self.handle_color.update(components);
+        self.background.update(components);
+        self.border.update(components);
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.text_color
+            .lerp(&start.text_color, &end.text_color, progress);
+        self.placeholder_color
+            .lerp(&start.placeholder_color, &end.placeholder_color, progress);
+        self.handle_color
+            .lerp(&start.handle_color, &end.handle_color, progress);
+        self.background
+            .lerp(&start.background, &end.background, progress);
+        self.border.lerp(&start.border, &end.border, progress);
+    }
+
+    fn reshape(&mut self, target: &Self) {
+        self.background.reshape(&target.background);
+    }
+}
+
+impl<T1, T2> Animate for (T1, T2)
+where
+    T1: Animate,
+    T2: Animate,
+{
+    fn components() -> usize {
+        T1::components() + T2::components()
+    }
+
+    fn instance_components(&self) -> usize {
+        self.0.instance_components() + self.1.instance_components()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.0.update(components);
+        self.1.update(components);
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [self.0.distance_to(&end.0), self.1.distance_to(&end.1)].concat()
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.0.lerp(&start.0, &end.0, progress);
+        self.1.lerp(&start.1, &end.1, progress);
+    }
+
+    fn reshape(&mut self, target: &Self) {
+        self.0.reshape(&target.0);
+        self.1.reshape(&target.1);
+    }
+}
+
+impl<T1, T2, T3> Animate for (T1, T2, T3)
+where
+    T1: Animate,
+    T2: Animate,
+    T3: Animate,
+{
+    fn components() -> usize {
+        T1::components() + T2::components() + T3::components()
+    }
+
+    fn instance_components(&self) -> usize {
+        self.0.instance_components() + self.1.instance_components() + self.2.instance_components()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.0.update(components);
+        self.1.update(components);
+        self.2.update(components);
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.0.distance_to(&end.0),
+            self.1.distance_to(&end.1),
+            self.2.distance_to(&end.2),
+        ]
+        .concat()
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.0.lerp(&start.0, &end.0, progress);
+        self.1.lerp(&start.1, &end.1, progress);
+        self.2.lerp(&start.2, &end.2, progress);
+    }
+
+    fn reshape(&mut self, target: &Self) {
+        self.0.reshape(&target.0);
+        self.1.reshape(&target.1);
+        self.2.reshape(&target.2);
+    }
+}
+
+impl<T1, T2, T3, T4> Animate for (T1, T2, T3, T4)
+where
+    T1: Animate,
+    T2: Animate,
+    T3: Animate,
+    T4: Animate,
+{
+    fn components() -> usize {
+        T1::components() + T2::components() + T3::components() + T4::components()
+    }
+
+    fn instance_components(&self) -> usize {
+        self.0.instance_components()
+            + self.1.instance_components()
+            + self.2.instance_components()
+            + self.3.instance_components()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.0.update(components);
+        self.1.update(components);
+        self.2.update(components);
+        self.3.update(components);
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.0.distance_to(&end.0),
+            self.1.distance_to(&end.1),
+            self.2.distance_to(&end.2),
+            self.3.distance_to(&end.3),
+        ]
+        .concat()
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.0.lerp(&start.0, &end.0, progress);
+        self.1.lerp(&start.1, &end.1, progress);
+        self.2.lerp(&start.2, &end.2, progress);
+        self.3.lerp(&start.3, &end.3, progress);
+    }
+
+    fn reshape(&mut self, target: &Self) {
+        self.0.reshape(&target.0);
+        self.1.reshape(&target.1);
+        self.2.reshape(&target.2);
+        self.3.reshape(&target.3);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f32_components() {
+        assert_eq!(f32::components(), 1);
+    }
+
+    /// Ticks `animated` at regular frames from `start` for the given number of `frames`.
+    fn run_frames(
+        animated: &mut crate::Animated<Vec<f32>>,
+        start: std::time::Instant,
+        frames: std::ops::Range<u64>,
+    ) {
+        for frame in frames {
+            animated.tick(start + std::time::Duration::from_millis(frame * 16));
+        }
+    }
+
+    /// Vectors should grow and shrink mid-animation with both springs and transitions, settling
+    /// at targets of a different length than they started with.
+    #[test]
+    fn vec_changes_length_mid_animation() {
+        use crate::{spring::Motion, transition::Easing, Animated};
+
+        for mut bars in [
+            Animated::new(vec![0.0_f32, 0.0], Motion::SMOOTH),
+            Animated::new(vec![0.0_f32, 0.0], Easing::LINEAR),
+        ] {
+            let start = std::time::Instant::now();
+            bars.resume_at(start);
+            bars.set_target(vec![10.0, 10.0]);
+            run_frames(&mut bars, start, 1..6);
+
+            bars.set_target(vec![5.0, 5.0, 5.0, 5.0]);
+            run_frames(&mut bars, start, 6..12);
+            assert_eq!(bars.value().len(), 4);
+
+            bars.set_target(vec![1.0]);
+            run_frames(&mut bars, start, 12..400);
+            assert_eq!(bars.value(), &vec![1.0]);
+            assert!(!bars.is_animating());
+        }
+    }
+
+    /// New elements should start from the last element, and extra elements should be removed.
+    #[test]
+    fn vec_reshape() {
+        let mut bars = vec![1.0_f32, 2.0];
+        bars.reshape(&vec![0.0; 4]);
+        assert_eq!(bars, [1.0, 2.0, 2.0, 2.0]);
+
+        bars.reshape(&vec![0.0]);
+        assert_eq!(bars, [1.0]);
+    }
+
+    /// Vectors should count the components of each element, including inside other types.
+    #[test]
+    fn vec_instance_components() {
+        assert_eq!(vec![iced::Point::ORIGIN; 3].instance_components(), 6);
+        assert_eq!(Some(vec![1.0_f32, 2.0]).instance_components(), 2);
+        assert_eq!([vec![1.0_f32], vec![]].instance_components(), 1);
+        assert_eq!((1.0_f32, vec![1.0_f32, 2.0]).instance_components(), 3);
+        assert_eq!(crate::Spring::new(vec![1.0_f32, 2.0]).velocity().len(), 2);
+    }
+
+    /// Asserts that two angles in degrees are within a small tolerance of each other.
+    fn assert_degrees(angle: WrappingAngle, expected: f32) {
+        let degrees = angle.radians().0.to_degrees();
+        assert!((degrees - expected).abs() < 1e-3, "{degrees} != {expected}");
+    }
+
+    #[test]
+    fn wrapping_angle_normalizes() {
+        assert_degrees(WrappingAngle::new(iced::Degrees(-90.0)), 270.0);
+        assert_degrees(WrappingAngle::new(iced::Degrees(450.0)), 90.0);
+        assert_eq!(WrappingAngle::new(iced::Radians(-1e-9)).radians().0, 0.0);
+    }
+
+    #[test]
+    fn wrapping_angle_crosses_zero_forwards() {
+        let start = WrappingAngle::new(iced::Degrees(350.0));
+        let end = WrappingAngle::new(iced::Degrees(10.0));
+        let distance = start.distance_to(&end)[0].to_degrees();
+        assert!((distance + 20.0).abs() < 1e-3);
+
+        let mut angle = start;
+        angle.lerp(&start, &end, 0.25);
+        assert_degrees(angle, 355.0);
+        angle.lerp(&start, &end, 0.75);
+        assert_degrees(angle, 5.0);
+    }
+
+    #[test]
+    fn wrapping_angle_crosses_zero_backwards() {
+        let start = WrappingAngle::new(iced::Degrees(10.0));
+        let end = WrappingAngle::new(iced::Degrees(350.0));
+        let distance = start.distance_to(&end)[0].to_degrees();
+        assert!((distance - 20.0).abs() < 1e-3);
+
+        let mut angle = start;
+        angle.lerp(&start, &end, 0.75);
+        assert_degrees(angle, 355.0);
+
+        // Springs move by adding the negated distance, which should also wrap.
+        let mut angle = start;
+        angle.update(&mut std::iter::once(-start.distance_to(&end)[0]));
+        assert_degrees(angle, 350.0);
+    }
+
+    #[test]
+    fn oklab_round_trips() {
+        for color in [
+            iced::Color::BLACK,
+            iced::Color::WHITE,
+            iced::Color::from_rgba(0.2, 0.4, 0.8, 0.5),
+        ] {
+            let round_trip = OklabColor::new(color).color();
+            for (channel, expected) in [
+                (round_trip.r, color.r),
+                (round_trip.g, color.g),
+                (round_trip.b, color.b),
+                (round_trip.a, color.a),
+            ] {
+                assert!(
+                    (channel - expected).abs() < 1e-3,
+                    "{round_trip:?} != {color:?}"
+                );
+            }
+        }
+    }
+
+    /// The midpoint between red and green should be a bright yellow rather than a dark brown.
+    #[test]
+    fn oklab_midpoint_keeps_lightness() {
+        let red = OklabColor::new(iced::Color::from_rgb(1.0, 0.0, 0.0));
+        let green = OklabColor::new(iced::Color::from_rgb(0.0, 1.0, 0.0));
+        let mut midpoint = red;
+        midpoint.lerp(&red, &green, 0.5);
+
+        let mut srgb_midpoint = iced::Color::BLACK;
+        srgb_midpoint.lerp(&red.color(), &green.color(), 0.5);
+        let srgb_lightness = OklabColor::new(srgb_midpoint).lightness();
+
+        assert!((midpoint.lightness() - 0.747).abs() < 1e-2);
+        assert!(midpoint.lightness() > srgb_lightness + 0.1);
+        assert!(midpoint.lightness() > red.lightness());
+
+        let color = midpoint.color();
+        assert!(color.r > 0.7 && color.g > 0.6 && color.b < 0.1, "{color:?}");
+    }
+
+    #[test]
+    fn f32_point_components() {
+        assert_eq!(iced::Point::<f32>::components(), 2);
+    }
+
+    #[test]
+    fn f32_color_components() {
+        assert_eq!(iced::Color::components(), 4);
+    }
+
+    #[test]
+    fn color_pair_components() {
+        assert_eq!(
+            iced::theme::palette::Pair::components(),
+            2 * iced::Color::components()
+        );
+    }
+
+    #[test]
+    fn primary_components() {
+        assert_eq!(
+            iced::theme::palette::Primary::components(),
+            3 * iced::theme::palette::Pair::components()
+        );
+    }
+
+    #[test]
+    fn secondary_components() {
+        assert_eq!(
+            iced::theme::palette::Secondary::components(),
+            3 * iced::theme::palette::Pair::components()
+        );
+    }
+
+    #[test]
+    fn success_components() {
+        assert_eq!(
+            iced::theme::palette::Success::components(),
+            3 * iced::theme::palette::Pair::components()
+        );
+    }
+
+    #[test]
+    fn danger_components() {
+        assert_eq!(
+            iced::theme::palette::Danger::components(),
+            3 * iced::theme::palette::Pair::components()
+        );
+    }
+
+    #[test]
+    fn background_components() {
+        assert_eq!(
+            iced::theme::palette::Background::components(),
+            3 * iced::theme::palette::Pair::components()
+        );
+    }
+
+    #[test]
+    fn extended_palette_components() {
+        assert_eq!(
+            iced::theme::palette::Extended::components(),
+            iced::theme::palette::Background::components()
+                + iced::theme::palette::Primary::components()
+                + iced::theme::palette::Secondary::components()
+                + iced::theme::palette::Success::components()
+                + iced::theme::palette::Danger::components()
+        );
+    }
+
+    #[test]
+    fn theme_components() {
+        assert_eq!(
+            Theme::components(),
+            iced::theme::Palette::components() + iced::theme::palette::Extended::components()
+        );
+    }
+
+    /// Animating a theme while something else holds the previous frame's theme, like the
+    /// runtime does, should reuse the same two allocations instead of allocating every frame.
+    #[test]
+    fn theme_animation_reuses_allocations() {
+        let (start, end) = (Theme::Light, Theme::Dark);
+        let mut theme = start.clone();
+        let mut held = theme.clone();
+        let mut allocations = Vec::new();
+
+        for step in 1..=20 {
+            theme.lerp(&start, &end, step as f32 / 20.0);
+            held = theme.clone();
+            if let Theme::Custom(custom) = &theme {
+                let pointer = Arc::as_ptr(custom);
+                if !allocations.contains(&pointer) {
+                    allocations.push(pointer);
+                }
+            }
+        }
+
+        drop(held);
+        assert!(allocations.len() <= 2, "{} allocations", allocations.len());
+    }
+
+    /// In-between themes should interpolate the palette like a newly generated theme would.
+    #[test]
+    fn theme_lerp_midpoint() {
+        let (start, end) = (Theme::Light, Theme::Dark);
+        let mut theme = start.clone();
+        theme.lerp(&start, &end, 0.5);
+
+        let mut palette = start.palette();
+        palette.lerp(&start.palette(), &end.palette(), 0.5);
+        assert_eq!(theme.palette(), palette);
+    }
+
+    /// Boxed values should animate through a spring like the value they hold.
+    #[test]
+    fn boxed_point_spring() {
+        let target = Box::new(iced::Point::new(10.0, -5.0));
+        let mut spring = crate::Spring::new(Box::new(iced::Point::ORIGIN)).to(target.clone());
+        assert_eq!(Box::<iced::Point>::components(), 2);
+
+        let mut now = spring.last_update();
+        for _ in 0..10 {
+            now += std::time::Duration::from_millis(16);
+            spring.tick(now);
+        }
+        assert!(spring.value().x > 0.0 && spring.value().y < 0.0);
+
+        spring.settle();
+        assert_eq!(spring.value(), &target);
+    }
+
+    /// Animating a shared value should copy it instead of changing it for everything sharing it.
+    #[test]
+    fn shared_values_copy_on_write() {
+        let shared = Arc::new(iced::Point::ORIGIN);
+        let mut value = shared.clone();
+        value.lerp(&shared, &Arc::new(iced::Point::new(10.0, 10.0)), 0.5);
+        assert_eq!(*value, iced::Point::new(5.0, 5.0));
+        assert_eq!(*shared, iced::Point::ORIGIN);
+        assert!(!Arc::ptr_eq(&value, &shared));
+
+        let shared = Rc::new(1.0_f32);
+        let mut value = shared.clone();
+        value.update(&mut [2.0].into_iter());
+        assert_eq!((*value, *shared), (3.0, 1.0));
+    }
+
+    #[test]
+    fn option_components() {
+        assert_eq!(Option::<f32>::components(), 1);
+    }
+
+    /// `Some` value should update the value with the next component.
+    #[test]
+    fn option_update_some() {
+        let mut option = Some(1.0);
+        option.update(&mut [2.0].iter().copied());
+        assert_eq!(option, Some(3.0));
+    }
+
+    /// Trying to update `None` should update the iterator, but not change the value, since
+    /// animations reshape `None` into [`Animate::absent`] before updating it.
     #[test]
     fn option_update_none() {
         let mut option: Option<f32> = None;
@@ -1040,9 +3077,103 @@ mod tests {
         option.update(&mut iter);
         assert_eq!(option, None);
 
-        // The last component should be left alone by the option update.
-        assert_eq!(iter.next(), Some(4.0));
-        assert_eq!(iter.next(), None);
+        // The last component should be left alone by the option update.
+        assert_eq!(iter.next(), Some(4.0));
+        assert_eq!(iter.next(), None);
+    }
+
+    /// `None` values without any components shouldn't consume the components that follow.
+    #[test]
+    fn option_update_none_without_components() {
+        let mut value: (Option<bool>, f32) = (None, 1.0);
+        value.update(&mut [2.0].iter().copied());
+        assert_eq!(value, (None, 3.0));
+    }
+
+    /// Settles a spring by ticking it one frame at a time, returning every value along the way.
+    fn spring_frames<T: Animate>(spring: &mut crate::Spring<T>) -> Vec<T> {
+        let start = spring.last_update();
+        (1..=200)
+            .map(|frame| {
+                spring.tick(start + std::time::Duration::from_millis(frame * 16));
+                spring.value().clone()
+            })
+            .collect()
+    }
+
+    /// Colors appearing from `None` should fade in from transparent instead of snapping.
+    #[test]
+    fn option_fades_in_from_none() {
+        let white = iced::Color::WHITE;
+        let mut spring = crate::Spring::new(None).to(Some(white));
+        assert_eq!(spring.value(), &Some(iced::Color { a: 0.0, ..white }));
+
+        let frames = spring_frames(&mut spring);
+        let first = frames[0].expect("the color should be visible while fading in");
+        assert!(first.a > 0.0 && first.a < 1.0, "{first:?}");
+        assert_eq!((first.r, first.g, first.b), (1.0, 1.0, 1.0));
+        assert_eq!(spring.value(), &Some(white));
+    }
+
+    /// Colors disappearing to `None` should fade out and only become `None` once they're gone.
+    #[test]
+    fn option_fades_out_to_none() {
+        let white = iced::Color::WHITE;
+        let mut spring = crate::Spring::new(Some(white)).to(None);
+        assert_eq!(spring.velocity().len(), 4);
+
+        let frames = spring_frames(&mut spring);
+        let first = frames[0].expect("the color should still be visible while fading out");
+        assert!(first.a < 1.0, "{first:?}");
+        let faded = frames.iter().position(Option::is_none).unwrap();
+        assert!(faded > 1, "the color disappeared after {faded} frames");
+        assert!(frames[faded..].iter().all(Option::is_none));
+    }
+
+    /// Transitions should lerp from and to [`Animate::absent`] too.
+    #[test]
+    fn option_lerp_from_none() {
+        let mut value = None;
+        value.lerp(&None, &Some(1.0_f32), 0.25);
+        assert_eq!(value, Some(0.25));
+
+        let mut value = Some(1.0_f32);
+        value.lerp(&Some(1.0), &None, 0.25);
+        assert_eq!(value, Some(0.75));
+    }
+
+    /// Types without an absent value should keep snapping between `None` and `Some`.
+    #[test]
+    fn option_without_absent_snaps() {
+        let mut value: Option<bool> = None;
+        value.lerp(&None, &Some(true), 0.5);
+        assert_eq!(value, None);
+        assert_eq!(value.distance_to(&Some(true)), Vec::<f32>::new());
+    }
+
+    /// A gradient using all 8 stops should animate every one of them.
+    #[test]
+    fn gradient_with_all_stops() {
+        let gradient = |shade: f32, offset: f32| {
+            (0..8).fold(
+                iced::gradient::Linear::new(iced::Radians(shade)),
+                |gradient, index| {
+                    let stop = (index as f32 + offset) / 8.0;
+                    gradient.add_stop(stop, iced::Color::from_rgb(shade, stop, 1.0 - stop))
+                },
+            )
+        };
+        let start = gradient(0.0, 0.0);
+        let end = gradient(1.0, 0.5);
+        assert_animate_laws(start, end);
+
+        let mut value = start;
+        value.lerp(&start, &end, 0.5);
+        for (index, stop) in value.stops.iter().enumerate() {
+            let stop = stop.expect("every stop should be present");
+            assert_eq!(stop.offset, (index as f32 + 0.25) / 8.0);
+            assert_eq!(stop.color.r, 0.5);
+        }
     }
 
     #[test]
@@ -1055,6 +3186,144 @@ mod tests {
         assert_eq!(components.len(), 0);
     }
 
+    /// A horizontal gradient that's entirely `color`.
+    fn solid_gradient(color: iced::Color) -> iced::Background {
+        iced::Gradient::Linear(
+            iced::gradient::Linear::new(iced::Radians(0.0))
+                .add_stop(0.0, color)
+                .add_stop(1.0, color),
+        )
+        .into()
+    }
+
+    fn stop_colors(background: &iced::Background) -> Vec<iced::Color> {
+        match background {
+            iced::Background::Gradient(iced::Gradient::Linear(linear)) => linear
+                .stops
+                .iter()
+                .flatten()
+                .map(|stop| stop.color)
+                .collect(),
+            iced::Background::Color(color) => panic!("expected a gradient, got {color:?}"),
+        }
+    }
+
+    fn two_stops() -> iced::gradient::Linear {
+        iced::gradient::Linear::new(iced::Radians(0.0))
+            .add_stop(0.0, iced::Color::BLACK)
+            .add_stop(1.0, iced::Color::WHITE)
+    }
+
+    fn three_stops() -> iced::gradient::Linear {
+        // Iced replaces the stop at the sorted position instead of inserting, so add them in order.
+        iced::gradient::Linear::new(iced::Radians(0.0))
+            .add_stop(0.0, iced::Color::BLACK)
+            .add_stop(0.5, iced::Color::from_rgb(1.0, 0.0, 0.0))
+            .add_stop(1.0, iced::Color::WHITE)
+    }
+
+    fn offsets(linear: &iced::gradient::Linear) -> Vec<f32> {
+        linear
+            .stops
+            .iter()
+            .flatten()
+            .map(|stop| stop.offset)
+            .collect()
+    }
+
+    /// A stop that's added should grow out of its neighbor, so every frame has all three stops.
+    #[test]
+    fn gradient_adds_stop() {
+        let (start, end) = (two_stops(), three_stops());
+        assert_eq!(offsets(&end), vec![0.0, 0.5, 1.0]);
+
+        let mut spring = crate::Spring::new(start).to(end);
+        assert_eq!(offsets(spring.value()), vec![0.0, 1.0, 1.0]);
+        let frames = spring_frames(&mut spring);
+        assert!(frames.iter().all(|frame| offsets(frame).len() == 3));
+        let middle = offsets(&frames[2])[1];
+        assert!(middle > 0.5 && middle < 1.0, "{middle}");
+        assert_eq!(spring.value(), &end);
+
+        let mut linear = start;
+        linear.lerp(&start, &end, 0.5);
+        assert_eq!(offsets(&linear), vec![0.0, 0.75, 1.0]);
+    }
+
+    /// A stop that's removed should merge into its neighbor before it disappears.
+    #[test]
+    fn gradient_removes_stop() {
+        let (start, end) = (three_stops(), two_stops());
+        assert!(start.distance_to(&end) != vec![0.0; iced::gradient::Linear::components()]);
+
+        let mut linear = start;
+        linear.lerp(&start, &end, 0.5);
+        assert_eq!(offsets(&linear), vec![0.0, 0.75, 1.0]);
+        linear.lerp(&start, &end, 1.0);
+        assert_eq!(linear, end);
+
+        let mut spring = crate::Spring::new(start).to(end);
+        spring_frames(&mut spring);
+        assert_eq!(spring.value(), &end);
+    }
+
+    /// Colors should cross-fade into gradients through uniform gradients of the color.
+    #[test]
+    fn background_color_to_gradient_midpoint() {
+        let start = iced::Background::Color(iced::Color::BLACK);
+        let end = solid_gradient(iced::Color::from_rgb(1.0, 0.0, 0.0));
+        let mut background = start;
+        background.lerp(&start, &end, 0.5);
+
+        let colors = stop_colors(&background);
+        assert_eq!(colors.len(), 2);
+        for color in colors {
+            assert!((color.r - 0.5).abs() < 1e-4, "{color:?}");
+            assert_eq!((color.g, color.b, color.a), (0.0, 0.0, 1.0));
+        }
+    }
+
+    /// Springs should animate between colors and gradients in both directions, only switching
+    /// back to a color once they've settled.
+    #[test]
+    fn background_cross_fade_spring() {
+        let color = iced::Background::Color(iced::Color::BLACK);
+        let gradient = solid_gradient(iced::Color::WHITE);
+        assert!(color.distance_to(&gradient).iter().any(|d| *d != 0.0));
+        assert!(gradient.distance_to(&color).iter().any(|d| *d != 0.0));
+
+        let mut spring = crate::Spring::new(color).to(gradient);
+        assert_eq!(stop_colors(spring.value()), vec![iced::Color::BLACK; 2]);
+        let frames = spring_frames(&mut spring);
+        let red = stop_colors(&frames[2])[0].r;
+        assert!(red > 0.0 && red < 1.0, "{red}");
+        assert_eq!(spring.value(), &gradient);
+
+        let mut spring = crate::Spring::new(gradient).to(color);
+        let frames = spring_frames(&mut spring);
+        let red = stop_colors(&frames[2])[0].r;
+        assert!(red > 0.0 && red < 1.0, "{red}");
+        assert_eq!(spring.value(), &color);
+    }
+
+    /// Styles should reshape their backgrounds so they fade in from `None` too.
+    #[test]
+    fn button_background_fades_in() {
+        let style = iced::widget::button::Style::default();
+        let target = iced::widget::button::Style {
+            background: Some(iced::Color::WHITE.into()),
+            ..style
+        };
+
+        let mut spring = crate::Spring::new(style).to(target);
+        let frames = spring_frames(&mut spring);
+        let Some(iced::Background::Color(color)) = frames[2].background else {
+            panic!("the background should be fading in: {:?}", frames[2]);
+        };
+        assert!(color.a > 0.0 && color.a < 1.0, "{color:?}");
+        assert_eq!(spring.value(), &target);
+    }
+
     #[test]
     fn update_button_style() {
         let style = iced::widget::button::Style {
@@ -1075,4 +3344,620 @@ mod tests {
         spring.tick(std::time::Instant::now());
         assert_ne!(*spring.value(), style);
     }
+
+    #[cfg(feature = "widgets")]
+    #[test]
+    fn container_style_laws() {
+        let start = iced::widget::container::Style {
+            text_color: Some(iced::Color::BLACK),
+            background: Some(iced::Background::Color(iced::Color::WHITE)),
+            border: iced::Border::default().rounded(2.0),
+            shadow: iced::Shadow::default(),
+        };
+        let end = iced::widget::container::Style {
+            text_color: Some(iced::Color::WHITE),
+            background: Some(iced::Background::Color(iced::Color::BLACK)),
+            border: iced::Border::default().rounded(8.0).width(1.0),
+            shadow: iced::Shadow {
+                blur_radius: 4.0,
+                ..Default::default()
+            },
+        };
+        assert_animate_laws(AnimatedContainerStyle(start), AnimatedContainerStyle(end));
+    }
+
+    #[cfg(feature = "widgets")]
+    #[test]
+    fn text_style_laws() {
+        let start = iced::widget::text::Style {
+            color: Some(iced::Color::BLACK),
+        };
+        let end = iced::widget::text::Style {
+            color: Some(iced::Color::from_rgb(1.0, 0.8, 0.0)),
+        };
+        assert_animate_laws(AnimatedTextStyle(start), AnimatedTextStyle(end));
+    }
+
+    #[cfg(feature = "widgets")]
+    #[test]
+    fn text_input_style_laws() {
+        let start = iced::widget::text_input::Style {
+            background: iced::Background::Color(iced::Color::WHITE),
+            border: iced::Border::default().width(1.0).color(iced::Color::BLACK),
+            icon: iced::Color::BLACK,
+            placeholder: iced::Color::from_rgb(0.5, 0.5, 0.5),
+            value: iced::Color::BLACK,
+            selection: iced::Color::from_rgb(0.6, 0.8, 1.0),
+        };
+        let end = iced::widget::text_input::Style {
+            border: iced::Border::default()
+                .width(2.0)
+                .color(iced::Color::from_rgb(0.2, 0.4, 1.0)),
+            ..start
+        };
+        assert_animate_laws(AnimatedTextInputStyle(start), AnimatedTextInputStyle(end));
+    }
+
+    #[cfg(feature = "widgets")]
+    #[test]
+    fn pick_list_style_laws() {
+        let start = iced::widget::pick_list::Style {
+            text_color: iced::Color::BLACK,
+            placeholder_color: iced::Color::from_rgb(0.5, 0.5, 0.5),
+            handle_color: iced::Color::BLACK,
+            background: iced::Background::Color(iced::Color::WHITE),
+            border: iced::Border::default().width(1.0).color(iced::Color::BLACK),
+        };
+        let end = iced::widget::pick_list::Style {
+            handle_color: iced::Color::from_rgb(0.2, 0.4, 1.0),
+            border: iced::Border::default()
+                .width(2.0)
+                .color(iced::Color::from_rgb(0.2, 0.4, 1.0)),
+            ..start
+        };
+        assert_animate_laws(AnimatedPickListStyle(start), AnimatedPickListStyle(end));
+    }
+
+    /// Checks the laws every [`Animate`] impl should uphold between two values.
+    fn assert_animate_laws<T: Animate + std::fmt::Debug>(a: T, b: T) {
+        assert_eq!(verify_animate_consistency(&a, &b), Ok(()));
+        assert_eq!(verify_animate_consistency(&b, &a), Ok(()));
+
+        // The distance should always have one entry per component.
+        assert_eq!(a.distance_to(&b).len(), T::components());
+        assert_eq!(b.distance_to(&a).len(), T::components());
+
+        // Updating with the distance from the target should reach the target.
+        let mut value = a.clone();
+        value.update(&mut b.distance_to(&a).into_iter());
+        if T::components() > 0 {
+            assert_eq!(value, b);
+        }
+
+        // Lerping should start at the start value and end at the end value.
+        let mut value = b.clone();
+        value.lerp(&a, &b, 0.0);
+        assert_eq!(value, a);
+        value.lerp(&a, &b, 1.0);
+        assert_eq!(value, b);
+    }
+
+    #[test]
+    fn point_laws() {
+        assert_animate_laws(iced::Point::new(1.0, 2.0), iced::Point::new(4.0, -2.0));
+    }
+
+    #[test]
+    fn vector_laws() {
+        assert_animate_laws(iced::Vector::new(1.0, 2.0), iced::Vector::new(4.0, -2.0));
+    }
+
+    #[test]
+    fn size_laws() {
+        assert_animate_laws(iced::Size::new(1.0, 2.0), iced::Size::new(4.0, 8.0));
+    }
+
+    #[test]
+    fn rectangle_laws() {
+        assert_animate_laws(
+            iced::Rectangle::new(iced::Point::new(1.0, 2.0), iced::Size::new(3.0, 4.0)),
+            iced::Rectangle::new(iced::Point::new(5.0, 6.0), iced::Size::new(7.0, 8.0)),
+        );
+    }
+
+    #[test]
+    fn padding_laws() {
+        assert_animate_laws(iced::Padding::new(2.0), iced::Padding::new(8.0).left(4.0));
+    }
+
+    #[test]
+    fn absolute_offset_laws() {
+        use iced::widget::scrollable::AbsoluteOffset;
+        assert_animate_laws(
+            AbsoluteOffset { x: 0.0, y: 40.0 },
+            AbsoluteOffset { x: 12.0, y: 900.0 },
+        );
+    }
+
+    #[test]
+    fn radians_laws() {
+        assert_animate_laws(iced::Radians(0.5), iced::Radians(2.0));
+    }
+
+    #[test]
+    fn degrees_laws() {
+        assert_animate_laws(iced::Degrees(45.0), iced::Degrees(180.0));
+    }
+
+    #[test]
+    fn rotation_laws() {
+        assert_animate_laws(
+            iced::Rotation::Floating(iced::Radians(0.5)),
+            iced::Rotation::Floating(iced::Radians(2.0)),
+        );
+    }
+
+    /// Rotations should interpolate the angle while snapping between variants halfway through.
+    #[test]
+    fn rotation_lerp_snaps_variant() {
+        let start = iced::Rotation::Floating(iced::Radians(0.0));
+        let end = iced::Rotation::Solid(iced::Radians(2.0));
+        let mut rotation = start;
+
+        rotation.lerp(&start, &end, 0.25);
+        assert_eq!(rotation, iced::Rotation::Floating(iced::Radians(0.5)));
+
+        rotation.lerp(&start, &end, 0.75);
+        assert_eq!(rotation, iced::Rotation::Solid(iced::Radians(1.5)));
+    }
+
+    /// Large doubles should still settle exactly on their target after animating.
+    #[test]
+    fn f64_settles_on_target() {
+        assert_animate_laws(0.25_f64, 1e6);
+
+        let start = std::time::Instant::now();
+        let mut spring = crate::Spring::new_at(0.0_f64, start);
+        spring.set_target_at(1e12 + 0.5, start);
+        for frame in 1..=200 {
+            spring.tick(start + std::time::Duration::from_millis(frame * 16));
+        }
+        assert!(!spring.has_energy());
+        assert_eq!(*spring.value(), 1e12 + 0.5);
+    }
+
+    #[test]
+    fn u32_rounds_updates() {
+        assert_animate_laws(400_u32, 800_u32);
+        assert_animate_laws(iced::Size::new(400_u32, 300), iced::Size::new(800, 600));
+
+        let mut value = 10_u32;
+        value.update(&mut [0.4].into_iter());
+        assert_eq!(value, 10);
+        value.update(&mut [0.6].into_iter());
+        assert_eq!(value, 11);
+        value.update(&mut [-20.0].into_iter());
+        assert_eq!(value, 0);
+
+        value.lerp(&10, &20, 0.26);
+        assert_eq!(value, 13);
+    }
+
+    #[test]
+    fn small_ints_round_and_saturate() {
+        assert_animate_laws(4_u8, 200_u8);
+        assert_animate_laws(12_u16, 48_u16);
+
+        let mut value = 250_u8;
+        value.update(&mut [20.0].into_iter());
+        assert_eq!(value, u8::MAX);
+        value.lerp(&0, &9, 0.5);
+        assert_eq!(value, 5);
+    }
+
+    /// Many tiny updates should add up to the whole distance instead of being rounded away.
+    #[test]
+    fn animated_int_keeps_fractions() {
+        assert_animate_laws(AnimatedInt::new(0_u8), AnimatedInt::new(10));
+
+        let mut plain = 0_u32;
+        let mut animated = AnimatedInt::new(0_u32);
+        for _ in 0..100 {
+            plain.update(&mut [0.1].into_iter());
+            animated.update(&mut [0.1].into_iter());
+        }
+        assert_eq!(plain, 0);
+        assert_eq!(animated.get(), 10);
+
+        // Fractions past the range of the integer are dropped.
+        let mut animated = AnimatedInt::new(1_u8);
+        animated.update(&mut [-5.0].into_iter());
+        assert_eq!(animated, AnimatedInt::new(0));
+        animated.update(&mut [0.6].into_iter());
+        assert_eq!(animated.get(), 1);
+    }
+
+    /// A spring taking tiny steps should still reach exactly 10, and never stall at 9.
+    #[test]
+    fn animated_int_spring_reaches_target() {
+        let start = std::time::Instant::now();
+        let mut spring = crate::Spring::new_at(AnimatedInt::new(0_u16), start);
+        spring.set_target_at(AnimatedInt::new(10), start);
+
+        let mut previous = 0;
+        for ms in 1..=2000 {
+            spring.tick(start + std::time::Duration::from_millis(ms));
+            let height = spring.value().get();
+            assert!(height >= previous, "went back from {previous} to {height}");
+            previous = height;
+        }
+        assert!(!spring.has_energy());
+        assert_eq!(spring.value().get(), 10);
+    }
+
+    #[test]
+    fn pixels_components() {
+        assert_eq!(iced::Pixels::components(), 1);
+        assert_animate_laws(iced::Pixels(12.0), iced::Pixels(20.0));
+    }
+
+    #[test]
+    fn line_height_components() {
+        use iced::widget::text::LineHeight;
+        assert_eq!(LineHeight::components(), 1);
+        assert_animate_laws(LineHeight::Relative(1.0), LineHeight::Relative(1.5));
+        assert_animate_laws(
+            LineHeight::Absolute(iced::Pixels(16.0)),
+            LineHeight::Absolute(iced::Pixels(24.0)),
+        );
+    }
+
+    #[test]
+    fn line_height_absolute_lerp() {
+        use iced::widget::text::LineHeight;
+        let start = LineHeight::Absolute(iced::Pixels(16.0));
+        let end = LineHeight::Absolute(iced::Pixels(24.0));
+        let mut line_height = start;
+        line_height.lerp(&start, &end, 0.25);
+        assert_eq!(line_height, LineHeight::Absolute(iced::Pixels(18.0)));
+    }
+
+    /// Mismatched variants can't be interpolated, so they have no distance and snap halfway.
+    #[test]
+    fn line_height_mismatch_snaps_halfway() {
+        use iced::widget::text::LineHeight;
+        let start = LineHeight::Relative(1.2);
+        let end = LineHeight::Absolute(iced::Pixels(24.0));
+        assert_eq!(start.distance_to(&end), vec![0.0]);
+
+        let mut line_height = start;
+        line_height.lerp(&start, &end, 0.49);
+        assert_eq!(line_height, start);
+        line_height.lerp(&start, &end, 0.5);
+        assert_eq!(line_height, end);
+    }
+
+    #[test]
+    fn length_components() {
+        use iced::Length;
+        assert_eq!(Length::components(), 1);
+        assert_animate_laws(Length::Fixed(100.0), Length::Fixed(240.0));
+        assert_eq!(
+            verify_animate_consistency(&Length::Fixed(100.0), &Length::Fill),
+            Ok(())
+        );
+        assert_eq!(
+            verify_animate_consistency(&Length::Shrink, &Length::FillPortion(2)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn length_fixed_lerp() {
+        use iced::Length;
+        let start = Length::Fixed(100.0);
+        let end = Length::Fixed(200.0);
+        assert_eq!(start.distance_to(&end), vec![-100.0]);
+
+        let mut length = start;
+        length.lerp(&start, &end, 0.25);
+        assert_eq!(length, Length::Fixed(125.0));
+    }
+
+    /// Flexible lengths can't be interpolated, so they have no distance and snap halfway.
+    #[test]
+    fn length_mismatch_snaps_halfway() {
+        use iced::Length;
+        let start = Length::Fixed(100.0);
+        let end = Length::Fill;
+        assert_eq!(start.distance_to(&end), vec![0.0]);
+
+        let mut length = start;
+        length.lerp(&start, &end, 0.49);
+        assert_eq!(length, start);
+        length.lerp(&start, &end, 0.5);
+        assert_eq!(length, end);
+        length.lerp(&end, &start, 0.75);
+        assert_eq!(length, start);
+    }
+
+    #[test]
+    fn snap_laws() {
+        assert_animate_laws(false, true);
+        assert_animate_laws(iced::ContentFit::Contain, iced::ContentFit::Cover);
+        assert_animate_laws(iced::Alignment::Start, iced::Alignment::End);
+        assert_animate_laws(
+            iced::alignment::Horizontal::Left,
+            iced::alignment::Horizontal::Right,
+        );
+        assert_animate_laws(
+            iced::alignment::Vertical::Top,
+            iced::alignment::Vertical::Bottom,
+        );
+    }
+
+    /// Markers should have no components regardless of the marked type.
+    #[test]
+    fn phantom_data_components() {
+        assert_eq!(PhantomData::<str>::components(), 0);
+        assert_animate_laws(PhantomData::<u8>, PhantomData::<u8>);
+    }
+
+    /// Snapping values should switch to the end value halfway through.
+    #[test]
+    fn snap_lerp_switches_halfway() {
+        let mut value = false;
+        value.lerp(&false, &true, 0.49);
+        assert!(!value);
+        value.lerp(&false, &true, 0.5);
+        assert!(value);
+    }
+
+    /// The support table in the module docs should list every geometry impl and its components.
+    #[test]
+    fn support_table_is_up_to_date() {
+        let rows = [
+            ("iced::Point<T>", "2 × `T`".to_owned(), "continuous"),
+            ("iced::Vector<T>", "2 × `T`".to_owned(), "continuous"),
+            ("iced::Size<T>", "2 × `T`".to_owned(), "continuous"),
+            ("iced::Rectangle<T>", "4 × `T`".to_owned(), "continuous"),
+            (
+                "iced::Padding",
+                iced::Padding::components().to_string(),
+                "continuous",
+            ),
+            (
+                "iced::Radians",
+                iced::Radians::components().to_string(),
+                "continuous",
+            ),
+            (
+                "iced::Degrees",
+                iced::Degrees::components().to_string(),
+                "continuous",
+            ),
+            (
+                "iced::Rotation",
+                iced::Rotation::components().to_string(),
+                "continuous angle, snapping variant",
+            ),
+            (
+                "iced::Pixels",
+                iced::Pixels::components().to_string(),
+                "continuous",
+            ),
+            ("f64", f64::components().to_string(), "continuous"),
+            ("u8", u8::components().to_string(), "continuous, rounded"),
+            ("u16", u16::components().to_string(), "continuous, rounded"),
+            ("u32", u32::components().to_string(), "continuous, rounded"),
+            (
+                "iced::widget::text::LineHeight",
+                iced::widget::text::LineHeight::components().to_string(),
+                "continuous, snapping variant",
+            ),
+            (
+                "iced::ContentFit",
+                iced::ContentFit::components().to_string(),
+                "snap",
+            ),
+            (
+                "iced::Alignment",
+                iced::Alignment::components().to_string(),
+                "snap",
+            ),
+            (
+                "iced::alignment::Horizontal",
+                iced::alignment::Horizontal::components().to_string(),
+                "snap",
+            ),
+            (
+                "iced::alignment::Vertical",
+                iced::alignment::Vertical::components().to_string(),
+                "snap",
+            ),
+            ("bool", bool::components().to_string(), "snap"),
+        ];
+
+        let source = include_str!("animate.rs");
+        for (name, components, behavior) in rows {
+            let row = format!("//! | `{name}` | {components} | {behavior} |");
+            assert!(source.contains(&row), "missing support table row: {row}");
+        }
+    }
+
+    /// A pair of floats whose methods can be broken one at a time.
+    #[derive(Debug, Clone, PartialEq)]
+    struct Broken<const MODE: u8>(f32, f32);
+
+    const CORRECT: u8 = 0;
+    const SHORT_DISTANCE: u8 = 1;
+    const GREEDY_UPDATE: u8 = 2;
+    const LAZY_UPDATE: u8 = 3;
+    const STALE_LERP: u8 = 4;
+
+    impl<const MODE: u8> Animate for Broken<MODE> {
+        fn components() -> usize {
+            2
+        }
+
+        fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+            self.0 += components.next().unwrap_or_default();
+            if MODE != LAZY_UPDATE {
+                self.1 += components.next().unwrap_or_default();
+            }
+            if MODE == GREEDY_UPDATE {
+                components.next();
+            }
+        }
+
+        fn distance_to(&self, end: &Self) -> Vec<f32> {
+            if MODE == SHORT_DISTANCE {
+                vec![self.0 - end.0]
+            } else {
+                vec![self.0 - end.0, self.1 - end.1]
+            }
+        }
+
+        fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+            self.0.lerp(&start.0, &end.0, progress);
+            if MODE != STALE_LERP {
+                self.1.lerp(&start.1, &end.1, progress);
+            }
+        }
+    }
+
+    #[test]
+    fn consistent_impl_passes() {
+        let result = verify_animate_consistency(&Broken::<CORRECT>(0.0, 1.0), &Broken(2.0, 3.0));
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn short_distance_is_reported() {
+        let result =
+            verify_animate_consistency(&Broken::<SHORT_DISTANCE>(0.0, 1.0), &Broken(2.0, 3.0));
+        assert_eq!(
+            result,
+            Err(ConsistencyError::DistanceLength {
+                components: 2,
+                distance: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn greedy_update_is_reported() {
+        let result =
+            verify_animate_consistency(&Broken::<GREEDY_UPDATE>(0.0, 1.0), &Broken(2.0, 3.0));
+        assert_eq!(
+            result,
+            Err(ConsistencyError::UpdateConsumed {
+                expected: 2,
+                consumed: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn lazy_update_is_reported() {
+        let result =
+            verify_animate_consistency(&Broken::<LAZY_UPDATE>(0.0, 1.0), &Broken(2.0, 3.0));
+        assert_eq!(
+            result,
+            Err(ConsistencyError::UpdateConsumed {
+                expected: 2,
+                consumed: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn stale_lerp_is_reported() {
+        let result = verify_animate_consistency(&Broken::<STALE_LERP>(0.0, 1.0), &Broken(2.0, 3.0));
+        assert_eq!(result, Err(ConsistencyError::LerpEnd));
+        assert!(result.unwrap_err().to_string().contains("lerp"));
+    }
+
+    /// Animations should refuse to start from values whose implementation is inconsistent.
+    #[test]
+    #[should_panic(expected = "inconsistent `Animate` implementation")]
+    fn spring_rejects_inconsistent_impl() {
+        crate::Spring::new(Broken::<GREEDY_UPDATE>(0.0, 1.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "inconsistent `Animate` implementation")]
+    fn transition_rejects_inconsistent_impl() {
+        crate::Transition::new(Broken::<SHORT_DISTANCE>(0.0, 1.0));
+    }
+
+    /// Collections are checked against the components of their elements, whatever their length.
+    #[test]
+    fn variable_length_values_pass() {
+        assert_eq!(
+            verify_animate_consistency(&vec![1.0, 2.0], &vec![3.0, 4.0]),
+            Ok(())
+        );
+        assert_eq!(
+            verify_animate_consistency(&vec![1.0], &vec![2.0, 3.0, 4.0]),
+            Ok(())
+        );
+        assert_eq!(
+            verify_animate_consistency(&Vec::<f32>::new(), &Vec::new()),
+            Ok(())
+        );
+        assert_eq!(verify_animate_consistency(&f32::NAN, &f32::NAN), Ok(()));
+    }
+
+    /// Broken elements should be reported even when they're in a collection.
+    #[test]
+    fn broken_elements_are_reported() {
+        let result = verify_animate_consistency(
+            &vec![Broken::<SHORT_DISTANCE>(0.0, 1.0)],
+            &vec![Broken(2.0, 3.0)],
+        );
+        assert_eq!(
+            result,
+            Err(ConsistencyError::DistanceLength {
+                components: 2,
+                distance: 1,
+            })
+        );
+    }
+
+    /// Implementations that return more components than `components()` should be reported even
+    /// when `update` consumes all of them.
+    #[test]
+    fn long_distance_is_reported() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Long(f32);
+
+        impl Animate for Long {
+            fn components() -> usize {
+                1
+            }
+
+            fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+                self.0 += components.next().unwrap_or_default();
+                components.next();
+            }
+
+            fn distance_to(&self, end: &Self) -> Vec<f32> {
+                vec![self.0 - end.0, 0.0]
+            }
+
+            fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+                self.0.lerp(&start.0, &end.0, progress);
+            }
+        }
+
+        let result = verify_animate_consistency(&Long(0.0), &Long(1.0));
+        assert_eq!(
+            result,
+            Err(ConsistencyError::DistanceLength {
+                components: 1,
+                distance: 2,
+            })
+        );
+    }
 }
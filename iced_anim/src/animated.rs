@@ -1,23 +1,75 @@
 mod animation_type;
+mod animator;
 mod mode;
 
 use crate::{
+    decay::Decay,
+    sequence::{Sequence, Step},
     spring::Motion,
+    stall::StallPolicy,
     transition::{Easing, Transition},
     Animate, Event, Spring,
 };
 pub use animation_type::AnimationType;
+pub use animator::{Animator, CloneAnimator};
 pub use mode::Mode;
-use std::time::{Duration, Instant};
+use std::{
+    collections::VecDeque,
+    ops::Range,
+    time::{Duration, Instant},
+};
 
 /// The default duration used by animations.
 pub const DEFAULT_DURATION: Duration = Duration::from_millis(500);
 
+/// The most target changes that can be scheduled at once with [`Animated::schedule_target`].
+pub const MAX_SCHEDULED: usize = 32;
+
+/// How much weight the most recent frame has when smoothing the observed frame interval.
+const FRAME_INTERVAL_SMOOTHING: f32 = 0.25;
+
+/// Blends the latest frame duration `dt` into the `previous` frame interval estimate.
+///
+/// Smoothing avoids reacting to a single slow frame while still adapting within a handful of
+/// frames when the display's refresh rate changes, e.g. when moving a window between monitors.
+pub(crate) fn smooth_frame_interval(previous: Option<Duration>, dt: Duration) -> Duration {
+    match previous {
+        Some(previous) => {
+            previous.mul_f32(1.0 - FRAME_INTERVAL_SMOOTHING) + dt.mul_f32(FRAME_INTERVAL_SMOOTHING)
+        }
+        None => dt,
+    }
+}
+
 /// Designed to wrap an [`Animate`] value and enable animating changes to it.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Two animated values are equal when they show the same [`value`](Animated::value), head
+/// towards the same [`target`](Animated::target), and share the same [`Mode`] and
+/// [`deadband`](Animated::deadband) and [`snap`](Animated::snap). Timing details like when the
+/// animation was last updated, its velocity, its progress, and any scheduled target changes are
+/// ignored, so animated values that look the same compare equal even if they were ticked at
+/// different times.
+#[derive(Debug, Clone)]
 pub struct Animated<T> {
     /// The animation that will be used to animate the value.
     animation: AnimationType<T>,
+    /// The smallest change in the value that's published, or `0.0` to publish every change.
+    deadband: f32,
+    /// The distance from the target within which the target is published, or `0.0` to not snap.
+    snap: f32,
+    /// The most recently published value while animating within a deadband or snap distance.
+    published: Option<T>,
+    /// Target changes scheduled to start at future instants, in time order.
+    scheduled: Vec<(Instant, T)>,
+    /// Follow-up targets queued with [`Animated::then_after`], with how long to hold the value
+    /// before starting each one.
+    chained: VecDeque<(T, Duration)>,
+    /// Whether the animation is paused, in which case ticks only keep its clock current.
+    is_paused: bool,
+    /// Whether the animation was resumed since its last tick.
+    is_resuming: bool,
+    /// The time scale that overrides the crate-wide one for this value, if any.
+    time_scale: Option<f32>,
 }
 
 impl<T> Animated<T>
@@ -32,21 +84,148 @@ where
         }
     }
 
-    /// Creates a new [`Animated`] value using a [`Spring`] animation.
-    pub fn spring(value: T, motion: Motion) -> Self {
+    /// Creates a new [`Animated`] value driven by the given `animation`.
+    fn from_animation(animation: AnimationType<T>) -> Self {
         Self {
-            animation: AnimationType::Spring(Spring::new(value).with_motion(motion)),
+            animation,
+            deadband: 0.0,
+            snap: 0.0,
+            published: None,
+            scheduled: Vec::new(),
+            chained: VecDeque::new(),
+            is_paused: false,
+            is_resuming: false,
+            time_scale: None,
         }
     }
 
+    /// Creates a new [`Animated`] value as of the given `now` instant.
+    ///
+    /// Along with [`Animated::schedule_target`] and [`Animated::tick`], this lets an animation be
+    /// driven entirely by synthetic instants, e.g. for deterministic tests.
+    ///
+    /// ```rust
+    /// # use std::time::{Duration, Instant};
+    /// # use iced_anim::{spring::Motion, Animated};
+    /// let start = Instant::now();
+    /// let mut size = Animated::new_at(0.0, Motion::SMOOTH, start);
+    /// size.schedule_target(100.0, start);
+    /// size.tick(start + Duration::from_millis(16));
+    /// assert!(*size.value() > 0.0);
+    /// ```
+    pub fn new_at(value: T, mode: impl Into<Mode>, now: Instant) -> Self {
+        let animation = match mode.into() {
+            Mode::Spring(motion) => {
+                AnimationType::Spring(Spring::new_at(value, now).with_motion(motion))
+            }
+            Mode::Transition(easing) => {
+                AnimationType::Transition(Transition::new_at(value, now).with_easing(easing))
+            }
+        };
+        Self::from_animation(animation)
+    }
+
+    /// Creates a new [`Animated`] value using a [`Spring`] animation.
+    pub fn spring(value: T, motion: Motion) -> Self {
+        Self::from_animation(AnimationType::Spring(
+            Spring::new(value).with_motion(motion),
+        ))
+    }
+
     /// Creates a new [`Animated`] value using a [`Transition`] animation.
     pub fn transition(value: T, easing: Easing) -> Self {
-        Self {
-            animation: AnimationType::Transition(Transition::new(value).with_easing(easing)),
+        Self::from_animation(AnimationType::Transition(
+            Transition::new(value).with_easing(easing),
+        ))
+    }
+
+    /// Creates a new [`Animated`] value that plays the given `steps` of a [`Sequence`], starting
+    /// from `value`.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use iced_anim::{sequence::Step, transition::Easing, Animated};
+    /// // Pulse a scale up, hold it for a moment, and then shrink back down.
+    /// let scale = Animated::sequence(
+    ///     1.0_f32,
+    ///     [
+    ///         Step::new(1.2, Easing::EASE_OUT).hold(Duration::from_millis(150)),
+    ///         Step::new(1.0, Easing::EASE_IN_OUT),
+    ///     ],
+    /// );
+    /// assert_eq!(scale.target(), &1.0);
+    /// assert!(scale.is_animating());
+    /// ```
+    pub fn sequence(value: T, steps: impl IntoIterator<Item = Step<T>>) -> Self {
+        let mut sequence = Sequence::new(value);
+        sequence.play(steps);
+        Self::from_animation(AnimationType::Sequence(sequence))
+    }
+
+    /// Creates a new [`Animated`] value that moves from `value` with the given `velocity` of each
+    /// component, in units per second, slowing down until it comes to rest. See [`Decay`].
+    ///
+    /// Setting a target aims the decay so that it comes to rest at the target, and switching the
+    /// animation to a spring with [`Mode::Spring`] carries its velocity over into the spring,
+    /// e.g. to pull a fling that ends near a snap point into place.
+    pub fn decay(value: T, velocity: Vec<f32>) -> Self {
+        Self::from_animation(AnimationType::Decay(
+            Decay::new(value).with_velocity(velocity),
+        ))
+    }
+
+    /// Flings the value from where it currently is with the given `velocity` of each
+    /// component, replacing any existing animation with a [`Decay`]. Existing decays keep their
+    /// deceleration rate.
+    ///
+    /// ```rust
+    /// # use iced_anim::{spring::Motion, Animated};
+    /// let mut offset = Animated::spring(0.0_f32, Motion::SMOOTH);
+    /// offset.fling(vec![1200.0]);
+    /// assert!(offset.is_animating());
+    /// assert!(*offset.target() > 500.0);
+    /// ```
+    pub fn fling(&mut self, velocity: Vec<f32>) {
+        if let AnimationType::Decay(decay) = &mut self.animation {
+            decay.set_velocity(velocity);
+        } else {
+            let decay = Decay::new(self.current().clone()).with_velocity(velocity);
+            self.animation = AnimationType::Decay(decay);
+        }
+        self.scheduled.clear();
+        self.publish();
+    }
+
+    /// Creates a new [`Animated`] value driven by a custom [`Animator`], e.g. a third-party
+    /// physics animation.
+    ///
+    /// Custom animators handle stalls in the event loop themselves, and switching the animation
+    /// to a [`Mode`] afterwards, e.g. through a widget's animation mode, replaces the animator
+    /// with a spring or transition that continues from its current value.
+    pub fn custom(animator: Box<dyn Animator<T>>) -> Self {
+        Self::from_animation(AnimationType::Custom(animator))
+    }
+
+    /// Plays the given `steps` of a [`Sequence`], starting from the current value and replacing
+    /// any existing animation.
+    ///
+    /// Switching the animation to a [`Mode`] afterwards, e.g. through a widget's animation mode,
+    /// animates straight to the final target of the steps instead.
+    pub fn play(&mut self, steps: impl IntoIterator<Item = Step<T>>) {
+        if let AnimationType::Sequence(sequence) = &mut self.animation {
+            sequence.play(steps);
+        } else {
+            let mut sequence = Sequence::new(self.current().clone());
+            sequence.play(steps);
+            self.animation = AnimationType::Sequence(sequence);
         }
+        self.publish();
     }
 
     /// Sets the duration that the animation will last and returns the updated animation.
+    ///
+    /// Each step of a sequence has its own duration, decays last as long as their velocity does,
+    /// and custom animators have their own configuration, so this does nothing for them.
     pub fn with_duration(mut self, duration: Duration) -> Self {
         match &mut self.animation {
             AnimationType::Spring(spring) => {
@@ -56,32 +235,256 @@ where
             AnimationType::Transition(transition) => {
                 transition.set_easing(transition.easing().with_duration(duration));
             }
+            AnimationType::Sequence(_) | AnimationType::Decay(_) | AnimationType::Custom(_) => {}
         }
 
         self
     }
 
+    /// Makes the components in `range` follow their own `motion` and returns the updated
+    /// animation, e.g. to give a field of a derived type a different motion from the rest.
+    ///
+    /// Only springs animate components separately, so this does nothing for transitions, and
+    /// switching to a transition with [`Mode::Transition`] discards the motion.
+    ///
+    /// ```rust
+    /// # use iced_anim::{spring::Motion, Animated};
+    /// // Animate the color of a background with a smooth motion and its alpha with a bouncy one.
+    /// let background = Animated::spring(iced::Color::BLACK, Motion::SMOOTH)
+    ///     .with_field_motion(3..4, Motion::BOUNCY);
+    /// ```
+    pub fn with_field_motion(mut self, range: Range<usize>, motion: Motion) -> Self {
+        if let AnimationType::Spring(spring) = &mut self.animation {
+            spring.set_component_motion(range, motion);
+        }
+        self
+    }
+
+    /// Sets the deadband of the animation and returns the updated animation.
+    ///
+    /// While animating, changes to the value smaller than the `deadband` aren't published:
+    /// [`Animated::value`] keeps returning the previously published value until the animation
+    /// moves further than the deadband away from it, or settles at its exact target. This avoids
+    /// endless sub-pixel changes in the long tail of an animation. The distance is measured as the
+    /// length of the [`Animate::distance_to`] components.
+    pub fn with_deadband(mut self, deadband: f32) -> Self {
+        self.set_deadband(deadband);
+        self
+    }
+
+    /// Sets the deadband of the animation. See [`Animated::with_deadband`] for details.
+    pub fn set_deadband(&mut self, deadband: f32) {
+        self.deadband = deadband.max(0.0);
+        self.publish();
+    }
+
+    /// Returns the deadband of the animation.
+    pub fn deadband(&self) -> f32 {
+        self.deadband
+    }
+
+    /// Sets the snap distance of the animation and returns the updated animation.
+    ///
+    /// Once every component of the value is within `snap` of the target, [`Animated::value`]
+    /// returns the exact target while the animation finishes in the background. This stops the
+    /// last imperceptible movements, like a size of `149.99` instead of `150.0`, from causing
+    /// extra layouts at the end of an animation.
+    ///
+    /// ```rust
+    /// # use iced_anim::{spring::Motion, Animated};
+    /// let mut width = Animated::spring(0.0, Motion::SMOOTH).with_snap(0.5).to(150.0);
+    /// width.settle_at(149.8);
+    /// width.set_target(150.0);
+    /// width.tick(std::time::Instant::now());
+    /// assert_eq!(width.value(), &150.0);
+    /// ```
+    pub fn with_snap(mut self, snap: f32) -> Self {
+        self.set_snap(snap);
+        self
+    }
+
+    /// Sets the snap distance of the animation. See [`Animated::with_snap`] for details.
+    pub fn set_snap(&mut self, snap: f32) {
+        self.snap = snap.max(0.0);
+        self.publish();
+    }
+
+    /// Scales the time that passes for this value by `scale` instead of the crate-wide
+    /// [time scale](crate::time_scale), e.g. to run one animation in slow motion in a test.
+    ///
+    /// Custom animators handle time themselves, so they aren't affected by the scale.
+    pub fn with_time_scale(mut self, scale: f32) -> Self {
+        self.time_scale = Some(scale);
+        self
+    }
+
+    /// Returns the time scale that overrides the crate-wide one for this value, if any.
+    pub fn time_scale(&self) -> Option<f32> {
+        self.time_scale
+    }
+
+    /// Returns the snap distance of the animation.
+    pub fn snap(&self) -> f32 {
+        self.snap
+    }
+
     /// Updates the animation based on some [`Event`] that occurred.
     pub fn update(&mut self, event: Event<T>) {
-        match &mut self.animation {
-            AnimationType::Spring(spring) => spring.update(event),
-            AnimationType::Transition(transition) => transition.update(event),
+        match event {
+            Event::Tick(now) => self.tick(now),
+            Event::Target(target) => self.set_target(target),
+            Event::Settle => self.settle(),
+            Event::SettleAt(target) => self.settle_at(target),
+            Event::Pause => self.pause(),
+            Event::Resume => self.resume(),
         }
     }
 
-    /// Whether this animated value is still undergoing an animation.
+    /// Pauses the animation where it is, e.g. to freeze background animations while a modal is
+    /// open. Ticks don't move a paused animation, and it's still animating if it was moving.
+    ///
+    /// ```rust
+    /// # use std::time::{Duration, Instant};
+    /// # use iced_anim::{transition::Easing, Animated};
+    /// let start = Instant::now();
+    /// let mut size = Animated::new_at(0.0, Easing::LINEAR, start);
+    /// size.schedule_target(100.0, start);
+    /// size.pause();
+    /// size.tick(start + Duration::from_millis(100));
+    /// assert_eq!(size.value(), &0.0);
+    /// assert!(size.is_animating());
+    /// ```
+    pub fn pause(&mut self) {
+        self.is_paused = true;
+    }
+
+    /// Resumes a paused animation from where it was paused.
+    ///
+    /// The first tick after resuming only restarts the clock, so the time spent paused is never
+    /// counted, even if the animation wasn't ticked while it was paused.
+    pub fn resume(&mut self) {
+        if self.is_paused {
+            self.is_paused = false;
+            self.is_resuming = true;
+        }
+    }
+
+    /// Whether the animation is paused.
+    pub fn is_paused(&self) -> bool {
+        self.is_paused
+    }
+
+    /// Whether this animated value is still undergoing an animation, or is waiting to start a
+    /// scheduled or chained one.
     pub fn is_animating(&self) -> bool {
+        self.is_moving() || !self.scheduled.is_empty() || !self.chained.is_empty()
+    }
+
+    /// Whether this animated value needs to be ticked on the next frame, which is when it's
+    /// animating and isn't paused. Widgets use this to decide whether to request a redraw.
+    pub fn needs_tick(&self) -> bool {
+        self.is_animating() && !self.is_paused
+    }
+
+    /// Whether the underlying animation is still moving towards its current target.
+    fn is_moving(&self) -> bool {
         match &self.animation {
             AnimationType::Spring(spring) => spring.has_energy(),
             AnimationType::Transition(transition) => transition.is_animating(),
+            AnimationType::Sequence(sequence) => sequence.is_animating(),
+            AnimationType::Decay(decay) => decay.is_animating(),
+            AnimationType::Custom(animator) => animator.is_animating(),
+        }
+    }
+
+    /// Returns the velocity of each component of a spring or decay animation, in units per
+    /// second, or `None` for transitions, sequences, and custom animators.
+    pub fn velocity(&self) -> Option<&[f32]> {
+        match &self.animation {
+            AnimationType::Spring(spring) => Some(spring.velocity()),
+            AnimationType::Decay(decay) => Some(decay.velocity()),
+            AnimationType::Transition(_)
+            | AnimationType::Sequence(_)
+            | AnimationType::Custom(_) => None,
+        }
+    }
+
+    /// Sets the velocity of each component of a spring animation and returns the updated
+    /// animation. See [`Animated::set_velocity`].
+    pub fn with_velocity(mut self, velocity: Vec<f32>) -> Self {
+        self.set_velocity(velocity);
+        self
+    }
+
+    /// Sets the velocity of each component of a spring or decay animation, in units per second,
+    /// e.g. to fling a dragged element with the velocity of the pointer when it's released.
+    ///
+    /// Transitions and sequences follow their easing curves instead of a velocity, so this does
+    /// nothing for them.
+    ///
+    /// ```rust
+    /// # use iced_anim::{spring::Motion, Animated};
+    /// let mut position = Animated::spring(iced::Point::ORIGIN, Motion::SMOOTH);
+    /// position.set_velocity(vec![400.0, -200.0]);
+    /// assert!(position.is_animating());
+    /// assert_eq!(position.velocity(), Some([400.0, -200.0].as_slice()));
+    /// ```
+    pub fn set_velocity(&mut self, velocity: Vec<f32>) {
+        match &mut self.animation {
+            AnimationType::Spring(spring) => spring.set_velocity(velocity),
+            AnimationType::Decay(decay) => decay.set_velocity(velocity),
+            _ => {}
+        }
+    }
+
+    /// Returns how far a transition is along the path from its initial value to its target, from
+    /// `0.0` to `1.0`, or `None` for springs, sequences, decays, and custom animators.
+    pub fn progress(&self) -> Option<f32> {
+        match &self.animation {
+            AnimationType::Transition(transition) => Some(transition.progress()),
+            AnimationType::Spring(_)
+            | AnimationType::Sequence(_)
+            | AnimationType::Decay(_)
+            | AnimationType::Custom(_) => None,
+        }
+    }
+
+    /// Moves a transition to the given `progress` along its path, clamped to `[0.0, 1.0]`, and
+    /// updates the value immediately. See [`Transition::set_progress`].
+    ///
+    /// Springs, sequences, and decays aren't driven by a single progress value, so this does
+    /// nothing for them.
+    ///
+    /// ```rust
+    /// # use iced_anim::{transition::Easing, Animated};
+    /// // Scrub through a fade with a slider instead of time.
+    /// let mut opacity = Animated::transition(0.0, Easing::LINEAR).to(1.0);
+    /// opacity.set_progress(0.25);
+    /// assert_eq!(opacity.value(), &0.25);
+    /// assert_eq!(opacity.progress(), Some(0.25));
+    /// ```
+    pub fn set_progress(&mut self, progress: f32) {
+        if let AnimationType::Transition(transition) = &mut self.animation {
+            transition.set_progress(progress);
+            self.publish();
         }
     }
 
     /// Returns a reference to the current `value` of the animated value.
+    ///
+    /// This is the most recently published value if the animation has a deadband.
     pub fn value(&self) -> &T {
+        self.published.as_ref().unwrap_or_else(|| self.current())
+    }
+
+    /// Returns the full precision value of the animation, ignoring any deadband.
+    fn current(&self) -> &T {
         match &self.animation {
             AnimationType::Spring(spring) => spring.value(),
             AnimationType::Transition(transition) => transition.value(),
+            AnimationType::Sequence(sequence) => sequence.value(),
+            AnimationType::Decay(decay) => decay.value(),
+            AnimationType::Custom(animator) => animator.value(),
         }
     }
 
@@ -91,54 +494,212 @@ where
         match &self.animation {
             AnimationType::Spring(spring) => spring.target(),
             AnimationType::Transition(transition) => transition.target(),
+            AnimationType::Sequence(sequence) => sequence.target(),
+            AnimationType::Decay(decay) => decay.projected_target(),
+            AnimationType::Custom(animator) => animator.target(),
         }
     }
 
     /// Sets the `target` value of the animation.
+    ///
+    /// Sequences skip any remaining steps and animate straight to the new `target`. Any target
+    /// changes scheduled with [`Animated::schedule_target`] or chained with [`Animated::then`] are
+    /// cancelled.
+    ///
+    /// The animation settles at the `target` immediately if animations are disabled with
+    /// [`set_animations_enabled`](crate::set_animations_enabled).
     pub fn set_target(&mut self, target: T) {
-        match &mut self.animation {
-            AnimationType::Spring(spring) => spring.set_target(target),
-            AnimationType::Transition(transition) => transition.set_target(target),
+        self.scheduled.clear();
+        self.chained.clear();
+        self.retarget(target, crate::clock::now());
+        if !crate::animations_enabled() {
+            self.settle();
         }
     }
 
     /// Sets the `target` value of the animation, and returns the updated animation.
     pub fn to(mut self, target: T) -> Self {
+        self.set_target(target);
+        self
+    }
+
+    /// Schedules the animation to start moving to the `target` at the given `start` instant,
+    /// e.g. to sync an animation with an audio clock.
+    ///
+    /// The change takes effect on the first tick at or after `start`, and the animation is
+    /// advanced as if it started exactly at `start`, so late ticks don't shorten or delay it.
+    /// Scheduled changes activate in time order, and scheduling another change at the same
+    /// instant replaces the earlier one. At most [`MAX_SCHEDULED`] changes are kept, dropping
+    /// the furthest ones first.
+    ///
+    /// Setting a target immediately with [`Animated::set_target`] or [`Animated::settle_at`]
+    /// cancels any scheduled changes. The animation counts as animating while changes are
+    /// scheduled so that it keeps getting ticked.
+    ///
+    /// ```rust
+    /// # use std::time::{Duration, Instant};
+    /// # use iced_anim::{transition::Easing, Animated};
+    /// let beat = Instant::now() + Duration::from_millis(500);
+    /// let mut highlight = Animated::transition(0.0_f32, Easing::EASE_OUT);
+    /// highlight.schedule_target(1.0, beat);
+    ///
+    /// assert_eq!(highlight.target(), &0.0);
+    /// highlight.tick(beat);
+    /// assert_eq!(highlight.target(), &1.0);
+    /// ```
+    pub fn schedule_target(&mut self, target: T, start: Instant) {
+        match self
+            .scheduled
+            .binary_search_by(|(scheduled, _)| scheduled.cmp(&start))
+        {
+            Ok(index) => self.scheduled[index].1 = target,
+            Err(index) => self.scheduled.insert(index, (start, target)),
+        }
+        self.scheduled.truncate(MAX_SCHEDULED);
+    }
+
+    /// Queues a follow-up `target` that the value starts animating to once it settles at its
+    /// current target, e.g. to slide a toast in and back out.
+    ///
+    /// Follow-ups can be chained several times and run in the order they were queued. The
+    /// animation counts as animating until the last one settles, and setting a new target with
+    /// [`Animated::set_target`] or [`Animated::settle_at`] cancels the rest of the chain.
+    ///
+    /// ```rust
+    /// # use iced_anim::{transition::Easing, Animated};
+    /// let mut offset = Animated::transition(0.0_f32, Easing::EASE_OUT);
+    /// offset.set_target(1.0);
+    /// offset.then(0.0);
+    ///
+    /// assert_eq!(offset.target(), &1.0);
+    /// offset.settle();
+    /// offset.tick(std::time::Instant::now());
+    /// assert_eq!(offset.target(), &0.0);
+    /// ```
+    pub fn then(&mut self, target: T) {
+        self.then_after(target, Duration::ZERO);
+    }
+
+    /// Queues a follow-up `target` like [`Animated::then`], but holds the settled value for the
+    /// given duration before starting it, e.g. to keep a toast on screen for a few seconds.
+    pub fn then_after(&mut self, target: T, hold: Duration) {
+        self.chained.push_back((target, hold));
+    }
+
+    /// Returns the target changes scheduled with [`Animated::schedule_target`], in time order.
+    pub fn scheduled(&self) -> &[(Instant, T)] {
+        &self.scheduled
+    }
+
+    /// Cancels any target changes scheduled with [`Animated::schedule_target`].
+    pub(crate) fn clear_scheduled(&mut self) {
+        self.scheduled.clear();
+    }
+
+    /// Sets the `target` of the underlying animation as of the given `now` instant without
+    /// touching the schedule.
+    fn retarget(&mut self, target: T, now: Instant) {
         match &mut self.animation {
-            AnimationType::Spring(spring) => spring.set_target(target),
-            AnimationType::Transition(transition) => transition.set_target(target),
+            AnimationType::Spring(spring) => spring.set_target_at(target, now),
+            AnimationType::Transition(transition) => transition.set_target_at(target, now),
+            AnimationType::Sequence(sequence) => sequence.set_target_at(target, now),
+            AnimationType::Decay(decay) => decay.set_target_at(target, now),
+            AnimationType::Custom(animator) => animator.set_target(target),
         }
+    }
 
-        self
+    /// Returns the [`Mode`] used to animate the value, which is the default mode for decays and
+    /// custom animators.
+    pub fn mode(&self) -> Mode {
+        match &self.animation {
+            AnimationType::Spring(spring) => Mode::Spring(spring.motion()),
+            AnimationType::Transition(transition) => Mode::Transition(transition.easing()),
+            AnimationType::Sequence(sequence) => Mode::Transition(sequence.easing()),
+            AnimationType::Decay(_) | AnimationType::Custom(_) => Mode::default(),
+        }
     }
 
-    /// Returns the duration of the animation.
+    /// Returns the duration of the animation, or the [`DEFAULT_DURATION`] for custom animators.
+    /// Decays return how much longer they'll keep moving.
     pub fn duration(&self) -> Duration {
         match &self.animation {
             AnimationType::Spring(spring) => spring.motion().duration(),
             AnimationType::Transition(transition) => transition.duration(),
+            AnimationType::Sequence(sequence) => sequence.duration(),
+            AnimationType::Decay(decay) => decay.duration(),
+            AnimationType::Custom(_) => DEFAULT_DURATION,
+        }
+    }
+
+    /// Returns a smoothed estimate of the time between recent ticks, if the animation has been
+    /// ticked yet.
+    pub fn observed_frame_interval(&self) -> Option<Duration> {
+        match &self.animation {
+            AnimationType::Spring(spring) => spring.observed_frame_interval(),
+            AnimationType::Transition(transition) => transition.observed_frame_interval(),
+            AnimationType::Sequence(sequence) => sequence.observed_frame_interval(),
+            AnimationType::Decay(decay) => decay.observed_frame_interval(),
+            AnimationType::Custom(_) => None,
         }
     }
 
+    /// Changes how the value is animated without restarting the animation.
+    ///
+    /// Changing the motion of a spring keeps its velocity, and switching from a transition or
+    /// decay to a spring carries over the speed the value was moving at. This makes it possible
+    /// to follow the pointer with [`Motion::INTERACTIVE`] while dragging, and then hand the
+    /// momentum of the drag to a softer spring when the pointer is released:
+    ///
+    /// ```rust
+    /// # use std::time::{Duration, Instant};
+    /// # use iced_anim::{spring::Motion, Animated};
+    /// let mut offset = Animated::spring(0.0_f32, Motion::INTERACTIVE);
+    /// let start = Instant::now();
+    /// offset.resume_at(start);
+    ///
+    /// // Drag the value towards the pointer.
+    /// offset.set_target(200.0);
+    /// offset.tick(start + Duration::from_millis(50));
+    /// let velocity = offset.velocity().unwrap().to_vec();
+    ///
+    /// // Release the pointer and let a bouncy spring take it from here.
+    /// offset.set_mode(Motion::BOUNCY);
+    /// assert_eq!(offset.velocity().unwrap(), velocity.as_slice());
+    /// ```
+    pub fn set_mode(&mut self, mode: impl Into<Mode>) {
+        self.apply(mode.into());
+    }
+
     /// Applies the given `config` to this animation, updating any duration/motion/curve settings.
-    /// Changing modes will reset the animation, interrupting any existing animation curve.
+    /// Changing to a different kind of animation interrupts any existing animation curve.
     pub(crate) fn apply(&mut self, mode: Mode) {
         match mode {
             Mode::Spring(motion) => {
                 if let AnimationType::Spring(spring) = &mut self.animation {
                     spring.set_motion(motion);
+                } else if let AnimationType::Decay(decay) = &self.animation {
+                    // Carry the momentum of the decay over into the spring.
+                    let target = decay.projected_target().clone();
+                    let spring = decay.clone().into_spring(target, motion);
+                    self.animation = AnimationType::Spring(spring);
                 } else {
-                    let value = self.value().clone();
+                    let value = self.current().clone();
                     let target = self.target().clone();
-                    self.animation =
-                        AnimationType::Spring(Spring::new(value).to(target).with_motion(motion));
+                    let mut spring = Spring::new(value).to(target).with_motion(motion);
+                    // Keep moving at the speed of an interrupted transition.
+                    if let AnimationType::Transition(transition) = &self.animation {
+                        if transition.is_animating() {
+                            spring.set_velocity(transition.velocity());
+                        }
+                    }
+                    self.animation = AnimationType::Spring(spring);
                 }
             }
             Mode::Transition(easing) => {
                 if let AnimationType::Transition(transition) = &mut self.animation {
                     transition.set_easing(easing);
                 } else {
-                    let value = self.value().clone();
+                    let value = self.current().clone();
                     let target = self.target().clone();
                     self.animation = AnimationType::Transition(
                         Transition::new(value).to(target).with_easing(easing),
@@ -153,34 +714,216 @@ where
         match &mut self.animation {
             AnimationType::Spring(spring) => spring.settle(),
             AnimationType::Transition(transition) => transition.settle(),
+            AnimationType::Sequence(sequence) => sequence.settle(),
+            AnimationType::Decay(decay) => decay.settle(),
+            AnimationType::Custom(animator) => animator.settle(),
         }
+        self.publish();
     }
 
-    /// Makes the animation immediately settle at the given `value`.
+    /// Makes the animation immediately settle at the given `value`, cancelling any scheduled or
+    /// chained target changes.
     pub fn settle_at(&mut self, target: T) {
+        self.scheduled.clear();
+        self.chained.clear();
         match &mut self.animation {
             AnimationType::Spring(spring) => spring.settle_at(target),
             AnimationType::Transition(transition) => transition.settle_at(target),
+            AnimationType::Sequence(sequence) => sequence.settle_at(target),
+            AnimationType::Decay(decay) => decay.settle_at(target),
+            AnimationType::Custom(animator) => animator.settle_at(target),
         }
+        self.publish();
+    }
+
+    /// Returns a copy of this animation settled at its target, suitable for storing in a
+    /// snapshot of your app's state, e.g. for undo and redo.
+    ///
+    /// Cloning an [`Animated`] value directly also clones any in-flight animation, including the
+    /// time it was last updated and its velocity, which is stale by the time the snapshot is
+    /// used. Settled copies only record where the value was headed. Pair them with
+    /// [`Animated::restore_from`] to animate back to the recorded state.
+    pub fn settled_clone(&self) -> Self {
+        let mut snapshot = self.clone();
+        snapshot.settle();
+        snapshot
+    }
+
+    /// Animates from the current value to the target of a `snapshot`, e.g. when undoing a change.
+    ///
+    /// Unlike replacing this value with the `snapshot` outright, this keeps the live animation's
+    /// value, velocity, and timing, so restoring an old snapshot moves smoothly back to the
+    /// recorded state instead of jumping to it or resuming a stale animation.
+    ///
+    /// ```rust
+    /// # use iced_anim::{spring::Motion, Animated};
+    /// let mut size = Animated::spring(10.0, Motion::SMOOTH);
+    /// let snapshot = size.settled_clone();
+    ///
+    /// size.set_target(50.0);
+    /// // Later, undo the change by animating back to the snapshot.
+    /// size.restore_from(&snapshot);
+    /// assert_eq!(size.target(), &10.0);
+    /// ```
+    pub fn restore_from(&mut self, snapshot: &Self) {
+        self.set_target(snapshot.target().clone());
     }
 
     /// Updates the animation's current value based on the elapsed time since the last update.
+    ///
+    /// Stalls in the event loop are handled according to the [`StallPolicy`], and the animation
+    /// settles at its target if animations are disabled with
+    /// [`set_animations_enabled`](crate::set_animations_enabled).
     pub fn tick(&mut self, now: Instant) {
+        self.tick_with(now, Some(StallPolicy::global()));
+    }
+
+    /// Updates the animation like [`Animated::tick`], handling stalls with the given `policy`,
+    /// or treating every gap as a regular frame if there's no policy.
+    ///
+    /// This is useful for state that drives an animation with its own clock and has already
+    /// handled any stalls itself.
+    pub(crate) fn tick_with(&mut self, now: Instant, policy: Option<StallPolicy>) {
+        if self.is_paused || std::mem::take(&mut self.is_resuming) {
+            self.resume_at(now);
+            return;
+        }
+
+        // Hold the value from before this tick if it's the first one within the deadband.
+        if self.deadband > 0.0 && self.published.is_none() {
+            self.published = Some(self.current().clone());
+        }
+
+        // Start any scheduled changes that are due as if they were set exactly on time.
+        while self
+            .scheduled
+            .first()
+            .is_some_and(|(start, _)| *start <= now)
+        {
+            let (start, target) = self.scheduled.remove(0);
+            self.advance(start, policy);
+            self.retarget(target, start);
+            self.resume_at(start);
+        }
+
+        self.advance(now, policy);
+        self.start_chained(now);
+        self.publish();
+    }
+
+    /// Starts the next chained target once the animation has settled, scheduling it after its
+    /// hold duration if it has one.
+    fn start_chained(&mut self, now: Instant) {
+        if self.is_moving() || !self.scheduled.is_empty() {
+            return;
+        }
+        let Some((target, hold)) = self.chained.pop_front() else {
+            return;
+        };
+
+        if hold.is_zero() {
+            self.retarget(target, now);
+            self.resume_at(now);
+        } else {
+            self.scheduled.push((now + hold, target));
+        }
+    }
+
+    /// Ticks the underlying animation without publishing its value.
+    fn advance(&mut self, now: Instant, policy: Option<StallPolicy>) {
+        if !crate::animations_enabled() {
+            match &mut self.animation {
+                AnimationType::Spring(spring) => spring.settle(),
+                AnimationType::Transition(transition) => transition.settle(),
+                AnimationType::Sequence(sequence) => sequence.settle(),
+                AnimationType::Decay(decay) => decay.settle(),
+                AnimationType::Custom(animator) => animator.settle(),
+            }
+            return;
+        }
+
+        let scale = self.time_scale.unwrap_or_else(crate::time_scale);
+        match &mut self.animation {
+            AnimationType::Spring(spring) => spring.tick_scaled(now, policy, scale),
+            AnimationType::Transition(transition) => transition.tick_scaled(now, policy, scale),
+            AnimationType::Sequence(sequence) => sequence.tick_scaled(now, policy, scale),
+            AnimationType::Decay(decay) => decay.tick_scaled(now, policy, scale),
+            AnimationType::Custom(animator) => animator.tick(now),
+        }
+    }
+
+    /// Publishes the current value if it has moved further than the deadband from the last
+    /// published value, or if the animation is no longer within a deadband at all. The target is
+    /// published instead once the value is within the snap distance of it.
+    fn publish(&mut self) {
+        if self.is_within_snap() {
+            self.published = Some(self.target().clone());
+            return;
+        }
+
+        let is_within_deadband = self.deadband > 0.0
+            && self.is_animating()
+            && self.published.as_ref().is_some_and(|published| {
+                let distance = published
+                    .distance_to(self.current())
+                    .iter()
+                    .map(|component| component * component)
+                    .sum::<f32>()
+                    .sqrt();
+                distance < self.deadband
+            });
+
+        if !is_within_deadband {
+            self.published =
+                (self.deadband > 0.0 && self.is_animating()).then(|| self.current().clone());
+        }
+    }
+
+    /// Whether the animation is close enough to its target to publish the target instead.
+    fn is_within_snap(&self) -> bool {
+        self.snap > 0.0
+            && self.is_animating()
+            && self
+                .current()
+                .distance_to(self.target())
+                .iter()
+                .all(|distance| distance.abs() <= self.snap)
+    }
+
+    /// Resumes the animation at `now` without advancing it by the time since its last update.
+    ///
+    /// Animations that stop being ticked for a while, e.g. while paused, would otherwise jump
+    /// forward by the paused time on their next tick.
+    pub fn resume_at(&mut self, now: Instant) {
         match &mut self.animation {
-            AnimationType::Spring(spring) => spring.tick(now),
-            AnimationType::Transition(transition) => transition.tick(now),
+            AnimationType::Spring(spring) => spring.resume_at(now),
+            AnimationType::Transition(transition) => transition.resume_at(now),
+            AnimationType::Sequence(sequence) => sequence.resume_at(now),
+            AnimationType::Decay(decay) => decay.resume_at(now),
+            AnimationType::Custom(animator) => animator.resume_at(now),
         }
     }
 }
 
+impl<T> PartialEq for Animated<T>
+where
+    T: Animate,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value() == other.value()
+            && self.target() == other.target()
+            && self.mode() == other.mode()
+            && self.deadband == other.deadband
+            && self.snap == other.snap
+    }
+}
+
 impl<T> From<T> for Animated<T>
 where
     T: Animate + Into<AnimationType<T>>,
 {
     fn from(value: T) -> Self {
-        Self {
-            animation: value.into(),
-        }
+        Self::from_animation(value.into())
     }
 }
 
@@ -192,3 +935,612 @@ where
         Animated::new(T::default(), Mode::default())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ticks a spring from 0 to 200 at 60Hz until it settles, returning the number of distinct
+    /// values that were published along the way and the final value.
+    fn published_values(deadband: f32) -> (usize, f32) {
+        let mut animated = Animated::spring(0.0_f32, Motion::SMOOTH)
+            .with_deadband(deadband)
+            .to(200.0);
+        let mut now = Instant::now();
+        let mut published = vec![*animated.value()];
+        while animated.is_animating() {
+            now += Duration::from_micros(16_667);
+            animated.tick(now);
+            if published.last() != Some(animated.value()) {
+                published.push(*animated.value());
+            }
+        }
+
+        (published.len(), *animated.value())
+    }
+
+    /// A deadband should drastically cut down on the changes published in a slow tail.
+    #[test]
+    fn deadband_reduces_published_values() {
+        let (without, _) = published_values(0.0);
+        let (with, _) = published_values(5.0);
+        assert!(with * 2 < without, "{with} vs {without}");
+    }
+
+    /// The final value should be exact once the animation settles.
+    #[test]
+    fn deadband_settles_exactly() {
+        let (_, value) = published_values(5.0);
+        assert_eq!(value, 200.0);
+    }
+
+    /// Changes smaller than the deadband shouldn't be published while animating.
+    #[test]
+    fn deadband_holds_small_changes() {
+        let mut animated = Animated::transition(0.0_f32, Easing::LINEAR)
+            .with_deadband(10.0)
+            .to(100.0);
+        let start = Instant::now();
+        animated.tick(start);
+        animated.tick(start + DEFAULT_DURATION / 100);
+        assert_eq!(*animated.value(), 0.0);
+
+        animated.tick(start + DEFAULT_DURATION / 5);
+        assert!(*animated.value() >= 10.0);
+    }
+
+    /// Settling should publish the target immediately, regardless of the deadband.
+    #[test]
+    fn settle_publishes_target() {
+        let mut animated = Animated::transition(0.0_f32, Easing::LINEAR)
+            .with_deadband(1000.0)
+            .to(100.0);
+        animated.tick(Instant::now() + DEFAULT_DURATION / 2);
+        animated.settle();
+        assert_eq!(*animated.value(), 100.0);
+    }
+
+    /// Snapping should publish the exact target for the tail of an animation while it finishes.
+    #[test]
+    fn snap_publishes_target_near_the_end() {
+        let mut animated = Animated::spring(0.0_f32, Motion::SMOOTH)
+            .with_snap(0.5)
+            .to(150.0);
+        let mut now = Instant::now();
+        let mut snapped_frames = 0;
+        while animated.is_animating() {
+            now += Duration::from_micros(16_667);
+            animated.tick(now);
+            let value = *animated.value();
+            assert!(value == 150.0 || (150.0 - value).abs() > 0.5, "{value}");
+            if value == 150.0 && animated.is_animating() {
+                snapped_frames += 1;
+            }
+        }
+        assert!(snapped_frames > 0);
+    }
+
+    /// Scrubbing should move transitions and leave springs untouched.
+    #[test]
+    fn set_progress_only_scrubs_transitions() {
+        let mut transition = Animated::transition(0.0_f32, Easing::LINEAR).to(100.0);
+        transition.set_progress(0.4);
+        assert_eq!(transition.progress(), Some(0.4));
+        assert_eq!(*transition.value(), 40.0);
+
+        let mut spring = Animated::spring(0.0_f32, Motion::SMOOTH).to(100.0);
+        spring.set_progress(0.4);
+        assert_eq!(spring.progress(), None);
+        assert_eq!(*spring.value(), 0.0);
+    }
+
+    /// Ticks the `animated` value at 60Hz starting from `start` for the given `duration`,
+    /// returning the last instant.
+    fn run(animated: &mut Animated<f32>, start: Instant, duration: Duration) -> Instant {
+        let mut now = start;
+        while now - start < duration {
+            now += Duration::from_micros(16_667);
+            animated.tick(now);
+        }
+        now
+    }
+
+    /// Settled clones shouldn't carry over an in-flight animation.
+    #[test]
+    fn settled_clone_is_at_rest() {
+        let mut animated = Animated::spring(0.0_f32, Motion::SMOOTH).to(100.0);
+        run(&mut animated, Instant::now(), DEFAULT_DURATION / 4);
+        assert!(animated.is_animating());
+
+        let snapshot = animated.settled_clone();
+        assert!(!snapshot.is_animating());
+        assert_eq!(*snapshot.value(), 100.0);
+        assert!(animated.is_animating());
+    }
+
+    /// Restoring a snapshot should animate back to it smoothly, without a large first step.
+    #[test]
+    fn restore_animates_back_to_snapshot() {
+        for mode in [Mode::from(Motion::SMOOTH), Mode::from(Easing::EASE_IN_OUT)] {
+            let mut animated = Animated::new(0.0_f32, mode);
+            let snapshot = animated.settled_clone();
+
+            // Make further changes and let them settle.
+            animated.set_target(100.0);
+            let start = Instant::now();
+            animated.resume_at(start);
+            run(&mut animated, start, DEFAULT_DURATION * 4);
+            assert_eq!(*animated.value(), 100.0);
+
+            // Restore the snapshot after the app has been idle for a long time.
+            animated.restore_from(&snapshot);
+            assert_eq!(animated.target(), &0.0);
+            assert_eq!(*animated.value(), 100.0);
+
+            let later = start + Duration::from_secs(600);
+            animated.resume_at(later);
+            let now = run(&mut animated, later, Duration::from_millis(20));
+            let value = *animated.value();
+            assert!(value < 100.0 && value > 80.0, "{value}");
+
+            run(&mut animated, now, DEFAULT_DURATION * 4);
+            assert_eq!(*animated.value(), 0.0);
+        }
+    }
+
+    /// Restoring should continue from the live value, even if it's still animating.
+    #[test]
+    fn restore_interrupts_live_animation() {
+        let mut animated = Animated::spring(0.0_f32, Motion::SMOOTH);
+        let snapshot = animated.settled_clone();
+
+        animated.set_target(100.0);
+        let start = Instant::now();
+        animated.resume_at(start);
+        let now = run(&mut animated, start, DEFAULT_DURATION / 4);
+        let value = *animated.value();
+
+        animated.restore_from(&snapshot);
+        assert_eq!(*animated.value(), value);
+        assert!(animated.is_animating());
+
+        run(&mut animated, now, DEFAULT_DURATION * 4);
+        assert_eq!(*animated.value(), 0.0);
+    }
+
+    /// Clones ticked at different instants should still be equal if they look the same.
+    #[test]
+    fn equality_ignores_timing() {
+        let start = Instant::now();
+        let mut a = Animated::transition(0.0_f32, Easing::LINEAR).to(100.0);
+        a.resume_at(start);
+        let mut b = a.clone();
+        b.resume_at(start + Duration::from_secs(1));
+        assert_eq!(a, b);
+
+        // Settled at the same value after being ticked at different times.
+        a.tick(start + DEFAULT_DURATION);
+        b.tick(start + Duration::from_secs(1) + DEFAULT_DURATION * 2);
+        assert_eq!(a, b);
+
+        let mut spring = Animated::spring(0.0_f32, Motion::SMOOTH).to(10.0);
+        let other = spring.clone();
+        spring.settle();
+        spring.tick(start + Duration::from_secs(5));
+        assert_eq!(spring, other.settled_clone());
+    }
+
+    /// The value, target, and configuration all affect equality.
+    #[test]
+    fn equality_compares_visible_state() {
+        let animated = Animated::transition(0.0_f32, Easing::LINEAR);
+        assert_ne!(animated, animated.clone().to(10.0));
+        assert_ne!(animated, Animated::transition(5.0, Easing::LINEAR));
+        assert_ne!(animated, Animated::transition(0.0, Easing::EASE));
+        assert_ne!(animated, Animated::spring(0.0, Motion::SMOOTH));
+        assert_ne!(animated, animated.clone().with_deadband(1.0));
+    }
+
+    /// Velocities only apply to springs, and are ignored by transitions.
+    #[test]
+    fn set_velocity_by_mode() {
+        let mut spring = Animated::spring(0.0_f32, Motion::SMOOTH);
+        spring.set_velocity(vec![10.0]);
+        assert_eq!(spring.velocity(), Some([10.0].as_slice()));
+        assert!(spring.is_animating());
+
+        let mut transition = Animated::transition(0.0_f32, Easing::LINEAR);
+        transition.set_velocity(vec![10.0]);
+        assert_eq!(transition.velocity(), None);
+        assert!(!transition.is_animating());
+    }
+
+    /// Playing a sequence should move through its steps, and switching modes should head
+    /// straight for its final target.
+    #[test]
+    fn play_sequence() {
+        let mut animated = Animated::transition(0.0_f32, Easing::LINEAR);
+        animated.play([
+            Step::new(
+                10.0,
+                Easing::LINEAR.with_duration(Duration::from_millis(200)),
+            ),
+            Step::new(
+                30.0,
+                Easing::LINEAR.with_duration(Duration::from_millis(200)),
+            ),
+        ]);
+        assert_eq!(animated.target(), &30.0);
+        assert_eq!(animated.duration(), Duration::from_millis(400));
+        assert_eq!(animated.velocity(), None);
+
+        let start = Instant::now();
+        animated.resume_at(start);
+        let now = run(&mut animated, start, Duration::from_millis(250));
+        assert!(*animated.value() > 10.0 && *animated.value() < 30.0);
+        assert!(animated.is_animating());
+
+        animated.apply(Mode::Spring(Motion::SMOOTH));
+        assert_eq!(animated.target(), &30.0);
+        run(&mut animated, now, Duration::from_secs(2));
+        assert_eq!(*animated.value(), 30.0);
+    }
+
+    /// A custom animator that moves a fixed step towards its target every tick.
+    #[derive(Debug, Clone)]
+    struct Stepper {
+        value: f32,
+        target: f32,
+    }
+
+    impl Animator<f32> for Stepper {
+        fn tick(&mut self, _now: Instant) {
+            let step = (self.target - self.value).clamp(-10.0, 10.0);
+            self.value += step;
+        }
+
+        fn value(&self) -> &f32 {
+            &self.value
+        }
+
+        fn target(&self) -> &f32 {
+            &self.target
+        }
+
+        fn set_target(&mut self, target: f32) {
+            self.target = target;
+        }
+
+        fn is_animating(&self) -> bool {
+            self.value != self.target
+        }
+
+        fn settle(&mut self) {
+            self.value = self.target;
+        }
+    }
+
+    /// Custom animators should drive the value, and switching to a mode should continue from
+    /// where they left off.
+    #[test]
+    fn custom_animator() {
+        let stepper = Stepper {
+            value: 0.0,
+            target: 0.0,
+        };
+        let mut animated = Animated::custom(Box::new(stepper)).to(25.0);
+        assert!(animated.is_animating());
+        assert_eq!(animated.velocity(), None);
+
+        let now = Instant::now();
+        animated.tick(now);
+        animated.tick(now);
+        assert_eq!(*animated.value(), 20.0);
+
+        let clone = animated.clone();
+        assert_eq!(clone, animated);
+        animated.tick(now);
+        assert_eq!(*animated.value(), 25.0);
+        assert!(!animated.is_animating());
+        assert_eq!(*clone.value(), 20.0);
+
+        animated.set_target(100.0);
+        animated.apply(Mode::Transition(Easing::LINEAR));
+        assert_eq!(*animated.value(), 25.0);
+        assert_eq!(animated.target(), &100.0);
+        assert!(animated.progress().is_some());
+    }
+
+    /// Flinging should decay towards a projected target, and switching to a spring should keep
+    /// the momentum of the fling.
+    #[test]
+    fn fling_into_spring() {
+        let mut animated = Animated::spring(0.0_f32, Motion::SMOOTH);
+        animated.fling(vec![1000.0]);
+        assert_eq!(animated.velocity(), Some([1000.0].as_slice()));
+        let projected = *animated.target();
+
+        let start = Instant::now();
+        animated.resume_at(start);
+        let now = run(&mut animated, start, Duration::from_millis(200));
+        let velocity = animated.velocity().unwrap()[0];
+        assert!(velocity > 0.0 && velocity < 1000.0);
+        assert_eq!(*animated.target(), projected);
+
+        // Snap to the nearest point without losing the momentum of the fling.
+        animated.apply(Mode::Spring(Motion::SMOOTH));
+        animated.set_target(500.0);
+        assert_eq!(animated.velocity(), Some([velocity].as_slice()));
+        run(&mut animated, now, Duration::from_secs(3));
+        assert_eq!(*animated.value(), 500.0);
+    }
+
+    /// Releasing a drag should hand the velocity of the interactive spring to the next motion
+    /// without a visible hitch in how far the value moves each frame.
+    #[test]
+    fn drag_release_keeps_momentum() {
+        let frame = Duration::from_micros(16_667);
+        let mut animated = Animated::spring(0.0_f32, Motion::INTERACTIVE);
+        let mut now = Instant::now();
+        animated.resume_at(now);
+
+        // Drag the pointer at a steady 600 units per second.
+        let mut pointer = 0.0;
+        let mut last_step = 0.0;
+        for _ in 0..30 {
+            pointer += 10.0;
+            animated.set_target(pointer);
+            now += frame;
+            let before = *animated.value();
+            animated.tick(now);
+            last_step = *animated.value() - before;
+        }
+        let velocity = animated.velocity().unwrap()[0];
+        assert!(velocity > 500.0, "{velocity}");
+
+        // Release the pointer and snap to a nearby point with a softer spring.
+        animated.set_mode(Motion::BOUNCY);
+        animated.set_target(pointer + 50.0);
+        assert_eq!(animated.velocity(), Some([velocity].as_slice()));
+        let before = *animated.value();
+        animated.tick(now + frame);
+        let step = *animated.value() - before;
+        assert!(
+            (step - last_step).abs() < last_step * 0.2,
+            "{step} vs {last_step}"
+        );
+    }
+
+    /// Switching an interrupted transition to a spring should keep its speed.
+    #[test]
+    fn transition_to_spring_keeps_velocity() {
+        let mut animated = Animated::transition(0.0_f32, Easing::LINEAR).to(100.0);
+        let start = Instant::now();
+        animated.resume_at(start);
+        animated.tick(start + DEFAULT_DURATION / 2);
+
+        animated.set_mode(Motion::SMOOTH);
+        let velocity = animated.velocity().unwrap()[0];
+        assert!((velocity - 100.0 / DEFAULT_DURATION.as_secs_f32()).abs() < 1e-3);
+    }
+
+    /// Asserts that two values are within a small tolerance of each other.
+    fn assert_near(value: f32, expected: f32) {
+        assert!((value - expected).abs() < 1e-4, "{value} != {expected}");
+    }
+
+    /// Scheduled targets should start exactly at their instants, regardless of when the ticks
+    /// that activate them land, and last their full duration.
+    #[test]
+    fn scheduled_targets_activate_on_time() {
+        let ms = Duration::from_millis;
+        let easing = Easing::LINEAR.with_duration(ms(120));
+        let origin = Instant::now();
+        let mut animated = Animated::transition(0.0_f32, easing);
+        animated.schedule_target(1.0, origin + ms(100));
+        animated.schedule_target(2.0, origin + ms(300));
+        assert!(animated.is_animating());
+
+        animated.tick(origin + ms(99));
+        assert_eq!(animated.target(), &0.0);
+        assert_eq!(*animated.value(), 0.0);
+
+        // Activated late, but still timed from the scheduled instant.
+        animated.tick(origin + ms(160));
+        assert_eq!(animated.target(), &1.0);
+        assert_near(*animated.value(), 0.5);
+        assert_eq!(animated.scheduled().len(), 1);
+
+        animated.tick(origin + ms(219));
+        assert_near(*animated.value(), 119.0 / 120.0);
+        animated.tick(origin + ms(221));
+        assert_eq!(*animated.value(), 1.0);
+
+        // The next change is still scheduled, so the animation keeps getting ticked.
+        assert!(animated.is_animating());
+        animated.tick(origin + ms(400));
+        assert_eq!(animated.target(), &2.0);
+        assert_near(*animated.value(), 1.0 + 100.0 / 120.0);
+
+        animated.tick(origin + ms(421));
+        assert_eq!(*animated.value(), 2.0);
+        assert!(!animated.is_animating());
+    }
+
+    /// A single tick should activate every change that's due in order, finishing the earlier
+    /// ones before starting the later ones.
+    #[test]
+    fn scheduled_targets_activate_in_order() {
+        let ms = Duration::from_millis;
+        let origin = Instant::now();
+        let mut animated = Animated::transition(0.0_f32, Easing::LINEAR.with_duration(ms(120)));
+        animated.schedule_target(2.0, origin + ms(400));
+        animated.schedule_target(1.0, origin + ms(100));
+
+        animated.tick(origin + ms(460));
+        assert_eq!(animated.target(), &2.0);
+        assert_near(*animated.value(), 1.5);
+        assert!(animated.scheduled().is_empty());
+    }
+
+    /// Scheduling the same instant twice should replace the earlier target, the schedule should
+    /// stay bounded, and setting a target immediately should cancel the schedule.
+    #[test]
+    fn schedule_replacement_and_cancellation() {
+        let origin = Instant::now();
+        let mut animated = Animated::transition(0.0_f32, Easing::LINEAR);
+        animated.schedule_target(1.0, origin + Duration::from_secs(1));
+        animated.schedule_target(3.0, origin + Duration::from_secs(1));
+        assert_eq!(
+            animated.scheduled(),
+            &[(origin + Duration::from_secs(1), 3.0)]
+        );
+
+        for beat in (0..MAX_SCHEDULED as u64 + 8).rev() {
+            animated.schedule_target(beat as f32, origin + Duration::from_millis(beat * 10));
+        }
+        assert_eq!(animated.scheduled().len(), MAX_SCHEDULED);
+        assert_eq!(animated.scheduled()[0].0, origin);
+        assert!(animated
+            .scheduled()
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0));
+
+        animated.set_target(5.0);
+        assert!(animated.scheduled().is_empty());
+        assert_eq!(animated.target(), &5.0);
+
+        animated.schedule_target(6.0, origin + Duration::from_secs(1));
+        animated.update(Event::SettleAt(7.0));
+        assert!(animated.scheduled().is_empty());
+        assert!(!animated.is_animating());
+    }
+
+    /// A chained target should start once the first animation settles, running both stages.
+    #[test]
+    fn chain_runs_both_stages() {
+        let mut animated = Animated::transition(0.0_f32, Easing::LINEAR).to(1.0);
+        animated.then(0.0);
+
+        let now = run(
+            &mut animated,
+            Instant::now(),
+            DEFAULT_DURATION + Duration::from_millis(50),
+        );
+        assert_eq!(animated.target(), &0.0);
+        assert!(animated.is_animating());
+        assert!(*animated.value() > 0.5);
+
+        run(&mut animated, now, DEFAULT_DURATION * 2);
+        assert!(!animated.is_animating());
+        assert_eq!(*animated.value(), 0.0);
+    }
+
+    /// A hold should keep the settled value until it elapses, while still counting as animating.
+    #[test]
+    fn chain_holds_before_next_stage() {
+        let hold = Duration::from_millis(400);
+        let mut animated = Animated::transition(0.0_f32, Easing::LINEAR).to(1.0);
+        animated.then_after(0.0, hold);
+
+        let now = run(&mut animated, Instant::now(), DEFAULT_DURATION + hold / 2);
+        assert!(animated.is_animating());
+        assert_eq!(animated.target(), &1.0);
+        assert_eq!(*animated.value(), 1.0);
+
+        run(&mut animated, now, hold + DEFAULT_DURATION * 2);
+        assert!(!animated.is_animating());
+        assert_eq!(*animated.value(), 0.0);
+    }
+
+    /// Setting a target in the middle of a chain should cancel the rest of it.
+    #[test]
+    fn set_target_interrupts_chain() {
+        let mut animated = Animated::spring(0.0_f32, Motion::SMOOTH).to(1.0);
+        animated.then(0.0);
+        animated.then_after(2.0, Duration::from_millis(100));
+
+        let now = run(&mut animated, Instant::now(), DEFAULT_DURATION / 4);
+        animated.set_target(5.0);
+        run(&mut animated, now, DEFAULT_DURATION * 4);
+        assert!(!animated.is_animating());
+        assert_eq!(animated.target(), &5.0);
+        assert_eq!(*animated.value(), 5.0);
+    }
+
+    /// Pausing mid-spring should freeze the value through any number of ticks, and resuming
+    /// should continue from there and complete without jumping ahead by the paused time.
+    #[test]
+    fn pause_and_resume_spring() {
+        let start = Instant::now();
+        let mut animated = Animated::new_at(0.0_f32, Motion::SMOOTH, start);
+        animated.schedule_target(100.0, start);
+        let now = run(&mut animated, start, DEFAULT_DURATION / 4);
+        let frozen = *animated.value();
+        assert!(frozen > 0.0 && frozen < 100.0);
+
+        animated.update(Event::Pause);
+        let now = run(&mut animated, now, Duration::from_secs(2));
+        assert!(animated.is_paused());
+        assert!(animated.is_animating());
+        assert!(!animated.needs_tick());
+        assert_eq!(*animated.value(), frozen);
+
+        // Resuming long after the last tick shouldn't count the gap either.
+        animated.update(Event::Resume);
+        assert!(animated.needs_tick());
+        let now = now + Duration::from_secs(10);
+        animated.tick(now);
+        assert_eq!(*animated.value(), frozen);
+        animated.tick(now + Duration::from_micros(16_667));
+        assert!(*animated.value() > frozen && *animated.value() < 100.0);
+
+        run(&mut animated, now, DEFAULT_DURATION * 4);
+        assert!(!animated.is_animating());
+        assert_eq!(*animated.value(), 100.0);
+    }
+
+    /// Pausing should also apply to the animation that replaces the current one.
+    #[test]
+    fn pause_survives_mode_changes() {
+        let mut animated = Animated::spring(0.0_f32, Motion::SMOOTH).to(100.0);
+        animated.pause();
+        animated.apply(Easing::LINEAR.into());
+        run(&mut animated, Instant::now(), DEFAULT_DURATION);
+        assert_eq!(*animated.value(), 0.0);
+        assert!(animated.is_paused());
+    }
+
+    /// Counts the 50Hz ticks a transition with the given time scale takes to complete.
+    fn ticks_to_complete(scale: f32) -> u32 {
+        let start = Instant::now();
+        let mut animated = Animated::new_at(0.0_f32, Easing::LINEAR, start).with_time_scale(scale);
+        animated.schedule_target(1.0, start);
+        let mut ticks = 0;
+        while animated.is_animating() {
+            ticks += 1;
+            animated.tick(start + Duration::from_millis(20) * ticks);
+        }
+        ticks
+    }
+
+    /// Halving the time scale should take about twice as many ticks to complete a transition.
+    #[test]
+    fn time_scale_slows_transitions() {
+        // Scaled frames are converted through `f32` seconds, so allow a single extra tick.
+        let normal = ticks_to_complete(1.0);
+        assert!((normal * 2..=normal * 2 + 1).contains(&ticks_to_complete(0.5)));
+        assert!((normal / 2..=normal / 2 + 1).contains(&ticks_to_complete(2.0)));
+    }
+
+    /// A time scale of zero should freeze the value without settling it.
+    #[test]
+    fn zero_time_scale_freezes() {
+        let start = Instant::now();
+        let mut animated = Animated::new_at(0.0_f32, Motion::SMOOTH, start).with_time_scale(0.0);
+        animated.schedule_target(1.0, start);
+        run(&mut animated, start, Duration::from_secs(2));
+        assert_eq!(*animated.value(), 0.0);
+        assert!(animated.is_animating());
+    }
+}
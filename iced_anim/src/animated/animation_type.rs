@@ -1,4 +1,5 @@
-use crate::{Animate, Spring, Transition};
+use super::Animator;
+use crate::{Animate, Decay, Sequence, Spring, Transition};
 
 /// The type of animation that will be used to animate a value.
 #[derive(Debug, Clone, PartialEq)]
@@ -12,6 +13,15 @@ pub enum AnimationType<T> {
     /// This is primarily a set of easing functions that can be used to animate
     /// between two values and is good for predictable animations.
     Transition(Transition<T>),
+    /// An animation that uses a [`Sequence`] to animate through several targets in order,
+    /// each with its own easing and an optional hold. This is good for choreographed
+    /// animations like pulses and attention grabbers.
+    Sequence(Sequence<T>),
+    /// An animation that uses a [`Decay`] to keep moving a value with a decaying velocity until
+    /// it comes to rest. This is good for flinging scrollable content.
+    Decay(Decay<T>),
+    /// A third-party animation that implements [`Animator`], e.g. a decaying fling for scrolling.
+    Custom(Box<dyn Animator<T>>),
 }
 
 impl<T> From<Spring<T>> for AnimationType<T>
@@ -31,3 +41,30 @@ where
         AnimationType::Transition(value)
     }
 }
+
+impl<T> From<Sequence<T>> for AnimationType<T>
+where
+    T: Animate,
+{
+    fn from(value: Sequence<T>) -> Self {
+        AnimationType::Sequence(value)
+    }
+}
+
+impl<T> From<Decay<T>> for AnimationType<T>
+where
+    T: Animate,
+{
+    fn from(value: Decay<T>) -> Self {
+        AnimationType::Decay(value)
+    }
+}
+
+impl<T> From<Box<dyn Animator<T>>> for AnimationType<T>
+where
+    T: Animate,
+{
+    fn from(value: Box<dyn Animator<T>>) -> Self {
+        AnimationType::Custom(value)
+    }
+}
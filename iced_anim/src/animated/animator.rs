@@ -0,0 +1,263 @@
+use std::{fmt::Debug, time::Instant};
+
+use crate::{Animate, Decay, Sequence, Spring, Transition};
+
+/// A type of animation that can drive an [`Animated`](crate::Animated) value.
+///
+/// [`Spring`], [`Transition`], [`Sequence`], and [`Decay`] all implement this, but you can also
+/// implement it for your own animation types, like a decaying fling, and use them with the
+/// [`Animation`](crate::Animation) widget and [`AnimatedState`](crate::AnimatedState) through
+/// [`Animated::custom`](crate::Animated::custom).
+///
+/// Custom animators are cloned along with the [`Animated`](crate::Animated) value that owns
+/// them, so they only need to implement [`Clone`] and [`Debug`] on top of this trait.
+///
+/// ```rust
+/// # use std::time::Instant;
+/// # use iced_anim::{Animated, Animator};
+/// /// Moves halfway to the target every tick.
+/// #[derive(Debug, Clone)]
+/// struct Halve {
+///     value: f32,
+///     target: f32,
+/// }
+///
+/// impl Animator<f32> for Halve {
+///     fn tick(&mut self, _now: Instant) {
+///         self.value += (self.target - self.value) / 2.0;
+///     }
+///     fn value(&self) -> &f32 {
+///         &self.value
+///     }
+///     fn target(&self) -> &f32 {
+///         &self.target
+///     }
+///     fn set_target(&mut self, target: f32) {
+///         self.target = target;
+///     }
+///     fn is_animating(&self) -> bool {
+///         (self.target - self.value).abs() > 0.01
+///     }
+///     fn settle(&mut self) {
+///         self.value = self.target;
+///     }
+/// }
+///
+/// let mut size = Animated::custom(Box::new(Halve { value: 0.0, target: 0.0 })).to(100.0);
+/// size.tick(Instant::now());
+/// assert_eq!(size.value(), &50.0);
+/// ```
+pub trait Animator<T>: Debug + CloneAnimator<T> {
+    /// Updates the animation's current value based on the elapsed time since the last update.
+    fn tick(&mut self, now: Instant);
+
+    /// Returns a reference to the current value of the animation.
+    fn value(&self) -> &T;
+
+    /// Returns a reference to the value that the animation is moving towards.
+    fn target(&self) -> &T;
+
+    /// Sets the value that the animation is moving towards.
+    fn set_target(&mut self, target: T);
+
+    /// Whether the animation is still moving towards its target.
+    fn is_animating(&self) -> bool;
+
+    /// Causes the animation to settle immediately at its target.
+    fn settle(&mut self);
+
+    /// Causes the animation to settle immediately at the given `target`.
+    fn settle_at(&mut self, target: T) {
+        self.set_target(target);
+        self.settle();
+    }
+
+    /// Resumes the animation at `now` without advancing it by the time since its last update.
+    ///
+    /// Animations that measure the time between ticks should override this, since they would
+    /// otherwise jump forward after not being ticked for a while.
+    fn resume_at(&mut self, now: Instant) {
+        let _ = now;
+    }
+}
+
+/// Clones boxed [`Animator`]s. This is implemented for every [`Animator`] that implements
+/// [`Clone`].
+pub trait CloneAnimator<T> {
+    /// Clones this animator into a new box.
+    fn clone_animator(&self) -> Box<dyn Animator<T>>;
+}
+
+impl<T, A> CloneAnimator<T> for A
+where
+    A: Animator<T> + Clone + 'static,
+{
+    fn clone_animator(&self) -> Box<dyn Animator<T>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<T> Clone for Box<dyn Animator<T>> {
+    fn clone(&self) -> Self {
+        self.clone_animator()
+    }
+}
+
+impl<T> PartialEq for dyn Animator<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value() == other.value()
+            && self.target() == other.target()
+            && self.is_animating() == other.is_animating()
+    }
+}
+
+impl<T> Animator<T> for Spring<T>
+where
+    T: Animate + Debug + 'static,
+{
+    fn tick(&mut self, now: Instant) {
+        Spring::tick(self, now);
+    }
+
+    fn value(&self) -> &T {
+        Spring::value(self)
+    }
+
+    fn target(&self) -> &T {
+        Spring::target(self)
+    }
+
+    fn set_target(&mut self, target: T) {
+        Spring::set_target(self, target);
+    }
+
+    fn is_animating(&self) -> bool {
+        self.has_energy()
+    }
+
+    fn settle(&mut self) {
+        Spring::settle(self);
+    }
+
+    fn settle_at(&mut self, target: T) {
+        Spring::settle_at(self, target);
+    }
+
+    fn resume_at(&mut self, now: Instant) {
+        Spring::resume_at(self, now);
+    }
+}
+
+impl<T> Animator<T> for Transition<T>
+where
+    T: Animate + Debug + 'static,
+{
+    fn tick(&mut self, now: Instant) {
+        Transition::tick(self, now);
+    }
+
+    fn value(&self) -> &T {
+        Transition::value(self)
+    }
+
+    fn target(&self) -> &T {
+        Transition::target(self)
+    }
+
+    fn set_target(&mut self, target: T) {
+        Transition::set_target(self, target);
+    }
+
+    fn is_animating(&self) -> bool {
+        Transition::is_animating(self)
+    }
+
+    fn settle(&mut self) {
+        Transition::settle(self);
+    }
+
+    fn settle_at(&mut self, target: T) {
+        Transition::settle_at(self, target);
+    }
+
+    fn resume_at(&mut self, now: Instant) {
+        Transition::resume_at(self, now);
+    }
+}
+
+impl<T> Animator<T> for Sequence<T>
+where
+    T: Animate + Debug + 'static,
+{
+    fn tick(&mut self, now: Instant) {
+        Sequence::tick(self, now);
+    }
+
+    fn value(&self) -> &T {
+        Sequence::value(self)
+    }
+
+    fn target(&self) -> &T {
+        Sequence::target(self)
+    }
+
+    fn set_target(&mut self, target: T) {
+        Sequence::set_target(self, target);
+    }
+
+    fn is_animating(&self) -> bool {
+        Sequence::is_animating(self)
+    }
+
+    fn settle(&mut self) {
+        Sequence::settle(self);
+    }
+
+    fn settle_at(&mut self, target: T) {
+        Sequence::settle_at(self, target);
+    }
+
+    fn resume_at(&mut self, now: Instant) {
+        Sequence::resume_at(self, now);
+    }
+}
+
+impl<T> Animator<T> for Decay<T>
+where
+    T: Animate + Debug + 'static,
+{
+    fn tick(&mut self, now: Instant) {
+        Decay::tick(self, now);
+    }
+
+    fn value(&self) -> &T {
+        Decay::value(self)
+    }
+
+    fn target(&self) -> &T {
+        self.projected_target()
+    }
+
+    fn set_target(&mut self, target: T) {
+        Decay::set_target(self, target);
+    }
+
+    fn is_animating(&self) -> bool {
+        Decay::is_animating(self)
+    }
+
+    fn settle(&mut self) {
+        Decay::settle(self);
+    }
+
+    fn settle_at(&mut self, target: T) {
+        Decay::settle_at(self, target);
+    }
+
+    fn resume_at(&mut self, now: Instant) {
+        Decay::resume_at(self, now);
+    }
+}
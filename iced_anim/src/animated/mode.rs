@@ -3,6 +3,7 @@ use crate::{spring::Motion, transition::Easing};
 /// The different animation modes that can be used to animate a value.
 /// Different modes have different configuration options.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Mode {
     Spring(Motion),
     Transition(Easing),
@@ -49,4 +50,17 @@ mod tests {
         let mode: Mode = easing.into();
         assert_eq!(mode, Mode::Transition(easing));
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let modes = [
+            Mode::Spring(Motion::SNAPPY),
+            Mode::Transition(Easing::EASE_IN_OUT),
+        ];
+        for mode in modes {
+            let json = serde_json::to_string(&mode).unwrap();
+            assert_eq!(serde_json::from_str::<Mode>(&json).unwrap(), mode);
+        }
+    }
 }
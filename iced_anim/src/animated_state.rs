@@ -61,9 +61,12 @@
 //!    }
 //!    ```
 //! 4. Update [`iced::advanced::Widget::state`] to get the initial status, then pass that status
-//!    and animation mode into [`AnimatedState::new`] to create the animated state.
+//!    and animation mode into [`AnimatedState::new`] to create the animated state. If the widget
+//!    already knows its initial value, e.g. because it doesn't depend on the theme, use
+//!    [`AnimatedState::with_value`] instead so the value exists before the first draw.
 //! 5. Update [`iced::advanced::Widget::diff`] to call call [`AnimatedState::diff`] if the mode
-//!    has changed externally.
+//!    has changed externally. Always call it, since it's also how the animated state notices that
+//!    the theme may have changed.
 //!    ```ignore
 //!    fn diff(&self, tree: &mut Tree) {
 //!        // Diff the animated state with a potentially new animation mode.
@@ -78,37 +81,36 @@
 //!    and pass in a callback to generate the style based on the theme and status, and it'll return
 //!    a reference to the latest animated style. The inner animated style will be updated if the
 //!    closure produces a style different from the current target.
-//! 7. Update [`iced::advanced::Widget::on_event`] to call [`AnimatedState::needs_redraw`] to
-//!    determine if the widget needs to redraw. If a redraw is needed, then use the shell to
-//!    request a redraw on the next frame. This may vary based on how your widget, but it will
-//!    generally look like this:
+//! 7. Update [`iced::advanced::Widget::on_event`] to call [`AnimatedState::handle_event`] with
+//!    every event and the widget's current status. It requests a redraw whenever the status
+//!    changes or the value is still animating, and ticks the value on
+//!    [`iced::window::Event::RedrawRequested`] events:
 //!    ```ignore
-//!    # use iced::window;
-//!    // Redraw anytime the status changes and would trigger a style change.
 //!    let state = tree.state.downcast_mut::<State>();
 //!    let status = self.get_status(&state, cursor, layout);
-//!    let needs_redraw = state.animated_state.needs_redraw(status);
-//!
-//!    if needs_redraw {
-//!        shell.request_redraw(window::RedrawRequest::NextFrame);
-//!    }
+//!    state.animated_state.handle_event(&event, status, shell);
 //!    ```
-//! 8. Finally, ensure your widget handles [`iced::window::Event::RedrawRequested`] events by
-//!    calling [`AnimatedState::tick`] to update the animated value with the current time. This
-//!    is how the animated state can update the value over time.
+//!    Widgets that need more control can do the same thing by hand: call
+//!    [`AnimatedState::needs_redraw`] and request a redraw on the next frame if it returns
+//!    `true`, then call [`AnimatedState::tick`] for `RedrawRequested` events.
 use std::{
     cell::{Ref, RefCell},
     time::Instant,
 };
 
-use crate::{animated::Mode, Animate, Animated};
+use iced::{advanced::Shell, window};
+
+use crate::{animated::Mode, Animate, Animated, Animator};
+
+/// Creates a custom [`Animator`] from the initial value of an [`AnimatedState`].
+type MakeAnimator<Value> = fn(Value) -> Box<dyn Animator<Value>>;
 
 /// Helps manage animating values for widgets.
 ///
 /// This maintains the current animated value for a widget, which depends on the `status`.
 /// - `Status`: The status of the widget which affects some `Value`, e.g. `button::Status`.
 /// - `Value`: The value that will be animated, e.g. `button::Style`.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct AnimatedState<Status, Value> {
     /// The current status of the widget, which may affect the styling.
     status: Status,
@@ -119,6 +121,23 @@ pub struct AnimatedState<Status, Value> {
     animated_value: RefCell<Option<Animated<Value>>>,
     /// The animation mode to use for the animated value.
     mode: Mode,
+    /// Creates a custom animator for the animated value from its initial value, if any.
+    animator: Option<MakeAnimator<Value>>,
+    /// Whether the widget was rebuilt since the last event, which may have changed values that
+    /// are only known while drawing, like the theme.
+    is_stale: bool,
+}
+
+impl<Status, Value> PartialEq for AnimatedState<Status, Value>
+where
+    Status: PartialEq,
+    Value: Animate,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.status == other.status
+            && self.animated_value == other.animated_value
+            && self.mode == other.mode
+    }
 }
 
 impl<Status, Value> AnimatedState<Status, Value>
@@ -132,6 +151,37 @@ where
             status,
             animated_value: RefCell::new(None),
             mode: mode.into(),
+            animator: None,
+            is_stale: false,
+        }
+    }
+
+    /// Creates a new [`AnimatedState`] with the given `status`, animation `mode`, and initial
+    /// `value`, for widgets that know their value before they're first drawn.
+    ///
+    /// [`AnimatedState::current_value`] still retargets the value while drawing.
+    pub fn with_value(status: Status, mode: impl Into<Mode>, value: Value) -> Self {
+        let mode = mode.into();
+        Self {
+            status,
+            animated_value: RefCell::new(Some(Animated::new(value, mode))),
+            mode,
+            animator: None,
+            is_stale: false,
+        }
+    }
+
+    /// Creates a new [`AnimatedState`] with the given `status` whose value is animated by a
+    /// custom [`Animator`], created from the initial value with the `animator` function.
+    ///
+    /// Passing a different mode to [`AnimatedState::diff`] later replaces the custom animator.
+    pub fn custom(status: Status, animator: MakeAnimator<Value>) -> Self {
+        Self {
+            status,
+            animated_value: RefCell::new(None),
+            mode: Mode::default(),
+            animator: Some(animator),
+            is_stale: false,
         }
     }
 
@@ -140,8 +190,23 @@ where
         &self.status
     }
 
+    /// Whether the animated value is still moving towards its target. This is `false` until the
+    /// value has been created.
+    pub fn is_animating(&self) -> bool {
+        self.animated_value
+            .borrow()
+            .as_ref()
+            .is_some_and(Animated::is_animating)
+    }
+
     /// Updates this animated state based on a potentially new `value` received by the widget.
+    ///
+    /// The widget is rebuilt whenever the application changes, which may also change the theme.
+    /// Since the theme is only available while drawing, the next call to
+    /// [`AnimatedState::needs_redraw`] requests one more frame so the value is retargeted in
+    /// `draw` and keeps animating, e.g. when switching from a light to a dark theme.
     pub fn diff(&mut self, mode: impl Into<Mode>) {
+        self.is_stale = true;
         let mode = mode.into();
         if self.mode != mode {
             self.mode = mode;
@@ -155,18 +220,79 @@ where
     /// Determines whether the widget needs to be redrawn based on events, updating the status and
     /// animated value as necessary. Generally called in a widget's `on_event` function.
     pub fn needs_redraw(&mut self, status: Status) -> bool {
+        let is_stale = std::mem::take(&mut self.is_stale);
         let animated_value = self.animated_value.borrow();
         if self.status != status {
             self.status = status;
             true
         } else if let Some(animated_value) = animated_value.as_ref() {
-            animated_value.is_animating()
+            // A rebuilt widget may have a new theme, which is only applied when drawing.
+            is_stale || animated_value.needs_tick()
         } else {
             // No animated value yet.
             false
         }
     }
 
+    /// Like [`AnimatedState::needs_redraw`], but animates a change to a new `status` with the mode
+    /// returned by `mode_for(from, to)`, e.g. to respond to a press faster than to a release.
+    ///
+    /// The mode is applied to the animated value before it's retargeted at the new status, and
+    /// keeps the velocity of a spring that's interrupted mid-animation.
+    pub fn needs_redraw_with(
+        &mut self,
+        status: Status,
+        mode_for: impl Fn(&Status, &Status) -> Mode,
+    ) -> bool {
+        if self.status != status {
+            if let Some(animated_value) = self.animated_value.get_mut() {
+                animated_value.apply(mode_for(&self.status, &status));
+            }
+        }
+        self.needs_redraw(status)
+    }
+
+    /// Handles an `event` received by the widget with its current `status`, requesting a redraw
+    /// if [`AnimatedState::needs_redraw`] and ticking the value on `RedrawRequested` events.
+    /// Generally called in a widget's `on_event` function.
+    pub fn handle_event<Message>(
+        &mut self,
+        event: &iced::Event,
+        status: Status,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let needs_redraw = self.needs_redraw(status);
+        self.finish_event(event, needs_redraw, shell);
+    }
+
+    /// Like [`AnimatedState::handle_event`], but animates a change to a new `status` with the
+    /// mode returned by `mode_for(from, to)`. See [`AnimatedState::needs_redraw_with`].
+    pub fn handle_event_with<Message>(
+        &mut self,
+        event: &iced::Event,
+        status: Status,
+        mode_for: impl Fn(&Status, &Status) -> Mode,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let needs_redraw = self.needs_redraw_with(status, mode_for);
+        self.finish_event(event, needs_redraw, shell);
+    }
+
+    /// Requests a redraw if needed and ticks the value on `RedrawRequested` events.
+    fn finish_event<Message>(
+        &mut self,
+        event: &iced::Event,
+        needs_redraw: bool,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if needs_redraw {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+        if let iced::Event::Window(window::Event::RedrawRequested(now)) = event {
+            self.tick(*now);
+        }
+    }
+
     /// Update the animated value with the current time.
     /// Call this for `RedrawRequested` events.
     pub fn tick(&mut self, now: Instant) {
@@ -176,6 +302,15 @@ where
         }
     }
 
+    /// Resumes the animated value at `now` without advancing it by the time since it was last
+    /// ticked. Call this when a widget's animations were paused and are starting again.
+    pub fn resume_at(&mut self, now: Instant) {
+        let mut animated_value = self.animated_value.borrow_mut();
+        if let Some(animated_value) = animated_value.as_mut() {
+            animated_value.resume_at(now);
+        }
+    }
+
     /// Causes the animation to immediately jump to the target value.
     pub fn settle(&mut self) {
         let mut animated_value = self.animated_value.borrow_mut();
@@ -209,7 +344,10 @@ where
                 }
             } else {
                 // Create a new animated style if one doesn't exist.
-                let animated_value = Animated::new(new_value.clone(), self.mode);
+                let animated_value = match self.animator {
+                    Some(animator) => Animated::custom(animator(new_value.clone())),
+                    None => Animated::new(new_value.clone(), self.mode),
+                };
                 animated_value_ref.replace(animated_value);
             }
         }
@@ -222,3 +360,129 @@ where
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transition::Easing;
+    use std::time::Duration;
+
+    /// A theme change rebuilds the widget without changing its status, so the widget should
+    /// request another frame to retarget the value while drawing.
+    #[test]
+    fn theme_change_requests_redraw() {
+        let mut state = AnimatedState::new((), Easing::LINEAR);
+        assert_eq!(*state.current_value(|_| 0.0), 0.0);
+        assert!(!state.needs_redraw(()));
+
+        // Rebuilding the widget requests one more frame to pick up the new theme.
+        state.diff(Easing::LINEAR);
+        assert!(state.needs_redraw(()));
+        assert_eq!(*state.current_value(|_| 1.0), 0.0);
+
+        // The retargeted value keeps animating on the following frames.
+        assert!(state.needs_redraw(()));
+        state.tick(Instant::now() + Easing::LINEAR.duration + Duration::from_millis(1));
+        assert_eq!(*state.current_value(|_| 1.0), 1.0);
+        assert!(!state.needs_redraw(()));
+    }
+
+    /// A paused value doesn't move, so it shouldn't request frames until it's resumed.
+    #[test]
+    fn paused_value_skips_redraws() {
+        let mut state = AnimatedState::with_value((), Easing::LINEAR, 0.0_f32);
+        let animated_value = state.animated_value.get_mut().as_mut().unwrap();
+        animated_value.set_target(1.0);
+        animated_value.pause();
+        assert!(!state.needs_redraw(()));
+
+        state.animated_value.get_mut().as_mut().unwrap().resume();
+        assert!(state.needs_redraw(()));
+    }
+
+    /// Rebuilding a widget whose value hasn't been created yet has nothing to redraw.
+    #[test]
+    fn stale_without_value() {
+        let mut state = AnimatedState::<(), f32>::new((), Easing::LINEAR);
+        state.diff(Easing::LINEAR);
+        assert!(!state.needs_redraw(()));
+    }
+
+    /// The resolved mode should be applied when the status changes in either direction.
+    #[test]
+    fn mode_for_status_change() {
+        use crate::spring::Motion;
+
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        enum Status {
+            Active,
+            Pressed,
+        }
+
+        fn mode_for(_from: &Status, to: &Status) -> Mode {
+            match to {
+                Status::Pressed => Motion::SNAPPY.into(),
+                Status::Active => Easing::EASE_OUT.into(),
+            }
+        }
+
+        let mode = |state: &AnimatedState<Status, f32>| {
+            state.animated_value.borrow().as_ref().map(Animated::mode)
+        };
+
+        let mut state = AnimatedState::new(Status::Active, Easing::LINEAR);
+        state.current_value(|_| 0.0);
+        assert_eq!(mode(&state), Some(Easing::LINEAR.into()));
+
+        // Unchanged statuses keep the current mode.
+        assert!(!state.needs_redraw_with(Status::Active, mode_for));
+        assert_eq!(mode(&state), Some(Easing::LINEAR.into()));
+
+        assert!(state.needs_redraw_with(Status::Pressed, mode_for));
+        assert_eq!(mode(&state), Some(Motion::SNAPPY.into()));
+
+        assert!(state.needs_redraw_with(Status::Active, mode_for));
+        assert_eq!(mode(&state), Some(Easing::EASE_OUT.into()));
+
+        // Diffing with the same base mode shouldn't undo the resolved mode.
+        state.diff(Easing::LINEAR);
+        assert_eq!(mode(&state), Some(Easing::EASE_OUT.into()));
+    }
+
+    /// A value given up front should be available before the first draw and animate once it's
+    /// retargeted while drawing.
+    #[test]
+    fn with_value_skips_lazy_creation() {
+        let state = AnimatedState::with_value((), Easing::LINEAR, 0.0);
+        assert!(!state.is_animating());
+        assert_eq!(*state.current_value(|_| 1.0), 0.0);
+        assert!(state.is_animating());
+    }
+
+    /// Handling events should request redraws for status changes and running animations, and
+    /// tick the value on redraws.
+    #[test]
+    fn handle_event_redraws_and_ticks() {
+        let mut messages: Vec<()> = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        let mut state = AnimatedState::with_value(false, Easing::LINEAR, 0.0);
+        let moved = iced::Event::Mouse(iced::mouse::Event::CursorLeft);
+
+        state.handle_event(&moved, false, &mut shell);
+        assert_eq!(shell.redraw_request(), None);
+
+        state.handle_event(&moved, true, &mut shell);
+        assert_eq!(
+            shell.redraw_request(),
+            Some(window::RedrawRequest::NextFrame)
+        );
+        state.current_value(|&hovered| if hovered { 1.0 } else { 0.0 });
+
+        let done = Instant::now() + Easing::LINEAR.duration + Duration::from_millis(1);
+        let redraw = iced::Event::Window(window::Event::RedrawRequested(done));
+        let mut shell = Shell::new(&mut messages);
+        state.handle_event(&redraw, true, &mut shell);
+        assert!(!state.is_animating());
+        assert_eq!(*state.current_value(|_| 1.0), 1.0);
+    }
+}
@@ -43,22 +43,55 @@
 //!     }
 //! }
 //! ```
-use std::time::Instant;
-
+//!
+//! # Animating multiple values
+//! A single `Animation` can drive up to four animated values by passing a tuple of references.
+//! It keeps publishing events while any of them is animating, and each event is for the tuple of
+//! their values, so it can be split back up with [`Event::map`]:
+//!
+//! ```rust
+//! # use iced::{widget::text, Color, Element};
+//! # use iced_anim::{Animation, Animated, Event};
+//! # struct State { size: Animated<f32>, color: Animated<Color> }
+//! # #[derive(Clone)]
+//! # enum Message { Tick(Event<(f32, Color)>) }
+//! # impl State {
+//! fn update(&mut self, message: Message) {
+//!     match message {
+//!         Message::Tick(event) => {
+//!             self.size.update(event.clone().map(|(size, _)| size));
+//!             self.color.update(event.map(|(_, color)| color));
+//!         }
+//!     }
+//! }
+//!
+//! fn view(&self) -> Element<Message> {
+//!     Animation::new(
+//!         (&self.size, &self.color),
+//!         text(self.size.value().to_string()).color(*self.color.value()),
+//!     )
+//!     .on_update(Message::Tick)
+//!     .into()
+//! }
+//! # }
+//! ```
 use iced::{
-    advanced::{widget::Tree, Widget},
+    advanced::{
+        widget::{tree, Tree},
+        Widget,
+    },
     Element,
 };
 
-use crate::{Animate, Animated, Event};
+use crate::{Animate, Animated, Event, HubHandle};
 
 /// A widget that helps you animate a value over time from your state.
 /// This is useful for animating changes to a widget's appearance or layout
 /// where you want to directly change the value stored in your state versus
 /// passively animating a value like the `AnimationBuilder`.
 pub struct Animation<'a, T: Animate, Message, Theme, Renderer> {
-    /// The animated value that will be updated over time.
-    animated_value: &'a Animated<T>,
+    /// The animated values that will be updated over time.
+    values: Box<dyn AnimatedValues<Value = T> + 'a>,
     /// The content that will respond to the animation.
     content: Element<'a, Message, Theme, Renderer>,
     /// The function that will be called when the spring needs to be updated.
@@ -66,6 +99,76 @@ pub struct Animation<'a, T: Animate, Message, Theme, Renderer> {
     /// Whether animations are disabled, in which case the value will be updated
     /// immediately without animating. Useful for reduced motion preferences.
     is_disabled: bool,
+    /// Whether the layout will be affected by the animated values.
+    animates_layout: bool,
+    /// The hub handle that batches ticks for this value instead of publishing them directly.
+    hub: Option<&'a HubHandle>,
+    /// Builds the message published once the animated value settles.
+    on_settle: Option<Box<dyn Fn() -> Message + 'a>>,
+}
+
+/// One or more animated values that an [`Animation`] widget drives together.
+///
+/// This is implemented for references to [`Animated`] values and tuples of up to four of them,
+/// whose events are for the tuple of their values.
+pub trait AnimatedValues {
+    /// The type of value in the events published for these animated values.
+    type Value: Animate;
+
+    /// Whether any of the values is still animating.
+    fn is_animating(&self) -> bool;
+
+    /// Whether every value is paused, in which case the widget stops ticking them until one is
+    /// resumed.
+    fn is_paused(&self) -> bool {
+        false
+    }
+}
+
+impl<T> AnimatedValues for &Animated<T>
+where
+    T: Animate,
+{
+    type Value = T;
+
+    fn is_animating(&self) -> bool {
+        Animated::is_animating(self)
+    }
+
+    fn is_paused(&self) -> bool {
+        Animated::is_paused(self)
+    }
+}
+
+/// Implements [`AnimatedValues`] for tuples of references to animated values.
+macro_rules! impl_animated_values {
+    ($($name:ident: $index:tt),+) => {
+        impl<$($name),+> AnimatedValues for ($(&Animated<$name>,)+)
+        where
+            $($name: Animate,)+
+        {
+            type Value = ($($name,)+);
+
+            fn is_animating(&self) -> bool {
+                $(self.$index.is_animating())||+
+            }
+
+            fn is_paused(&self) -> bool {
+                $(self.$index.is_paused())&&+
+            }
+        }
+    };
+}
+
+impl_animated_values!(T1: 0, T2: 1);
+impl_animated_values!(T1: 0, T2: 1, T3: 2);
+impl_animated_values!(T1: 0, T2: 1, T3: 2, T4: 3);
+
+/// The internal state of an [`Animation`] widget.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct State {
+    /// Whether the animated value was animating the last time the widget handled an event.
+    was_animating: bool,
 }
 
 impl<'a, T, Message, Theme, Renderer> Animation<'a, T, Message, Theme, Renderer>
@@ -73,16 +176,22 @@ where
     T: 'static + Animate,
     Message: 'a,
 {
-    /// Creates a new `Animation` with the given `animated_value` and `content`.
+    /// Creates a new `Animation` with the given animated `values` and `content`.
+    ///
+    /// The `values` can be a reference to a single [`Animated`] value, or a tuple of up to four
+    /// references that are animated together.
     pub fn new(
-        animated_value: &'a Animated<T>,
+        values: impl AnimatedValues<Value = T> + 'a,
         content: impl Into<Element<'a, Message, Theme, Renderer>>,
     ) -> Self {
         Self {
-            animated_value,
+            values: Box::new(values),
             content: content.into(),
             on_update: None,
             is_disabled: false,
+            animates_layout: false,
+            hub: None,
+            on_settle: None,
         }
     }
 
@@ -101,6 +210,37 @@ where
         self.is_disabled = disabled;
         self
     }
+
+    /// Whether the layout should be invalidated while the values animate.
+    ///
+    /// This is set to `false` by default for performance reasons, but you may want to set this
+    /// to `true` if you're animating a value that affects the layout of the content (e.g. its
+    /// size, padding, font size, etc). The layout is also invalidated once more on the frame the
+    /// values settle, so their final values are laid out.
+    pub fn animates_layout(mut self, animates_layout: bool) -> Self {
+        self.animates_layout = animates_layout;
+        self
+    }
+
+    /// Sets the `message` that's published once the animated value finishes animating, e.g. to
+    /// remove an item from a list after its exit animation completes.
+    ///
+    /// The message is published once each time the value goes from animating to settled, so
+    /// changing the target mid-animation or after settling arms it again.
+    pub fn on_settle(mut self, message: Message) -> Self
+    where
+        Message: Clone,
+    {
+        self.on_settle = Some(Box::new(move || message.clone()));
+        self
+    }
+
+    /// Reports tick needs to an [`crate::AnimationHub`] through the given `handle` instead of
+    /// publishing a message for this value every frame.
+    pub fn hub(mut self, handle: &'a HubHandle) -> Self {
+        self.hub = Some(handle);
+        self
+    }
 }
 
 impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -155,8 +295,14 @@ where
             .operate(&mut state.children[0], layout, renderer, operation);
     }
 
-    fn state(&self) -> iced::advanced::widget::tree::State {
-        iced::advanced::widget::tree::State::None
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            was_animating: self.values.is_animating(),
+        })
     }
 
     fn overlay<'b>(
@@ -225,7 +371,31 @@ where
             viewport,
         );
 
-        if !self.animated_value.is_animating() {
+        let is_animating = self.values.is_animating();
+        let state = tree.state.downcast_mut::<State>();
+        let just_settled = state.was_animating && !is_animating;
+        state.was_animating = is_animating;
+
+        // Paused values don't change, so they don't need a new layout or any ticks until
+        // they're resumed.
+        let is_paused = self.values.is_paused();
+        if self.animates_layout && !is_paused && (is_animating || just_settled) {
+            shell.invalidate_layout();
+        }
+
+        if just_settled {
+            if let Some(on_settle) = &self.on_settle {
+                shell.publish(on_settle());
+            }
+        }
+
+        if !is_animating || is_paused {
+            return status;
+        }
+
+        // The hub publishes a single tick for every value it tracks.
+        if let Some(hub) = self.hub.filter(|_| !self.is_disabled) {
+            hub.request_tick();
             return status;
         }
 
@@ -233,7 +403,7 @@ where
             let event: Event<T> = if self.is_disabled {
                 Event::Settle
             } else {
-                let now = Instant::now();
+                let now = crate::clock::now();
                 Event::Tick(now)
             };
             shell.publish(on_update(event));
@@ -256,14 +426,153 @@ where
     }
 }
 
-/// A helper function to create an [`Animation`] with a given value.
+/// A helper function to create an [`Animation`] with the given animated `values`.
 pub fn animation<'a, T, Message, Theme, Renderer>(
-    value: &'a Animated<T>,
+    values: impl AnimatedValues<Value = T> + 'a,
     content: impl Into<Element<'a, Message, Theme, Renderer>>,
 ) -> Animation<'a, T, Message, Theme, Renderer>
 where
     T: 'static + Animate,
     Message: 'a,
 {
-    Animation::new(value, content)
+    Animation::new(values, content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spring::Motion;
+    use iced::{
+        advanced::{clipboard, layout::Limits, Layout, Shell},
+        mouse, widget, window, Rectangle, Size,
+    };
+    use std::time::Instant;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Update,
+        Settled,
+    }
+
+    /// Sends a redraw event to a fresh `Animation` widget for `values`, like an app that
+    /// rebuilt its view, and returns the messages it published.
+    fn redraw<'a, T: 'static + Animate>(
+        values: impl AnimatedValues<Value = T> + 'a,
+        tree: &mut Tree,
+    ) -> Vec<Message> {
+        redraw_layout(values, tree, false).0
+    }
+
+    /// Like [`redraw`], but also returns whether the widget invalidated the layout.
+    fn redraw_layout<'a, T: 'static + Animate>(
+        values: impl AnimatedValues<Value = T> + 'a,
+        tree: &mut Tree,
+        animates_layout: bool,
+    ) -> (Vec<Message>, bool) {
+        let mut element: Element<'a, Message, iced::Theme, ()> =
+            Animation::new(values, widget::Space::new(10.0, 10.0))
+                .on_update(|_| Message::Update)
+                .on_settle(Message::Settled)
+                .animates_layout(animates_layout)
+                .into();
+        tree.diff(&element);
+        let node = element
+            .as_widget()
+            .layout(tree, &(), &Limits::new(Size::ZERO, Size::INFINITY));
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        element.as_widget_mut().on_event(
+            tree,
+            iced::Event::Window(window::Event::RedrawRequested(Instant::now())),
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::with_size(Size::INFINITY),
+        );
+        let is_layout_invalid = shell.is_layout_invalid();
+        (messages, is_layout_invalid)
+    }
+
+    /// Settling should publish the message once, and changing the target should arm it again.
+    #[test]
+    fn on_settle_publishes_once_per_animation() {
+        let mut opacity = Animated::spring(1.0_f32, Motion::SMOOTH);
+        let mut tree = Tree::new(Element::<'_, Message, iced::Theme, ()>::from(
+            Animation::new(&opacity, widget::Space::new(10.0, 10.0)),
+        ));
+        assert_eq!(redraw(&opacity, &mut tree), vec![]);
+
+        opacity.set_target(0.0);
+        assert_eq!(redraw(&opacity, &mut tree), vec![Message::Update]);
+
+        // The app settles the value, e.g. after the last tick, and can now remove the row.
+        opacity.settle();
+        assert_eq!(redraw(&opacity, &mut tree), vec![Message::Settled]);
+        assert_eq!(redraw(&opacity, &mut tree), vec![]);
+
+        opacity.set_target(1.0);
+        assert_eq!(redraw(&opacity, &mut tree), vec![Message::Update]);
+        opacity.settle();
+        assert_eq!(redraw(&opacity, &mut tree), vec![Message::Settled]);
+    }
+
+    /// Batched values should keep publishing events while any of them is animating.
+    #[test]
+    fn batched_values_animate_together() {
+        let mut size = Animated::spring(0.0_f32, Motion::SMOOTH);
+        let color = Animated::spring(iced::Color::BLACK, Motion::SMOOTH);
+        let mut tree = Tree::new(Element::<'_, Message, iced::Theme, ()>::from(
+            Animation::new((&size, &color), widget::Space::new(10.0, 10.0)),
+        ));
+        assert_eq!(redraw((&size, &color), &mut tree), vec![]);
+
+        size.set_target(10.0);
+        assert_eq!(redraw((&size, &color), &mut tree), vec![Message::Update]);
+
+        size.settle();
+        assert_eq!(redraw((&size, &color), &mut tree), vec![Message::Settled]);
+    }
+
+    /// Animating the layout should invalidate it on every tick and once more when settling.
+    #[test]
+    fn animates_layout_invalidates_until_settled() {
+        let mut size = Animated::spring(0.0_f32, Motion::SMOOTH);
+        let mut tree = Tree::new(Element::<'_, Message, iced::Theme, ()>::from(
+            Animation::new(&size, widget::Space::new(10.0, 10.0)),
+        ));
+        assert_eq!(redraw_layout(&size, &mut tree, true), (vec![], false));
+
+        size.set_target(10.0);
+        assert!(!redraw_layout(&size, &mut tree, false).1);
+        assert!(redraw_layout(&size, &mut tree, true).1);
+
+        size.settle();
+        assert_eq!(
+            redraw_layout(&size, &mut tree, true),
+            (vec![Message::Settled], true)
+        );
+        assert_eq!(redraw_layout(&size, &mut tree, true), (vec![], false));
+    }
+
+    /// Paused values shouldn't be ticked, and resuming them should start ticking them again.
+    #[test]
+    fn paused_values_skip_ticks() {
+        let mut size = Animated::spring(0.0_f32, Motion::SMOOTH);
+        let mut tree = Tree::new(Element::<'_, Message, iced::Theme, ()>::from(
+            Animation::new(&size, widget::Space::new(10.0, 10.0)),
+        ));
+
+        size.set_target(10.0);
+        size.pause();
+        assert_eq!(redraw_layout(&size, &mut tree, true), (vec![], false));
+
+        size.update(Event::Resume);
+        assert_eq!(
+            redraw_layout(&size, &mut tree, true),
+            (vec![Message::Update], true)
+        );
+    }
 }
@@ -66,20 +66,21 @@
 //! invoked multiple times to animate between values. Making reusable functions
 //! that use this widget and also take a generic element might be difficult.
 //!
-//! Nested animations also don't work if both properties are actively being
-//! animated. One animation at a time will function correctly, but trying to adjust
-//! both at the same time leads to the inner property skipping to the final value.
-//! Use the `Animation` widget if you need any of these properties.
-//!
-//! If these limitations apply to you, consider using the `Animation` widget instead.
-use crate::{animate::Animate, animated::Mode, Animated};
+//! If this limitation applies to you, consider using the `Animation` widget instead.
+use std::{
+    cell::{Cell, RefCell},
+    time::{Duration, Instant},
+};
+
+use crate::{animate::Animate, animated::Mode, Animated, SharedMotion};
 use iced::{
     advanced::{
         graphics::core::event,
         layout,
         widget::{tree, Tree},
-        Widget,
+        Shell, Widget,
     },
+    window::RedrawRequest,
     Element,
 };
 
@@ -105,7 +106,32 @@ where
     /// immediately without animating. Useful for reduced motion preferences.
     is_disabled: bool,
     /// The cached element built using the most recent animated value and `builder`.
-    cached_element: Element<'a, Message, Theme, Renderer>,
+    ///
+    /// This is rebuilt in `diff` when the widget is recreated mid-animation, e.g. by an outer
+    /// `AnimationBuilder` or a new view, so it's drawn at the in-flight value instead of the
+    /// target.
+    cached_element: RefCell<Element<'a, Message, Theme, Renderer>>,
+    /// An optional shared progress that drives the value instead of the widget's own animation.
+    driver: Option<Driver<'a, T>>,
+    /// The smallest change in the animated value that causes the element to be rebuilt.
+    deadband: f32,
+    /// The distance from the target within which the element is built with the target.
+    snap: f32,
+    /// Whether the cached element was built from the animated value rather than the target.
+    is_cache_animated: Cell<bool>,
+    /// Builds a message with the in-flight value each time the animation changes it.
+    on_change: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    /// The most frames per second that the widget requests while animating, if it's capped.
+    max_fps: Option<u32>,
+}
+
+/// A [`SharedMotion`] driving an `AnimationBuilder` along with the function that maps the shared
+/// progress to the builder's value.
+struct Driver<'a, T> {
+    motion: SharedMotion,
+    map: Box<dyn Fn(f32) -> T + 'a>,
+    /// The value the cached element was last built with.
+    built: RefCell<T>,
 }
 
 impl<'a, T, Message, Theme, Renderer> AnimationBuilder<'a, T, Message, Theme, Renderer>
@@ -121,10 +147,16 @@ where
         Self {
             target,
             builder: Box::new(builder),
-            cached_element: element,
+            cached_element: RefCell::new(element),
             mode: Mode::default(),
             animates_layout: false,
             is_disabled: false,
+            driver: None,
+            deadband: 0.0,
+            snap: 0.0,
+            is_cache_animated: Cell::new(false),
+            on_change: None,
+            max_fps: None,
         }
     }
 
@@ -151,6 +183,61 @@ where
         self.is_disabled = disabled;
         self
     }
+
+    /// Ignores changes to the animated value smaller than the `deadband` while animating.
+    ///
+    /// The content is only rebuilt, and the layout only invalidated, when the value moves
+    /// further than the deadband or settles at its target. See [`Animated::with_deadband`].
+    pub fn deadband(mut self, deadband: f32) -> Self {
+        self.deadband = deadband;
+        self
+    }
+
+    /// Builds the element with the exact target once the animated value is within `snap` of it.
+    ///
+    /// This avoids rebuilding the content, and invalidating the layout, for imperceptible
+    /// changes at the end of an animation. See [`Animated::with_snap`].
+    pub fn snap(mut self, snap: f32) -> Self {
+        self.snap = snap;
+        self
+    }
+
+    /// Publishes a message with the current animated value each time the animation changes it,
+    /// e.g. to start another animation from wherever this one currently is.
+    ///
+    /// Messages are only published while animating, so a settled widget doesn't publish
+    /// anything. The last message carries the value the animation settled at.
+    pub fn on_change(mut self, on_change: impl Fn(T) -> Message + 'a) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    /// Caps how many frames per second the widget requests while animating.
+    ///
+    /// By default the widget asks for every frame the display can show, which is wasteful for
+    /// slow animations on high refresh rate displays. With a cap, the widget waits until the next
+    /// allowed frame instead. Springs and transitions handle the longer time between ticks, so
+    /// the animation still takes the same amount of time.
+    pub fn max_fps(mut self, max_fps: u32) -> Self {
+        self.max_fps = Some(max_fps);
+        self
+    }
+
+    /// Drives the value of this widget with a [`SharedMotion`] instead of its own animation.
+    ///
+    /// The `map` function converts the shared progress into the value passed to the builder,
+    /// letting several widgets animate their own value ranges in exact lockstep.
+    pub fn driven_by(mut self, motion: &SharedMotion, map: impl Fn(f32) -> T + 'a) -> Self {
+        self.target = map(motion.target());
+        let built = map(motion.progress());
+        self.cached_element = RefCell::new((self.builder)(built.clone()));
+        self.driver = Some(Driver {
+            motion: motion.clone(),
+            map: Box::new(map),
+            built: RefCell::new(built),
+        });
+        self
+    }
 }
 
 impl<'a, T, Message, Theme, Renderer> From<AnimationBuilder<'a, T, Message, Theme, Renderer>>
@@ -166,6 +253,113 @@ where
     }
 }
 
+impl<'a, T, Message, Theme, Renderer> AnimationBuilder<'a, T, Message, Theme, Renderer>
+where
+    T: 'static + Animate,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Rebuilds the cached element with the given `value` and diffs the child `tree` against it.
+    ///
+    /// The builder may produce a different structure than the last element, e.g. a row that
+    /// gains a child past some size, so the child tree has to match the new element before its
+    /// next layout. A new structure also needs a new layout, even if the value isn't meant to
+    /// animate the layout.
+    fn rebuild(&mut self, value: T, tree: &mut Tree, shell: &mut Shell<'_, Message>) {
+        let cached_element = self.cached_element.get_mut();
+        *cached_element = (self.builder)(value);
+
+        let previous = shape(&tree.children[0]);
+        tree.diff_children(std::slice::from_ref(cached_element));
+        if shape(&tree.children[0]) != previous {
+            shell.invalidate_layout();
+        }
+    }
+
+    /// Advances the animation to `now` and rebuilds the element with the new value.
+    ///
+    /// Rebuilding also diffs the child tree, so the content can handle the frame right after
+    /// even if the builder changed its structure.
+    fn tick(&mut self, now: Instant, tree: &mut Tree, shell: &mut Shell<'_, Message>) {
+        // Shared motions are ticked by the first participant to see this frame.
+        if let Some(driver) = &self.driver {
+            if driver.motion.is_animating() {
+                shell.request_redraw(self.redraw_request(now));
+                driver.motion.tick(now);
+            }
+
+            // The first participant to tick the final frame settles the motion, so the others
+            // compare the value with the one they were built with instead of checking whether
+            // the motion is still animating.
+            let value = (driver.map)(driver.motion.progress());
+            if value != *driver.built.borrow() {
+                if self.animates_layout {
+                    shell.invalidate_layout();
+                }
+
+                driver.built.replace(value.clone());
+                self.publish_change(&value, shell);
+                self.rebuild(value, tree, shell);
+            }
+
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        // Request a redraw if the spring has remaining energy
+        if state.animation.is_animating() {
+            shell.request_redraw(self.redraw_request(now));
+
+            // Update the animation, which may not publish a new value within its deadband or
+            // once it has snapped to its target.
+            let previous = state.animation.value().clone();
+            state.animation.tick(now);
+            if !self.is_cache_animated.get() || state.animation.value() != &previous {
+                // Only invalidate the layout if the user indicates to do so
+                if self.animates_layout {
+                    shell.invalidate_layout();
+                }
+
+                let value = state.animation.value().clone();
+                self.publish_change(&value, shell);
+                self.rebuild(value, tree, shell);
+                self.is_cache_animated.set(true);
+            }
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer> AnimationBuilder<'a, T, Message, Theme, Renderer>
+where
+    T: 'static + Animate,
+{
+    /// The redraw to request after ticking the animation at `now`, which waits for the next frame
+    /// allowed by [`max_fps`](Self::max_fps) if there is a cap.
+    fn redraw_request(&self, now: Instant) -> RedrawRequest {
+        match self.max_fps {
+            Some(max_fps) if max_fps > 0 => {
+                RedrawRequest::At(now + Duration::from_secs_f64(1.0 / f64::from(max_fps)))
+            }
+            _ => RedrawRequest::NextFrame,
+        }
+    }
+
+    /// Publishes the animated `value` through the `on_change` function, if there is one.
+    fn publish_change(&self, value: &T, shell: &mut Shell<'_, Message>) {
+        if let Some(on_change) = &self.on_change {
+            shell.publish(on_change(value.clone()));
+        }
+    }
+}
+
+/// The tag and number of children of every tree within `tree`, used to tell when a rebuilt
+/// element has a different structure from the previous one.
+fn shape(tree: &Tree) -> Vec<(tree::Tag, usize)> {
+    let mut nodes = vec![(tree.tag, tree.children.len())];
+    nodes.extend(tree.children.iter().flat_map(shape));
+    nodes
+}
+
 struct State<T> {
     animation: Animated<T>,
     mode: Mode,
@@ -178,12 +372,14 @@ where
     Renderer: iced::advanced::Renderer,
 {
     fn size(&self) -> iced::Size<iced::Length> {
-        self.cached_element.as_widget().size()
+        self.cached_element.borrow().as_widget().size()
     }
 
     fn state(&self) -> tree::State {
         tree::State::new(State {
-            animation: Animated::new(self.target.clone(), self.mode),
+            animation: Animated::new(self.target.clone(), self.mode)
+                .with_deadband(self.deadband)
+                .with_snap(self.snap),
             mode: self.mode,
         })
     }
@@ -195,12 +391,21 @@ where
     fn diff(&self, tree: &mut Tree) {
         // Update the spring's target if it has changed
         let state = tree.state.downcast_mut::<State<T>>();
-        if state.animation.target() != &self.target {
+        if let Some(driver) = &self.driver {
+            // Driven widgets follow the shared progress, so keep the inner animation at rest.
             if self.is_disabled {
-                state.animation.settle();
-            } else {
-                state.animation.set_target(self.target.clone());
+                driver.motion.settle();
+            }
+            if state.animation.target() != &self.target {
+                state.animation.settle_at(self.target.clone());
             }
+        } else if self.is_disabled {
+            // Disabling animations mid-flight also jumps straight to the target.
+            if state.animation.target() != &self.target || state.animation.is_animating() {
+                state.animation.settle_at(self.target.clone());
+            }
+        } else if state.animation.target() != &self.target {
+            state.animation.set_target(self.target.clone());
         }
 
         if state.mode != self.mode {
@@ -208,7 +413,35 @@ where
             state.animation.apply(self.mode);
         }
 
-        tree.diff_children(std::slice::from_ref(&self.cached_element));
+        if state.animation.deadband() != self.deadband {
+            state.animation.set_deadband(self.deadband);
+        }
+
+        if state.animation.snap() != self.snap {
+            state.animation.set_snap(self.snap);
+        }
+
+        // A widget recreated mid-animation built its element from the target, so rebuild it from
+        // the in-flight value to avoid jumping ahead. Nested builders rely on this, since the
+        // outer builder recreates the inner one every frame.
+        let value = match &self.driver {
+            Some(driver) => (driver.map)(driver.motion.progress()),
+            None => state.animation.value().clone(),
+        };
+        if let Some(driver) = &self.driver {
+            driver.built.replace(value.clone());
+        }
+        if !self.is_cache_animated.get() && value != self.target {
+            *self.cached_element.borrow_mut() = (self.builder)(value);
+            self.is_cache_animated.set(true);
+        } else if self.is_cache_animated.get() && value == self.target {
+            // The value settled since the element was built, e.g. because animations were
+            // disabled, so show the settled value without waiting for another frame.
+            *self.cached_element.borrow_mut() = (self.builder)(value);
+            self.is_cache_animated.set(false);
+        }
+
+        tree.diff_children(std::slice::from_ref(&*self.cached_element.borrow()));
     }
 
     fn layout(
@@ -218,6 +451,7 @@ where
         limits: &layout::Limits,
     ) -> layout::Node {
         self.cached_element
+            .borrow()
             .as_widget()
             .layout(&mut tree.children[0], renderer, limits)
     }
@@ -229,7 +463,12 @@ where
         renderer: &Renderer,
         operation: &mut dyn iced::advanced::widget::Operation<()>,
     ) {
-        self.cached_element.as_widget().operate(
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = state.state.downcast_mut::<State<T>>();
+            state.animation.resume_at(now);
+        }
+
+        self.cached_element.borrow().as_widget().operate(
             &mut state.children[0],
             layout,
             renderer,
@@ -244,7 +483,7 @@ where
         renderer: &Renderer,
         translation: iced::Vector,
     ) -> Option<iced::advanced::overlay::Element<'b, Message, Theme, Renderer>> {
-        self.cached_element.as_widget_mut().overlay(
+        self.cached_element.get_mut().as_widget_mut().overlay(
             &mut tree.children[0],
             layout,
             renderer,
@@ -260,7 +499,7 @@ where
         viewport: &iced::Rectangle,
         renderer: &Renderer,
     ) -> iced::advanced::mouse::Interaction {
-        self.cached_element.as_widget().mouse_interaction(
+        self.cached_element.borrow().as_widget().mouse_interaction(
             &tree.children[0],
             layout,
             cursor,
@@ -270,7 +509,7 @@ where
     }
 
     fn children(&self) -> Vec<Tree> {
-        vec![Tree::new(&self.cached_element)]
+        vec![Tree::new(&*self.cached_element.borrow())]
     }
 
     fn draw(
@@ -283,7 +522,7 @@ where
         cursor: iced::advanced::mouse::Cursor,
         viewport: &iced::Rectangle,
     ) {
-        self.cached_element.as_widget().draw(
+        self.cached_element.borrow().as_widget().draw(
             &tree.children[0],
             renderer,
             theme,
@@ -305,37 +544,23 @@ where
         shell: &mut iced::advanced::Shell<'_, Message>,
         viewport: &iced::Rectangle,
     ) -> event::Status {
-        let status = self.cached_element.as_widget_mut().on_event(
+        // Tick the animation before forwarding a new frame, so the content handles it after being
+        // rebuilt with the value it's drawn with, e.g. to update hover styles at the new position,
+        // rather than with the value from the previous frame.
+        if let iced::Event::Window(iced::window::Event::RedrawRequested(now)) = event {
+            self.tick(now, tree, shell);
+        }
+
+        self.cached_element.get_mut().as_widget_mut().on_event(
             &mut tree.children[0],
-            event.clone(),
+            event,
             layout,
             cursor,
             renderer,
             clipboard,
             shell,
             viewport,
-        );
-
-        let iced::Event::Window(iced::window::Event::RedrawRequested(now)) = event else {
-            return status;
-        };
-
-        let state = tree.state.downcast_mut::<State<T>>();
-
-        // Request a redraw if the spring has remaining energy
-        if state.animation.is_animating() {
-            shell.request_redraw(iced::window::RedrawRequest::NextFrame);
-            // Only invalidate the layout if the user indicates to do so
-            if self.animates_layout {
-                shell.invalidate_layout();
-            }
-
-            // Update the animation and request a redraw
-            state.animation.tick(now);
-            self.cached_element = (self.builder)(state.animation.value().clone());
-        }
-
-        status
+        )
     }
 }
 
@@ -349,3 +574,428 @@ where
 {
     AnimationBuilder::new(value, builder)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        rc::Rc,
+        time::{Duration, Instant},
+    };
+
+    use super::*;
+    use iced::{
+        advanced::{clipboard, layout::Limits, Layout},
+        mouse, widget, window, Color, Event, Rectangle, Size,
+    };
+
+    /// Builds a row that gains a second child once the value passes 50.
+    fn view(value: f32) -> Element<'static, (), iced::Theme, ()> {
+        AnimationBuilder::new(value, |value| {
+            let mut row = widget::row![widget::text("first")];
+            if value > 50.0 {
+                row = row.push(widget::text("second"));
+            }
+            row.into()
+        })
+        .into()
+    }
+
+    /// Animating across a value where the built element changes its number of children should
+    /// keep the child tree in sync with the element, so the next layout doesn't panic.
+    #[test]
+    fn child_count_changes_mid_animation() {
+        let limits = Limits::new(Size::ZERO, Size::INFINITY);
+        let mut element = view(0.0);
+        let mut tree = Tree::new(&element);
+        let mut node = element.as_widget().layout(&mut tree, &(), &limits);
+        assert_eq!(tree.children[0].children.len(), 1);
+
+        // Rebuild the view with a new target, like an app would after a state change.
+        element = view(100.0);
+        tree.diff(&element);
+
+        let mut now = Instant::now();
+        let mut crossed_at = None;
+        for frame in 0..120 {
+            now += Duration::from_millis(16);
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+            element.as_widget_mut().on_event(
+                &mut tree,
+                Event::Window(window::Event::RedrawRequested(now)),
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+
+            let is_layout_invalid = shell.is_layout_invalid();
+            if tree.children[0].children.len() == 2 && crossed_at.is_none() {
+                // Crossing the threshold changes the structure, so the layout has to be redone.
+                assert!(is_layout_invalid);
+                crossed_at = Some(frame);
+            }
+            node = element.as_widget().layout(&mut tree, &(), &limits);
+        }
+
+        assert!(
+            crossed_at.is_some(),
+            "the animation should cross the threshold"
+        );
+        assert_eq!(node.children().len(), 2);
+    }
+
+    /// Builds a column of two builders driven by the same `motion`, recording the latest value
+    /// that each of them was built with.
+    fn driven_pair(
+        motion: &SharedMotion,
+        seen: &Rc<[Cell<f32>; 2]>,
+    ) -> Element<'static, (), iced::Theme, ()> {
+        let driven = |index: usize| {
+            let seen = seen.clone();
+            AnimationBuilder::new(0.0, move |value: f32| {
+                seen[index].set(value);
+                widget::Space::new(value, value).into()
+            })
+            .driven_by(motion, |progress| progress * 100.0)
+        };
+        widget::column![driven(0), driven(1)].into()
+    }
+
+    /// Every builder driven by the same motion should be built with the same value each frame,
+    /// including the frame where the first of them settles the motion.
+    #[test]
+    fn driven_builders_finish_in_lockstep() {
+        let limits = Limits::new(Size::ZERO, Size::INFINITY);
+        let motion = SharedMotion::new(0.0, crate::transition::Easing::LINEAR);
+        let seen = Rc::new([Cell::new(f32::NAN), Cell::new(f32::NAN)]);
+        let mut element = driven_pair(&motion, &seen);
+        let mut tree = Tree::new(&element);
+
+        motion.set_target(1.0);
+        element = driven_pair(&motion, &seen);
+        tree.diff(&element);
+
+        let mut now = Instant::now();
+        while motion.is_animating() {
+            let node = element.as_widget().layout(&mut tree, &(), &limits);
+            now += Duration::from_millis(16);
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+            element.as_widget_mut().on_event(
+                &mut tree,
+                Event::Window(window::Event::RedrawRequested(now)),
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+            assert_eq!(seen[0].get(), seen[1].get());
+        }
+
+        assert_eq!(seen[0].get(), 100.0);
+        assert_eq!(seen[1].get(), 100.0);
+    }
+
+    /// Builds a builder for a `size` around a builder for a `color`, recording the latest values
+    /// that the inner builder was called with.
+    fn nested_view(
+        size: f32,
+        color: Color,
+        seen: &Rc<Cell<Option<(f32, Color)>>>,
+    ) -> Element<'static, (), iced::Theme, ()> {
+        let seen = seen.clone();
+        AnimationBuilder::new(size, move |size| {
+            let seen = seen.clone();
+            AnimationBuilder::new(color, move |color| {
+                seen.set(Some((size, color)));
+                widget::Space::new(size, size).into()
+            })
+            .into()
+        })
+        .into()
+    }
+
+    /// Nested builders should both animate when their values change at the same time, rather
+    /// than the inner value skipping to its target whenever the outer builder rebuilds it.
+    #[test]
+    fn nested_builders_animate_together() {
+        let limits = Limits::new(Size::ZERO, Size::INFINITY);
+        let seen = Rc::new(Cell::new(None));
+        let mut element = nested_view(0.0, Color::BLACK, &seen);
+        let mut tree = Tree::new(&element);
+
+        element = nested_view(100.0, Color::WHITE, &seen);
+        tree.diff(&element);
+        assert_eq!(seen.get(), Some((0.0, Color::BLACK)));
+
+        let mut now = Instant::now();
+        for frame in 0..120 {
+            let node = element.as_widget().layout(&mut tree, &(), &limits);
+            now += Duration::from_millis(16);
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+            element.as_widget_mut().on_event(
+                &mut tree,
+                Event::Window(window::Event::RedrawRequested(now)),
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+
+            let (size, color) = seen.get().unwrap();
+            if frame < 10 {
+                assert!(size > 0.0 && size < 100.0, "size {size} at frame {frame}");
+                assert!(
+                    color.r > 0.0 && color.r < 1.0,
+                    "color {color:?} at frame {frame}"
+                );
+            }
+        }
+
+        assert_eq!(seen.get(), Some((100.0, Color::WHITE)));
+    }
+
+    /// Builds a builder for a `value` that records the latest value it was built with.
+    fn recorded_view(
+        value: f32,
+        is_disabled: bool,
+        seen: &Rc<Cell<f32>>,
+    ) -> Element<'static, (), iced::Theme, ()> {
+        let seen = seen.clone();
+        AnimationBuilder::new(value, move |value| {
+            seen.set(value);
+            widget::Space::new(value, value).into()
+        })
+        .disabled(is_disabled)
+        .into()
+    }
+
+    /// Disabling animations should show the target right away, whether it's disabled in the
+    /// middle of an animation or the target changes while it's disabled.
+    #[test]
+    fn disabling_rebuilds_with_target() {
+        let limits = Limits::new(Size::ZERO, Size::INFINITY);
+        let seen = Rc::new(Cell::new(f32::NAN));
+        let mut element = recorded_view(0.0, false, &seen);
+        let mut tree = Tree::new(&element);
+
+        element = recorded_view(100.0, false, &seen);
+        tree.diff(&element);
+        let node = element.as_widget().layout(&mut tree, &(), &limits);
+        let mut now = Instant::now();
+        for _ in 0..5 {
+            now += Duration::from_millis(16);
+            element.as_widget_mut().on_event(
+                &mut tree,
+                Event::Window(window::Event::RedrawRequested(now)),
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &(),
+                &mut clipboard::Null,
+                &mut Shell::new(&mut Vec::new()),
+                &Rectangle::with_size(Size::INFINITY),
+            );
+        }
+        assert!(seen.get() > 0.0 && seen.get() < 100.0, "{}", seen.get());
+
+        tree.diff(recorded_view(100.0, true, &seen));
+        assert_eq!(seen.get(), 100.0);
+
+        tree.diff(recorded_view(200.0, true, &seen));
+        assert_eq!(seen.get(), 200.0);
+        let state = tree.state.downcast_ref::<State<f32>>();
+        assert_eq!(*state.animation.value(), 200.0);
+        assert!(!state.animation.is_animating());
+    }
+
+    /// Content that records the value it was built with whenever it handles a frame.
+    struct Probe {
+        value: f32,
+        seen: Rc<Cell<f32>>,
+    }
+
+    impl Widget<(), iced::Theme, ()> for Probe {
+        fn size(&self) -> Size<iced::Length> {
+            Size::new(iced::Length::Shrink, iced::Length::Shrink)
+        }
+
+        fn layout(&self, _tree: &mut Tree, _renderer: &(), _limits: &Limits) -> layout::Node {
+            layout::Node::new(Size::new(self.value, self.value))
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            _renderer: &mut (),
+            _theme: &iced::Theme,
+            _style: &iced::advanced::renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+
+        fn on_event(
+            &mut self,
+            _tree: &mut Tree,
+            event: Event,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _renderer: &(),
+            _clipboard: &mut dyn clipboard::Clipboard,
+            _shell: &mut Shell<'_, ()>,
+            _viewport: &Rectangle,
+        ) -> event::Status {
+            if let Event::Window(window::Event::RedrawRequested(_)) = event {
+                self.seen.set(self.value);
+            }
+            event::Status::Ignored
+        }
+    }
+
+    /// Content should handle each frame after being rebuilt with the value of that frame, so
+    /// hover styles inside moving content don't lag a frame behind.
+    #[test]
+    fn frames_reach_rebuilt_content() {
+        let seen = Rc::new(Cell::new(f32::NAN));
+        let view = |value: f32| -> Element<'static, (), iced::Theme, ()> {
+            let seen = seen.clone();
+            AnimationBuilder::new(value, move |value| {
+                Element::new(Probe {
+                    value,
+                    seen: seen.clone(),
+                })
+            })
+            .animates_layout(true)
+            .into()
+        };
+        let limits = Limits::new(Size::ZERO, Size::INFINITY);
+        let mut element = view(0.0);
+        let mut tree = Tree::new(&element);
+        element = view(100.0);
+        tree.diff(&element);
+
+        let mut now = Instant::now();
+        for _ in 0..60 {
+            let node = element.as_widget().layout(&mut tree, &(), &limits);
+            now += Duration::from_millis(16);
+            element.as_widget_mut().on_event(
+                &mut tree,
+                Event::Window(window::Event::RedrawRequested(now)),
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &(),
+                &mut clipboard::Null,
+                &mut Shell::new(&mut Vec::new()),
+                &Rectangle::with_size(Size::INFINITY),
+            );
+
+            let state = tree.state.downcast_ref::<State<f32>>();
+            assert_eq!(seen.get(), *state.animation.value());
+        }
+        assert_eq!(seen.get(), 100.0);
+    }
+
+    /// Sends redraw events at 60fps for a second, returning every message that was published.
+    fn redraw_for_a_second(
+        element: &mut Element<'static, f32, iced::Theme, ()>,
+        tree: &mut Tree,
+    ) -> Vec<f32> {
+        let node = element
+            .as_widget()
+            .layout(tree, &(), &Limits::new(Size::ZERO, Size::INFINITY));
+        let mut now = Instant::now();
+        let mut messages = Vec::new();
+        for _ in 0..60 {
+            now += Duration::from_millis(16);
+            let mut shell = Shell::new(&mut messages);
+            element.as_widget_mut().on_event(
+                tree,
+                Event::Window(window::Event::RedrawRequested(now)),
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &(),
+                &mut clipboard::Null,
+                &mut shell,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+        }
+        messages
+    }
+
+    /// Changes should be published with the in-flight value while animating, and stop once the
+    /// animation settles.
+    #[test]
+    fn on_change_publishes_while_animating() {
+        let view = |value: f32| -> Element<'static, f32, iced::Theme, ()> {
+            AnimationBuilder::new(value, |_| widget::Space::new(10.0, 10.0).into())
+                .animation(crate::spring::Motion::SMOOTH.with_duration(Duration::from_millis(200)))
+                .on_change(|value| value)
+                .into()
+        };
+        let mut element = view(0.0);
+        let mut tree = Tree::new(&element);
+        assert!(redraw_for_a_second(&mut element, &mut tree).is_empty());
+
+        element = view(100.0);
+        tree.diff(&element);
+        let changes = redraw_for_a_second(&mut element, &mut tree);
+        assert!(changes.len() > 1);
+        assert!(changes.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(changes.last(), Some(&100.0));
+
+        assert!(redraw_for_a_second(&mut element, &mut tree).is_empty());
+    }
+
+    /// Starts animating a builder with the given frame rate cap and returns the redraw it
+    /// requests after a tick at `now`.
+    fn first_redraw(max_fps: Option<u32>, now: Instant) -> Option<RedrawRequest> {
+        let view = |value: f32| -> Element<'static, (), iced::Theme, ()> {
+            let builder = AnimationBuilder::new(value, |_| widget::Space::new(10.0, 10.0).into());
+            match max_fps {
+                Some(max_fps) => builder.max_fps(max_fps).into(),
+                None => builder.into(),
+            }
+        };
+        let mut element = view(0.0);
+        let mut tree = Tree::new(&element);
+        element = view(100.0);
+        tree.diff(&element);
+
+        let limits = Limits::new(Size::ZERO, Size::INFINITY);
+        let node = element.as_widget().layout(&mut tree, &(), &limits);
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        element.as_widget_mut().on_event(
+            &mut tree,
+            Event::Window(window::Event::RedrawRequested(now)),
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::with_size(Size::INFINITY),
+        );
+        shell.redraw_request()
+    }
+
+    /// Capping the frame rate should schedule each redraw for the next allowed frame instead of
+    /// the next frame the display can show.
+    #[test]
+    fn max_fps_schedules_redraws() {
+        let now = Instant::now();
+        assert_eq!(first_redraw(None, now), Some(RedrawRequest::NextFrame));
+        assert_eq!(
+            first_redraw(Some(30), now),
+            Some(RedrawRequest::At(now + Duration::from_secs_f64(1.0 / 30.0)))
+        );
+    }
+}
@@ -0,0 +1,508 @@
+//! A widget that batches the ticks of many animated values into a single message per frame.
+//!
+//! Wrapping every animated value in its own [`crate::Animation`] publishes one message per value
+//! each frame, which adds up when lots of values animate at once. An [`AnimationHub`] wraps your
+//! content once near the root instead. Each animated value is paired with a [`HubHandle`] in your
+//! state, and the hub publishes a single message per frame with the shared [`Instant`] whenever
+//! any of the tracked values is animating. Your app then ticks all of its values at once with
+//! [`Hub::apply_tick`] or the [`apply_tick!`](crate::apply_tick) macro.
+//!
+//! ```rust
+//! # use std::time::Instant;
+//! # use iced::{Element, widget::{column, text}};
+//! # use iced_anim::{apply_tick, Animated, Animation, AnimationHub, Hub, HubHandle};
+//! #[derive(Clone)]
+//! enum Message {
+//!     Tick(Instant),
+//! }
+//!
+//! struct State {
+//!     hub: Hub,
+//!     width: (Animated<f32>, HubHandle),
+//!     color: (Animated<iced::Color>, HubHandle),
+//! }
+//!
+//! impl State {
+//!     fn update(&mut self, message: Message) {
+//!         match message {
+//!             Message::Tick(now) => {
+//!                 apply_tick!(self.hub, now, self.width.0, self.color.0);
+//!             }
+//!         }
+//!     }
+//!
+//!     fn view(&self) -> Element<Message> {
+//!         let content = column![
+//!             Animation::new(&self.width.0, text("Width")).hub(&self.width.1),
+//!             Animation::new(&self.color.0, text("Color")).hub(&self.color.1),
+//!         ];
+//!
+//!         AnimationHub::new(&self.hub, content)
+//!             .on_tick(Message::Tick)
+//!             .into()
+//!     }
+//! }
+//! ```
+use std::{
+    cell::Cell,
+    cell::RefCell,
+    rc::{Rc, Weak},
+    time::Instant,
+};
+
+use iced::{
+    advanced::{
+        graphics::core::event,
+        layout, mouse, overlay, renderer,
+        widget::{Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    Element, Event, Length, Rectangle, Size, Vector,
+};
+
+use crate::{Animate, Animated};
+
+/// A registry of animated values whose ticks are batched by an [`AnimationHub`].
+///
+/// Cloning a [`Hub`] produces another handle to the same registry.
+#[derive(Debug, Clone, Default)]
+pub struct Hub {
+    inner: Rc<RefCell<Registry>>,
+}
+
+#[derive(Debug, Default)]
+struct Registry {
+    /// The tick flags of every registered handle. Dropped handles are pruned lazily.
+    handles: Vec<Weak<Cell<bool>>>,
+}
+
+impl Hub {
+    /// Creates a new, empty [`Hub`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a new animated value with the hub, returning the handle used to track it.
+    ///
+    /// The registration lasts as long as the returned handle, so store it alongside the
+    /// [`Animated`] value in your state.
+    pub fn register(&self) -> HubHandle {
+        let needs_tick = Rc::new(Cell::new(false));
+        self.inner
+            .borrow_mut()
+            .handles
+            .push(Rc::downgrade(&needs_tick));
+        HubHandle { needs_tick }
+    }
+
+    /// The number of handles that are currently registered with the hub.
+    pub fn registered(&self) -> usize {
+        let mut inner = self.inner.borrow_mut();
+        inner.prune();
+        inner.handles.len()
+    }
+
+    /// Whether any registered value has requested a tick since the last frame.
+    pub fn needs_tick(&self) -> bool {
+        self.inner
+            .borrow()
+            .handles
+            .iter()
+            .filter_map(Weak::upgrade)
+            .any(|needs_tick| needs_tick.get())
+    }
+
+    /// Returns whether any registered value requested a tick, resetting every request.
+    pub(crate) fn take_tick(&self) -> bool {
+        let mut inner = self.inner.borrow_mut();
+        inner.prune();
+        inner
+            .handles
+            .iter()
+            .filter_map(Weak::upgrade)
+            // Every request is taken, so this can't short-circuit.
+            .fold(false, |needs_tick, handle| needs_tick | handle.take())
+    }
+
+    /// Ticks each of the given `values` with the shared instant `now`.
+    ///
+    /// Returns whether any of the values is still animating afterwards. See the
+    /// [`apply_tick!`](crate::apply_tick) macro for a more concise way to call this with values
+    /// of different types.
+    pub fn apply_tick<'a>(
+        &self,
+        now: Instant,
+        values: impl IntoIterator<Item = &'a mut dyn Tickable>,
+    ) -> bool {
+        values.into_iter().fold(false, |is_animating, value| {
+            value.tick(now);
+            value.is_animating() || is_animating
+        })
+    }
+
+    /// Whether the two hubs refer to the same registry.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Registry {
+    /// Removes the flags of handles that have been dropped.
+    fn prune(&mut self) {
+        self.handles.retain(|handle| handle.strong_count() > 0);
+    }
+}
+
+impl PartialEq for Hub {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other)
+    }
+}
+
+/// A lightweight handle that reports when an animated value needs to be ticked by its [`Hub`].
+///
+/// Dropping the handle unregisters it from the hub.
+#[derive(Debug)]
+pub struct HubHandle {
+    /// Whether the tracked value has requested a tick since the hub last published one.
+    needs_tick: Rc<Cell<bool>>,
+}
+
+impl HubHandle {
+    /// Requests a tick from the hub if the `animated` value still needs to be ticked, i.e. it's
+    /// animating and isn't paused.
+    pub fn track(&self, animated: &impl Tickable) {
+        if animated.needs_tick() {
+            self.request_tick();
+        }
+    }
+
+    /// Requests a tick from the hub.
+    pub(crate) fn request_tick(&self) {
+        self.needs_tick.set(true);
+    }
+
+    /// Whether this handle has requested a tick that hasn't been published yet.
+    pub fn needs_tick(&self) -> bool {
+        self.needs_tick.get()
+    }
+}
+
+impl PartialEq for HubHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.needs_tick, &other.needs_tick)
+    }
+}
+
+/// A value that can be ticked by [`Hub::apply_tick`].
+pub trait Tickable {
+    /// Updates the value based on the elapsed time since its last update.
+    fn tick(&mut self, now: Instant);
+
+    /// Whether the value is still animating.
+    fn is_animating(&self) -> bool;
+
+    /// Whether the value needs to be ticked on the next frame, which is whenever it's animating
+    /// unless it can be paused.
+    fn needs_tick(&self) -> bool {
+        self.is_animating()
+    }
+}
+
+impl<T: Animate> Tickable for Animated<T> {
+    fn tick(&mut self, now: Instant) {
+        Animated::tick(self, now);
+    }
+
+    fn is_animating(&self) -> bool {
+        Animated::is_animating(self)
+    }
+
+    fn needs_tick(&self) -> bool {
+        Animated::needs_tick(self)
+    }
+}
+
+/// Ticks several animated values of possibly different types with a [`Hub`].
+///
+/// This expands to [`Hub::apply_tick`] and returns whether any value is still animating.
+///
+/// ```rust
+/// # use std::time::Instant;
+/// # use iced_anim::{apply_tick, Animated, Hub};
+/// let hub = Hub::new();
+/// let mut size = Animated::transition(0.0_f32, Default::default()).to(10.0);
+/// let mut color = Animated::transition(iced::Color::BLACK, Default::default());
+/// let is_animating = apply_tick!(hub, Instant::now(), size, color);
+/// assert!(is_animating);
+/// ```
+#[macro_export]
+macro_rules! apply_tick {
+    ($hub:expr, $now:expr, $($value:expr),+ $(,)?) => {
+        $hub.apply_tick(
+            $now,
+            [$(&mut $value as &mut dyn $crate::animation_hub::Tickable),+],
+        )
+    };
+}
+
+/// A widget that publishes a single tick message per frame for all values in its [`Hub`].
+pub struct AnimationHub<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// The registry of values that this widget publishes ticks for.
+    hub: &'a Hub,
+    /// The content containing the animated values.
+    content: Element<'a, Message, Theme, Renderer>,
+    /// Builds the message published when the registered values need to be ticked.
+    on_tick: Option<Box<dyn Fn(Instant) -> Message + 'a>>,
+}
+
+impl<'a, Message, Theme, Renderer> AnimationHub<'a, Message, Theme, Renderer> {
+    /// Creates a new [`AnimationHub`] that batches ticks for the values in `hub`.
+    pub fn new(hub: &'a Hub, content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            hub,
+            content: content.into(),
+            on_tick: None,
+        }
+    }
+
+    /// Sets the message that will be published once per frame while any value is animating.
+    pub fn on_tick(mut self, on_tick: impl Fn(Instant) -> Message + 'a) -> Self {
+        self.on_tick = Some(Box::new(on_tick));
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for AnimationHub<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // Descendants report their tick needs while handling the event.
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        // Nothing is published while every tracked value is settled.
+        if self.hub.take_tick() {
+            if let Some(on_tick) = &self.on_tick {
+                shell.publish(on_tick(crate::clock::now()));
+            }
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<AnimationHub<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(hub: AnimationHub<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(hub)
+    }
+}
+
+/// Creates a new [`AnimationHub`] that batches ticks for the values in `hub`.
+pub fn animation_hub<'a, Message, Theme, Renderer>(
+    hub: &'a Hub,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> AnimationHub<'a, Message, Theme, Renderer> {
+    AnimationHub::new(hub, content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transition::Easing;
+    use std::time::Duration;
+
+    /// Handles should stay registered until they're dropped.
+    #[test]
+    fn registration_lasts_as_long_as_the_handle() {
+        let hub = Hub::new();
+        let first = hub.register();
+        let second = hub.register();
+        assert_eq!(hub.registered(), 2);
+
+        drop(first);
+        assert_eq!(hub.registered(), 1);
+        drop(second);
+        assert_eq!(hub.registered(), 0);
+    }
+
+    /// Settled values should never cause a tick to be published.
+    #[test]
+    fn settled_values_do_not_request_ticks() {
+        let hub = Hub::new();
+        let handle = hub.register();
+        let value = Animated::transition(0.0_f32, Easing::LINEAR);
+
+        handle.track(&value);
+        assert!(!hub.needs_tick());
+        assert!(!hub.take_tick());
+    }
+
+    /// Paused values shouldn't request ticks until they're resumed.
+    #[test]
+    fn paused_values_do_not_request_ticks() {
+        let hub = Hub::new();
+        let handle = hub.register();
+        let mut value = Animated::transition(0.0_f32, Easing::LINEAR).to(1.0);
+
+        value.pause();
+        handle.track(&value);
+        assert!(!hub.take_tick());
+
+        value.resume();
+        handle.track(&value);
+        assert!(hub.take_tick());
+    }
+
+    /// Any animating value should request a single tick, which is reset once taken.
+    #[test]
+    fn animating_values_request_one_tick() {
+        let hub = Hub::new();
+        let first = hub.register();
+        let second = hub.register();
+        let value = Animated::transition(0.0_f32, Easing::LINEAR).to(1.0);
+
+        first.track(&value);
+        second.track(&value);
+        assert!(hub.needs_tick());
+        assert!(hub.take_tick());
+
+        assert!(!first.needs_tick());
+        assert!(!second.needs_tick());
+        assert!(!hub.take_tick());
+    }
+
+    /// Applying a tick should advance every value with the same instant.
+    #[test]
+    fn apply_tick_advances_all_values() {
+        let hub = Hub::new();
+        let easing = Easing::LINEAR.with_duration(Duration::from_millis(100));
+        let mut size = Animated::transition(0.0_f32, easing).to(1.0);
+        let mut color = Animated::transition(iced::Color::BLACK, easing).to(iced::Color::WHITE);
+
+        let now = Instant::now() + Duration::from_millis(50);
+        assert!(apply_tick!(hub, now, size, color));
+        assert!(*size.value() > 0.0);
+        assert!(color.value().r > 0.0);
+
+        let now = now + Duration::from_millis(100);
+        assert!(!apply_tick!(hub, now, size, color));
+        assert_eq!(*size.value(), 1.0);
+        assert_eq!(*color.value(), iced::Color::WHITE);
+    }
+}
@@ -0,0 +1,285 @@
+//! A sequence of values that animates between its entries, like a slideshow for values.
+//!
+//! A [`Carousel`] holds a list of entries and animates an inner [`Animated`] value to whichever
+//! entry is selected. Navigating with [`Carousel::next`], [`Carousel::prev`], or
+//! [`Carousel::jump`] always animates directly to the selected entry, even when wrapping around,
+//! instead of passing through the entries in between.
+//!
+//! Carousels can also advance on their own with [`Carousel::with_auto_advance`], driven by the
+//! timestamps passed to [`Carousel::tick`]. They implement [`Tickable`], so an
+//! [`AnimationHub`](crate::AnimationHub) can keep them ticking without any other messages.
+//!
+//! ```rust
+//! # use std::time::{Duration, Instant};
+//! # use iced::Color;
+//! # use iced_anim::{transition::Easing, Carousel};
+//! let mut colors = Carousel::new([Color::BLACK, Color::WHITE], Easing::EASE_IN_OUT)
+//!     .with_auto_advance(Duration::from_secs(3));
+//!
+//! colors.next();
+//! assert_eq!(colors.index(), 1);
+//! assert_eq!(colors.target(), &Color::WHITE);
+//! ```
+use std::time::{Duration, Instant};
+
+use crate::{
+    animated::Mode,
+    animation_hub::Tickable,
+    stall::{FrameClock, StallPolicy},
+    Animate, Animated,
+};
+
+/// A sequence of values that animates between its entries.
+#[derive(Debug, Clone)]
+pub struct Carousel<T> {
+    /// The entries to animate between.
+    entries: Vec<T>,
+    /// The index of the selected entry.
+    index: usize,
+    /// The animated value moving towards the selected entry.
+    animated: Animated<T>,
+    /// How long to wait between advancing automatically, if at all.
+    auto_advance: Option<Duration>,
+    /// How long it's been since the carousel last moved to a new entry.
+    elapsed: Duration,
+    /// Measures the time between ticks towards the next automatic advance.
+    clock: FrameClock,
+}
+
+impl<T> Carousel<T>
+where
+    T: Animate,
+{
+    /// Creates a new [`Carousel`] that starts at the first of the `entries` and animates
+    /// between them with the given `mode`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no `entries`.
+    pub fn new(entries: impl IntoIterator<Item = T>, mode: impl Into<Mode>) -> Self {
+        let entries: Vec<T> = entries.into_iter().collect();
+        let first = entries
+            .first()
+            .expect("A carousel needs at least one entry")
+            .clone();
+
+        Self {
+            entries,
+            index: 0,
+            animated: Animated::new(first, mode),
+            auto_advance: None,
+            elapsed: Duration::ZERO,
+            clock: FrameClock::default(),
+        }
+    }
+
+    /// Advances to the next entry every `interval` and returns the updated carousel.
+    pub fn with_auto_advance(mut self, interval: Duration) -> Self {
+        self.set_auto_advance(Some(interval));
+        self
+    }
+
+    /// Sets how long to wait between advancing automatically, or `None` to stop.
+    pub fn set_auto_advance(&mut self, interval: Option<Duration>) {
+        self.auto_advance = interval;
+        self.elapsed = Duration::ZERO;
+    }
+
+    /// Returns the entries of the carousel.
+    pub fn entries(&self) -> &[T] {
+        &self.entries
+    }
+
+    /// Returns the index of the selected entry.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the current animated value.
+    pub fn value(&self) -> &T {
+        self.animated.value()
+    }
+
+    /// Returns the selected entry that the carousel is animating towards.
+    pub fn target(&self) -> &T {
+        self.animated.target()
+    }
+
+    /// Returns the inner animated value, e.g. to pass to an [`Animation`](crate::Animation).
+    pub fn animated(&self) -> &Animated<T> {
+        &self.animated
+    }
+
+    /// Animates to the next entry, wrapping around to the first after the last.
+    pub fn next(&mut self) {
+        self.jump(self.index + 1);
+    }
+
+    /// Animates to the previous entry, wrapping around to the last before the first.
+    pub fn prev(&mut self) {
+        self.jump(self.index + self.entries.len() - 1);
+    }
+
+    /// Animates directly to the entry at `index`, which wraps around the number of entries.
+    ///
+    /// This restarts the wait before the next automatic advance.
+    pub fn jump(&mut self, index: usize) {
+        self.index = index % self.entries.len();
+        self.elapsed = Duration::ZERO;
+        self.animated.set_target(self.entries[self.index].clone());
+    }
+
+    /// Whether the carousel needs to keep being ticked, either because it's animating
+    /// or waiting to advance automatically.
+    pub fn is_animating(&self) -> bool {
+        self.animated.is_animating() || (self.auto_advance.is_some() && self.entries.len() > 1)
+    }
+
+    /// Updates the animated value and advances automatically if it's time to.
+    ///
+    /// Time spent while the event loop was stalled only counts towards the next automatic
+    /// advance if the [`StallPolicy`] settles stalls.
+    pub fn tick(&mut self, now: Instant) {
+        let dt = self.clock.tick(now).elapsed(StallPolicy::global());
+
+        if let Some(interval) = self.auto_advance {
+            self.elapsed += dt;
+            // Only advance once per tick so a long gap between ticks doesn't skip entries.
+            if self.elapsed >= interval && self.entries.len() > 1 {
+                self.next();
+                self.animated.resume_at(now);
+            }
+        }
+
+        self.animated.tick(now);
+    }
+}
+
+/// Like [`Animated`], carousels ignore timing details when comparing for equality.
+impl<T: Animate> PartialEq for Carousel<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+            && self.index == other.index
+            && self.animated == other.animated
+            && self.auto_advance == other.auto_advance
+    }
+}
+
+impl<T: Animate> Tickable for Carousel<T> {
+    fn tick(&mut self, now: Instant) {
+        Carousel::tick(self, now);
+    }
+
+    fn is_animating(&self) -> bool {
+        Carousel::is_animating(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{animated::DEFAULT_DURATION, transition::Easing};
+    use iced::Color;
+
+    fn carousel() -> Carousel<f32> {
+        Carousel::new([0.0, 10.0, 20.0, 30.0], Easing::LINEAR)
+    }
+
+    /// Navigating should wrap around in either direction.
+    #[test]
+    fn index_bookkeeping() {
+        let mut carousel = carousel();
+        carousel.prev();
+        assert_eq!(carousel.index(), 3);
+        carousel.next();
+        assert_eq!(carousel.index(), 0);
+        carousel.jump(6);
+        assert_eq!(carousel.index(), 2);
+        assert_eq!(carousel.target(), &20.0);
+    }
+
+    /// Wrapping around should animate directly to the entry instead of through the others.
+    #[test]
+    fn wrap_animates_directly() {
+        let entries = [
+            Color::from_rgb(1.0, 0.0, 0.0),
+            Color::from_rgb(0.0, 1.0, 0.0),
+            Color::from_rgb(0.0, 0.0, 1.0),
+        ];
+        let mut carousel = Carousel::new(entries, Easing::LINEAR);
+        carousel.prev();
+        let start = Instant::now();
+        carousel.tick(start);
+        carousel.animated.resume_at(start);
+        carousel.tick(start + DEFAULT_DURATION / 2);
+
+        // Going through green would have picked up some of its green component.
+        let value = carousel.value();
+        assert_eq!(value.g, 0.0);
+        assert!((value.r - 0.5).abs() < 0.01 && (value.b - 0.5).abs() < 0.01);
+    }
+
+    /// Carousels should advance once every interval.
+    #[test]
+    fn auto_advance_timing() {
+        let interval = Duration::from_secs(2);
+        let mut carousel = carousel().with_auto_advance(interval);
+        let start = Instant::now();
+        carousel.tick(start);
+
+        carousel.tick(start + interval - Duration::from_millis(10));
+        assert_eq!(carousel.index(), 0);
+        carousel.tick(start + interval);
+        assert_eq!(carousel.index(), 1);
+        carousel.tick(start + interval * 2);
+        assert_eq!(carousel.index(), 2);
+        assert!(carousel.is_animating());
+    }
+
+    /// A long gap between ticks should only advance a single entry.
+    #[test]
+    fn auto_advance_skips_nothing_after_gap() {
+        let interval = Duration::from_secs(1);
+        let mut carousel = carousel().with_auto_advance(interval);
+        let start = Instant::now();
+        carousel.tick(start);
+        carousel.tick(start + interval * 10);
+        assert_eq!(carousel.index(), 1);
+    }
+
+    /// Navigating during an automatic transition should retarget it and restart the wait.
+    #[test]
+    fn navigation_interrupts_auto_advance() {
+        let interval = Duration::from_secs(1);
+        let mut carousel = carousel().with_auto_advance(interval);
+        let start = Instant::now();
+        carousel.tick(start);
+        carousel.tick(start + interval);
+        assert_eq!(carousel.index(), 1);
+
+        // Midway through the transition to the second entry, go back to the first.
+        let midway = start + interval + DEFAULT_DURATION / 2;
+        carousel.tick(midway);
+        let value = *carousel.value();
+        assert!(value > 0.0 && value < 10.0);
+        carousel.prev();
+        assert_eq!(carousel.target(), &0.0);
+        assert_eq!(*carousel.value(), value);
+
+        // The wait restarts from the navigation.
+        carousel.tick(midway + interval - Duration::from_millis(10));
+        assert_eq!(carousel.index(), 0);
+        carousel.tick(midway + interval);
+        assert_eq!(carousel.index(), 1);
+    }
+
+    /// Single-entry carousels have nothing to advance to.
+    #[test]
+    fn single_entry_never_advances() {
+        let mut carousel =
+            Carousel::new([5.0_f32], Easing::LINEAR).with_auto_advance(Duration::from_millis(10));
+        assert!(!carousel.is_animating());
+        carousel.next();
+        assert_eq!(carousel.index(), 0);
+    }
+}
@@ -0,0 +1,12 @@
+//! The source of the current time for animations.
+//!
+//! Animations only read the clock here when they aren't given an instant, so the `*_at` variants
+//! like [`Spring::new_at`](crate::Spring::new_at) and
+//! [`Transition::set_target_at`](crate::Transition::set_target_at) can drive an animation
+//! entirely from instants provided by a test.
+use std::time::Instant;
+
+/// Returns the current instant.
+pub(crate) fn now() -> Instant {
+    Instant::now()
+}
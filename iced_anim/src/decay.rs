@@ -0,0 +1,408 @@
+//! A momentum animation that keeps moving a value with a decaying velocity, like a scroll fling.
+//!
+//! Unlike springs and transitions, a [`Decay`] doesn't have a fixed target. It starts with an
+//! initial velocity that slows down exponentially at its deceleration [`rate`](Decay::rate)
+//! until it comes to rest at its [`projected_target`](Decay::projected_target):
+//!
+//! ```rust
+//! # use iced_anim::Decay;
+//! // Fling a scroll offset at 1000 pixels per second.
+//! let fling = Decay::new(0.0_f32).with_velocity(vec![1000.0]);
+//! assert!(fling.is_animating());
+//! assert!((fling.projected_target() - 499.5).abs() < 0.1);
+//! ```
+use std::time::{Duration, Instant};
+
+use crate::{
+    spring::Motion,
+    stall::{Gap, StallPolicy},
+    Animate, Event, Spring,
+};
+
+/// The deceleration rate of a regular scroll fling, as the fraction of velocity kept every
+/// millisecond.
+pub const NORMAL_RATE: f32 = 0.998;
+
+/// The deceleration rate of a fling that stops quickly, like paging through content.
+pub const FAST_RATE: f32 = 0.99;
+
+/// The default speed, in units per second, below which a decay comes to rest.
+pub const DEFAULT_THRESHOLD: f32 = 0.5;
+
+/// An animation that moves a value with an exponentially decaying velocity.
+///
+/// The motion is solved in closed form, so a decay follows the same path and comes to rest at
+/// the same place regardless of the refresh rate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decay<T> {
+    /// The current value of the decay.
+    value: T,
+    /// Where the value comes to rest with the current velocity.
+    target: T,
+    /// The current velocity of each component, in units per second.
+    velocity: Vec<f32>,
+    /// The fraction of velocity kept every millisecond.
+    rate: f32,
+    /// The speed below which the decay comes to rest.
+    threshold: f32,
+    /// The last instant at which the decay was updated.
+    last_update: Instant,
+    /// A smoothed estimate of the time between recent ticks.
+    frame_interval: Option<Duration>,
+}
+
+// Impls that don't require an `Animate` bound.
+impl<T> Decay<T> {
+    /// Returns a reference to the current value of the decay.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns where the decay comes to rest with its current velocity.
+    pub fn projected_target(&self) -> &T {
+        &self.target
+    }
+
+    /// Returns the current velocity of each component, in units per second.
+    pub fn velocity(&self) -> &[f32] {
+        &self.velocity
+    }
+
+    /// Returns the fraction of velocity kept every millisecond.
+    pub fn rate(&self) -> f32 {
+        self.rate
+    }
+
+    /// Returns the speed, in units per second, below which the decay comes to rest.
+    pub fn threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    /// Returns a smoothed estimate of the time between recent ticks, if the decay has been
+    /// ticked yet.
+    pub fn observed_frame_interval(&self) -> Option<Duration> {
+        self.frame_interval
+    }
+
+    /// Whether the decay is still moving.
+    pub fn is_animating(&self) -> bool {
+        self.velocity.iter().any(|&v| v != 0.0)
+    }
+
+    /// How long the decay keeps moving before it comes to rest.
+    pub fn duration(&self) -> Duration {
+        let speed = self
+            .velocity
+            .iter()
+            .fold(0.0_f32, |max, v| max.max(v.abs()));
+        if speed <= self.threshold {
+            return Duration::ZERO;
+        }
+
+        Duration::from_secs_f32((self.threshold / speed).ln() / self.exponent())
+    }
+
+    /// The exponent of the velocity's decay per second, which is always negative.
+    fn exponent(&self) -> f32 {
+        1000.0 * self.rate.ln()
+    }
+}
+
+impl<T> Decay<T>
+where
+    T: Animate,
+{
+    /// Creates a new [`Decay`] at rest at the initial `value`, decaying at the [`NORMAL_RATE`].
+    pub fn new(value: T) -> Self {
+        Self::new_at(value, crate::clock::now())
+    }
+
+    /// Creates a new [`Decay`] at rest at the initial `value` as of the given `now` instant.
+    pub fn new_at(value: T, now: Instant) -> Self {
+        Self {
+            target: value.clone(),
+            velocity: vec![0.0; value.instance_components()],
+            value,
+            rate: NORMAL_RATE,
+            threshold: DEFAULT_THRESHOLD,
+            last_update: now,
+            frame_interval: None,
+        }
+    }
+
+    /// Sets the deceleration `rate` and returns the updated decay. See [`Decay::set_rate`].
+    pub fn with_rate(mut self, rate: f32) -> Self {
+        self.set_rate(rate);
+        self
+    }
+
+    /// Sets the deceleration `rate` as the fraction of velocity kept every millisecond, e.g.
+    /// [`NORMAL_RATE`] or [`FAST_RATE`]. Rates are clamped between `0.5` and `0.9999`.
+    pub fn set_rate(&mut self, rate: f32) {
+        self.rate = rate.clamp(0.5, 0.9999);
+        self.project();
+    }
+
+    /// Sets the speed, in units per second, below which the decay comes to rest and returns the
+    /// updated decay.
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold.max(f32::EPSILON);
+        self
+    }
+
+    /// Sets the `velocity` of each component and returns the updated decay.
+    pub fn with_velocity(mut self, velocity: Vec<f32>) -> Self {
+        self.set_velocity(velocity);
+        self
+    }
+
+    /// Sets the velocity of each component, in units per second, e.g. the velocity of the
+    /// pointer when a drag is released.
+    pub fn set_velocity(&mut self, velocity: Vec<f32>) {
+        self.set_velocity_at(velocity, crate::clock::now());
+    }
+
+    /// Sets the velocity of each component like [`Decay::set_velocity`] as of the given `now`
+    /// instant.
+    pub fn set_velocity_at(&mut self, velocity: Vec<f32>, now: Instant) {
+        // Avoid integrating the time the decay spent at rest.
+        if !self.is_animating() {
+            self.last_update = now;
+        }
+
+        self.velocity = velocity;
+        self.project();
+    }
+
+    /// Aims the decay to come to rest exactly at the `target`, e.g. the nearest snap point of a
+    /// fling, by adjusting its velocity.
+    ///
+    /// ```rust
+    /// # use iced_anim::Decay;
+    /// let mut fling = Decay::new(0.0_f32).with_velocity(vec![1000.0]);
+    /// fling.set_target(400.0);
+    /// assert_eq!(fling.projected_target(), &400.0);
+    /// assert!(fling.velocity()[0] < 1000.0);
+    /// ```
+    pub fn set_target(&mut self, target: T) {
+        self.set_target_at(target, crate::clock::now());
+    }
+
+    /// Aims the decay at the `target` like [`Decay::set_target`] as of the given `now` instant.
+    pub fn set_target_at(&mut self, target: T, now: Instant) {
+        if !self.is_animating() {
+            self.last_update = now;
+        }
+
+        self.value.reshape(&target);
+        let exponent = self.exponent();
+        self.velocity = target
+            .distance_to(&self.value)
+            .into_iter()
+            .map(|d| -d * exponent)
+            .collect();
+        self.target = target;
+    }
+
+    /// Returns a [`Spring`] that continues from the current value and velocity of this decay
+    /// towards the `target` with the given `motion`, e.g. to pull a fling that ends near a snap
+    /// point into place.
+    pub fn into_spring(self, target: T, motion: Motion) -> Spring<T> {
+        let mut spring = Spring::new_at(self.value, self.last_update).with_motion(motion);
+        spring.set_target_at(target, self.last_update);
+        spring.set_velocity_at(self.velocity, self.last_update);
+        spring
+    }
+
+    /// Updates the decay based on the given `event`.
+    ///
+    /// Since a decay doesn't have a fixed target, [`Event::Target`] aims it at the target with
+    /// [`Decay::set_target`].
+    pub fn update(&mut self, event: Event<T>) {
+        match event {
+            Event::Tick(now) => self.tick(now),
+            Event::Target(target) => self.set_target(target),
+            Event::Settle => self.settle(),
+            Event::SettleAt(target) => self.settle_at(target),
+            // Only `Animated` values can be paused, since they stop ticking their animation.
+            Event::Pause | Event::Resume => {}
+        }
+    }
+
+    /// Brings the decay to rest at its projected target immediately.
+    pub fn settle(&mut self) {
+        self.value = self.target.clone();
+        self.velocity.iter_mut().for_each(|v| *v = 0.0);
+    }
+
+    /// Brings the decay to rest at the given `target` immediately.
+    pub fn settle_at(&mut self, target: T) {
+        self.target = target;
+        self.settle();
+    }
+
+    /// Rebases the decay's clock to `now` without advancing it, so the time since the last
+    /// update isn't integrated on the next tick. Useful when resuming a paused decay.
+    pub fn resume_at(&mut self, now: Instant) {
+        self.last_update = now;
+    }
+
+    /// Updates the decay's value based on the elapsed time since the last update, coming to rest
+    /// at its projected target once its speed drops below the threshold.
+    ///
+    /// If the event loop stalled since the last update, the decay either resumes where it was
+    /// or settles at its projected target depending on the [`StallPolicy`].
+    pub fn tick(&mut self, now: Instant) {
+        self.tick_with(now, Some(StallPolicy::global()));
+    }
+
+    /// Updates the decay like [`Decay::tick`], handling stalls with the given `policy`, or
+    /// treating every gap as a regular frame if there's no policy.
+    pub(crate) fn tick_with(&mut self, now: Instant, policy: Option<StallPolicy>) {
+        self.tick_scaled(now, policy, crate::time_scale());
+    }
+
+    /// Updates the decay like [`Decay::tick_with`], scaling the time since the last update by
+    /// `scale` instead of the crate-wide [time scale](crate::time_scale).
+    pub(crate) fn tick_scaled(&mut self, now: Instant, policy: Option<StallPolicy>, scale: f32) {
+        if !self.is_animating() {
+            return;
+        }
+
+        let gap = Gap::detect(self.last_update, now, self.frame_interval);
+        self.last_update = now;
+        match (gap, policy) {
+            (Gap::Stall(_), Some(StallPolicy::Pause)) => return,
+            (Gap::Stall(_), Some(StallPolicy::Settle)) => return self.settle(),
+            _ => {}
+        }
+
+        let dt = gap.duration();
+        self.frame_interval = Some(crate::animated::smooth_frame_interval(
+            self.frame_interval,
+            dt,
+        ));
+        let dt = crate::time_scale::scale(dt, scale);
+
+        // The velocity decays as `v * e^(k * t)`, which moves the value by its integral.
+        let exponent = self.exponent();
+        let decay = (exponent * dt.as_secs_f32()).exp();
+        let deltas: Vec<f32> = self
+            .velocity
+            .iter()
+            .map(|v| v * (decay - 1.0) / exponent)
+            .collect();
+        self.velocity.iter_mut().for_each(|v| *v *= decay);
+        self.value.update(&mut deltas.into_iter());
+
+        if self.velocity.iter().all(|v| v.abs() < self.threshold) {
+            self.settle();
+        }
+    }
+
+    /// Recomputes where the decay comes to rest with its current velocity.
+    fn project(&mut self) {
+        let exponent = self.exponent();
+        let mut target = self.value.clone();
+        target.update(&mut self.velocity.iter().map(|v| -v / exponent));
+        self.target = target;
+    }
+}
+
+impl<T> Default for Decay<T>
+where
+    T: Animate + Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ticks the `decay` at 60Hz starting from `start` until it comes to rest.
+    fn run(decay: &mut Decay<f32>, start: Instant) {
+        let mut now = start;
+        decay.resume_at(start);
+        while decay.is_animating() {
+            now += Duration::from_micros(16_667);
+            decay.tick_with(now, None);
+        }
+    }
+
+    /// A fling should come to rest where it was projected to, which is `v / -ln(rate)` away.
+    #[test]
+    fn comes_to_rest_at_projection() {
+        let mut decay = Decay::new(0.0_f32).with_velocity(vec![1000.0]);
+        let projected = 1000.0 / -(1000.0 * NORMAL_RATE.ln());
+        assert!((decay.projected_target() - projected).abs() < 1e-3);
+
+        let before = *decay.value();
+        let mut now = Instant::now();
+        decay.resume_at(now);
+        now += Duration::from_micros(16_667);
+        decay.tick_with(now, None);
+        assert!(*decay.value() > before);
+
+        run(&mut decay, now);
+        assert!((decay.value() - projected).abs() < 0.5, "{}", decay.value());
+        assert!(!decay.is_animating());
+    }
+
+    /// The path of a decay shouldn't depend on the refresh rate.
+    #[test]
+    fn frame_rate_independent() {
+        let start = Instant::now();
+        let mut slow = Decay::new(0.0_f32).with_velocity(vec![1000.0]);
+        let mut fast = slow.clone();
+        slow.resume_at(start);
+        fast.resume_at(start);
+        slow.tick_with(start + Duration::from_millis(100), None);
+        for frame in 1..=10 {
+            fast.tick_with(start + Duration::from_millis(frame * 10), None);
+        }
+        assert!((slow.value() - fast.value()).abs() < 1e-3);
+    }
+
+    /// Faster rates should stop sooner.
+    #[test]
+    fn fast_rate_stops_sooner() {
+        let normal = Decay::new(0.0_f32).with_velocity(vec![1000.0]);
+        let fast = normal.clone().with_rate(FAST_RATE);
+        assert!(fast.projected_target() < normal.projected_target());
+        assert!(fast.duration() < normal.duration());
+    }
+
+    /// Aiming a decay at a target should make it come to rest there.
+    #[test]
+    fn set_target_lands_on_target() {
+        let mut decay = Decay::new(0.0_f32).with_velocity(vec![1000.0]);
+        decay.set_target(300.0);
+        run(&mut decay, Instant::now());
+        assert_eq!(*decay.value(), 300.0);
+    }
+
+    /// Handing a decay off to a spring should keep its velocity.
+    #[test]
+    fn into_spring_keeps_velocity() {
+        let decay = Decay::new(0.0_f32).with_velocity(vec![800.0]);
+        let spring = decay.into_spring(450.0, Motion::SMOOTH);
+        assert_eq!(spring.velocity(), &[800.0]);
+        assert_eq!(spring.target(), &450.0);
+    }
+
+    /// Springs taking over from a decay should continue from the decay's clock rather than the
+    /// wall clock.
+    #[test]
+    fn into_spring_keeps_clock() {
+        let start = Instant::now() + Duration::from_secs(1);
+        let mut decay = Decay::new_at(0.0_f32, start);
+        decay.set_velocity_at(vec![800.0], start);
+        decay.tick_with(start + Duration::from_millis(16), None);
+
+        let spring = decay.into_spring(450.0, Motion::SMOOTH);
+        assert_eq!(spring.last_update(), start + Duration::from_millis(16));
+    }
+}
@@ -0,0 +1,102 @@
+//! Shadow presets for consistent elevation levels, similar to the levels in Material Design.
+//!
+//! Elevation describes how far a surface is raised above the one behind it, from level 0, which
+//! has no shadow, to level 5. Since [`Shadow`] can be animated, moving between levels gives raised
+//! surfaces a consistent lift without writing shadows by hand:
+//!
+//! ```rust
+//! # use iced_anim::elevation::{self, Elevation};
+//! let resting = elevation::shadow_for(1);
+//! let hovered = Elevation(2).shadow();
+//! assert!(hovered.blur_radius > resting.blur_radius);
+//! ```
+//!
+//! The animated `button` and `container` widgets also accept an `.elevation(level)` that
+//! overrides the shadow of their style, and buttons lift by one level while they're hovered.
+use iced::{Color, Shadow, Vector};
+
+/// An elevation level from 0 to [`Elevation::MAX`], where higher levels cast larger shadows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Elevation(pub u8);
+
+impl Elevation {
+    /// The highest elevation level. Higher levels are clamped to this one.
+    pub const MAX: u8 = 5;
+
+    /// The shadow of each elevation level, from level 0 to [`Elevation::MAX`].
+    pub const LEVELS: [Shadow; 6] = [
+        level(0.0, 0.0, 0.0),
+        level(0.20, 1.0, 3.0),
+        level(0.22, 2.0, 6.0),
+        level(0.24, 4.0, 8.0),
+        level(0.26, 6.0, 10.0),
+        level(0.28, 8.0, 12.0),
+    ];
+
+    /// Returns the shadow of this elevation level.
+    pub fn shadow(self) -> Shadow {
+        shadow_for(self.0)
+    }
+
+    /// Returns this level raised by the given number of levels, up to [`Elevation::MAX`].
+    pub fn raised(self, levels: u8) -> Self {
+        Self(self.0.saturating_add(levels).min(Self::MAX))
+    }
+}
+
+impl From<u8> for Elevation {
+    fn from(level: u8) -> Self {
+        Self(level.min(Self::MAX))
+    }
+}
+
+impl From<Elevation> for Shadow {
+    fn from(elevation: Elevation) -> Self {
+        elevation.shadow()
+    }
+}
+
+/// Returns the shadow of the given elevation `level`, clamped to [`Elevation::MAX`].
+pub fn shadow_for(level: u8) -> Shadow {
+    Elevation::LEVELS[usize::from(level.min(Elevation::MAX))]
+}
+
+/// A shadow cast straight down, whose transparent color at level 0 still animates to the others.
+const fn level(alpha: f32, offset: f32, blur_radius: f32) -> Shadow {
+    Shadow {
+        color: Color::from_rgba(0.0, 0.0, 0.0, alpha),
+        offset: Vector::new(0.0, offset),
+        blur_radius,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Animate;
+
+    /// Moving from level 1 to level 3 should lift the surface steadily.
+    #[test]
+    fn lerp_is_monotonic() {
+        let start = shadow_for(1);
+        let end = shadow_for(3);
+        let mut shadow = start;
+        let mut previous = start;
+
+        for step in 1..=10 {
+            shadow.lerp(&start, &end, step as f32 / 10.0);
+            assert!(shadow.blur_radius > previous.blur_radius, "{shadow:?}");
+            assert!(shadow.offset.y > previous.offset.y, "{shadow:?}");
+            previous = shadow;
+        }
+        assert_eq!(shadow, end);
+    }
+
+    /// Levels past the highest one should use its shadow.
+    #[test]
+    fn levels_are_clamped() {
+        assert_eq!(shadow_for(9), Elevation::LEVELS[5]);
+        assert_eq!(Elevation::from(9), Elevation(Elevation::MAX));
+        assert_eq!(Elevation(4).raised(3), Elevation(Elevation::MAX));
+    }
+}
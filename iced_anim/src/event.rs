@@ -1,14 +1,18 @@
 //! An event associated with an [`crate::Animated`] value.
 //!
-//! Spring events can represent three general types of events:
+//! Spring events can represent six general types of events:
 //! - A tick event that updates the animated value
 //! - A target event that sets the animated target value
 //! - A settle event that ends the animation early.
+//! - A settle at event that jumps to a new value without animating, e.g. when restoring
+//!   persisted state.
+//! - Pause and resume events that freeze the animation where it is and continue it later.
 //!
 //! This event can be passed to [`crate::Animated::update`] to update the current value.
-//! You can also use the `From` impl to create a [`Event::Target`] from a
-//! value, e.g. `Message::ChangeSize(5.0.into())` instead of
-//! `Message::ChangeSize(Event::Target(5.0))`.
+//! You can also use the `From` impls to create a [`Event::Target`] from a
+//! value or a reference to one, e.g. `Message::ChangeSize(5.0.into())` instead of
+//! `Message::ChangeSize(Event::Target(5.0))`. Events for one type can be adapted
+//! to another with [`Event::map`].
 //!
 //! ```rust
 //! # use iced_anim::{Animated, spring::Motion, Event};
@@ -20,19 +24,28 @@
 //!
 //! assert_eq!(spring_1.target(), &5.0);
 //! assert_eq!(spring_2.target(), &5.0);
+//!
+//! // Restoring a saved value shouldn't animate to it.
+//! spring_1.update(Event::settle_at(2.0));
+//! assert_eq!(spring_1.value(), &2.0);
+//! assert!(!spring_1.is_animating());
 //! ```
 use crate::Animate;
 use std::time::Instant;
 
 /// An event associated with an animated `Spring` value.
 ///
-/// This event represents one of three things:
+/// This event represents one of six things:
 /// - A tick event that updates the spring's value, e.g. a frame is rendered
 ///   and the spring's value should be updated.
 /// - A target event that sets the spring's target value, e.g. a user presses
 ///   a button and changes the target size of an animated value.
 /// - A settle event that ends the animation early by jumping to the target
 ///   value.
+/// - A settle at event that jumps to a new target value without animating, in a
+///   single event instead of a target followed by a settle.
+/// - Pause and resume events that freeze the animation, e.g. while a modal is open, and
+///   continue it from where it was.
 ///
 /// This event can be passed to [`crate::Animated::update`] to update the spring's value.
 #[derive(Debug, Clone, PartialEq)]
@@ -49,6 +62,47 @@ pub enum Event<T> {
     /// Causes the spring to settle to a specific target value immediately.
     /// This is useful if you want to jump to a specific value without animating.
     SettleAt(T),
+    /// Freezes the animation where it is until it's resumed, ignoring ticks in the meantime.
+    ///
+    /// Only [`crate::Animated`] values can be paused. Other animations ignore this event.
+    Pause,
+    /// Continues a paused animation from where it was paused.
+    Resume,
+}
+
+impl<T> Event<T> {
+    /// Creates an [`Event::SettleAt`] event that jumps to the given `value` without animating.
+    ///
+    /// Unlike sending [`Event::Target`] followed by [`Event::Settle`], this never draws a frame
+    /// of the animation towards the new value.
+    pub fn settle_at(value: T) -> Self {
+        Event::SettleAt(value)
+    }
+
+    /// Maps the value of a [`Event::Target`] or [`Event::SettleAt`] event with `f`, passing
+    /// every other event through unchanged.
+    ///
+    /// This is useful to adapt events between animated values of different types, e.g. when a
+    /// widget only animates one field of a larger animated struct.
+    ///
+    /// ```rust
+    /// # use iced_anim::Event;
+    /// let event = Event::Target(5.0_f32).map(|size| (size, 1.0_f32));
+    /// assert_eq!(event, Event::Target((5.0, 1.0)));
+    ///
+    /// let event = Event::<f32>::Settle.map(|size| (size, 1.0_f32));
+    /// assert_eq!(event, Event::Settle);
+    /// ```
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Event<U> {
+        match self {
+            Event::Tick(now) => Event::Tick(now),
+            Event::Target(target) => Event::Target(f(target)),
+            Event::Settle => Event::Settle,
+            Event::SettleAt(target) => Event::SettleAt(f(target)),
+            Event::Pause => Event::Pause,
+            Event::Resume => Event::Resume,
+        }
+    }
 }
 
 // Impl `Copy` for `Event` when `T` is `Copy`.
@@ -64,6 +118,16 @@ where
     }
 }
 
+// Targets can also be created from references, cloning the value.
+impl<T> From<&T> for Event<T>
+where
+    T: Animate,
+{
+    fn from(value: &T) -> Self {
+        Event::Target(value.clone())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -79,6 +143,33 @@ mod tests {
         assert!(matches!(update, Event::Target(5.0)));
     }
 
+    /// Targets should also be created from references.
+    #[test]
+    fn from_ref_impl_sets_target() {
+        let size = 5.0_f32;
+        assert_eq!(Event::from(&size), Event::Target(5.0));
+    }
+
+    /// Mapping should only change the values of targets, keeping ticks and settles as they are.
+    #[test]
+    fn map_only_changes_values() {
+        let now = Instant::now();
+        assert_eq!(Event::<f32>::Tick(now).map(f64::from), Event::Tick(now));
+        assert_eq!(Event::<f32>::Settle.map(f64::from), Event::Settle);
+        assert_eq!(Event::<f32>::Pause.map(f64::from), Event::Pause);
+        assert_eq!(Event::Target(2.0_f32).map(|x| x * 2.0), Event::Target(4.0));
+        assert_eq!(
+            Event::SettleAt(2.0_f32).map(|x| [x, x]),
+            Event::SettleAt([2.0, 2.0])
+        );
+    }
+
+    /// The `settle_at` constructor should create a [`Event::SettleAt`] variant.
+    #[test]
+    fn settle_at_constructor() {
+        assert_eq!(Event::settle_at(5.0), Event::SettleAt(5.0));
+    }
+
     /// [`Event`] should implement `Copy` when `T` does.
     #[test]
     fn copy_impl() {
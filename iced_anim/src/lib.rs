@@ -15,6 +15,8 @@
 //! flag. You can use these as drop-in replacements for the existing widgets:
 //!
 //! ```rust
+//! # #[cfg(feature = "widgets")]
+//! # mod example {
 //! # #[derive(Clone)] enum Message { DoSomething }
 //! use iced::{Element, widget::text};
 //! use iced_anim::widget::button;
@@ -24,6 +26,7 @@
 //!         .on_press(Message::DoSomething)
 //!         .into()
 //! }
+//! # }
 //! ```
 //!
 //! ## Animating types
@@ -34,6 +37,8 @@
 //! by enabling the `derive` feature flag and adding `#[derive(Animate)]` to your type:
 //!
 //! ```rust
+//! # #[cfg(feature = "derive")]
+//! # mod example {
 //! use iced_anim::Animate;
 //!
 //! // Note that animate also requires `Clone` and `PartialEq` impls.
@@ -42,34 +47,74 @@
 //!     size: f32,
 //!     color: iced::Color,
 //! }
+//! # }
 //! ```
 //!
 //! ## Controlling the spring motion
 //!
 //! The spring motion of an [`AnimationBuilder`] can be customized. There are some presets like
-//! [`spring::Motion::smooth`] and [`spring::Motion::bouncy`], but you can also create your own.
+//! [`spring::Motion::SMOOTH`] and [`spring::Motion::BOUNCY`], but you can also create your own.
+//!
+//! ## Feature flags
+//!
+//! | Feature   | Enables                                                     |
+//! |-----------|-------------------------------------------------------------|
+//! | `derive`  | `#[derive(Animate)]` for your own types                     |
+//! | `serde`   | `Serialize`/`Deserialize` for animation modes               |
+//! | `widgets` | The implicitly animated widgets in the `widget` module      |
+//!
+//! No features are enabled by default, and every combination of them is supported.
 //!
 //! ## Supported Iced versions
 //!
 //! This crate supports Iced 0.13 and newer.
 pub mod animate;
 pub mod animated;
-mod animated_state;
+pub mod animated_state;
 pub mod animation;
 pub mod animation_builder;
+pub mod animation_hub;
+pub mod carousel;
+mod clock;
+pub mod decay;
+pub mod elevation;
 pub mod event;
+#[cfg(feature = "serde")]
+mod millis;
+pub mod reduced_motion;
+pub mod relative;
+mod resume;
+pub mod sequence;
+pub mod shared_motion;
 pub mod spring;
+pub mod stagger;
+pub mod stall;
+pub mod subscription;
+pub mod time_scale;
+pub mod tokens;
 pub mod transition;
 #[cfg(feature = "widgets")]
 pub mod widget;
+pub mod window;
 
-pub use animate::Animate;
-pub use animated::{Animated, AnimationType};
+pub use animate::{Animate, AnimatedInt, OklabColor, WrappingAngle};
+pub use animated::{Animated, AnimationType, Animator};
 pub use animated_state::AnimatedState;
 pub use animation::Animation;
 pub use animation_builder::*;
+pub use animation_hub::{AnimationHub, Hub, HubHandle};
+pub use carousel::Carousel;
+pub use decay::Decay;
 pub use event::Event;
+pub use reduced_motion::{animations_enabled, set_animations_enabled};
+pub use relative::{RelativePoint, RelativeRect};
+pub use sequence::Sequence;
+pub use shared_motion::SharedMotion;
 pub use spring::Spring;
+pub use stagger::StaggeredAnimated;
+pub use stall::StallPolicy;
+pub use time_scale::{set_time_scale, time_scale};
+pub use tokens::{MotionTokens, Token};
 pub use transition::Transition;
 
 #[cfg(feature = "derive")]
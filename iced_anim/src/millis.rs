@@ -0,0 +1,19 @@
+//! Serializes a [`Duration`] as a whole number of milliseconds, which reads naturally in config
+//! files, e.g. `"duration": 250`.
+//!
+//! Durations too long to fit in a `u64` of milliseconds, like [`Duration::MAX`], are saturated
+//! and deserialized back as [`Duration::MAX`].
+use serde::{Deserialize, Deserializer, Serializer};
+use std::time::Duration;
+
+pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    let millis = u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
+    serializer.serialize_u64(millis)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+    match u64::deserialize(deserializer)? {
+        u64::MAX => Ok(Duration::MAX),
+        millis => Ok(Duration::from_millis(millis)),
+    }
+}
@@ -0,0 +1,40 @@
+//! A crate-wide switch for turning animations off, e.g. to respect the operating system's
+//! "reduce motion" accessibility preference.
+//!
+//! Widgets like [`Animation`](crate::Animation) and [`AnimationBuilder`](crate::AnimationBuilder)
+//! can be disabled one at a time, but an app that follows a system preference would need to pass
+//! that preference into every view. Disabling animations here instead makes every [`Animated`]
+//! value, and every widget built on one, jump straight to its targets:
+//!
+//! ```rust
+//! # use iced_anim::{spring::Motion, Animated};
+//! # let prefers_reduced_motion = true;
+//! iced_anim::set_animations_enabled(!prefers_reduced_motion);
+//!
+//! let mut size = Animated::spring(0.0_f32, Motion::SMOOTH);
+//! size.set_target(100.0);
+//! assert_eq!(size.value(), &100.0);
+//! # iced_anim::set_animations_enabled(true);
+//! ```
+//!
+//! Disabling animations while something is animating settles it on its next tick.
+//!
+//! [`Animated`]: crate::Animated
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether animations are enabled across the crate.
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Returns whether animations are enabled, which is `true` unless changed with
+/// [`set_animations_enabled`].
+pub fn animations_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Enables or disables animations across the crate.
+///
+/// While disabled, animated values settle at their targets as soon as the targets change, and
+/// any animation that was already running settles on its next tick.
+pub fn set_animations_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
@@ -0,0 +1,262 @@
+//! Positions and rectangles expressed as fractions of some reference size.
+//!
+//! Animating absolute pixel positions breaks down when the container they're relative to is
+//! resized mid-animation, since the targets no longer line up with the new size. These types
+//! express positions in resolution-independent space instead, where `0.0` is the start of the
+//! reference size and `1.0` is the end. They can be animated like any other [`Animate`] type and
+//! resolved against a concrete [`Size`] at layout or draw time.
+//!
+//! ```rust
+//! # use iced::{Point, Size};
+//! # use iced_anim::RelativePoint;
+//! let center = RelativePoint::new(0.5, 0.5);
+//! assert_eq!(center.resolve(Size::new(200.0, 100.0)), Point::new(100.0, 50.0));
+//! ```
+use iced::{Point, Rectangle, Size, Vector};
+
+use crate::Animate;
+
+/// A point expressed as fractions of some reference size.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RelativePoint {
+    /// The horizontal position as a fraction of the reference width.
+    pub x: f32,
+    /// The vertical position as a fraction of the reference height.
+    pub y: f32,
+}
+
+impl RelativePoint {
+    /// The top-left corner of the reference size.
+    pub const ORIGIN: Self = Self::new(0.0, 0.0);
+
+    /// The center of the reference size.
+    pub const CENTER: Self = Self::new(0.5, 0.5);
+
+    /// Creates a new [`RelativePoint`] from the given fractions.
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    /// Resolves this point into an absolute [`Point`] within the given `size`.
+    pub fn resolve(&self, size: Size) -> Point {
+        Point::new(self.x * size.width, self.y * size.height)
+    }
+
+    /// Converts an absolute `point` into a [`RelativePoint`] within the given `size`.
+    ///
+    /// Zero-sized dimensions can't express a fraction, so they resolve to `0.0`.
+    pub fn from_absolute(point: Point, size: Size) -> Self {
+        Self::new(
+            fraction(point.x, size.width),
+            fraction(point.y, size.height),
+        )
+    }
+
+    /// Resolves this point into an absolute [`Vector`] within the given `size`.
+    pub fn resolve_vector(&self, size: Size) -> Vector {
+        Vector::new(self.x * size.width, self.y * size.height)
+    }
+}
+
+/// A rectangle expressed as fractions of some reference size.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RelativeRect {
+    /// The horizontal position as a fraction of the reference width.
+    pub x: f32,
+    /// The vertical position as a fraction of the reference height.
+    pub y: f32,
+    /// The width as a fraction of the reference width.
+    pub width: f32,
+    /// The height as a fraction of the reference height.
+    pub height: f32,
+}
+
+impl RelativeRect {
+    /// A rectangle covering the entire reference size.
+    pub const FULL: Self = Self::new(0.0, 0.0, 1.0, 1.0);
+
+    /// Creates a new [`RelativeRect`] from the given fractions.
+    pub const fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// The top-left corner of this rectangle.
+    pub fn position(&self) -> RelativePoint {
+        RelativePoint::new(self.x, self.y)
+    }
+
+    /// Resolves this rectangle into an absolute [`Rectangle`] within the given `size`.
+    pub fn resolve(&self, size: Size) -> Rectangle {
+        Rectangle {
+            x: self.x * size.width,
+            y: self.y * size.height,
+            width: self.width * size.width,
+            height: self.height * size.height,
+        }
+    }
+
+    /// Converts an absolute `rectangle` into a [`RelativeRect`] within the given `size`.
+    ///
+    /// Zero-sized dimensions can't express a fraction, so they resolve to `0.0`.
+    pub fn from_absolute(rectangle: Rectangle, size: Size) -> Self {
+        Self::new(
+            fraction(rectangle.x, size.width),
+            fraction(rectangle.y, size.height),
+            fraction(rectangle.width, size.width),
+            fraction(rectangle.height, size.height),
+        )
+    }
+}
+
+/// The fraction of `total` that `value` represents, or `0.0` if `total` is zero.
+fn fraction(value: f32, total: f32) -> f32 {
+    if total == 0.0 {
+        0.0
+    } else {
+        value / total
+    }
+}
+
+impl Animate for RelativePoint {
+    fn components() -> usize {
+        2 * f32::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [self.x.distance_to(&end.x), self.y.distance_to(&end.y)].concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.x.update(components);
+        self.y.update(components);
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.x.lerp(&start.x, &end.x, progress);
+        self.y.lerp(&start.y, &end.y, progress);
+    }
+}
+
+impl Animate for RelativeRect {
+    fn components() -> usize {
+        4 * f32::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.x.distance_to(&end.x),
+            self.y.distance_to(&end.y),
+            self.width.distance_to(&end.width),
+            self.height.distance_to(&end.height),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.x.update(components);
+        self.y.update(components);
+        self.width.update(components);
+        self.height.update(components);
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.x.lerp(&start.x, &end.x, progress);
+        self.y.lerp(&start.y, &end.y, progress);
+        self.width.lerp(&start.width, &end.width, progress);
+        self.height.lerp(&start.height, &end.height, progress);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_point() {
+        let point = RelativePoint::new(0.25, 0.5);
+        assert_eq!(
+            point.resolve(Size::new(400.0, 200.0)),
+            Point::new(100.0, 100.0)
+        );
+    }
+
+    /// Converting to and from absolute coordinates should round-trip.
+    #[test]
+    fn point_round_trip() {
+        let size = Size::new(400.0, 200.0);
+        let point = Point::new(100.0, 50.0);
+        let relative = RelativePoint::from_absolute(point, size);
+        assert_eq!(relative, RelativePoint::new(0.25, 0.25));
+        assert_eq!(relative.resolve(size), point);
+    }
+
+    /// Resizing the reference should keep the point proportionally in the same place.
+    #[test]
+    fn resolve_after_resize() {
+        let point = RelativePoint::CENTER;
+        assert_eq!(
+            point.resolve(Size::new(100.0, 100.0)),
+            Point::new(50.0, 50.0)
+        );
+        assert_eq!(
+            point.resolve(Size::new(300.0, 50.0)),
+            Point::new(150.0, 25.0)
+        );
+    }
+
+    #[test]
+    fn resolve_rect() {
+        let rect = RelativeRect::new(0.5, 0.25, 0.5, 0.5);
+        assert_eq!(
+            rect.resolve(Size::new(200.0, 400.0)),
+            Rectangle::new(Point::new(100.0, 100.0), Size::new(100.0, 200.0))
+        );
+        assert_eq!(rect.position(), RelativePoint::new(0.5, 0.25));
+    }
+
+    #[test]
+    fn rect_round_trip() {
+        let size = Size::new(200.0, 400.0);
+        let rect = Rectangle::new(Point::new(50.0, 100.0), Size::new(100.0, 200.0));
+        let relative = RelativeRect::from_absolute(rect, size);
+        assert_eq!(relative, RelativeRect::new(0.25, 0.25, 0.5, 0.5));
+        assert_eq!(relative.resolve(size), rect);
+    }
+
+    /// Zero-sized references can't express fractions, so they should resolve to zero
+    /// instead of producing `NaN` or infinite values.
+    #[test]
+    fn zero_size_reference() {
+        let relative = RelativePoint::from_absolute(Point::new(10.0, 20.0), Size::ZERO);
+        assert_eq!(relative, RelativePoint::ORIGIN);
+
+        let relative = RelativeRect::from_absolute(
+            Rectangle::new(Point::new(10.0, 20.0), Size::new(30.0, 40.0)),
+            Size::new(0.0, 100.0),
+        );
+        assert_eq!(relative, RelativeRect::new(0.0, 0.2, 0.0, 0.4));
+
+        assert_eq!(
+            RelativeRect::FULL.resolve(Size::ZERO),
+            Rectangle::new(Point::ORIGIN, Size::ZERO)
+        );
+    }
+
+    #[test]
+    fn animate_components() {
+        assert_eq!(RelativePoint::components(), 2);
+        assert_eq!(RelativeRect::components(), 4);
+    }
+
+    #[test]
+    fn lerp_point() {
+        let mut point = RelativePoint::ORIGIN;
+        point.lerp(&RelativePoint::ORIGIN, &RelativePoint::new(1.0, 0.5), 0.5);
+        assert_eq!(point, RelativePoint::new(0.5, 0.25));
+    }
+}
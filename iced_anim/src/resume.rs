@@ -0,0 +1,78 @@
+//! An operation that lets paused animations in a widget tree resume without jumping forward.
+//!
+//! Widgets that own animations call [`requested`] from their `operate` function and resume their
+//! animations at the returned instant, if any. Running a [`Resume`] operation over a subtree
+//! then rebases every participating animation's clock at once.
+use std::{any::Any, time::Instant};
+
+use iced::{
+    advanced::widget::{Id, Operation},
+    Rectangle,
+};
+
+/// An operation that asks every animation in a subtree to resume at a given instant.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(not(feature = "widgets"), allow(dead_code))]
+pub(crate) struct Resume {
+    /// The instant the animations should resume at.
+    pub now: Instant,
+}
+
+impl Operation for Resume {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, _id: Option<&Id>) {
+        if let Some(signal) = state.downcast_mut::<Signal>() {
+            signal.0 = Some(self.now);
+        }
+    }
+}
+
+/// The state a widget exposes to find out whether it's being asked to resume.
+#[derive(Debug, Default)]
+struct Signal(Option<Instant>);
+
+/// Returns the instant to resume at if the `operation` is a [`Resume`].
+pub(crate) fn requested(operation: &mut dyn Operation) -> Option<Instant> {
+    let mut signal = Signal::default();
+    operation.custom(&mut signal, None);
+    signal.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An operation that ignores custom state, like most operations do.
+    struct Noop;
+
+    impl Operation for Noop {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+        ) {
+            operate_on_children(self);
+        }
+    }
+
+    #[test]
+    fn resume_is_requested() {
+        let now = Instant::now();
+        assert_eq!(requested(&mut Resume { now }), Some(now));
+    }
+
+    /// Other operations shouldn't cause animations to resume.
+    #[test]
+    fn other_operations_are_ignored() {
+        assert_eq!(requested(&mut Noop), None);
+    }
+}
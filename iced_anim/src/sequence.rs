@@ -0,0 +1,464 @@
+//! A type of animation that moves through a sequence of targets, one step after another.
+//!
+//! Each [`Step`] transitions to its target with its own [`Easing`] and can hold at the target for
+//! a while before the next step starts, e.g. moving to `a` over 300ms, holding for 100ms, and
+//! then easing out to `b`:
+//!
+//! ```rust
+//! # use std::time::Duration;
+//! # use iced_anim::{sequence::Sequence, transition::Easing};
+//! let sequence = Sequence::new(0.0_f32)
+//!     .then(10.0, Easing::LINEAR.with_duration(Duration::from_millis(300)))
+//!     .hold(Duration::from_millis(100))
+//!     .then(20.0, Easing::EASE_OUT);
+//!
+//! assert_eq!(sequence.target(), &20.0);
+//! assert!(sequence.is_animating());
+//! ```
+use std::time::{Duration, Instant};
+
+use crate::{
+    stall::{Gap, StallPolicy},
+    transition::Easing,
+    Animate, Event,
+};
+
+/// A single step of a [`Sequence`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step<T> {
+    /// The value that this step animates to.
+    pub target: T,
+    /// How the value animates to the target.
+    pub easing: Easing,
+    /// How long to stay at the target before the next step starts, if at all.
+    pub hold: Option<Duration>,
+}
+
+impl<T> Step<T> {
+    /// Creates a new [`Step`] that animates to the `target` with the given `easing`.
+    pub fn new(target: T, easing: Easing) -> Self {
+        Self {
+            target,
+            easing,
+            hold: None,
+        }
+    }
+
+    /// Sets how long to stay at the target before the next step starts.
+    pub fn hold(mut self, hold: Duration) -> Self {
+        self.hold = Some(hold);
+        self
+    }
+
+    /// The full length of the step, including its hold.
+    fn duration(&self) -> Duration {
+        self.easing.duration + self.hold.unwrap_or_default()
+    }
+}
+
+/// A type of animation that moves through a sequence of targets in order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sequence<T> {
+    /// The current value of the sequence.
+    value: T,
+    /// The value that the current step started from.
+    start: T,
+    /// Every step of the sequence, including those that already completed.
+    steps: Vec<Step<T>>,
+    /// The index of the current step, which is the number of steps if the sequence is done.
+    index: usize,
+    /// How far into the current step the sequence is, including its hold.
+    elapsed: Duration,
+    /// The time at which the sequence was last updated.
+    last_update: Instant,
+    /// A smoothed estimate of the time between recent ticks.
+    frame_interval: Option<Duration>,
+}
+
+impl<T> Sequence<T>
+where
+    T: Animate,
+{
+    /// Creates a new sequence at the given `value` without any steps.
+    pub fn new(value: T) -> Self {
+        Self::new_at(value, crate::clock::now())
+    }
+
+    /// Creates a new sequence at the given `value` without any steps, as of the given `now`
+    /// instant.
+    pub fn new_at(value: T, now: Instant) -> Self {
+        Self {
+            start: value.clone(),
+            value,
+            steps: Vec::new(),
+            index: 0,
+            elapsed: Duration::ZERO,
+            last_update: now,
+            frame_interval: None,
+        }
+    }
+
+    /// Adds a step that animates to the `target` with the given `easing` and returns the
+    /// updated sequence.
+    pub fn then(mut self, target: T, easing: Easing) -> Self {
+        self.push(Step::new(target, easing));
+        self
+    }
+
+    /// Holds at the target of the last step for the given `duration` before the next step
+    /// starts, and returns the updated sequence.
+    pub fn hold(mut self, duration: Duration) -> Self {
+        if let Some(step) = self.steps.last_mut() {
+            step.hold = Some(duration);
+        }
+        self
+    }
+
+    /// Adds a step to the end of the sequence, starting over from the current value if the
+    /// sequence already finished.
+    pub fn push(&mut self, step: Step<T>) {
+        if !self.is_animating() {
+            self.restart(Vec::new(), crate::clock::now());
+        }
+        self.steps.push(step);
+    }
+
+    /// Replaces the steps of the sequence with the given `steps`, starting from the current
+    /// value.
+    pub fn play(&mut self, steps: impl IntoIterator<Item = Step<T>>) {
+        self.restart(steps.into_iter().collect(), crate::clock::now());
+    }
+
+    /// Starts the given `steps` from the beginning at the current value as of the given `now`
+    /// instant.
+    fn restart(&mut self, steps: Vec<Step<T>>, now: Instant) {
+        // Avoid counting the time spent at rest towards the new steps.
+        if !self.is_animating() {
+            self.last_update = now;
+        }
+        self.steps = steps;
+        self.start = self.value.clone();
+        self.index = 0;
+        self.elapsed = Duration::ZERO;
+    }
+
+    /// Returns a reference to the current `value` of the sequence.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns a reference to the target of the last step, which is where the sequence ends.
+    pub fn target(&self) -> &T {
+        self.steps.last().map_or(&self.value, |step| &step.target)
+    }
+
+    /// Returns the steps of the sequence, including those that already completed.
+    pub fn steps(&self) -> &[Step<T>] {
+        &self.steps
+    }
+
+    /// Returns the index of the step that's currently animating or holding, if any.
+    pub fn current_step(&self) -> Option<usize> {
+        (self.index < self.steps.len()).then_some(self.index)
+    }
+
+    /// Returns the easing of the current step, or of the last step once the sequence is done.
+    pub fn easing(&self) -> Easing {
+        self.steps
+            .get(self.index)
+            .or(self.steps.last())
+            .map_or_else(Easing::default, |step| step.easing)
+    }
+
+    /// Returns the full duration of the sequence, including every step and hold.
+    pub fn duration(&self) -> Duration {
+        self.steps.iter().map(Step::duration).sum()
+    }
+
+    /// Updates the sequence with details of the given `event`.
+    pub fn update(&mut self, event: Event<T>) {
+        match event {
+            Event::Settle => self.settle(),
+            Event::Tick(now) => self.tick(now),
+            Event::Target(target) => self.set_target(target),
+            Event::SettleAt(target) => self.settle_at(target),
+            // Only `Animated` values can be paused, since they stop ticking their animation.
+            Event::Pause | Event::Resume => {}
+        }
+    }
+
+    /// Interrupts the sequence, clearing any remaining steps, and animates to the new `target`
+    /// with the easing of the current step.
+    pub fn set_target(&mut self, target: T) {
+        self.set_target_at(target, crate::clock::now());
+    }
+
+    /// Interrupts the sequence like [`Sequence::set_target`] as of the given `now` instant.
+    pub fn set_target_at(&mut self, target: T, now: Instant) {
+        // Don't do anything if the target hasn't changed.
+        if self.target() == &target {
+            return;
+        }
+
+        let easing = self.easing();
+        self.restart(vec![Step::new(target, easing)], now);
+    }
+
+    /// Ends the sequence, skipping any remaining steps to settle at the final target.
+    pub fn settle(&mut self) {
+        self.value = self.target().clone();
+        self.index = self.steps.len();
+        self.elapsed = Duration::ZERO;
+    }
+
+    /// Makes the sequence immediately settle at the given `target`, clearing any steps.
+    pub fn settle_at(&mut self, target: T) {
+        self.steps.clear();
+        self.value = target;
+        self.index = 0;
+        self.elapsed = Duration::ZERO;
+    }
+
+    /// Returns a smoothed estimate of the time between recent ticks, if the sequence has been
+    /// ticked yet.
+    pub fn observed_frame_interval(&self) -> Option<Duration> {
+        self.frame_interval
+    }
+
+    /// Rebases the sequence's clock to `now` without advancing it, so the time since the last
+    /// update doesn't count towards its progress. Useful when resuming a paused sequence.
+    pub fn resume_at(&mut self, now: Instant) {
+        self.last_update = now;
+    }
+
+    /// Updates the sequence's value based on the elapsed time since the last update, moving on
+    /// to the following steps as earlier ones complete.
+    ///
+    /// If the event loop stalled since the last update, the sequence either resumes where it was
+    /// or settles at its final target depending on the [`StallPolicy`].
+    pub fn tick(&mut self, now: Instant) {
+        self.tick_with(now, Some(StallPolicy::global()));
+    }
+
+    /// Updates the sequence like [`Sequence::tick`], handling stalls with the given `policy`,
+    /// or treating every gap as a regular frame if there's no policy.
+    pub(crate) fn tick_with(&mut self, now: Instant, policy: Option<StallPolicy>) {
+        self.tick_scaled(now, policy, crate::time_scale());
+    }
+
+    /// Updates the sequence like [`Sequence::tick_with`], scaling the time since the last update by
+    /// `scale` instead of the crate-wide [time scale](crate::time_scale).
+    pub(crate) fn tick_scaled(&mut self, now: Instant, policy: Option<StallPolicy>, scale: f32) {
+        if !self.is_animating() {
+            return;
+        }
+
+        let gap = Gap::detect(self.last_update, now, self.frame_interval);
+        self.last_update = now;
+        match (gap, policy) {
+            (Gap::Stall(_), Some(StallPolicy::Pause)) => return,
+            (Gap::Stall(_), Some(StallPolicy::Settle)) => return self.settle(),
+            _ => {}
+        }
+
+        let delta = gap.duration();
+        self.frame_interval = Some(crate::animated::smooth_frame_interval(
+            self.frame_interval,
+            delta,
+        ));
+        let delta = crate::time_scale::scale(delta, scale);
+
+        // Carry any time left over from completed steps into the steps after them.
+        let mut remaining = self.elapsed + delta;
+        while let Some(step) = self.steps.get(self.index) {
+            if remaining < step.duration() {
+                break;
+            }
+
+            remaining -= step.duration();
+            self.value = step.target.clone();
+            self.start = step.target.clone();
+            self.index += 1;
+        }
+
+        let Some(step) = self.steps.get(self.index) else {
+            self.elapsed = Duration::ZERO;
+            return;
+        };
+
+        self.elapsed = remaining;
+        let progress = if step.easing.duration.is_zero() {
+            1.0
+        } else {
+            (remaining.as_secs_f32() / step.easing.duration.as_secs_f32()).min(1.0)
+        };
+
+        if progress >= 1.0 {
+            // Holding at the target of the current step.
+            self.value = step.target.clone();
+        } else {
+            self.value
+                .lerp(&self.start, &step.target, step.easing.curve.value(progress));
+        }
+    }
+
+    /// Whether this sequence still has steps to animate or hold.
+    pub fn is_animating(&self) -> bool {
+        self.index < self.steps.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: Duration = Duration::from_millis(10);
+
+    /// Moves to 10 over 300ms, holds for 100ms, moves back to 0 over 200ms, then on to 40 over
+    /// 100ms, all with linear easing.
+    fn three_steps() -> Sequence<f32> {
+        Sequence::new(0.0)
+            .then(
+                10.0,
+                Easing::LINEAR.with_duration(Duration::from_millis(300)),
+            )
+            .hold(Duration::from_millis(100))
+            .then(
+                0.0,
+                Easing::LINEAR.with_duration(Duration::from_millis(200)),
+            )
+            .then(
+                40.0,
+                Easing::LINEAR.with_duration(Duration::from_millis(100)),
+            )
+    }
+
+    /// Ticks the sequence at regular frames from `start` until `end`.
+    fn run(sequence: &mut Sequence<f32>, start: Instant, end: Duration) {
+        let mut elapsed = Duration::ZERO;
+        while elapsed < end {
+            elapsed += FRAME;
+            sequence.tick(start + elapsed);
+        }
+    }
+
+    fn assert_near(value: f32, expected: f32) {
+        assert!((value - expected).abs() < 1e-3, "{value} != {expected}");
+    }
+
+    /// Ticking through the sequence should pass through each step, hold, and then complete.
+    #[test]
+    fn three_step_sequence() {
+        let mut sequence = three_steps();
+        assert_eq!(sequence.target(), &40.0);
+        assert_eq!(sequence.duration(), Duration::from_millis(700));
+
+        let start = Instant::now();
+        sequence.resume_at(start);
+
+        run(&mut sequence, start, Duration::from_millis(150));
+        assert_eq!(sequence.current_step(), Some(0));
+        assert_near(*sequence.value(), 5.0);
+
+        // Holding at the first target.
+        run(
+            &mut sequence,
+            start + Duration::from_millis(150),
+            Duration::from_millis(200),
+        );
+        assert_eq!(sequence.current_step(), Some(0));
+        assert_eq!(*sequence.value(), 10.0);
+
+        run(
+            &mut sequence,
+            start + Duration::from_millis(350),
+            Duration::from_millis(150),
+        );
+        assert_eq!(sequence.current_step(), Some(1));
+        assert_near(*sequence.value(), 5.0);
+
+        run(
+            &mut sequence,
+            start + Duration::from_millis(500),
+            Duration::from_millis(150),
+        );
+        assert_eq!(sequence.current_step(), Some(2));
+        assert_near(*sequence.value(), 20.0);
+        assert!(sequence.is_animating());
+
+        run(
+            &mut sequence,
+            start + Duration::from_millis(650),
+            Duration::from_millis(50),
+        );
+        assert_eq!(sequence.current_step(), None);
+        assert_eq!(*sequence.value(), 40.0);
+        assert!(!sequence.is_animating());
+    }
+
+    /// A single long tick should carry over into the following steps.
+    #[test]
+    fn long_ticks_skip_steps() {
+        let mut sequence = three_steps();
+        let start = Instant::now();
+        sequence.resume_at(start);
+
+        sequence.tick_with(start + Duration::from_millis(450), None);
+        assert_eq!(sequence.current_step(), Some(1));
+        assert_near(*sequence.value(), 7.5);
+    }
+
+    /// Interrupting the sequence should clear the remaining steps and animate to the new target.
+    #[test]
+    fn set_target_clears_steps() {
+        let mut sequence = three_steps();
+        let start = Instant::now();
+        sequence.resume_at(start);
+        run(&mut sequence, start, Duration::from_millis(150));
+
+        sequence.set_target(20.0);
+        assert_eq!(sequence.steps().len(), 1);
+        assert_eq!(sequence.target(), &20.0);
+        assert_eq!(
+            sequence.easing(),
+            Easing::LINEAR.with_duration(Duration::from_millis(300))
+        );
+
+        let now = Instant::now();
+        sequence.resume_at(now);
+        sequence.tick(now + Duration::from_millis(150));
+        assert_near(*sequence.value(), 12.5);
+        sequence.tick(now + Duration::from_millis(300));
+        assert_eq!(*sequence.value(), 20.0);
+        assert!(!sequence.is_animating());
+    }
+
+    /// Settling should skip to the final target.
+    #[test]
+    fn settle() {
+        let mut sequence = three_steps();
+        sequence.settle();
+        assert_eq!(*sequence.value(), 40.0);
+        assert!(!sequence.is_animating());
+
+        sequence.settle_at(5.0);
+        assert_eq!(sequence.target(), &5.0);
+        assert!(sequence.steps().is_empty());
+    }
+
+    /// Adding steps to a finished sequence should start from its current value.
+    #[test]
+    fn push_after_completion() {
+        let mut sequence = Sequence::new(0.0).then(10.0, Easing::LINEAR);
+        sequence.settle();
+
+        sequence.push(Step::new(20.0, Easing::LINEAR));
+        assert_eq!(sequence.steps().len(), 1);
+        assert_eq!(sequence.current_step(), Some(0));
+
+        let now = Instant::now();
+        sequence.resume_at(now);
+        sequence.tick(now + Easing::LINEAR.duration / 2);
+        assert_near(*sequence.value(), 15.0);
+    }
+}
@@ -0,0 +1,181 @@
+//! A shared animation progress that several widgets can follow in lockstep.
+//!
+//! Giving each widget its own animation means each one ticks independently, which can lead to
+//! tiny phase differences between widgets that should move together. A [`SharedMotion`] is a
+//! cheaply-clonable handle to a single animated progress value that multiple `AnimationBuilder`s
+//! can be driven by, with each widget mapping the shared progress to its own value.
+//!
+//! ```rust
+//! # use iced::{Element, widget::container};
+//! # use iced_anim::{AnimationBuilder, SharedMotion, transition::Easing};
+//! # #[derive(Clone)]
+//! # enum Message {}
+//! # fn view<'a>(motion: &SharedMotion) -> Element<'a, Message> {
+//! AnimationBuilder::new(0.0_f32, |width| container("").width(width).into())
+//!     .driven_by(motion, |progress| 100.0 + 200.0 * progress)
+//!     .animates_layout(true)
+//!     .into()
+//! # }
+//! ```
+//!
+//! Only the first participant to receive a given frame's tick will advance the progress, so
+//! every widget driven by the same [`SharedMotion`] sees the exact same value each frame.
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use crate::{animated::Mode, Animated, Event};
+
+/// A shared animated progress that multiple widgets can be driven by.
+///
+/// Cloning a [`SharedMotion`] produces another handle to the same progress value.
+#[derive(Debug, Clone)]
+pub struct SharedMotion {
+    inner: Rc<RefCell<Inner>>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    /// The shared animated progress.
+    progress: Animated<f32>,
+    /// The instant of the most recent tick, used to avoid ticking more than once per frame.
+    last_tick: Option<Instant>,
+}
+
+impl SharedMotion {
+    /// Creates a new [`SharedMotion`] starting at the given `progress` and animating with `mode`.
+    pub fn new(progress: f32, mode: impl Into<Mode>) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(Inner {
+                progress: Animated::new(progress, mode),
+                last_tick: None,
+            })),
+        }
+    }
+
+    /// Returns the current shared progress.
+    pub fn progress(&self) -> f32 {
+        *self.inner.borrow().progress.value()
+    }
+
+    /// Returns the progress that the shared motion is animating towards.
+    pub fn target(&self) -> f32 {
+        *self.inner.borrow().progress.target()
+    }
+
+    /// Sets the progress that the shared motion will animate towards.
+    pub fn set_target(&self, target: f32) {
+        self.inner.borrow_mut().progress.set_target(target);
+    }
+
+    /// Sets the duration of the shared animation.
+    pub fn set_duration(&self, duration: Duration) {
+        let mut inner = self.inner.borrow_mut();
+        let progress = inner.progress.clone().with_duration(duration);
+        inner.progress = progress;
+    }
+
+    /// Whether the shared progress is still animating.
+    pub fn is_animating(&self) -> bool {
+        self.inner.borrow().progress.is_animating()
+    }
+
+    /// Updates the shared progress based on some [`Event`] that occurred.
+    pub fn update(&self, event: Event<f32>) {
+        match event {
+            Event::Tick(now) => self.tick(now),
+            event => self.inner.borrow_mut().progress.update(event),
+        }
+    }
+
+    /// Causes the shared progress to settle immediately at its target.
+    pub fn settle(&self) {
+        self.inner.borrow_mut().progress.settle();
+    }
+
+    /// Advances the shared progress to the given instant.
+    ///
+    /// Ticks at or before the most recent tick are ignored, so every participant can call this
+    /// for the same frame while the progress is only advanced once.
+    pub fn tick(&self, now: Instant) {
+        let mut inner = self.inner.borrow_mut();
+        if inner.last_tick.is_some_and(|last_tick| now <= last_tick) {
+            return;
+        }
+
+        inner.last_tick = Some(now);
+        inner.progress.tick(now);
+    }
+
+    /// Whether the two handles refer to the same shared progress.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Default for SharedMotion {
+    fn default() -> Self {
+        Self::new(0.0, Mode::default())
+    }
+}
+
+impl PartialEq for SharedMotion {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transition::Easing;
+
+    /// Clones should share the same underlying progress.
+    #[test]
+    fn clones_share_progress() {
+        let motion = SharedMotion::new(0.0, Easing::LINEAR);
+        let other = motion.clone();
+        motion.set_target(1.0);
+        assert_eq!(other.target(), 1.0);
+        assert!(other.ptr_eq(&motion));
+    }
+
+    /// Ticking twice with the same instant should only advance the progress once.
+    #[test]
+    fn double_tick_is_ignored() {
+        let motion = SharedMotion::new(0.0, Easing::LINEAR);
+        motion.set_target(1.0);
+
+        let now = Instant::now() + Duration::from_millis(100);
+        motion.tick(now);
+        let progress = motion.progress();
+        assert!(progress > 0.0);
+
+        motion.clone().tick(now);
+        assert_eq!(motion.progress(), progress);
+    }
+
+    /// Ticks from the past should be ignored.
+    #[test]
+    fn earlier_ticks_are_ignored() {
+        let motion = SharedMotion::new(0.0, Easing::LINEAR);
+        motion.set_target(1.0);
+
+        let now = Instant::now() + Duration::from_millis(200);
+        motion.tick(now);
+        let progress = motion.progress();
+
+        motion.tick(now - Duration::from_millis(100));
+        assert_eq!(motion.progress(), progress);
+    }
+
+    /// Distinct shared motions should not compare as equal.
+    #[test]
+    fn equality_is_by_identity() {
+        let motion = SharedMotion::default();
+        assert_eq!(motion, motion.clone());
+        assert_ne!(motion, SharedMotion::default());
+    }
+}
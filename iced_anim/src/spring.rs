@@ -1,28 +1,52 @@
 //! Spring physics to enable natural and interactive animations.
 pub mod motion;
 
-pub use motion::Motion;
+pub use motion::{Motion, Physics};
 use std::{
     fmt::Debug,
+    ops::Range,
     time::{Duration, Instant},
 };
 
-use crate::{event::Event, Animate};
+use crate::{
+    event::Event,
+    stall::{Gap, StallPolicy},
+    Animate,
+};
 
 /// The minimum percent at which a spring is considered near its target.
 ///
 /// This value is used to determine when a spring is near its target and has low velocity.
 /// It needs to strike a balance between stopping the animation too early and continuing
 /// needlessly when the target is effectively reached.
+///
+/// Both checks are independent of the frame rate: the remaining distance is a fraction of the
+/// initial distance, and the velocity is a fraction of the initial distance per second.
+///
+/// The fraction is clamped between the motion's [`epsilon`](Motion::epsilon) and
+/// [`max_epsilon`](Motion::max_epsilon) so very small and very large animations still settle at
+/// a sensible absolute distance.
 pub const ESPILON: f32 = 0.005;
 
-/// The maximum duration between spring updates that is allowed before clamping the time
+/// The default absolute distance and speed below which a spring component is always considered
+/// near its target. See [`Motion::with_epsilon`].
+pub const MIN_EPSILON: f32 = 0.001;
+
+/// The default largest absolute distance and speed at which a spring component can be considered
+/// near its target. See [`Motion::with_max_epsilon`].
+pub const MAX_EPSILON: f32 = 0.5;
+
+/// The default maximum duration between spring updates that is allowed before clamping the time
 /// to avoid large jumps in the spring's value.
 ///
 /// This is particularly noticeable when the window loses focus and the app stops receiving
 /// redraws, causing the next update to have a much larger duration than the last one.
+/// Springs can use a different limit with [`Motion::with_max_duration`].
 pub const MAX_DURATION: Duration = Duration::from_millis(33);
 
+/// How close the damping ratio needs to be to 1 for a spring to count as critically damped.
+const CRITICAL_DAMPING_TOLERANCE: f32 = 1e-4;
+
 /// A representation of a spring animation that interpolates between values.
 ///
 /// You can use this alongside the `Animation` widget to animate changes to your UI
@@ -39,6 +63,9 @@ pub struct Spring<T> {
     target: T,
     /// The type of motion that the spring will follow, which controls damping/stiffness.
     motion: Motion,
+    /// Motions that override `motion` for ranges of components, with later ranges taking
+    /// precedence where they overlap.
+    component_motions: Vec<(Range<usize>, Motion)>,
     /// The last instant at which this spring's value was updated.
     last_update: Instant,
     /// The current velocity components that make up this spring animation.
@@ -47,16 +74,18 @@ pub struct Spring<T> {
     /// This is used to help determine when the spring is near its target and is precomputed
     /// to avoid recalculating it every frame.
     initial_distance: Vec<f32>,
+    /// The indices of the components that are moving, which are the ones that started away from
+    /// the target or with a velocity. The rest are left untouched while ticking, e.g. the colors
+    /// a theme animation doesn't change.
+    active: Vec<usize>,
+    /// A smoothed estimate of the time between recent ticks.
+    frame_interval: Option<Duration>,
+    /// When the spring was last retargeted while it still had energy, until its next tick.
+    interrupted_at: Option<Instant>,
 }
 
 // Impls that don't require an `Animate` bound.
 impl<T> Spring<T> {
-    /// Returns an updated spring with the given `velocity`.
-    pub fn with_velocity(mut self, velocity: Vec<f32>) -> Self {
-        self.velocity = velocity;
-        self
-    }
-
     /// Returns a reference to this spring's current value.
     pub fn value(&self) -> &T {
         &self.value
@@ -67,6 +96,14 @@ impl<T> Spring<T> {
         &self.target
     }
 
+    /// Returns the current velocity of each component of the spring, in units per second.
+    ///
+    /// The velocity is kept when the target changes, so it can be handed off to another spring
+    /// with [`Spring::with_velocity`], e.g. when a dragged element is released.
+    pub fn velocity(&self) -> &[f32] {
+        &self.velocity
+    }
+
     /// Returns the spring's current [`Motion`].
     pub fn motion(&self) -> Motion {
         self.motion
@@ -77,6 +114,12 @@ impl<T> Spring<T> {
         self.last_update
     }
 
+    /// Returns a smoothed estimate of the time between recent ticks, if the spring has been
+    /// ticked yet. This adapts to displays whose refresh rate changes during an animation.
+    pub fn observed_frame_interval(&self) -> Option<Duration> {
+        self.frame_interval
+    }
+
     /// Updates the spring's `motion` to the given value.
     pub fn set_motion(&mut self, motion: Motion) {
         self.motion = motion;
@@ -87,6 +130,40 @@ impl<T> Spring<T> {
         self.motion = motion;
         self
     }
+
+    /// Returns an updated spring where the components in `range` follow their own `motion`.
+    ///
+    /// This lets parts of a value move differently, e.g. a bouncy position with a smooth color.
+    /// Types deriving `Animate` provide the range of each field with a `<field>_range()`
+    /// method, and the rest of the components keep following the spring's main motion.
+    ///
+    /// ```rust
+    /// # use iced_anim::{spring::Motion, Spring};
+    /// let spring = Spring::new(iced::Point::ORIGIN).with_component_motion(1..2, Motion::BOUNCY);
+    /// assert_eq!(spring.component_motion(0), Motion::default());
+    /// assert_eq!(spring.component_motion(1), Motion::BOUNCY);
+    /// ```
+    pub fn with_component_motion(mut self, range: Range<usize>, motion: Motion) -> Self {
+        self.set_component_motion(range, motion);
+        self
+    }
+
+    /// Makes the components in `range` follow their own `motion`, replacing any motion that was
+    /// previously set for the same range.
+    pub fn set_component_motion(&mut self, range: Range<usize>, motion: Motion) {
+        self.component_motions
+            .retain(|(existing, _)| *existing != range);
+        self.component_motions.push((range, motion));
+    }
+
+    /// Returns the [`Motion`] followed by the component at `index`.
+    pub fn component_motion(&self, index: usize) -> Motion {
+        self.component_motions
+            .iter()
+            .rev()
+            .find(|(range, _)| range.contains(&index))
+            .map_or(self.motion, |(_, motion)| *motion)
+    }
 }
 
 impl<T> Spring<T>
@@ -97,14 +174,38 @@ where
     ///
     /// Use the builder methods to customize the spring's behavior and target.
     pub fn new(value: T) -> Self {
+        Self::new_at(value, crate::clock::now())
+    }
+
+    /// Creates a new [`Spring`] with the initial `value` as of the given `now` instant.
+    ///
+    /// Along with [`Spring::set_target_at`] and [`Spring::tick`], this lets a spring be driven
+    /// entirely by synthetic instants, e.g. for deterministic tests.
+    ///
+    /// ```rust
+    /// # use std::time::{Duration, Instant};
+    /// # use iced_anim::Spring;
+    /// let start = Instant::now();
+    /// let mut spring = Spring::new_at(0.0, start);
+    /// spring.set_target_at(1.0, start);
+    /// spring.tick(start + Duration::from_millis(16));
+    /// assert!(*spring.value() > 0.0);
+    /// ```
+    pub fn new_at(value: T, now: Instant) -> Self {
+        crate::animate::debug_verify(&value);
         let motion = Motion::default();
+        let components = value.instance_components();
         Self {
             value: value.clone(),
             target: value,
             motion,
-            last_update: Instant::now(),
-            velocity: vec![0.0; T::components()],
-            initial_distance: vec![0.0; T::components()],
+            component_motions: Vec::new(),
+            last_update: now,
+            velocity: vec![0.0; components],
+            initial_distance: vec![0.0; components],
+            active: Vec::new(),
+            frame_interval: None,
+            interrupted_at: None,
         }
     }
 
@@ -114,6 +215,62 @@ where
         self
     }
 
+    /// Returns an updated spring with the given `velocity`. See [`Spring::set_velocity`].
+    pub fn with_velocity(mut self, velocity: Vec<f32>) -> Self {
+        self.set_velocity(velocity);
+        self
+    }
+
+    /// Sets the velocity of each component of the spring, in units per second.
+    ///
+    /// This starts the spring moving even if it's already at its target, e.g. to fling an element
+    /// with the velocity of the pointer when it's released. The spring overshoots in the direction
+    /// of the velocity before settling back at its target.
+    pub fn set_velocity(&mut self, velocity: Vec<f32>) {
+        self.set_velocity_at(velocity, crate::clock::now());
+    }
+
+    /// Sets the velocity of each component like [`Spring::set_velocity`] as of the given `now`
+    /// instant.
+    pub fn set_velocity_at(&mut self, velocity: Vec<f32>, now: Instant) {
+        // Avoid integrating the time the spring spent at rest, like when changing its target.
+        if !self.has_energy() {
+            self.last_update = now;
+        }
+
+        // Measure the end of the animation against how far the velocity would carry each
+        // component too, so flinging a spring at rest doesn't count as already being near its end.
+        let response = self.motion.duration().as_secs_f32();
+        self.initial_distance = self
+            .value
+            .distance_to(&self.target)
+            .into_iter()
+            .zip(&velocity)
+            .map(|(d, v)| {
+                if d.abs() >= (v * response).abs() {
+                    d
+                } else {
+                    v * response
+                }
+            })
+            .collect();
+        self.velocity = velocity;
+        self.refresh_active();
+    }
+
+    /// Recomputes which components are moving from the initial distance and velocity.
+    fn refresh_active(&mut self) {
+        self.active.clear();
+        self.active.extend(
+            self.initial_distance
+                .iter()
+                .zip(&self.velocity)
+                .enumerate()
+                .filter(|(_, (&d, &v))| d != 0.0 || v != 0.0)
+                .map(|(index, _)| index),
+        );
+    }
+
     /// A spring has energy if it has not yet reached its target or if it is still moving.
     /// This being `true` means the spring is at rest and doesn't need to be updated.
     pub fn has_energy(&self) -> bool {
@@ -146,66 +303,201 @@ where
             Event::Target(target) => self.set_target(target),
             Event::Settle => self.settle(),
             Event::SettleAt(target) => self.settle_at(target),
+            // Only `Animated` values can be paused, since they stop ticking their animation.
+            Event::Pause | Event::Resume => {}
         }
     }
 
     /// Updates the spring's value based on the elapsed time since the last update.
     /// The spring will automatically reach its target when the remaining time reaches zero.
     /// This function will do nothing if the spring has no energy.
+    ///
+    /// If the event loop stalled since the last update, the spring either resumes where it was
+    /// or settles at its target depending on the [`StallPolicy`].
     pub fn tick(&mut self, now: Instant) {
+        self.tick_with(now, Some(StallPolicy::global()));
+    }
+
+    /// Updates the spring like [`Spring::tick`], handling stalls with the given `policy`, or
+    /// treating every gap as a regular frame if there's no policy.
+    pub(crate) fn tick_with(&mut self, now: Instant, policy: Option<StallPolicy>) {
+        self.tick_scaled(now, policy, crate::time_scale());
+    }
+
+    /// Updates the spring like [`Spring::tick_with`], scaling the time since the last update by
+    /// `scale` instead of the crate-wide [time scale](crate::time_scale).
+    pub(crate) fn tick_scaled(&mut self, now: Instant, policy: Option<StallPolicy>, scale: f32) {
         // Don't attempt to update anything if the spring has no energy.
         if !self.has_energy() {
             return;
         }
 
-        let dt = now.duration_since(self.last_update).min(MAX_DURATION);
+        let interrupted_at = self.interrupted_at.take();
+        let gap = Gap::detect(self.last_update, now, self.frame_interval);
         self.last_update = now;
+        match (gap, policy) {
+            (Gap::Stall(_), Some(StallPolicy::Pause)) => return,
+            (Gap::Stall(_), Some(StallPolicy::Settle)) => {
+                self.settle();
+                return;
+            }
+            _ => {}
+        }
+
+        let mut dt = gap.duration();
+        if let (Some(interrupted_at), Some(frame)) = (interrupted_at, self.frame_interval) {
+            // Time the spring went without ticks before it was interrupted, like a skipped frame
+            // as it was settling, would make the new animation jump. A full frame is still
+            // allowed so springs retargeted right before every frame keep moving.
+            let since_interrupt = now.saturating_duration_since(interrupted_at);
+            dt = dt.min(since_interrupt.max(frame));
+        }
+        let dt = dt.min(self.motion.max_duration());
+        self.frame_interval = Some(crate::animated::smooth_frame_interval(
+            self.frame_interval,
+            dt,
+        ));
+
+        // Scale after limiting the step, so slow motion still takes small steps.
+        let dt = crate::time_scale::scale(dt, scale);
 
-        // End the animation if the spring is near the target wiht low velocity.
-        if self.is_near_end() {
+        // End the animation if the spring is near the target with low velocity.
+        // Types like `Vec<T>` can change how many components they have between targets.
+        let distance = self.target.distance_to(&self.value);
+        if self.is_near_end(&distance) {
             self.settle();
             return;
         }
 
-        // Still animating, so calculate the new velocity and update the values.
-        let velocity: Vec<f32> = self
-            .target
-            .distance_to(&self.value)
-            .into_iter()
-            .zip(self.velocity.iter().copied())
-            .map(|(d, v)| self.new_velocity(d, v, dt.as_secs_f32()))
-            .collect();
+        self.step(&distance, dt.as_secs_f32());
+    }
 
-        self.velocity.clone_from(&velocity);
-        let mut components = velocity.iter().map(|v| v * dt.as_secs_f32());
-        self.value.update(&mut components);
+    /// Rebases the spring's clock to `now` without advancing it, so the time since the last
+    /// update isn't integrated on the next tick. Useful when resuming a paused spring.
+    pub fn resume_at(&mut self, now: Instant) {
+        self.last_update = now;
     }
 
-    /// Gets the new velocity of the spring given the `displacement` and `velocity`.
-    fn new_velocity(&self, displacement: f32, velocity: f32, dt: f32) -> f32 {
-        let spring: f32 = displacement * self.motion.applied_stiffness();
-        let damping = -self.motion.applied_damping() * velocity;
+    /// Advances the spring's velocity and value by `dt` seconds, given the `distance` from the
+    /// value to the target.
+    fn step(&mut self, distance: &[f32], dt: f32) {
+        self.velocity.resize(distance.len(), 0.0);
+
+        // Only the active components move, since the rest are already at rest at their target.
+        let mut steps = Vec::with_capacity(self.active.len());
+        for &index in self.active.iter().filter(|&&index| index < distance.len()) {
+            let (d, v) = (distance[index], self.velocity[index]);
+            let motion = self.component_motion(index);
+            let (velocity, delta) = if motion.duration().is_zero() {
+                // Instant components jump straight to their target.
+                (0.0, d)
+            } else {
+                // The displacement from the target is the opposite of the distance to it.
+                let (delta, v) = Self::oscillate(motion, -d, v, dt);
+                (v, delta)
+            };
+            steps.push((index, velocity, delta));
+        }
+
+        // Degenerate motions, like raw physics without stiffness, can't produce a meaningful
+        // step, so settle instead of leaving the value stuck at NaN forever.
+        let is_finite =
+            |&(_, velocity, delta): &(usize, f32, f32)| velocity.is_finite() && delta.is_finite();
+        if !steps.iter().all(is_finite) {
+            self.settle();
+            return;
+        }
+
+        for &(index, velocity, _) in &steps {
+            self.velocity[index] = velocity;
+        }
+
+        // The active indices are sorted, so the deltas can be merged in as the value is updated.
+        let mut steps = steps.into_iter().peekable();
+        let mut deltas = (0..distance.len()).map(|index| {
+            steps
+                .next_if(|&(active, ..)| active == index)
+                .map_or(0.0, |(_, _, delta)| delta)
+        });
+        self.value.update(&mut deltas);
+    }
 
-        let acceleration = spring + damping;
+    /// Solves the damped harmonic oscillator of a component following `motion`, returning how
+    /// far its displacement from the target changes and its velocity after `dt` seconds given its
+    /// current `displacement` and `velocity`.
+    ///
+    /// The closed-form solution is exact for any `dt`, so stiff springs stay stable on slow
+    /// frames and the spring follows the same path regardless of the refresh rate. The change is
+    /// solved for directly rather than subtracting the displacements, which would round tiny
+    /// steps away entirely.
+    fn oscillate(motion: Motion, displacement: f32, velocity: f32, dt: f32) -> (f32, f32) {
+        let (stiffness, mass) = (motion.applied_stiffness(), motion.mass());
+        let omega = (stiffness / mass).sqrt();
+        let zeta = motion.applied_damping() / (2.0 * (stiffness * mass).sqrt());
+        let (x0, v0) = (displacement, velocity);
 
-        velocity + acceleration * dt
+        if (zeta - 1.0).abs() <= CRITICAL_DAMPING_TOLERANCE {
+            // Critically damped: returns as fast as possible without crossing the target.
+            let decay_m1 = (-omega * dt).exp_m1();
+            let decay = decay_m1 + 1.0;
+            let b = v0 + omega * x0;
+            (
+                x0 * decay_m1 + decay * b * dt,
+                decay * (v0 - omega * b * dt),
+            )
+        } else if zeta < 1.0 {
+            // Under-damped: oscillates around the target with a decaying amplitude.
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+            let decay_m1 = (-zeta * omega * dt).exp_m1();
+            let decay = decay_m1 + 1.0;
+            let (sin, cos) = (omega_d * dt).sin_cos();
+            // `decay * cos - 1` without cancelling out for small steps.
+            let half_sin = (omega_d * dt / 2.0).sin();
+            let decay_cos_m1 = decay_m1 * cos - 2.0 * half_sin * half_sin;
+            let dx = x0 * decay_cos_m1 + decay * (v0 + zeta * omega * x0) / omega_d * sin;
+            let v = decay * (v0 * cos - (zeta * omega * v0 + omega * omega * x0) / omega_d * sin);
+            (dx, v)
+        } else {
+            // Over-damped: creeps towards the target without oscillating.
+            let root = (zeta * zeta - 1.0).sqrt();
+            let r1 = -omega * (zeta - root);
+            let r2 = -omega * (zeta + root);
+            let c1 = (v0 - r2 * x0) / (r1 - r2);
+            let c2 = x0 - c1;
+            let (e1_m1, e2_m1) = ((r1 * dt).exp_m1(), (r2 * dt).exp_m1());
+            let (e1, e2) = (e1_m1 + 1.0, e2_m1 + 1.0);
+            (c1 * e1_m1 + c2 * e2_m1, c1 * r1 * e1 + c2 * r2 * e2)
+        }
     }
 
     /// Interrupts the existing animation and starts a new one with the `new_target`.
     pub fn set_target(&mut self, new_target: T) {
+        self.set_target_at(new_target, crate::clock::now());
+    }
+
+    /// Interrupts the existing animation and starts a new one with the `new_target` as of the
+    /// given `now` instant.
+    pub fn set_target_at(&mut self, new_target: T, now: Instant) {
         // Don't do anything if the target hasn't changed.
         if self.target == new_target {
             return;
         }
 
         // Reset the last update if the spring doesn't have any energy.
-        // This avoids resetting the last update during continuously interrupted animations.
-        if !self.has_energy() {
-            self.last_update = Instant::now();
+        // This avoids resetting the last update during continuously interrupted animations,
+        // which instead limit their next step by the time since this interruption.
+        if self.has_energy() {
+            self.interrupted_at = Some(now);
+        } else {
+            self.last_update = now;
+            self.interrupted_at = None;
         }
 
         self.target = new_target;
+        self.value.reshape(&self.target);
         self.initial_distance = self.value.distance_to(&self.target);
+        self.velocity.resize(self.initial_distance.len(), 0.0);
+        self.refresh_active();
     }
 
     /// Causes the spring to settle immediately at the target value,
@@ -214,36 +506,48 @@ where
         // Setting the `value` to the `target` ensures that the value is exactly the target value,
         // even if the curve doesn't reach it or the animation implementation isn't correct.
         self.value = self.target.clone();
-        self.velocity = vec![0.0; T::components()];
+        self.velocity = vec![0.0; self.value.instance_components()];
+        self.active.clear();
     }
 
     /// Makes the spring value and target immediately settle at the given `target`.
     pub fn settle_at(&mut self, target: T) {
         self.value = target.clone();
         self.target = target;
-        self.velocity = vec![0.0; T::components()];
+        self.velocity = vec![0.0; self.value.instance_components()];
+        self.active.clear();
     }
 
     /// Whether the spring is near the end of its animation.
     ///
     /// The animation will be stopped when the spring is near the target and has low velocity
     /// to avoid needlessly animating imperceptible changes.
-    fn is_near_end(&self) -> bool {
-        self.motion.duration().is_zero()
-            || self
-                .value
-                .distance_to(&self.target)
-                .iter()
-                .zip(&self.initial_distance)
-                .zip(&self.velocity)
-                .all(|((d, i), v)| match i {
+    fn is_near_end(&self, distance: &[f32]) -> bool {
+        // Components that aren't active started at rest at their target.
+        self.active
+            .iter()
+            .filter_map(|&index| {
+                let d = distance.get(index)?;
+                let i = self.initial_distance.get(index)?;
+                let v = self.velocity.get(index)?;
+                Some((index, (d, i), v))
+            })
+            .all(|(index, (d, i), v)| {
+                let motion = self.component_motion(index);
+                if motion.duration().is_zero() {
+                    return true;
+                }
+
+                match i {
                     0.0 => true,
                     _ => {
-                        let d_percent = (d / i).abs();
-                        let v_percent = (v / i).abs();
-                        d_percent <= ESPILON && v_percent <= ESPILON
+                        let tolerance = (i.abs() * ESPILON)
+                            .min(motion.max_epsilon())
+                            .max(motion.epsilon());
+                        d.abs() <= tolerance && v.abs() <= tolerance
                     }
-                })
+                }
+            })
     }
 }
 
@@ -300,10 +604,22 @@ mod tests {
         assert_eq!(spring.velocity, vec![0.0]);
     }
 
+    /// Updating with [`Event::SettleAt`] should jump to the new value without animating.
+    #[test]
+    fn update_settle_at() {
+        let mut spring = Spring::new(0.0).to(3.0).with_velocity(vec![1.0]);
+        spring.update(Event::settle_at(5.0));
+        assert_eq!(spring.value(), &5.0);
+        assert_eq!(spring.target(), &5.0);
+        assert!(!spring.has_energy());
+    }
+
     #[test]
     fn tick_changes_value_and_last_update_time() {
-        let mut spring = Spring::new(0.0).to(1.0);
-        let now = Instant::now();
+        let start = Instant::now();
+        let mut spring = Spring::new_at(0.0, start);
+        spring.set_target_at(1.0, start);
+        let now = start + Duration::from_millis(16);
         spring.tick(now);
 
         // Updating should move the spring's value closer to the target
@@ -379,8 +695,152 @@ mod tests {
         let spring = Spring::new(0.0).to(1.0).with_motion(Motion {
             damping: 0.5,
             response: Duration::ZERO,
+            ..Motion::SMOOTH
         });
-        assert!(spring.is_near_end());
+        assert!(spring.is_near_end(&spring.target.distance_to(&spring.value)));
+    }
+
+    /// Ticks a spring from 0 to 1 at 60Hz, starting at the given `origin`, and returns its value
+    /// after each frame.
+    fn trajectory(origin: Instant) -> Vec<f32> {
+        let mut spring = Spring::new_at(0.0_f32, origin).with_motion(Motion::BOUNCY);
+        spring.set_target_at(1.0, origin);
+        (1..=30)
+            .map(|frame| {
+                spring.tick(origin + Duration::from_micros(16_667) * frame);
+                *spring.value()
+            })
+            .collect()
+    }
+
+    /// Springs driven by synthetic instants should follow the same trajectory no matter when
+    /// they're created or what the wall clock says.
+    #[test]
+    fn synthetic_instants_are_deterministic() {
+        let origin = Instant::now();
+        let first = trajectory(origin);
+        assert_eq!(first, trajectory(origin));
+        assert_eq!(first, trajectory(origin + Duration::from_secs(3600)));
+        assert!(first[0] > 0.0 && first[0] < 0.1, "{}", first[0]);
+    }
+
+    /// Flinging a spring at rest as of an instant should time the fling from that instant
+    /// rather than the wall clock.
+    #[test]
+    fn velocity_at_instant() {
+        let start = Instant::now() + Duration::from_secs(1);
+        let mut spring = Spring::new_at(0.0_f32, start);
+        spring.set_velocity_at(vec![100.0], start + Duration::from_millis(100));
+        spring.tick(start + Duration::from_millis(116));
+        assert!(*spring.value() > 0.0 && *spring.value() < 1.6);
+    }
+
+    /// Large animations shouldn't visibly jump to their target when they settle.
+    #[test]
+    fn large_distances_settle_close_to_target() {
+        let mut spring = Spring::new(0.0_f32).to(1000.0);
+        let mut now = spring.last_update();
+        let mut last_value = *spring.value();
+        while spring.has_energy() {
+            last_value = *spring.value();
+            now += Duration::from_millis(16);
+            spring.tick(now);
+        }
+        assert!(
+            (1000.0 - last_value).abs() <= 0.5,
+            "settled from {last_value}"
+        );
+    }
+
+    /// Tiny animations shouldn't keep animating imperceptible changes.
+    #[test]
+    fn tiny_distances_settle_quickly() {
+        let mut spring = Spring::new(0.0_f32).to(0.001);
+        let mut now = spring.last_update();
+        for _ in 0..3 {
+            now += Duration::from_millis(16);
+            spring.tick(now);
+        }
+        assert!(!spring.has_energy());
+        assert_eq!(spring.value(), &0.001);
+    }
+
+    /// Ticks a spring from 0 to 1 at the given frame interval and returns how long it took
+    /// to settle in simulated wall-clock time.
+    fn settle_time(frame: Duration) -> Duration {
+        let mut spring = Spring::new(0.0_f32).to(1.0);
+        let start = spring.last_update();
+        let mut now = start;
+        while spring.has_energy() {
+            now += frame;
+            spring.tick(now);
+            assert!(now - start < Duration::from_secs(5), "spring never settled");
+        }
+        now - start
+    }
+
+    /// Springs should settle at roughly the same time regardless of the refresh rate.
+    #[test]
+    fn settle_time_is_refresh_rate_independent() {
+        let tolerance = Duration::from_millis(50).as_secs_f32();
+        let at_30hz = settle_time(Duration::from_micros(33_333)).as_secs_f32();
+        let at_60hz = settle_time(Duration::from_micros(16_667)).as_secs_f32();
+        let at_120hz = settle_time(Duration::from_micros(8_333)).as_secs_f32();
+
+        assert!(
+            (at_30hz - at_60hz).abs() <= tolerance,
+            "{at_30hz} vs {at_60hz}"
+        );
+        assert!(
+            (at_60hz - at_120hz).abs() <= tolerance,
+            "{at_60hz} vs {at_120hz}"
+        );
+    }
+
+    /// The observed frame interval should converge on the rate the spring is ticked at.
+    #[test]
+    fn observed_frame_interval() {
+        let mut spring = Spring::new(0.0_f32).to(100.0);
+        assert_eq!(spring.observed_frame_interval(), None);
+
+        let frame = Duration::from_millis(8);
+        let mut now = spring.last_update();
+        for _ in 0..30 {
+            now += frame;
+            spring.tick(now);
+        }
+
+        let interval = spring.observed_frame_interval().unwrap();
+        assert!((interval.as_secs_f32() - frame.as_secs_f32()).abs() < 0.001);
+    }
+
+    /// Resuming after a pause should hold the value during the pause and then continue exactly
+    /// as if the paused time never happened.
+    #[test]
+    fn resume_skips_paused_time() {
+        let frame = Duration::from_micros(16_667);
+        let pause = Duration::from_secs(2);
+        let mut reference = Spring::new(0.0_f32).to(1.0);
+        let mut paused = reference.clone();
+
+        let mut now = reference.last_update();
+        for _ in 0..5 {
+            now += frame;
+            reference.tick(now);
+            paused.tick(now);
+        }
+
+        // The paused spring isn't ticked, so its value is untouched.
+        let frozen_value = paused.value().to_bits();
+        paused.resume_at(now + pause);
+        assert_eq!(paused.value().to_bits(), frozen_value);
+
+        for _ in 0..5 {
+            now += frame;
+            reference.tick(now);
+            paused.tick(now + pause);
+            assert_eq!(paused.value().to_bits(), reference.value().to_bits());
+        }
     }
 
     /// A spring with a response of zero should settle immediately.
@@ -390,8 +850,429 @@ mod tests {
         spring.set_motion(Motion {
             response: Duration::ZERO,
             damping: 0.5,
+            ..Motion::SMOOTH
         });
         spring.update(Event::Tick(Instant::now()));
         assert_eq!(spring.value(), spring.target());
     }
+
+    /// Components that start at their target shouldn't be touched, while the rest still settle.
+    #[test]
+    fn mostly_settled_components() {
+        let start = [1.5_f32; 64];
+        let mut target = start;
+        target[3] = 10.0;
+        target[40] = -2.0;
+
+        let mut spring = Spring::new(start).to(target);
+        assert_eq!(spring.active, vec![3, 40]);
+
+        let mut now = spring.last_update();
+        let mut ticks = 0;
+        while spring.has_energy() && ticks < 600 {
+            now += Duration::from_millis(16);
+            spring.tick_with(now, None);
+            let value = spring.value();
+            assert!((0..64)
+                .filter(|index| ![3, 40].contains(index))
+                .all(|index| value[index] == 1.5));
+            ticks += 1;
+        }
+        assert_eq!(spring.value(), &target);
+        assert!(spring.active.is_empty());
+    }
+
+    /// Flinging a component at rest should make only that component active.
+    #[test]
+    fn velocity_activates_components() {
+        let spring = Spring::new(iced::Point::ORIGIN).with_velocity(vec![0.0, 100.0]);
+        assert_eq!(spring.active, vec![1]);
+    }
+
+    /// Ticks the spring at 60fps for up to ten seconds, asserting that its value stays finite,
+    /// and returns whether it settled.
+    fn settles_finitely(spring: &mut Spring<f32>) -> bool {
+        let mut now = spring.last_update();
+        for _ in 0..600 {
+            now += Duration::from_millis(16);
+            spring.tick_with(now, None);
+            assert!(spring.value().is_finite(), "{spring:?}");
+            assert!(
+                spring.velocity().iter().all(|v| v.is_finite()),
+                "{spring:?}"
+            );
+            if !spring.has_energy() {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Switching to a zero or tiny response in the middle of an animation shouldn't produce
+    /// non-finite values.
+    #[test]
+    fn zero_response_interrupted() {
+        for response in [Duration::ZERO, Duration::from_nanos(1)] {
+            let mut spring = Spring::new(0.0_f32).to(100.0);
+            let start = spring.last_update();
+            for frame in 1..=5 {
+                spring.tick_with(start + Duration::from_millis(16 * frame), None);
+            }
+            spring.set_motion(Motion::SMOOTH.with_duration(response));
+            spring.set_target(-50.0);
+            assert!(settles_finitely(&mut spring), "{response:?}");
+            assert_eq!(*spring.value(), -50.0);
+        }
+    }
+
+    /// Springs without damping, or with negative damping, should still come to a stop.
+    #[test]
+    fn undamped_springs_settle() {
+        for damping in [0.0, -1.0] {
+            let motion = Motion::SMOOTH
+                .with_duration(Duration::from_millis(100))
+                .with_damping(damping);
+            let mut spring = Spring::new(0.0_f32).with_motion(motion).to(1.0);
+            assert!(settles_finitely(&mut spring), "{damping}");
+            assert_eq!(*spring.value(), 1.0);
+        }
+    }
+
+    /// Raw physics that can't be solved should settle at the target instead of becoming NaN.
+    #[test]
+    fn degenerate_physics_settle() {
+        let motion = Motion::SMOOTH.with_physics(Physics::new(0.0, 10.0));
+        let mut spring = Spring::new(0.0_f32).with_motion(motion).to(1.0);
+        assert!(settles_finitely(&mut spring));
+        assert_eq!(*spring.value(), 1.0);
+    }
+
+    /// Ticks a spring towards 1 at 60fps for a while and then once more after a long stall.
+    fn tick_through_stall(policy: StallPolicy) -> (f32, Spring<f32>) {
+        let frame = Duration::from_millis(16);
+        let mut spring = Spring::new(0.0_f32).to(1.0);
+        let mut now = spring.last_update();
+        for _ in 0..5 {
+            now += frame;
+            spring.tick_with(now, Some(policy));
+        }
+
+        let before = *spring.value();
+        spring.tick_with(now + Duration::from_secs(2), Some(policy));
+        (before, spring)
+    }
+
+    /// Pausing should continue the spring from where it was before the stall.
+    #[test]
+    fn stall_pauses_spring() {
+        let (before, mut spring) = tick_through_stall(StallPolicy::Pause);
+        assert_eq!(*spring.value(), before);
+        assert!(spring.has_energy());
+
+        spring.tick_with(spring.last_update() + Duration::from_millis(16), None);
+        assert!(*spring.value() > before);
+    }
+
+    /// Settling should jump the spring to its target after a stall.
+    #[test]
+    fn stall_settles_spring() {
+        let (before, spring) = tick_through_stall(StallPolicy::Settle);
+        assert!(before < 1.0);
+        assert_eq!(*spring.value(), 1.0);
+        assert!(!spring.has_energy());
+    }
+
+    /// Ticks a spring from the origin towards `(10, 10)` for a few frames.
+    fn tick_point(mut spring: Spring<iced::Point>) -> iced::Point {
+        let mut now = spring.last_update();
+        for _ in 0..10 {
+            now += Duration::from_millis(16);
+            spring.tick(now);
+        }
+        *spring.value()
+    }
+
+    /// Components with their own motion should move independently of the rest of the spring.
+    #[test]
+    fn component_motions() {
+        let slow = Motion::SMOOTH.with_duration(Duration::from_secs(2));
+        let spring = Spring::new(iced::Point::ORIGIN)
+            .to(iced::Point::new(10.0, 10.0))
+            .with_motion(Motion::SNAPPY)
+            .with_component_motion(1..2, slow);
+        assert_eq!(spring.component_motion(0), Motion::SNAPPY);
+        assert_eq!(spring.component_motion(1), slow);
+
+        let value = tick_point(spring);
+        assert!(value.x > value.y && value.y > 0.0);
+    }
+
+    /// Later component motions should replace earlier ones for the same range.
+    #[test]
+    fn component_motions_are_replaced() {
+        let spring = Spring::new(0.0_f32)
+            .with_component_motion(0..1, Motion::BOUNCY)
+            .with_component_motion(0..1, Motion::SNAPPY);
+        assert_eq!(spring.component_motions.len(), 1);
+        assert_eq!(spring.component_motion(0), Motion::SNAPPY);
+    }
+
+    /// Instant components should jump to their target while the rest keep animating.
+    #[test]
+    fn instant_component_motion() {
+        let spring = Spring::new(iced::Point::ORIGIN)
+            .to(iced::Point::new(10.0, 10.0))
+            .with_component_motion(0..1, Motion::INSTANT);
+
+        let value = tick_point(spring);
+        assert_eq!(value.x, 10.0);
+        assert!(value.y < 10.0);
+    }
+
+    /// Ticks a spring at 60fps until it settles, returning the smallest and largest `x` values
+    /// that it passed through.
+    fn fling(mut spring: Spring<iced::Point>) -> (f32, f32, Spring<iced::Point>) {
+        let (mut min, mut max) = (spring.value().x, spring.value().x);
+        let start = spring.last_update();
+        let mut now = start;
+        while spring.has_energy() {
+            now += Duration::from_millis(16);
+            spring.tick(now);
+            assert_eq!(
+                spring.value().y,
+                0.0,
+                "only the flung component should move"
+            );
+            min = min.min(spring.value().x);
+            max = max.max(spring.value().x);
+            assert!(now - start < Duration::from_secs(5), "spring never settled");
+        }
+        (min, max, spring)
+    }
+
+    /// Flinging a spring at rest should move it in the direction of the velocity and come back.
+    #[test]
+    fn fling_at_rest_overshoots() {
+        let spring = Spring::new(iced::Point::ORIGIN).with_velocity(vec![500.0, 0.0]);
+        assert!(spring.has_energy());
+
+        let (min, max, spring) = fling(spring);
+        assert!(
+            max > 10.0,
+            "the fling should carry the value away from its target"
+        );
+        assert!(min >= -max);
+        assert_eq!(spring.value(), &iced::Point::ORIGIN);
+        assert_eq!(spring.velocity(), &[0.0, 0.0]);
+    }
+
+    /// Flinging away from the target should overshoot in the direction of the velocity first.
+    #[test]
+    fn fling_against_target_overshoots() {
+        let target = iced::Point::new(100.0, 0.0);
+        let spring = Spring::new(iced::Point::ORIGIN)
+            .to(target)
+            .with_velocity(vec![-800.0, 0.0]);
+
+        let (min, _, spring) = fling(spring);
+        assert!(min < 0.0);
+        assert_eq!(spring.value(), &target);
+    }
+
+    /// The velocity should be kept when the target changes so it can be handed off.
+    #[test]
+    fn velocity_survives_interruptions() {
+        let mut spring = Spring::new(0.0_f32).to(100.0);
+        let mut now = spring.last_update();
+        for _ in 0..5 {
+            now += Duration::from_millis(16);
+            spring.tick(now);
+        }
+
+        let velocity = spring.velocity().to_vec();
+        assert!(velocity[0] > 0.0);
+        spring.set_target(50.0);
+        assert_eq!(spring.velocity(), velocity.as_slice());
+
+        let handoff = Spring::new(*spring.value()).with_velocity(velocity.clone());
+        assert_eq!(handoff.velocity(), velocity.as_slice());
+    }
+
+    /// Critically and over-damped springs should never cross their target, even when stiff
+    /// springs are ticked on slow frames.
+    #[test]
+    fn damped_springs_never_cross_target() {
+        for damping in [1.0, 2.0] {
+            let motion = Motion::SMOOTH
+                .with_damping(damping)
+                .with_duration(Duration::from_millis(100));
+            let mut spring = Spring::new(0.0_f32).to(1.0).with_motion(motion);
+            let start = spring.last_update();
+            let mut now = start;
+            while spring.has_energy() {
+                now += MAX_DURATION;
+                spring.tick_with(now, None);
+                assert!(
+                    *spring.value() <= 1.0,
+                    "{} crossed the target",
+                    spring.value()
+                );
+                assert!(now - start < Duration::from_secs(5), "spring never settled");
+            }
+        }
+    }
+
+    /// Ticks a spring from 0 to 1 with the given `motion` in `frame` steps for 160ms, returning
+    /// its value and velocity.
+    fn tick_for_160ms(motion: Motion, frame: Duration) -> (f32, f32) {
+        let mut spring = Spring::new(0.0_f32).to(1.0).with_motion(motion);
+        let start = spring.last_update();
+        let mut now = start;
+        while now - start < Duration::from_millis(160) {
+            now += frame;
+            spring.tick_with(now, None);
+        }
+        (*spring.value(), spring.velocity()[0])
+    }
+
+    /// The spring should follow the same path no matter how often it's ticked.
+    #[test]
+    fn motion_is_tick_granularity_independent() {
+        for motion in [Motion::SMOOTH, Motion::SNAPPY, Motion::BOUNCY] {
+            let (fine_value, fine_velocity) = tick_for_160ms(motion, Duration::from_millis(1));
+            let (coarse_value, coarse_velocity) = tick_for_160ms(motion, Duration::from_millis(16));
+            assert!(
+                (fine_value - coarse_value).abs() < 1e-3,
+                "{fine_value} vs {coarse_value}"
+            );
+            assert!(
+                (fine_velocity - coarse_velocity).abs() < 1e-2,
+                "{fine_velocity} vs {coarse_velocity}"
+            );
+        }
+    }
+
+    /// Springs should only clamp long frames to their motion's max duration.
+    #[test]
+    fn max_duration_is_configurable() {
+        let tick_200ms = |motion: Motion| {
+            let mut spring = Spring::new(0.0_f32).to(1.0).with_motion(motion);
+            let now = spring.last_update() + Duration::from_millis(200);
+            spring.tick_with(now, None);
+            *spring.value()
+        };
+
+        let clamped = tick_200ms(Motion::SMOOTH);
+        let loose = tick_200ms(Motion::SMOOTH.with_max_duration(Duration::from_millis(100)));
+        let unclamped = tick_200ms(Motion::SMOOTH.with_max_duration(Duration::MAX));
+        assert!(clamped < loose && loose < unclamped);
+        assert!(
+            unclamped > 0.5,
+            "200ms should cover a large part of a 500ms spring"
+        );
+    }
+
+    /// Ticks a spring following `motion` from 0 to 100 at 60 Hz and returns how long it took
+    /// to settle in simulated wall-clock time.
+    fn settle_time_with(motion: Motion) -> f32 {
+        let mut spring = Spring::new(0.0_f32).to(100.0).with_motion(motion);
+        let start = spring.last_update();
+        let mut now = start;
+        while spring.has_energy() {
+            now += Duration::from_micros(16_667);
+            spring.tick_with(now, None);
+            assert!(now - start < Duration::from_secs(5), "spring never settled");
+        }
+        (now - start).as_secs_f32()
+    }
+
+    /// A react-spring spec of stiffness 170 and damping 26 is nearly critically damped, so it
+    /// should settle like the equivalent duration-based motion in about three quarters of a second.
+    #[test]
+    fn physics_settle_time() {
+        let physics = Physics::new(170.0, 26.0);
+        let settle_time = settle_time_with(Motion::from(physics));
+        assert!(
+            (settle_time - 0.75).abs() <= 0.1,
+            "settled in {settle_time}s"
+        );
+
+        let equivalent = Motion::SMOOTH
+            .with_duration(physics.response())
+            .with_damping(physics.damping_ratio());
+        let equivalent_time = settle_time_with(equivalent);
+        assert!(
+            (settle_time - equivalent_time).abs() <= 0.02,
+            "{settle_time} vs {equivalent_time}"
+        );
+
+        // Heavier springs are slower to settle.
+        let heavy_time = settle_time_with(Motion::from(physics.with_mass(4.0)));
+        assert!(heavy_time > settle_time, "{heavy_time} vs {settle_time}");
+    }
+
+    /// Long frames should be limited before the time scale applies, so slow motion advances by
+    /// a fraction of the limit instead of the whole frame.
+    #[test]
+    fn time_scale_applies_after_max_duration() {
+        let start = Instant::now();
+        let mut slowed = Spring::new_at(0.0_f32, start).to(1.0);
+        slowed.tick_scaled(start + Duration::from_millis(200), None, 0.5);
+
+        let mut reference = Spring::new_at(0.0_f32, start).to(1.0);
+        reference.tick_scaled(start + MAX_DURATION / 2, None, 1.0);
+        assert!((slowed.value() - reference.value()).abs() < 1e-4);
+    }
+
+    /// Ticks a spring at 60Hz from rest towards `1.0` until only a little energy remains.
+    fn nearly_settled(start: Instant, frame: Duration) -> (Spring<f32>, Instant) {
+        let mut spring = Spring::new_at(0.0_f32, start);
+        spring.set_target_at(1.0, start);
+        let mut now = start;
+        while (1.0 - spring.value()).abs() > 0.01 {
+            now += frame;
+            spring.tick(now);
+        }
+        assert!(spring.has_energy());
+        (spring, now)
+    }
+
+    /// Interrupting a spring with residual energy after it missed a frame shouldn't count the
+    /// missed frame towards the first step of the new animation.
+    #[test]
+    fn interrupt_after_missed_frame_doesnt_jump() {
+        let frame = Duration::from_millis(16);
+        let (mut spring, last_tick) = nearly_settled(Instant::now(), frame);
+
+        // The tick at `last_tick + frame` never happens before the spring is interrupted.
+        let mut reference = spring.clone();
+        spring.set_target_at(2.0, last_tick + frame);
+        spring.tick(last_tick + frame * 2);
+
+        // Only the frame since the interruption should have been integrated.
+        reference.resume_at(last_tick + frame);
+        reference.set_target_at(2.0, last_tick + frame);
+        reference.tick(last_tick + frame * 2);
+        assert!((spring.value() - reference.value()).abs() < 1e-6);
+        assert_eq!(spring.velocity(), reference.velocity());
+    }
+
+    /// Springs retargeted right before every tick should still advance by a full frame.
+    #[test]
+    fn continuous_interrupts_keep_moving() {
+        let frame = Duration::from_millis(16);
+        let start = Instant::now();
+        let mut interrupted = Spring::new_at(0.0_f32, start);
+        interrupted.set_target_at(10.0, start);
+        let mut reference = interrupted.clone();
+
+        for index in 1..=10 {
+            let now = start + frame * index;
+            interrupted.set_target_at(10.0 + index as f32 * 1e-3, now - Duration::from_millis(1));
+            interrupted.tick(now);
+            reference.set_target_at(10.0 + index as f32 * 1e-3, now - frame);
+            reference.tick(now);
+        }
+        assert!((interrupted.value() - reference.value()).abs() < 1e-3);
+    }
 }
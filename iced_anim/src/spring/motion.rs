@@ -1,20 +1,117 @@
 //! Motion that defines how a spring animation will behave.
-use std::time::Duration;
+use std::{f32::consts::TAU, time::Duration};
 
-use crate::animated::DEFAULT_DURATION;
+use crate::{
+    animated::DEFAULT_DURATION,
+    spring::{MAX_DURATION, MAX_EPSILON, MIN_EPSILON},
+};
+
+/// The smallest damping fraction applied to a spring, so that it always comes to a stop.
+///
+/// Springs without any damping would oscillate around their target forever, and negative
+/// damping would make them fly away from it.
+const MIN_DAMPING: f32 = 0.05;
+
+/// The shortest response, in seconds, used to compute the stiffness and damping of a spring.
+///
+/// Shorter responses produce such large forces that a single step can overflow. A response of
+/// exactly zero still moves the spring straight to its target.
+const MIN_RESPONSE: f32 = 0.001;
 
 /// The motion associated with a spring animation.
+///
+/// With the `serde` feature, durations are (de)serialized as milliseconds and missing fields
+/// fall back to [`Motion::SMOOTH`].
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Motion {
     /// The fractional amount of drag applied needed to produce critical damping.
     /// A value of 1 will smoothly decelerate the spring to its target, while values
     /// less than 1 will cause the spring to oscillate around the target more before
-    /// coming to a stop.
+    /// coming to a stop. Values below 0.05, including negative ones, are treated as 0.05 so the
+    /// spring always settles.
     pub damping: f32,
     /// The stiffness of the spring, defined as an approximate duration.
     /// A value of zero requests an infinitely-stiff spring, suitable for driving
     /// interactive animations.
+    #[cfg_attr(feature = "serde", serde(with = "crate::millis"))]
     pub response: Duration,
+    /// The longest time between ticks that the spring advances by in a single update, which
+    /// avoids large jumps after long frames. Use [`Duration::MAX`] to disable clamping, e.g. for
+    /// apps that intentionally tick slowly or headless simulations with large steps.
+    #[cfg_attr(feature = "serde", serde(with = "crate::millis"))]
+    pub max_duration: Duration,
+    /// The absolute distance and speed below which a component is always considered settled,
+    /// no matter how small its animation is. This stops tiny animations from animating
+    /// imperceptible changes for many frames.
+    pub epsilon: f32,
+    /// The largest absolute distance and speed at which a component can be considered settled,
+    /// no matter how large its animation is. This stops large animations from visibly jumping
+    /// to their target when they settle.
+    pub max_epsilon: f32,
+    /// The raw physical parameters of the spring, if it was created from them with
+    /// [`Motion::with_physics`]. These take precedence over the `response` and `damping`.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub physics: Option<Physics>,
+}
+
+/// The physical parameters of a spring, like those used by react-spring and Framer Motion.
+///
+/// A [`Motion`] can be created from these with [`Motion::with_physics`] or [`Motion::from`], and
+/// any motion can be converted back with [`Motion::physics`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Physics {
+    /// How strongly the spring pulls the value towards its target.
+    pub stiffness: f32,
+    /// The friction that slows the spring down, as a coefficient rather than a fraction.
+    pub damping: f32,
+    /// The mass attached to the spring. Heavier masses move slower and overshoot more.
+    #[cfg_attr(feature = "serde", serde(default = "Physics::default_mass"))]
+    pub mass: f32,
+}
+
+impl Physics {
+    /// Creates new spring [`Physics`] with the given `stiffness` and `damping` and a mass of 1.
+    pub fn new(stiffness: f32, damping: f32) -> Self {
+        Self {
+            stiffness,
+            damping,
+            mass: Self::default_mass(),
+        }
+    }
+
+    /// Sets the `mass` attached to the spring.
+    pub fn with_mass(mut self, mass: f32) -> Self {
+        self.mass = mass;
+        self
+    }
+
+    /// The duration of one oscillation of the spring without any damping, which is what
+    /// [`Motion::response`] describes.
+    ///
+    /// Springs without any stiffness never oscillate, so their response is [`Duration::MAX`].
+    pub fn response(&self) -> Duration {
+        Duration::try_from_secs_f32(TAU * (self.mass / self.stiffness).sqrt())
+            .unwrap_or(Duration::MAX)
+    }
+
+    /// The damping of the spring as a fraction of critical damping, which is what
+    /// [`Motion::damping`] describes.
+    pub fn damping_ratio(&self) -> f32 {
+        self.damping / (2.0 * (self.stiffness * self.mass).sqrt())
+    }
+
+    fn default_mass() -> f32 {
+        1.0
+    }
+}
+
+impl From<Physics> for Motion {
+    fn from(physics: Physics) -> Self {
+        Motion::SMOOTH.with_physics(physics)
+    }
 }
 
 impl Motion {
@@ -22,35 +119,113 @@ impl Motion {
     pub const SMOOTH: Self = Self {
         damping: 1.0,
         response: DEFAULT_DURATION,
+        max_duration: MAX_DURATION,
+        epsilon: MIN_EPSILON,
+        max_epsilon: MAX_EPSILON,
+        physics: None,
     };
 
     /// A small overshoot of the target before settling.
     pub const SNAPPY: Self = Self {
         damping: 0.85,
         response: DEFAULT_DURATION,
+        max_duration: MAX_DURATION,
+        epsilon: MIN_EPSILON,
+        max_epsilon: MAX_EPSILON,
+        physics: None,
     };
 
     /// A bouncier animation where the value overshoots the target before settling.
     pub const BOUNCY: Self = Self {
         damping: 0.7,
         response: DEFAULT_DURATION,
+        max_duration: MAX_DURATION,
+        epsilon: MIN_EPSILON,
+        max_epsilon: MAX_EPSILON,
+        physics: None,
+    };
+
+    /// A very stiff, critically damped motion for values that track the pointer while dragging.
+    ///
+    /// The value follows the pointer closely without lagging behind, while still keeping its
+    /// velocity so that switching to a softer motion when the pointer is released carries the
+    /// momentum of the drag into the next animation. See [`Animated::set_mode`].
+    ///
+    /// [`Animated::set_mode`]: crate::Animated::set_mode
+    pub const INTERACTIVE: Self = Self {
+        damping: 1.0,
+        response: Duration::from_millis(150),
+        max_duration: MAX_DURATION,
+        epsilon: MIN_EPSILON,
+        max_epsilon: MAX_EPSILON,
+        physics: None,
     };
 
     /// A motion that causes all animations to transition instantly.
     pub const INSTANT: Self = Self {
         damping: 1.0,
         response: Duration::ZERO,
+        max_duration: MAX_DURATION,
+        epsilon: MIN_EPSILON,
+        max_epsilon: MAX_EPSILON,
+        physics: None,
     };
 
     /// Create a custom spring motion with the given response `duration`.
     pub fn with_duration(mut self, duration: Duration) -> Self {
         self.response = duration;
+        self.physics = None;
         self
     }
 
     /// Create a custom spring motion with the given `damping` fraction.
     pub fn with_damping(mut self, damping: f32) -> Self {
         self.damping = damping;
+        self.physics = None;
+        self
+    }
+
+    /// Create a custom spring motion from raw physical parameters, e.g. to match an animation
+    /// spec from react-spring or Framer Motion.
+    ///
+    /// The `response` and `damping` are updated to the equivalent duration and damping fraction,
+    /// while the spring itself uses the raw parameters.
+    ///
+    /// ```rust
+    /// # use iced_anim::spring::{Motion, Physics};
+    /// let motion = Motion::SMOOTH.with_physics(Physics::new(170.0, 26.0));
+    /// assert_eq!(motion.applied_stiffness(), 170.0);
+    /// assert!((motion.damping() - 1.0).abs() < 0.01);
+    /// ```
+    pub fn with_physics(mut self, physics: Physics) -> Self {
+        self.response = physics.response();
+        self.damping = physics.damping_ratio();
+        self.physics = Some(physics);
+        self
+    }
+
+    /// Create a custom spring motion that advances by at most `max_duration` per tick.
+    ///
+    /// Use [`Duration::MAX`] to disable clamping entirely.
+    pub fn with_max_duration(mut self, max_duration: Duration) -> Self {
+        self.max_duration = max_duration;
+        self
+    }
+
+    /// Create a custom spring motion that always settles once its remaining distance and speed
+    /// are within `epsilon`.
+    ///
+    /// Values with very small units, like colors, may want a smaller epsilon, while values with
+    /// large units can use a larger one to avoid animating imperceptible changes.
+    pub fn with_epsilon(mut self, epsilon: f32) -> Self {
+        self.epsilon = epsilon;
+        self
+    }
+
+    /// Create a custom spring motion that only settles once its remaining distance and speed are
+    /// within `max_epsilon`, no matter how far it moved.
+    pub fn with_max_epsilon(mut self, max_epsilon: f32) -> Self {
+        self.max_epsilon = max_epsilon;
         self
     }
 
@@ -66,16 +241,54 @@ impl Motion {
         self.damping
     }
 
-    /// The amount of stiffness applied to the spring, which varies based on the `duration`.
+    /// The longest time between ticks that the spring advances by in a single update.
+    pub fn max_duration(&self) -> Duration {
+        self.max_duration
+    }
+
+    /// The absolute distance and speed below which a component is always considered settled.
+    pub fn epsilon(&self) -> f32 {
+        self.epsilon
+    }
+
+    /// The largest absolute distance and speed at which a component can be considered settled.
+    pub fn max_epsilon(&self) -> f32 {
+        self.max_epsilon
+    }
+
+    /// The mass attached to the spring, which is 1 unless set with [`Motion::with_physics`].
+    pub fn mass(&self) -> f32 {
+        self.physics.map_or(1.0, |physics| physics.mass)
+    }
+
+    /// The physical parameters of the spring, converting the `response` and `damping` to an
+    /// equivalent spring with a mass of 1 if it wasn't created with [`Motion::with_physics`].
+    pub fn physics(&self) -> Physics {
+        self.physics.unwrap_or_else(|| Physics {
+            stiffness: self.applied_stiffness(),
+            damping: self.applied_damping(),
+            mass: 1.0,
+        })
+    }
+
+    /// The amount of stiffness applied to the spring, which varies based on the `duration`
+    /// unless the motion has raw [`Physics`].
     pub fn applied_stiffness(&self) -> f32 {
-        let duration_fraction = self.duration().as_secs_f32();
+        if let Some(physics) = self.physics {
+            return physics.stiffness;
+        }
+        let duration_fraction = self.duration().as_secs_f32().max(MIN_RESPONSE);
         39.478_416 / duration_fraction.powi(2)
     }
 
-    /// The amount of damping applied to the spring, which varies based on the `duration`.
+    /// The amount of damping applied to the spring, which varies based on the `duration`
+    /// unless the motion has raw [`Physics`].
     pub fn applied_damping(&self) -> f32 {
-        let duration = self.duration().as_secs_f32();
-        self.damping() * 12.566_371 / duration
+        if let Some(physics) = self.physics {
+            return physics.damping;
+        }
+        let duration = self.duration().as_secs_f32().max(MIN_RESPONSE);
+        self.damping().max(MIN_DAMPING) * 12.566_371 / duration
     }
 }
 
@@ -102,7 +315,8 @@ mod tests {
             motion,
             Motion {
                 response: Duration::from_millis(300),
-                damping: Motion::SMOOTH.damping()
+                damping: Motion::SMOOTH.damping(),
+                ..Motion::SMOOTH
             }
         );
     }
@@ -114,7 +328,8 @@ mod tests {
             motion,
             Motion {
                 response: Motion::SMOOTH.duration(),
-                damping: 0.5
+                damping: 0.5,
+                ..Motion::SMOOTH
             }
         );
     }
@@ -127,7 +342,8 @@ mod tests {
         assert_eq!(
             Motion {
                 response: Duration::from_millis(300),
-                damping: 0.5
+                damping: 0.5,
+                ..Motion::SMOOTH
             }
             .duration(),
             Duration::from_millis(300)
@@ -142,7 +358,8 @@ mod tests {
         assert_eq!(
             Motion {
                 response: Duration::from_millis(300),
-                damping: 0.5
+                damping: 0.5,
+                ..Motion::SMOOTH
             }
             .damping(),
             0.5
@@ -154,6 +371,7 @@ mod tests {
         let motion = Motion {
             response: Duration::from_millis(500),
             damping: 1.0,
+            ..Motion::SMOOTH
         };
         assert_eq!(motion.applied_damping().trunc(), 25.0);
         assert_eq!(motion.applied_stiffness().trunc(), 157.0);
@@ -176,14 +394,119 @@ mod tests {
         let motion = Motion {
             response: Duration::from_millis(250),
             damping: 0.75,
+            ..Motion::SMOOTH
         };
         assert_eq!(motion.applied_damping().trunc(), 37.0);
         assert_eq!(motion.applied_stiffness().trunc(), 631.0);
     }
 
+    #[test]
+    fn with_max_duration() {
+        assert_eq!(Motion::SMOOTH.max_duration(), MAX_DURATION);
+        let motion = Motion::SMOOTH.with_max_duration(Duration::MAX);
+        assert_eq!(
+            motion,
+            Motion {
+                max_duration: Duration::MAX,
+                ..Motion::SMOOTH
+            }
+        );
+    }
+
+    #[test]
+    fn with_epsilon() {
+        assert_eq!(Motion::SMOOTH.epsilon(), MIN_EPSILON);
+        assert_eq!(Motion::SMOOTH.max_epsilon(), MAX_EPSILON);
+        let motion = Motion::SMOOTH.with_epsilon(0.1).with_max_epsilon(2.0);
+        assert_eq!(
+            motion,
+            Motion {
+                epsilon: 0.1,
+                max_epsilon: 2.0,
+                ..Motion::SMOOTH
+            }
+        );
+    }
+
     /// [MotionINSTANT] should have zero duration and the default damping.
     #[test]
     fn instant() {
         assert_eq!(Motion::INSTANT.duration(), Duration::ZERO);
     }
+
+    /// Durations should be serialized as milliseconds.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let motion = Motion::BOUNCY.with_duration(Duration::from_millis(300));
+        let json = serde_json::to_value(motion).unwrap();
+        assert_eq!(json["response"], 300);
+        assert_eq!(serde_json::from_value::<Motion>(json).unwrap(), motion);
+    }
+
+    /// [`Duration::MAX`] doesn't fit in milliseconds, but should still round-trip.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_unclamped_round_trip() {
+        let motion = Motion::SMOOTH.with_max_duration(Duration::MAX);
+        let json = serde_json::to_string(&motion).unwrap();
+        assert_eq!(serde_json::from_str::<Motion>(&json).unwrap(), motion);
+    }
+
+    /// Raw physics should round-trip, with the mass defaulting to 1. The derived response is
+    /// only kept to the millisecond, but the spring follows the raw physics anyway.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_physics_round_trip() {
+        let motion = Motion::from(Physics::new(170.0, 26.0).with_mass(2.0));
+        let json = serde_json::to_string(&motion).unwrap();
+        let deserialized: Motion = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.physics, motion.physics);
+        assert_eq!(
+            deserialized.response.as_millis(),
+            motion.response.as_millis()
+        );
+
+        let json = r#"{ "stiffness": 170, "damping": 26 }"#;
+        let physics: Physics = serde_json::from_str(json).unwrap();
+        assert_eq!(physics, Physics::new(170.0, 26.0));
+    }
+
+    /// Missing fields should fall back to [`Motion::SMOOTH`].
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_missing_fields() {
+        let motion: Motion = serde_json::from_str(r#"{ "damping": 0.7 }"#).unwrap();
+        assert_eq!(motion, Motion::BOUNCY);
+    }
+
+    /// Raw physics should be used directly and converted to an equivalent response and damping.
+    #[test]
+    fn with_physics() {
+        let physics = Physics::new(170.0, 26.0).with_mass(2.0);
+        let motion = Motion::BOUNCY.with_physics(physics);
+        assert_eq!(motion.physics(), physics);
+        assert_eq!(motion.applied_stiffness(), 170.0);
+        assert_eq!(motion.applied_damping(), 26.0);
+        assert_eq!(motion.mass(), 2.0);
+        assert_eq!(motion.max_duration(), Motion::BOUNCY.max_duration());
+        assert!((motion.duration().as_secs_f32() - 0.6815).abs() < 1e-3);
+        assert!((motion.damping() - 0.7050).abs() < 1e-3);
+
+        // Changing the duration or damping goes back to the derived physics.
+        assert_eq!(motion.with_damping(0.5).physics, None);
+        assert_eq!(motion.with_duration(DEFAULT_DURATION).mass(), 1.0);
+    }
+
+    /// Converting a motion to physics and back should describe the same spring.
+    #[test]
+    fn physics_round_trip() {
+        let physics = Motion::SNAPPY.physics();
+        assert_eq!(physics.mass, 1.0);
+
+        let motion = Motion::from(physics);
+        assert!((motion.damping() - Motion::SNAPPY.damping()).abs() < 1e-4);
+        let duration = motion.duration().as_secs_f32();
+        assert!((duration - Motion::SNAPPY.duration().as_secs_f32()).abs() < 1e-4);
+    }
 }
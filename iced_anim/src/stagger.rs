@@ -0,0 +1,192 @@
+//! Animations for lists of values that cascade with a delay between each element.
+//!
+//! A [`StaggeredAnimated`] holds one [`Animated`] value per element and starts each element's
+//! animation a little after the one before it, e.g. list items fading in one after another:
+//!
+//! ```rust
+//! # use std::time::{Duration, Instant};
+//! # use iced_anim::{spring::Motion, StaggeredAnimated};
+//! let mut opacities =
+//!     StaggeredAnimated::new(vec![0.0_f32; 3], Motion::SMOOTH, Duration::from_millis(50));
+//! opacities.set_target(vec![1.0; 3]);
+//!
+//! // The first item starts right away, and the rest follow every 50ms.
+//! opacities.tick(Instant::now() + Duration::from_millis(16));
+//! assert!(opacities.is_animating());
+//! ```
+use std::time::{Duration, Instant};
+
+use crate::{animated::Mode, Animate, Animated};
+
+/// A list of animated values whose animations start one after another.
+#[derive(Debug, Clone)]
+pub struct StaggeredAnimated<T> {
+    /// The animated value of each element, in order.
+    elements: Vec<Animated<T>>,
+    /// The animation used for every element.
+    mode: Mode,
+    /// The delay between the start of one element's animation and the next.
+    stagger: Duration,
+}
+
+impl<T> StaggeredAnimated<T>
+where
+    T: Animate,
+{
+    /// Creates a new [`StaggeredAnimated`] list of `values` that animate with the given `mode`,
+    /// starting each element `stagger` after the one before it.
+    pub fn new(values: Vec<T>, mode: impl Into<Mode>, stagger: Duration) -> Self {
+        let mode = mode.into();
+        Self {
+            elements: values
+                .into_iter()
+                .map(|value| Animated::new(value, mode))
+                .collect(),
+            mode,
+            stagger,
+        }
+    }
+
+    /// Sets the targets of the list, starting the first element now and each following element
+    /// [`stagger`](Self::stagger) after the one before it.
+    ///
+    /// See [`StaggeredAnimated::set_target_at`] for how lists of different lengths are handled.
+    pub fn set_target(&mut self, targets: Vec<T>) {
+        self.set_target_at(targets, crate::clock::now());
+    }
+
+    /// Sets the targets of the list, starting the first element at `start` and each following
+    /// element [`stagger`](Self::stagger) after the one before it.
+    ///
+    /// Elements whose target doesn't change keep animating as they were. New elements past the
+    /// end of the current list appear at their targets right away, and elements past the end of
+    /// `targets` are removed.
+    pub fn set_target_at(&mut self, targets: Vec<T>, start: Instant) {
+        self.elements.truncate(targets.len());
+
+        let mut delay = Duration::ZERO;
+        for (index, target) in targets.into_iter().enumerate() {
+            match self.elements.get_mut(index) {
+                Some(element) => {
+                    // Restaggering replaces any changes that haven't started yet.
+                    element.clear_scheduled();
+                    if element.target() != &target {
+                        element.schedule_target(target, start + delay);
+                    }
+                }
+                None => self.elements.push(Animated::new(target, self.mode)),
+            }
+            delay += self.stagger;
+        }
+    }
+
+    /// Updates every element based on the time since its last update.
+    pub fn tick(&mut self, now: Instant) {
+        for element in &mut self.elements {
+            element.tick(now);
+        }
+    }
+
+    /// Whether any element is still animating or waiting for its turn to start.
+    pub fn is_animating(&self) -> bool {
+        self.elements.iter().any(Animated::is_animating)
+    }
+
+    /// Returns the current value of each element, in order.
+    pub fn values(&self) -> Vec<&T> {
+        self.elements.iter().map(Animated::value).collect()
+    }
+
+    /// Returns the target of each element, in order.
+    pub fn targets(&self) -> Vec<&T> {
+        self.elements.iter().map(Animated::target).collect()
+    }
+
+    /// Returns the animated value of each element, in order.
+    pub fn elements(&self) -> &[Animated<T>] {
+        &self.elements
+    }
+
+    /// The number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Whether the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// The animation used for every element.
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// The delay between the start of one element's animation and the next.
+    pub fn stagger(&self) -> Duration {
+        self.stagger
+    }
+
+    /// Settles every element at its target immediately, skipping any delays.
+    pub fn settle(&mut self) {
+        for element in &mut self.elements {
+            if let Some((_, target)) = element.scheduled().last().cloned() {
+                element.settle_at(target);
+            } else {
+                element.settle();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{spring::Motion, transition::Easing};
+
+    /// With a 100ms stagger, only the first element should have moved after 50ms.
+    #[test]
+    fn elements_start_in_turn() {
+        for mode in [Mode::from(Motion::SMOOTH), Mode::from(Easing::LINEAR)] {
+            let mut list =
+                StaggeredAnimated::new(vec![0.0_f32; 3], mode, Duration::from_millis(100));
+            let start = Instant::now();
+            list.set_target_at(vec![1.0; 3], start);
+
+            list.tick(start + Duration::from_millis(50));
+            let values = list.values();
+            assert!(*values[0] > 0.0);
+            assert_eq!(values[1..], [&0.0, &0.0]);
+            assert!(list.is_animating());
+
+            list.tick(start + Duration::from_millis(150));
+            let values = list.values();
+            assert!(*values[1] > 0.0);
+            assert_eq!(*values[2], 0.0);
+        }
+    }
+
+    /// Growing the list should add elements at their targets, and shrinking it should drop the
+    /// elements at the end.
+    #[test]
+    fn length_changes() {
+        let mut list = StaggeredAnimated::new(vec![0.0_f32], Motion::SMOOTH, Duration::ZERO);
+        list.set_target(vec![1.0, 2.0]);
+        assert_eq!(list.values(), [&0.0, &2.0]);
+        assert_eq!(list.targets(), [&0.0, &2.0]);
+
+        list.set_target(vec![3.0]);
+        assert_eq!(list.len(), 1);
+    }
+
+    /// Settling should skip the delays and jump every element to its new target.
+    #[test]
+    fn settle_skips_delays() {
+        let mut list =
+            StaggeredAnimated::new(vec![0.0_f32; 3], Motion::SMOOTH, Duration::from_secs(1));
+        list.set_target(vec![1.0; 3]);
+        list.settle();
+        assert_eq!(list.values(), [&1.0; 3]);
+        assert!(!list.is_animating());
+    }
+}
@@ -0,0 +1,203 @@
+//! Detects stalls in the event loop and decides how animations should handle the missing time.
+//!
+//! On some platforms, opening a native context menu or dragging the window stops redraws from
+//! being delivered until the menu closes or the drag ends. The next tick then arrives long after
+//! the previous one, which would make transitions jump and timed behaviors like the automatic
+//! advance of a [`Carousel`](crate::Carousel) fire late and all at once.
+//!
+//! Every animation measures the time between its ticks with the same rules, so a gap counts as a
+//! stall when it's both longer than [`STALL_THRESHOLD`] and several times longer than the frames
+//! the animation has been receiving. Animations that haven't been ticked continuously yet never
+//! see a stall, so ticking an animation once after a long wait still catches it up as usual.
+//!
+//! The crate-wide [`StallPolicy`] then decides what happens to the stalled time:
+//!
+//! - [`StallPolicy::Pause`] treats the stall as paused time. Animations continue from where they
+//!   were, and deadlines and loop phases are shifted forward by the length of the stall.
+//! - [`StallPolicy::Settle`] treats the stall as elapsed time. Springs and transitions settle at
+//!   their targets, and deadlines and loop phases advance by the length of the stall.
+use std::{
+    sync::atomic::{AtomicU8, Ordering},
+    time::{Duration, Instant},
+};
+
+/// How long a gap between ticks needs to be before it can count as a stall.
+pub const STALL_THRESHOLD: Duration = Duration::from_millis(500);
+
+/// How many times longer than the observed frame interval a gap needs to be to count as a stall.
+const STALL_RATIO: u32 = 4;
+
+/// The crate-wide policy, stored as the discriminant of a [`StallPolicy`].
+static POLICY: AtomicU8 = AtomicU8::new(StallPolicy::Pause as u8);
+
+/// How animations handle the time that passed while the event loop was stalled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StallPolicy {
+    /// Treat the stall as paused time, shifting deadlines and loop phases forward by the gap so
+    /// animations continue from where they were.
+    #[default]
+    Pause = 0,
+    /// Treat the stall as elapsed time, settling springs and transitions at their targets and
+    /// advancing deadlines and loop phases by the gap.
+    Settle = 1,
+}
+
+impl StallPolicy {
+    /// Returns the policy used by every animation in the crate, which is
+    /// [`StallPolicy::Pause`] unless changed with [`StallPolicy::set_global`].
+    pub fn global() -> Self {
+        match POLICY.load(Ordering::Relaxed) {
+            0 => StallPolicy::Pause,
+            _ => StallPolicy::Settle,
+        }
+    }
+
+    /// Sets the policy used by every animation in the crate.
+    pub fn set_global(policy: Self) {
+        POLICY.store(policy as u8, Ordering::Relaxed);
+    }
+}
+
+/// The time between two consecutive ticks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Gap {
+    /// A regular frame.
+    Frame(Duration),
+    /// A gap that's much longer than the frames before it, caused by a stalled event loop.
+    Stall(Duration),
+}
+
+impl Gap {
+    /// Measures the gap between the `last` tick and `now`, given the smoothed `frame_interval`
+    /// of the ticks before it, if any.
+    pub fn detect(last: Instant, now: Instant, frame_interval: Option<Duration>) -> Self {
+        let gap = now.saturating_duration_since(last);
+        let is_stall = frame_interval
+            .is_some_and(|interval| gap > STALL_THRESHOLD && gap > interval * STALL_RATIO);
+
+        if is_stall {
+            Gap::Stall(gap)
+        } else {
+            Gap::Frame(gap)
+        }
+    }
+
+    /// The full length of the gap.
+    pub fn duration(self) -> Duration {
+        match self {
+            Gap::Frame(duration) | Gap::Stall(duration) => duration,
+        }
+    }
+
+    /// How much of the gap counts as elapsed time for deadlines and loop phases under `policy`.
+    pub fn elapsed(self, policy: StallPolicy) -> Duration {
+        match (self, policy) {
+            (Gap::Stall(_), StallPolicy::Pause) => Duration::ZERO,
+            (gap, _) => gap.duration(),
+        }
+    }
+}
+
+/// Measures the gaps between the ticks of timed state that isn't itself an animation, like
+/// deadlines and loop phases.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct FrameClock {
+    /// The last time the clock was ticked.
+    last_tick: Option<Instant>,
+    /// A smoothed estimate of the time between recent ticks.
+    frame_interval: Option<Duration>,
+}
+
+impl FrameClock {
+    /// Advances the clock to `now`, returning the gap since the last tick.
+    ///
+    /// The first tick only starts the clock, so its gap is always an empty frame.
+    pub fn tick(&mut self, now: Instant) -> Gap {
+        let Some(last_tick) = self.last_tick.replace(now) else {
+            return Gap::Frame(Duration::ZERO);
+        };
+
+        let gap = Gap::detect(last_tick, now, self.frame_interval);
+        if let Gap::Frame(dt) = gap {
+            self.frame_interval = Some(crate::animated::smooth_frame_interval(
+                self.frame_interval,
+                dt,
+            ));
+        }
+        gap
+    }
+
+    /// Rebases the clock to `now`, so the time since the last tick doesn't count as a gap.
+    #[cfg(feature = "widgets")]
+    pub fn resume_at(&mut self, now: Instant) {
+        self.last_tick = Some(now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: Duration = Duration::from_millis(16);
+
+    /// The policy is paused unless changed.
+    #[test]
+    fn default_policy() {
+        assert_eq!(StallPolicy::default(), StallPolicy::Pause);
+    }
+
+    /// Gaps only count as stalls when they're long compared to the threshold and recent frames.
+    #[test]
+    fn detection() {
+        let start = Instant::now();
+        let long = STALL_THRESHOLD * 2;
+        assert_eq!(Gap::detect(start, start + long, None), Gap::Frame(long));
+        assert_eq!(
+            Gap::detect(start, start + long, Some(FRAME)),
+            Gap::Stall(long)
+        );
+        assert_eq!(
+            Gap::detect(start, start + long, Some(long)),
+            Gap::Frame(long)
+        );
+        assert_eq!(
+            Gap::detect(start, start + FRAME, Some(FRAME)),
+            Gap::Frame(FRAME)
+        );
+
+        // Ticks from the past don't count as a gap at all.
+        assert_eq!(
+            Gap::detect(start + FRAME, start, Some(FRAME)),
+            Gap::Frame(Duration::ZERO)
+        );
+    }
+
+    /// Stalls count as elapsed time only when settling.
+    #[test]
+    fn elapsed_by_policy() {
+        let stall = Gap::Stall(Duration::from_secs(3));
+        assert_eq!(stall.elapsed(StallPolicy::Pause), Duration::ZERO);
+        assert_eq!(stall.elapsed(StallPolicy::Settle), Duration::from_secs(3));
+        assert_eq!(Gap::Frame(FRAME).elapsed(StallPolicy::Pause), FRAME);
+    }
+
+    /// A clock sees a stall in a gapped sequence once it's been receiving frames.
+    #[test]
+    fn clock_detects_gapped_sequence() {
+        let mut clock = FrameClock::default();
+        let mut now = Instant::now();
+        assert_eq!(clock.tick(now), Gap::Frame(Duration::ZERO));
+
+        for _ in 0..10 {
+            now += FRAME;
+            assert_eq!(clock.tick(now), Gap::Frame(FRAME));
+        }
+
+        now += Duration::from_secs(2);
+        assert_eq!(clock.tick(now), Gap::Stall(Duration::from_secs(2)));
+
+        // The stall doesn't skew the frame interval, so regular frames continue afterwards.
+        now += FRAME;
+        assert_eq!(clock.tick(now), Gap::Frame(FRAME));
+    }
+}
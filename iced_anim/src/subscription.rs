@@ -0,0 +1,57 @@
+//! Drives animations from an application's `update` instead of from widgets.
+//!
+//! Animated widgets tick themselves while they're drawn, but values that need to keep animating
+//! while they're not in the view, like a background task's progress that's also logged, can be
+//! ticked from a [`ticks`] subscription instead:
+//!
+//! ```rust,no_run
+//! # use iced::Subscription;
+//! # use iced_anim::{transition::Easing, Animated};
+//! # use std::time::Instant;
+//! # enum Message { Tick(Instant) }
+//! # struct App { progress: Animated<f32> }
+//! # impl App {
+//! fn update(&mut self, message: Message) {
+//!     match message {
+//!         Message::Tick(now) => self.progress.tick(now),
+//!     }
+//! }
+//!
+//! fn subscription(&self) -> Subscription<Message> {
+//!     iced_anim::subscription::ticks(self.progress.is_animating()).map(Message::Tick)
+//! }
+//! # }
+//! ```
+use std::time::Instant;
+
+use iced::{window, Subscription};
+
+/// Returns a subscription that produces the time of every frame while `is_animating` is `true`,
+/// and nothing otherwise.
+///
+/// Since the subscription is recreated each time the application's `subscription` function is
+/// called, passing whether anything is still animating stops the ticks once every animation has
+/// settled instead of waking the application on every frame forever. Combine the flags of several
+/// values with `||` to drive them all from the same ticks.
+///
+/// Ticks follow the display's refresh rate so animations stay as smooth as those driven by widgets.
+pub fn ticks(is_animating: bool) -> Subscription<Instant> {
+    if is_animating {
+        window::frames()
+    } else {
+        Subscription::none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::advanced::subscription::into_recipes;
+
+    /// Ticks should only be produced while something is animating.
+    #[test]
+    fn ticks_while_animating() {
+        assert_eq!(into_recipes(ticks(true)).len(), 1);
+        assert!(into_recipes(ticks(false)).is_empty());
+    }
+}
@@ -0,0 +1,80 @@
+//! A crate-wide time scale for slowing animations down or speeding them up.
+//!
+//! Slowing every animation down 5–10x makes it much easier to inspect easing curves and spring
+//! motions while developing them:
+//!
+//! ```rust
+//! # use std::time::{Duration, Instant};
+//! # use iced_anim::{transition::Easing, Animated};
+//! iced_anim::set_time_scale(0.1);
+//!
+//! let start = Instant::now();
+//! let mut size = Animated::new_at(0.0_f32, Easing::LINEAR, start);
+//! size.schedule_target(100.0, start);
+//! size.tick(start + Duration::from_millis(100));
+//!
+//! // Only 10ms of the 500ms transition has passed.
+//! assert!((size.value() - 2.0).abs() < 1e-3);
+//! # iced_anim::set_time_scale(1.0);
+//! ```
+//!
+//! A scale of `1.0` is normal speed, smaller scales slow animations down, and larger ones speed
+//! them up. A scale of `0.0` freezes every animation where it is. Springs limit how far they
+//! advance per tick before the scale is applied, so slow motion takes the same small steps as
+//! normal speed.
+//!
+//! Individual values can override the crate-wide scale with
+//! [`Animated::with_time_scale`](crate::Animated::with_time_scale).
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+/// The crate-wide time scale, stored as the bits of an `f32`, which starts at `1.0`.
+static SCALE: AtomicU32 = AtomicU32::new(0x3f80_0000);
+
+/// Returns the time scale applied to every animation, which is `1.0` unless changed with
+/// [`set_time_scale`].
+pub fn time_scale() -> f32 {
+    f32::from_bits(SCALE.load(Ordering::Relaxed))
+}
+
+/// Sets the time scale applied to every animation, where `1.0` is normal speed and `0.0`
+/// freezes them. Negative and NaN scales freeze animations as well.
+pub fn set_time_scale(scale: f32) {
+    SCALE.store(sanitize(scale).to_bits(), Ordering::Relaxed);
+}
+
+/// Treats negative and NaN scales as `0.0`, since time can't run backwards.
+fn sanitize(scale: f32) -> f32 {
+    if scale > 0.0 {
+        scale
+    } else {
+        0.0
+    }
+}
+
+/// Scales the time that passed between two ticks by the given `scale`.
+pub(crate) fn scale(dt: Duration, scale: f32) -> Duration {
+    // Converting through seconds loses precision, so avoid it at normal speed.
+    if scale == 1.0 {
+        return dt;
+    }
+    Duration::try_from_secs_f32(dt.as_secs_f32() * sanitize(scale)).unwrap_or(Duration::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scaling_durations() {
+        let frame = Duration::from_millis(16);
+        assert_eq!(scale(frame, 1.0), frame);
+        assert_eq!(scale(frame, 0.5), Duration::from_millis(8));
+        assert_eq!(scale(frame, 0.0), Duration::ZERO);
+        assert_eq!(scale(frame, -1.0), Duration::ZERO);
+        assert_eq!(scale(frame, f32::NAN), Duration::ZERO);
+        assert_eq!(scale(frame, f32::INFINITY), Duration::MAX);
+    }
+}
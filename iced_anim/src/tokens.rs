@@ -0,0 +1,177 @@
+//! Named motion tokens that map to animation modes and can be re-themed at runtime.
+//!
+//! Design systems often describe motion with semantic names like `"fast.springy"` rather than
+//! concrete spring or easing values, so that a brand can be made snappier or gentler in one place.
+//! A [`MotionTokens`] registry maps those names to a [`Mode`], and a [`Token`] can be passed
+//! anywhere an animation mode is expected, e.g. `.animation(Token("fast.springy"))`.
+//!
+//! Tokens are resolved against the ambient registry, which is installed with
+//! [`MotionTokens::install`]. Widgets receive their animation mode each time the view is built
+//! and apply it when diffing their state, so installing a new registry (e.g. when switching
+//! brands) re-resolves every widget animation on the next view without any other code changes.
+//!
+//! ```rust
+//! # use std::time::Duration;
+//! # use iced_anim::{animated::Mode, spring::Motion, transition::Easing, MotionTokens, Token};
+//! let snappy = MotionTokens::default()
+//!     .with("fast.springy", Motion::SNAPPY.with_duration(Duration::from_millis(150)));
+//! snappy.install();
+//!
+//! let mode: Mode = Token("fast.springy").into();
+//! assert_eq!(mode, Motion::SNAPPY.with_duration(Duration::from_millis(150)).into());
+//! ```
+use std::{cell::RefCell, collections::HashMap, time::Duration};
+
+use crate::{animated::Mode, spring::Motion, transition::Easing};
+
+thread_local! {
+    /// The registry that tokens are resolved against, which is per-thread like the view.
+    static AMBIENT: RefCell<MotionTokens> = RefCell::new(MotionTokens::default());
+}
+
+/// The response of the `fast` tokens.
+const FAST: Duration = Duration::from_millis(200);
+
+/// The response of the `slow` tokens.
+const SLOW: Duration = Duration::from_millis(800);
+
+/// A named motion token, resolved to a [`Mode`] against the ambient [`MotionTokens`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Token(pub &'static str);
+
+impl Token {
+    /// Resolves this token against the given `tokens` instead of the ambient registry.
+    pub fn resolve_with(self, tokens: &MotionTokens) -> Mode {
+        tokens.resolve(self.0)
+    }
+}
+
+impl From<Token> for Mode {
+    fn from(token: Token) -> Self {
+        MotionTokens::with_ambient(|tokens| token.resolve_with(tokens))
+    }
+}
+
+/// A registry mapping motion token names to animation modes.
+///
+/// The default registry includes `fast`, `default`, and `slow` variants of `gentle` easing
+/// transitions and `springy` spring motions, e.g. `"default.gentle"` or `"fast.springy"`, as well
+/// as an `"instant"` token. Unknown tokens resolve to the fallback mode, which is
+/// [`Mode::default`] unless changed with [`MotionTokens::with_fallback`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MotionTokens {
+    tokens: HashMap<String, Mode>,
+    fallback: Mode,
+}
+
+impl Default for MotionTokens {
+    fn default() -> Self {
+        Self::empty()
+            .with("instant", Motion::INSTANT)
+            .with("fast.gentle", Easing::EASE_IN_OUT.with_duration(FAST))
+            .with("default.gentle", Easing::EASE_IN_OUT)
+            .with("slow.gentle", Easing::EASE_IN_OUT.with_duration(SLOW))
+            .with("fast.springy", Motion::SNAPPY.with_duration(FAST))
+            .with("default.springy", Motion::SNAPPY)
+            .with("slow.springy", Motion::BOUNCY.with_duration(SLOW))
+    }
+}
+
+impl MotionTokens {
+    /// Creates a registry without any tokens, where every token resolves to the fallback.
+    pub fn empty() -> Self {
+        Self {
+            tokens: HashMap::new(),
+            fallback: Mode::default(),
+        }
+    }
+
+    /// Adds or overrides the token with the given `name` and returns the updated registry.
+    pub fn with(mut self, name: impl Into<String>, mode: impl Into<Mode>) -> Self {
+        self.tokens.insert(name.into(), mode.into());
+        self
+    }
+
+    /// Sets the mode that unknown tokens resolve to and returns the updated registry.
+    pub fn with_fallback(mut self, mode: impl Into<Mode>) -> Self {
+        self.fallback = mode.into();
+        self
+    }
+
+    /// Returns the mode of the token with the given `name`, if there is one.
+    pub fn get(&self, name: &str) -> Option<Mode> {
+        self.tokens.get(name).copied()
+    }
+
+    /// Returns the mode of the token with the given `name`, or the fallback if it's unknown.
+    pub fn resolve(&self, name: &str) -> Mode {
+        self.get(name).unwrap_or(self.fallback)
+    }
+
+    /// Makes this the ambient registry that [`Token`]s resolve against on the current thread,
+    /// returning the previous registry.
+    pub fn install(self) -> MotionTokens {
+        AMBIENT.with(|ambient| ambient.replace(self))
+    }
+
+    /// Calls `f` with the ambient registry of the current thread.
+    pub fn with_ambient<R>(f: impl FnOnce(&MotionTokens) -> R) -> R {
+        AMBIENT.with(|ambient| f(&ambient.borrow()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+    use crate::AnimatedState;
+
+    /// Known tokens resolve to their registered mode.
+    #[test]
+    fn resolution() {
+        let tokens = MotionTokens::default();
+        assert_eq!(tokens.resolve("instant"), Mode::Spring(Motion::INSTANT));
+        assert_eq!(
+            Token("default.gentle").resolve_with(&tokens),
+            Mode::Transition(Easing::EASE_IN_OUT)
+        );
+
+        let tokens = tokens.with("default.gentle", Easing::LINEAR);
+        assert_eq!(tokens.resolve("default.gentle"), Easing::LINEAR.into());
+    }
+
+    /// Unknown tokens resolve to the fallback mode.
+    #[test]
+    fn unknown_tokens_fall_back() {
+        let tokens = MotionTokens::default();
+        assert_eq!(tokens.get("motion.unknown"), None);
+        assert_eq!(tokens.resolve("motion.unknown"), Mode::default());
+
+        let tokens = tokens.with_fallback(Motion::SMOOTH);
+        assert_eq!(tokens.resolve("motion.unknown"), Motion::SMOOTH.into());
+    }
+
+    /// Installing a new registry should change how an existing widget animates once it's diffed.
+    #[test]
+    fn registry_swap_affects_existing_state() {
+        let token = Token("brand.emphasis");
+        let slow = Easing::LINEAR.with_duration(Duration::from_secs(1));
+        let fast = Easing::LINEAR.with_duration(Duration::from_millis(100));
+        MotionTokens::default().with(token.0, slow).install();
+
+        let mut state = AnimatedState::<(), f32>::new((), token);
+        assert_eq!(*state.current_value(|_| 0.0), 0.0);
+
+        // Switch brands, then rebuild the view which diffs the widget with the same token.
+        let previous = MotionTokens::default().with(token.0, fast).install();
+        assert_eq!(previous.get(token.0), Some(slow.into()));
+        state.diff(token);
+
+        let now = Instant::now();
+        assert_eq!(*state.current_value(|_| 1.0), 0.0);
+        state.resume_at(now);
+        state.tick(now + Duration::from_millis(100));
+        assert_eq!(*state.current_value(|_| 1.0), 1.0);
+    }
+}
@@ -4,12 +4,32 @@ pub mod curve;
 mod easing;
 mod progress;
 
-use crate::{Animate, Event};
+use crate::{
+    stall::{Gap, StallPolicy},
+    Animate, Event,
+};
 pub use curve::Curve;
 pub use easing::Easing;
 pub use progress::Progress;
 use std::time::{Duration, Instant};
 
+/// How long after a transition completes that a new target is still considered a chained
+/// transition when [`Easing::chain_smoothing`] is enabled.
+pub const CHAIN_SMOOTHING_WINDOW: Duration = Duration::from_millis(100);
+
+/// The progress after which a new target is considered a chained transition when
+/// [`Easing::chain_smoothing`] is enabled, even if the transition hasn't completed yet.
+pub const CHAIN_SMOOTHING_THRESHOLD: f32 = 0.95;
+
+/// How long an interrupted transition takes to blend from the velocity it had onto the curve of
+/// the new transition when [`Easing::velocity_matching`] is enabled, or the whole duration of
+/// shorter transitions.
+pub const VELOCITY_MATCHING_WINDOW: Duration = Duration::from_millis(150);
+
+/// Transitions with a shorter duration than this complete on their next tick, since dividing
+/// the time between frames by such small durations overshoots or isn't a number at all.
+pub const MIN_DURATION: Duration = Duration::from_millis(1);
+
 /// A type of animation that transitions between two values.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Transition<T> {
@@ -25,6 +45,14 @@ pub struct Transition<T> {
     progress: Progress,
     /// The time at which the transition was last updated.
     last_update: Instant,
+    /// The time at which the transition last completed, used to detect chained transitions.
+    completed_at: Option<Instant>,
+    /// A smoothed estimate of the time between recent ticks.
+    frame_interval: Option<Duration>,
+    /// The index and velocity of each component that an interrupted transition carried into the
+    /// start of this one, on top of the velocity of the curve, sorted by index. Components that
+    /// didn't carry any velocity are left out, and it's empty unless velocity matching applies.
+    carried_velocity: Vec<(usize, f32)>,
 }
 
 impl<T> Transition<T>
@@ -33,13 +61,25 @@ where
 {
     /// Creates a new transition with the given `value`.
     pub fn new(value: T) -> Self {
+        Self::new_at(value, crate::clock::now())
+    }
+
+    /// Creates a new transition with the given `value` as of the given `now` instant.
+    ///
+    /// Along with [`Transition::set_target_at`] and [`Transition::tick`], this lets a transition
+    /// be driven entirely by synthetic instants, e.g. for deterministic tests.
+    pub fn new_at(value: T, now: Instant) -> Self {
+        crate::animate::debug_verify(&value);
         Self {
             initial: value.clone(),
             target: value.clone(),
             value,
             easing: Easing::default(),
             progress: Progress::default(),
-            last_update: Instant::now(),
+            last_update: now,
+            completed_at: None,
+            frame_interval: None,
+            carried_velocity: Vec::new(),
         }
     }
 
@@ -86,6 +126,7 @@ where
     /// If the transition is not reversible, this will use the initial value as the new target
     /// and reset the progress to start from the beginning.
     pub fn reverse(&mut self) {
+        self.carried_velocity.clear();
         if self.easing.reversible {
             self.progress.reverse();
         } else {
@@ -99,6 +140,7 @@ where
 
     /// Ends the transition, immediately setting the current value to the target value.
     pub fn settle(&mut self) {
+        self.carried_velocity.clear();
         self.progress.settle();
         match self.progress {
             Progress::Forward(_) => self.value = self.target.clone(),
@@ -108,6 +150,7 @@ where
 
     /// Makes the transition immediately settle at the given `target`.
     pub fn settle_at(&mut self, target: T) {
+        self.carried_velocity.clear();
         self.value = target.clone();
         self.target = target;
         self.progress = Progress::Forward(1.0);
@@ -120,6 +163,8 @@ where
             Event::Tick(now) => self.tick(now),
             Event::Target(target) => self.set_target(target),
             Event::SettleAt(target) => self.settle_at(target),
+            // Only `Animated` values can be paused, since they stop ticking their animation.
+            Event::Pause | Event::Resume => {}
         }
     }
 
@@ -131,6 +176,12 @@ where
 
     /// Interrupts the existing transition and starts a new one with the new `target`.
     pub fn set_target(&mut self, target: T) {
+        self.set_target_at(target, crate::clock::now());
+    }
+
+    /// Interrupts the existing transition and starts a new one with the new `target` as of the
+    /// given `now` instant.
+    pub fn set_target_at(&mut self, target: T, now: Instant) {
         // Don't do anything if the target hasn't changed.
         if self.target() == &target {
             return;
@@ -139,7 +190,7 @@ where
         // Reset the last update if the transition isn't moving.
         // This avoids resetting the last update during continuously interrupted animations.
         if !self.is_animating() {
-            self.last_update = Instant::now();
+            self.last_update = now;
         }
 
         // Reverse the transition if the new target is the initial
@@ -150,42 +201,276 @@ where
             Progress::Reverse(_) => target == self.target,
         };
 
+        let entry_velocity =
+            (self.easing.velocity_matching && self.is_animating()).then(|| self.velocity());
+
         if is_initial_target && !self.progress.is_complete() {
             self.reverse();
         } else if &target != self.target() {
+            let chained_progress = self.chained_progress(now);
+
             // Target has changed, reset the progress and update the initial value.
             self.progress = Progress::Forward(0.0);
             self.initial = self.value.clone();
             self.target = target;
+            self.carried_velocity.clear();
+
+            if let Some(progress) = chained_progress {
+                self.start_at(progress);
+            }
+        }
+
+        // Transitions that restarted from the beginning of the curve keep the velocity they had,
+        // while reversible transitions already follow the same curve back.
+        if let Some(entry_velocity) = entry_velocity {
+            if self.progress == Progress::Forward(0.0) {
+                self.carry_velocity(entry_velocity);
+            }
         }
 
-        self.last_update = Instant::now();
+        self.last_update = now;
+    }
+
+    /// The progress that a chained transition started at `now` should start at to keep the speed
+    /// the current transition is ending with, if [`Easing::chain_smoothing`] applies.
+    fn chained_progress(&self, now: Instant) -> Option<f32> {
+        if !self.easing.chain_smoothing {
+            return None;
+        }
+
+        let Progress::Forward(progress) = self.progress else {
+            return None;
+        };
+
+        let is_chained = if self.progress.is_complete() {
+            self.completed_at.is_some_and(|completed_at| {
+                now.saturating_duration_since(completed_at) <= CHAIN_SMOOTHING_WINDOW
+            })
+        } else {
+            progress >= CHAIN_SMOOTHING_THRESHOLD
+        };
+
+        is_chained.then(|| {
+            let slope = self.easing.curve.slope(progress);
+            self.easing.curve.progress_for_slope(slope)
+        })
+    }
+
+    /// Carries the difference between the `entry_velocity` and the initial velocity of the curve
+    /// into the start of the transition, so the value doesn't change speed when it's retargeted.
+    fn carry_velocity(&mut self, entry_velocity: Vec<f32>) {
+        let curve_velocity = self.velocity();
+        // Values whose number of components changed, like a `Vec`, start from the curve instead.
+        if curve_velocity.len() != entry_velocity.len() || self.window() <= 0.0 {
+            return;
+        }
+
+        self.carried_velocity = entry_velocity
+            .into_iter()
+            .zip(curve_velocity)
+            .map(|(entry, curve)| entry - curve)
+            .enumerate()
+            .filter(|&(_, velocity)| velocity != 0.0)
+            .collect();
+    }
+
+    /// The length in seconds of the window the carried velocity is blended away over.
+    fn window(&self) -> f32 {
+        VELOCITY_MATCHING_WINDOW
+            .min(self.easing.duration)
+            .as_secs_f32()
+    }
+
+    /// Returns how far the carried velocity has moved the value from the curve so far, and how
+    /// much of the carried velocity is left, as factors of the carried velocity. Returns `None`
+    /// once the carried velocity has been blended away.
+    fn carry(&self) -> Option<(f32, f32)> {
+        let Progress::Forward(progress) = self.progress else {
+            return None;
+        };
+        let window = self.window();
+        let elapsed = progress * self.easing.duration.as_secs_f32();
+        if self.carried_velocity.is_empty() || elapsed >= window {
+            return None;
+        }
+
+        // A cubic that starts at the carried velocity and comes to rest at the end of the window
+        // without moving the value away from the curve at either end.
+        let t = elapsed / window;
+        let offset = elapsed * (1.0 - t).powi(2);
+        let rate = (1.0 - t) * (1.0 - 3.0 * t);
+        Some((offset, rate))
+    }
+
+    /// Moves the value to the given `progress` along the curve, including any carried velocity.
+    fn ease_to(&mut self, progress: f32) {
+        self.value.lerp(
+            &self.initial,
+            &self.target,
+            self.easing.curve.value(progress),
+        );
+        if let Some((offset, _)) = self.carry() {
+            // Only the components that carried velocity move away from the curve.
+            let mut carried = self.carried_velocity.iter().peekable();
+            let mut offsets = (0..).map(|index| {
+                carried
+                    .next_if(|&&(component, _)| component == index)
+                    .map_or(0.0, |(_, velocity)| velocity * offset)
+            });
+            self.value.update(&mut offsets);
+        }
+    }
+
+    /// Starts the transition partway along the curve at the given `progress` while keeping the
+    /// current value where it is.
+    fn start_at(&mut self, progress: f32) {
+        let curve_value = self.easing.curve.value(progress);
+        if progress <= 0.0 || curve_value >= 1.0 {
+            return;
+        }
+
+        // Extrapolate the initial value so that the curve passes through the current value at
+        // the new progress, which avoids a jump in the value.
+        let mut initial = self.target.clone();
+        initial.lerp(&self.target, &self.value, 1.0 / (1.0 - curve_value));
+        self.initial = initial;
+        self.progress = Progress::Forward(progress);
+    }
+
+    /// Returns how far the value is along the path from the initial value to the target, from
+    /// `0.0` at the initial value to `1.0` at the target, before the easing curve is applied.
+    ///
+    /// This counts towards the initial value while the transition is reversing.
+    pub fn progress(&self) -> f32 {
+        self.progress.value()
+    }
+
+    /// Moves the transition to the given `progress` along the path from the initial value to the
+    /// target, clamped to `[0.0, 1.0]`, and updates the value immediately through the easing curve.
+    ///
+    /// This lets the transition be driven by something other than time, e.g. scrubbing through
+    /// an animation with a scroll position or a slider. Ticking the transition afterwards
+    /// continues the animation from this progress.
+    ///
+    /// ```rust
+    /// # use iced_anim::{transition::Easing, Transition};
+    /// let mut transition = Transition::new(0.0).with_easing(Easing::LINEAR).to(10.0);
+    /// transition.set_progress(0.25);
+    /// assert_eq!(transition.value(), &2.5);
+    /// assert_eq!(transition.progress(), 0.25);
+    /// ```
+    pub fn set_progress(&mut self, progress: f32) {
+        let progress = progress.clamp(0.0, 1.0);
+        self.progress = match self.progress {
+            Progress::Forward(_) => Progress::Forward(progress),
+            Progress::Reverse(_) => Progress::Reverse(1.0 - progress),
+        };
+
+        if self.progress.is_complete() {
+            self.value = self.target().clone();
+        } else {
+            self.ease_to(progress);
+        }
+    }
+
+    /// Returns the velocity of each component, in units per second, following the slope of the
+    /// easing curve at the current progress. Transitions that aren't animating have no velocity.
+    ///
+    /// This includes any velocity carried over from an interrupted transition with
+    /// [`Easing::velocity_matching`], which makes it a good starting point for switching to a
+    /// spring manually.
+    pub fn velocity(&self) -> Vec<f32> {
+        let distance = self.target.distance_to(&self.initial);
+        if !self.is_animating() || self.easing.duration < MIN_DURATION {
+            return vec![0.0; distance.len()];
+        }
+
+        let direction = match self.progress {
+            Progress::Forward(_) => 1.0,
+            Progress::Reverse(_) => -1.0,
+        };
+        let duration = self.easing.duration.as_secs_f32();
+        let rate = direction * self.easing.curve.slope(self.progress.value()) / duration;
+        let mut velocity: Vec<f32> = distance.into_iter().map(|d| d * rate).collect();
+        if let Some((_, carried)) = self.carry() {
+            for &(index, carried_velocity) in &self.carried_velocity {
+                if let Some(velocity) = velocity.get_mut(index) {
+                    *velocity += carried * carried_velocity;
+                }
+            }
+        }
+        velocity
+    }
+
+    /// Returns a smoothed estimate of the time between recent ticks, if the transition has
+    /// been ticked yet.
+    pub fn observed_frame_interval(&self) -> Option<Duration> {
+        self.frame_interval
+    }
+
+    /// Rebases the transition's clock to `now` without advancing it, so the time since the last
+    /// update doesn't count towards its progress. Useful when resuming a paused transition.
+    pub fn resume_at(&mut self, now: Instant) {
+        self.last_update = now;
     }
 
     /// Updates the transition's value based on the elapsed time since the last update.
+    ///
+    /// If the event loop stalled since the last update, the transition either resumes where it
+    /// was or settles at its target depending on the [`StallPolicy`].
     pub fn tick(&mut self, now: Instant) {
+        self.tick_with(now, Some(StallPolicy::global()));
+    }
+
+    /// Updates the transition like [`Transition::tick`], handling stalls with the given `policy`,
+    /// or treating every gap as a regular frame if there's no policy.
+    pub(crate) fn tick_with(&mut self, now: Instant, policy: Option<StallPolicy>) {
+        self.tick_scaled(now, policy, crate::time_scale());
+    }
+
+    /// Updates the transition like [`Transition::tick_with`], scaling the time since the last
+    /// update by `scale` instead of the crate-wide [time scale](crate::time_scale).
+    pub(crate) fn tick_scaled(&mut self, now: Instant, policy: Option<StallPolicy>, scale: f32) {
         if !self.is_animating() {
             return;
         }
 
         // Figure out how much time has passed since the last update
-        let delta = now.duration_since(self.last_update);
+        let gap = Gap::detect(self.last_update, now, self.frame_interval);
         self.last_update = now;
+        match (gap, policy) {
+            (Gap::Stall(_), Some(StallPolicy::Pause)) => return,
+            (Gap::Stall(_), Some(StallPolicy::Settle)) => {
+                self.settle();
+                self.completed_at = Some(now);
+                return;
+            }
+            _ => {}
+        }
 
-        self.progress
-            .update(delta.as_secs_f32() / self.easing.duration.as_secs_f32());
+        let delta = gap.duration();
+        self.frame_interval = Some(crate::animated::smooth_frame_interval(
+            self.frame_interval,
+            delta,
+        ));
+        let delta = crate::time_scale::scale(delta, scale);
+
+        let delta_progress = if self.easing.duration < MIN_DURATION {
+            1.0
+        } else {
+            delta.as_secs_f32() / self.easing.duration.as_secs_f32()
+        };
+        self.progress.update(delta_progress);
         if self.progress.is_complete() {
+            self.completed_at = Some(now);
+
             // We're at the target - assign the current value to the target value.
             // This ensures that the value is exactly the target value, even if the
             // curve doesn't reach it or the animation implementation isn't correct.
             self.value = self.target().clone();
         } else {
             // Continue to lerp the value towards the target
-            self.value.lerp(
-                &self.initial,
-                &self.target,
-                self.easing.curve.value(self.progress.value()),
-            );
+            self.ease_to(self.progress.value());
         }
     }
 
@@ -264,6 +549,61 @@ mod tests {
         assert!(matches!(transition.progress, Progress::Forward(_)));
     }
 
+    /// Transitions driven by synthetic instants should reach the same values no matter when
+    /// they're created.
+    #[test]
+    fn synthetic_instants_are_deterministic() {
+        let origin = Instant::now() + Duration::from_secs(3600);
+        let mut transition = Transition::new_at(0.0_f32, origin).with_easing(Easing::LINEAR);
+        transition.set_target_at(10.0, origin);
+
+        transition.tick(origin + DEFAULT_DURATION / 4);
+        assert_eq!(*transition.value(), 2.5);
+        for quarter in 2..=4 {
+            transition.tick(origin + DEFAULT_DURATION / 4 * quarter);
+        }
+        assert_eq!(*transition.value(), 10.0);
+        assert!(!transition.is_animating());
+    }
+
+    /// Setting the progress should move the value through the easing curve immediately.
+    #[test]
+    fn set_progress_follows_curve() {
+        let mut transition = Transition::new(0.0_f32)
+            .with_easing(Easing::EASE_IN)
+            .to(10.0);
+        for progress in [0.0, 0.25, 0.5, 0.75] {
+            transition.set_progress(progress);
+            assert_eq!(transition.progress(), progress);
+            assert_eq!(*transition.value(), 10.0 * Curve::EaseIn.value(progress));
+        }
+
+        transition.set_progress(2.0);
+        assert_eq!(transition.progress(), 1.0);
+        assert_eq!(*transition.value(), 10.0);
+        assert!(!transition.is_animating());
+
+        transition.set_progress(-1.0);
+        assert_eq!(transition.progress(), 0.0);
+        assert_eq!(*transition.value(), 0.0);
+    }
+
+    /// Progress should be measured from the initial value to the target while reversing.
+    #[test]
+    fn set_progress_while_reversing() {
+        let mut transition = Transition::new(0.0_f32)
+            .with_easing(Easing::LINEAR.reversible(true))
+            .to(10.0);
+        transition.reverse();
+        transition.set_progress(0.75);
+        assert_eq!(transition.progress(), 0.75);
+        assert_eq!(*transition.value(), 7.5);
+
+        transition.set_progress(0.0);
+        assert_eq!(*transition.value(), 0.0);
+        assert!(!transition.is_animating());
+    }
+
     /// [`Transition::is_animating`] should return `true` when the transition is still in progress.
     #[test]
     fn is_animating() {
@@ -289,4 +629,247 @@ mod tests {
         assert!(!transition.is_animating());
         assert_eq!(transition.progress, Progress::Forward(1.0));
     }
+
+    /// Updating with [`Event::SettleAt`] should jump to the new value without animating.
+    #[test]
+    fn update_settle_at() {
+        let mut transition = Transition::new(0.0).to(1.0);
+        transition.update(Event::settle_at(0.5));
+        assert_eq!(*transition.value(), 0.5);
+        assert_eq!(*transition.target(), 0.5);
+        assert!(!transition.is_animating());
+    }
+
+    /// Measures the change in value across the joint of two chained transitions,
+    /// returning the delta of the last tick before the joint and the first tick after.
+    fn chained_deltas(easing: Easing) -> (f32, f32) {
+        let frame = Duration::from_millis(16);
+        let mut transition = Transition::new(0.0).to(1.0).with_easing(easing);
+        let start = transition.last_update;
+
+        transition.tick(start + DEFAULT_DURATION - frame);
+        let before = *transition.value();
+        transition.tick(start + DEFAULT_DURATION);
+        let delta_before = *transition.value() - before;
+
+        transition.set_target(2.0);
+        let joint = *transition.value();
+        transition.tick(transition.last_update + frame);
+        let delta_after = *transition.value() - joint;
+
+        (delta_before, delta_after)
+    }
+
+    /// Chain smoothing should carry the ending speed into the next transition instead of
+    /// starting from a dead stop.
+    #[test]
+    fn chain_smoothing_keeps_speed_across_joint() {
+        let (before, unsmoothed) = chained_deltas(Easing::EASE_IN);
+        let (_, smoothed) = chained_deltas(Easing::EASE_IN.chain_smoothing(true));
+
+        assert!(smoothed > unsmoothed);
+        assert!((smoothed - before).abs() < (unsmoothed - before).abs());
+    }
+
+    /// Chain smoothing shouldn't cause the value to jump when retargeting.
+    #[test]
+    fn chain_smoothing_keeps_value() {
+        let easing = Easing::EASE_IN.chain_smoothing(true);
+        let mut transition = Transition::new(0.0).to(1.0).with_easing(easing);
+        transition.tick(transition.last_update + DEFAULT_DURATION);
+        transition.set_target(2.0);
+
+        assert_eq!(*transition.value(), 1.0);
+        assert!(transition.progress.progress() > 0.0);
+
+        transition.tick(transition.last_update + DEFAULT_DURATION);
+        assert_eq!(*transition.value(), 2.0);
+    }
+
+    /// Transitions retargeted long after they complete should start from the beginning.
+    #[test]
+    fn chain_smoothing_ignores_stale_completions() {
+        let easing = Easing::EASE_IN.chain_smoothing(true);
+        let mut transition = Transition::new(0.0).to(1.0).with_easing(easing);
+        transition.tick(transition.last_update + DEFAULT_DURATION);
+        transition.completed_at = Some(Instant::now() - 2 * CHAIN_SMOOTHING_WINDOW);
+        transition.set_target(2.0);
+
+        assert_eq!(transition.progress, Progress::Forward(0.0));
+    }
+
+    /// The observed frame interval should follow the refresh rate when it changes.
+    #[test]
+    fn observed_frame_interval_adapts() {
+        let mut transition = Transition::new(0.0)
+            .to(1.0)
+            .with_easing(Easing::LINEAR.with_duration(Duration::from_secs(10)));
+        assert_eq!(transition.observed_frame_interval(), None);
+
+        let mut now = transition.last_update;
+        for frame in [Duration::from_millis(16), Duration::from_millis(8)] {
+            for _ in 0..30 {
+                now += frame;
+                transition.tick(now);
+            }
+
+            let interval = transition.observed_frame_interval().unwrap();
+            assert!((interval.as_secs_f32() - frame.as_secs_f32()).abs() < 0.001);
+        }
+    }
+
+    /// Ticks a one second transition at 60fps for a while and then once more after a long stall.
+    fn tick_through_stall(policy: StallPolicy) -> (f32, Transition<f32>) {
+        let frame = Duration::from_millis(16);
+        let mut transition = Transition::new(0.0)
+            .to(1.0)
+            .with_easing(Easing::LINEAR.with_duration(Duration::from_secs(1)));
+        let mut now = Instant::now();
+        transition.resume_at(now);
+        for _ in 0..10 {
+            now += frame;
+            transition.tick_with(now, Some(policy));
+        }
+
+        let before = *transition.value();
+        transition.tick_with(now + Duration::from_secs(2), Some(policy));
+        (before, transition)
+    }
+
+    /// Pausing should continue the transition from where it was before the stall.
+    #[test]
+    fn stall_pauses_transition() {
+        let (before, transition) = tick_through_stall(StallPolicy::Pause);
+        assert!(before > 0.0);
+        assert_eq!(*transition.value(), before);
+        assert!(transition.is_animating());
+    }
+
+    /// Settling should complete the transition after a stall.
+    #[test]
+    fn stall_settles_transition() {
+        let (before, transition) = tick_through_stall(StallPolicy::Settle);
+        assert!(before < 1.0);
+        assert_eq!(*transition.value(), 1.0);
+        assert!(!transition.is_animating());
+    }
+
+    /// Returns the per-frame change in value right before and right after interrupting an
+    /// `EASE_IN_OUT` transition partway through with a target further along.
+    fn interrupted_deltas(easing: Easing) -> (f32, f32, Transition<f32>) {
+        let start = Instant::now();
+        let frame = Duration::from_millis(16);
+        let mut transition = Transition::new_at(0.0_f32, start).with_easing(easing);
+        transition.set_target_at(100.0, start);
+
+        for index in 1..=12 {
+            transition.tick(start + frame * index);
+        }
+        let before = *transition.value();
+        transition.tick(start + frame * 13);
+        let delta_before = *transition.value() - before;
+
+        let interrupted = *transition.value();
+        transition.set_target_at(200.0, start + frame * 13);
+        assert_eq!(*transition.value(), interrupted);
+        transition.tick(start + frame * 14);
+        let delta_after = *transition.value() - interrupted;
+
+        for index in 15..60 {
+            transition.tick(start + frame * index);
+        }
+        (delta_before, delta_after, transition)
+    }
+
+    /// Interrupting a transition normally restarts the curve from its initial speed, which for
+    /// an ease-in curve is a near stop, while velocity matching keeps the value moving.
+    #[test]
+    fn velocity_matching_avoids_dead_stop() {
+        let (before, unmatched, _) = interrupted_deltas(Easing::EASE_IN_OUT);
+        assert!(unmatched < before * 0.1, "{before} -> {unmatched}");
+
+        let (before, matched, transition) =
+            interrupted_deltas(Easing::EASE_IN_OUT.velocity_matching(true));
+        assert!(matched > before * 0.8, "{before} -> {matched}");
+        assert_eq!(*transition.value(), 200.0);
+        assert!(!transition.is_animating());
+    }
+
+    /// The reported velocity should include the carried velocity, so it stays continuous across
+    /// the interruption.
+    #[test]
+    fn velocity_matching_reports_carried_velocity() {
+        let start = Instant::now();
+        let easing = Easing::EASE_IN_OUT.velocity_matching(true);
+        let mut transition = Transition::new_at(0.0_f32, start).with_easing(easing);
+        transition.set_target_at(100.0, start);
+        transition.tick(start + Duration::from_millis(200));
+        let velocity = transition.velocity()[0];
+
+        transition.set_target_at(50.0, start + Duration::from_millis(200));
+        assert!((transition.velocity()[0] - velocity).abs() < 1e-3);
+
+        // The carried velocity is blended away by the end of the window.
+        transition.tick(start + Duration::from_millis(200) + VELOCITY_MATCHING_WINDOW);
+        assert!(transition.carry().is_none());
+    }
+
+    /// Only the components that were moving should carry velocity into an interrupted transition,
+    /// and the rest should stay where they are.
+    #[test]
+    fn velocity_matching_only_carries_moving_components() {
+        let start = Instant::now();
+        let easing = Easing::EASE_IN_OUT.velocity_matching(true);
+        let mut transition = Transition::new_at([1.5_f32; 8], start).with_easing(easing);
+        let mut target = [1.5; 8];
+        target[2] = 10.0;
+        transition.set_target_at(target, start);
+        transition.tick(start + Duration::from_millis(100));
+
+        target[2] = -10.0;
+        transition.set_target_at(target, start + Duration::from_millis(100));
+        assert_eq!(transition.carried_velocity.len(), 1);
+        assert_eq!(transition.carried_velocity[0].0, 2);
+
+        transition.tick(start + Duration::from_millis(150));
+        let value = transition.value();
+        assert!((0..8)
+            .filter(|&index| index != 2)
+            .all(|index| value[index] == 1.5));
+    }
+
+    /// Zero durations used to divide by zero and produce NaN progress that never completed, so
+    /// the transition kept animating forever. It should settle on its first tick instead, even
+    /// if no time has passed.
+    #[test]
+    fn zero_duration_settles_on_first_tick() {
+        let start = Instant::now();
+        let easing = Easing::LINEAR.with_duration(Duration::ZERO);
+        let mut transition = Transition::new_at(0.0_f32, start).with_easing(easing);
+        transition.set_target_at(100.0, start);
+        assert!(transition.is_animating());
+
+        transition.tick(start);
+        assert_eq!(*transition.value(), 100.0);
+        assert_eq!(transition.progress(), 1.0);
+        assert!(!transition.is_animating());
+        assert_eq!(transition.velocity(), vec![0.0]);
+
+        transition.tick(start + Duration::from_millis(16));
+        assert_eq!(*transition.value(), 100.0);
+        assert!(!transition.is_animating());
+    }
+
+    /// Sub-millisecond durations should complete on their next tick rather than overshooting.
+    #[test]
+    fn sub_millisecond_duration_settles_on_first_tick() {
+        let start = Instant::now();
+        let easing = Easing::EASE.with_duration(Duration::from_micros(500));
+        let mut transition = Transition::new_at(0.0_f32, start).with_easing(easing);
+        transition.set_target_at(100.0, start);
+
+        transition.tick(start + Duration::from_millis(16));
+        assert_eq!(*transition.value(), 100.0);
+        assert!(!transition.is_animating());
+    }
 }
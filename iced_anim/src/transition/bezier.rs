@@ -11,7 +11,10 @@
 //! - [Recreating CSS3 Cubic-Bezier Curves](https://stackoverflow.com/a/11697909)
 //! - [WebKit implementation](https://github.com/WebKit/WebKit/blob/main/Source/WebCore/platform/graphics/UnitBezier.h)
 //! - [Firefox implementation](https://github.com/mozilla/gecko-dev/blob/master/dom/smil/SMILKeySpline.cpp)
-use std::sync::LazyLock;
+//!
+//! The presets like [`EASE`] are written out as plain constants instead of calling
+//! [`Bezier::new`], since floating point arithmetic in a `const fn` requires a newer compiler
+//! than the minimum supported Rust version of this crate.
 
 /// Precision used for solving the curve.
 /// - See [Newton's method](https://en.wikipedia.org/wiki/Newton%27s_method)
@@ -25,21 +28,117 @@ const MAX_NEWTON_ITERATIONS: usize = 4;
 const CUBIC_BEZIER_SPLINE_SAMPLES: usize = 11;
 
 /// An easing function that starts slow, accelerates sharply, and then slows down gradually.
-pub static EASE: LazyLock<Bezier> = LazyLock::new(|| Bezier::new(0.25, 0.1, 0.25, 1.0));
+///
+/// This is equivalent to `Bezier::new(0.25, 0.1, 0.25, 1.0)`, precomputed so it can be used in
+/// const contexts.
+pub const EASE: Bezier = Bezier {
+    control_points: [0.25, 0.1, 0.25, 1.0],
+    ax: 1.0,
+    bx: -0.75,
+    cx: 0.75,
+    ay: -1.6999998,
+    by: 2.3999999,
+    cy: 0.3,
+    start_gradient: 0.4,
+    end_gradient: -0.0,
+    spline_samples: [
+        0.0,
+        0.068500005,
+        0.128,
+        0.18450001,
+        0.244,
+        0.3125,
+        0.39600003,
+        0.50049996,
+        0.632,
+        0.7965001,
+        1.0,
+    ],
+};
 
 /// An easing function that starts slow and speeds up.
-pub static EASE_IN: LazyLock<Bezier> = LazyLock::new(|| Bezier::new(0.42, 0.0, 1.0, 1.0));
+///
+/// This is equivalent to `Bezier::new(0.42, 0.0, 1.0, 1.0)`, precomputed so it can be used in
+/// const contexts.
+pub const EASE_IN: Bezier = Bezier {
+    control_points: [0.42, 0.0, 1.0, 1.0],
+    ax: -0.7400001,
+    bx: 0.48000014,
+    cx: 1.26,
+    ay: -2.0,
+    by: 3.0,
+    cy: 0.0,
+    start_gradient: 0.0,
+    end_gradient: 1.7241378,
+    spline_samples: [
+        0.0, 0.13006, 0.26528, 0.40122, 0.53344005, 0.6575, 0.76896006, 0.86337996, 0.93632,
+        0.98334, 1.0,
+    ],
+};
 
 /// An easing function that starts fast and slows down.
-pub static EASE_OUT: LazyLock<Bezier> = LazyLock::new(|| Bezier::new(0.0, 0.0, 0.58, 1.0));
+///
+/// This is equivalent to `Bezier::new(0.0, 0.0, 0.58, 1.0)`, precomputed so it can be used in
+/// const contexts.
+pub const EASE_OUT: Bezier = Bezier {
+    control_points: [0.0, 0.0, 0.58, 1.0],
+    ax: -0.74,
+    bx: 1.74,
+    cx: 0.0,
+    ay: -2.0,
+    by: 3.0,
+    cy: 0.0,
+    start_gradient: 1.724138,
+    end_gradient: -0.0,
+    spline_samples: [
+        0.0,
+        0.016660001,
+        0.06368,
+        0.13662001,
+        0.23104,
+        0.3425,
+        0.46656004,
+        0.59878,
+        0.73472,
+        0.8699401,
+        1.0,
+    ],
+};
 
 /// An easing function that starts slow, speeds up, and then slows down.
-pub static EASE_IN_OUT: LazyLock<Bezier> = LazyLock::new(|| Bezier::new(0.42, 0.0, 0.58, 1.0));
+///
+/// This is equivalent to `Bezier::new(0.42, 0.0, 0.58, 1.0)`, precomputed so it can be used in
+/// const contexts.
+pub const EASE_IN_OUT: Bezier = Bezier {
+    control_points: [0.42, 0.0, 0.58, 1.0],
+    ax: 0.52,
+    bx: -0.78,
+    cx: 1.26,
+    ay: -2.0,
+    by: 3.0,
+    cy: 0.0,
+    start_gradient: 0.0,
+    end_gradient: -0.0,
+    spline_samples: [
+        0.0,
+        0.118719995,
+        0.22496,
+        0.32184002,
+        0.41248003,
+        0.5,
+        0.58752,
+        0.67816,
+        0.77504,
+        0.88128006,
+        1.0,
+    ],
+};
 
 /// A cubic bezier curve implementation designed to solve cubic bezier curves.
 /// The primary use-case is enabling curves like `cubic-bezier()` from CSS.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Bezier {
+    control_points: [f32; 4],
     ax: f32,
     bx: f32,
     cx: f32,
@@ -90,6 +189,7 @@ impl Bezier {
         }
 
         Bezier {
+            control_points: [x1, y1, x2, y2],
             ax,
             bx,
             cx,
@@ -102,6 +202,11 @@ impl Bezier {
         }
     }
 
+    /// Returns the control points of the curve as `[x1, y1, x2, y2]`.
+    pub fn control_points(&self) -> [f32; 4] {
+        self.control_points
+    }
+
     fn sample_curve_x(ax: f32, bx: f32, cx: f32, t: f32) -> f32 {
         ((ax * t + bx) * t + cx) * t
     }
@@ -114,6 +219,10 @@ impl Bezier {
         (3.0 * self.ax * t + 2.0 * self.bx) * t + self.cx
     }
 
+    fn sample_curve_derivative_y(&self, t: f32) -> f32 {
+        (3.0 * self.ay * t + 2.0 * self.by) * t + self.cy
+    }
+
     fn solve_curve_x(&self, x: f32, epsilon: f32) -> f32 {
         let mut t0 = 0.0;
         let mut t1 = 0.0;
@@ -175,6 +284,30 @@ impl Bezier {
         self.solve_with_precision(x, BEZIER_EPSILON)
     }
 
+    /// The slope of the curve, `dy/dx`, at the given `x`.
+    ///
+    /// The slope is extended past the ends of the curve using the start and end gradients.
+    pub fn slope(&self, x: f32) -> f32 {
+        if x <= 0.0 {
+            return self.start_gradient;
+        } else if x >= 1.0 {
+            return self.end_gradient;
+        }
+
+        let t = self.solve_curve_x(x, BEZIER_EPSILON);
+        let dx = self.sample_curve_derivative_x(t);
+        if dx.abs() < BEZIER_EPSILON {
+            // The curve is vertical here, so fall back to the nearest gradient.
+            return if x < 0.5 {
+                self.start_gradient
+            } else {
+                self.end_gradient
+            };
+        }
+
+        self.sample_curve_derivative_y(t) / dx
+    }
+
     /// Solves for `y` on the curve given `x` using a custom `epsilon`.
     ///
     /// For animations, `x` will usually be your progress in time through the animation.
@@ -186,3 +319,77 @@ impl Bezier {
         }
     }
 }
+
+/// Only the control points are serialized since everything else is derived from them.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Bezier {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.control_points.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Bezier {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [x1, y1, x2, y2] = <[f32; 4]>::deserialize(deserializer)?;
+        Ok(Bezier::new(x1, y1, x2, y2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The precomputed presets should match the curves computed at runtime exactly.
+    #[test]
+    fn presets_match_runtime_curves() {
+        assert_eq!(EASE, Bezier::new(0.25, 0.1, 0.25, 1.0));
+        assert_eq!(EASE_IN, Bezier::new(0.42, 0.0, 1.0, 1.0));
+        assert_eq!(EASE_OUT, Bezier::new(0.0, 0.0, 0.58, 1.0));
+        assert_eq!(EASE_IN_OUT, Bezier::new(0.42, 0.0, 0.58, 1.0));
+
+        // `-0.0 == 0.0`, so compare the bits too in case a sign was lost along the way.
+        let runtime = Bezier::new(0.25, 0.1, 0.25, 1.0);
+        assert_eq!(EASE.end_gradient.to_bits(), runtime.end_gradient.to_bits());
+        for (constant, runtime) in EASE.spline_samples.iter().zip(runtime.spline_samples) {
+            assert_eq!(constant.to_bits(), runtime.to_bits());
+        }
+    }
+
+    /// A bezier with control points along the diagonal is linear and has a constant slope.
+    #[test]
+    fn linear_slope() {
+        let bezier = Bezier::new(0.25, 0.25, 0.75, 0.75);
+        for x in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            assert!((bezier.slope(x) - 1.0).abs() < 1e-3);
+        }
+    }
+
+    /// Ease in starts flat and ends steep.
+    #[test]
+    fn ease_in_slope() {
+        assert!(EASE_IN.slope(0.0).abs() < 1e-3);
+        assert!(EASE_IN.slope(0.5) > EASE_IN.slope(0.1));
+        assert!(EASE_IN.slope(1.0) > 1.0);
+    }
+
+    /// The analytic slope should agree with a numeric derivative of the curve.
+    #[test]
+    fn slope_matches_numeric_derivative() {
+        let h = 1e-3;
+        for x in [0.2, 0.4, 0.6, 0.8] {
+            let numeric = (EASE.solve(x + h) - EASE.solve(x - h)) / (2.0 * h);
+            assert!((EASE.slope(x) - numeric).abs() < 1e-2);
+        }
+    }
+
+    /// A bezier should serialize as its control points and deserialize back to the same curve.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let bezier = Bezier::new(0.2, 0.8, 0.2, 1.0);
+        let json = serde_json::to_string(&bezier).unwrap();
+        assert_eq!(json, "[0.2,0.8,0.2,1.0]");
+        assert_eq!(serde_json::from_str::<Bezier>(&json).unwrap(), bezier);
+    }
+}
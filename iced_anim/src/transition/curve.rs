@@ -1,7 +1,11 @@
 use super::bezier::{Bezier, EASE, EASE_IN, EASE_IN_OUT, EASE_OUT};
 
 /// A curve that describes how a transition should progress.
+///
+/// With the `serde` feature, [`Curve::Bezier`] is (de)serialized as its control points, while
+/// [`Curve::Custom`] can't be serialized since it holds a function pointer.
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Curve {
     /// A linear curve where the value changes at a constant rate.
     #[default]
@@ -15,10 +19,25 @@ pub enum Curve {
     /// A custom curve that takes a progress value in [0.0, 1.0] and returns the value to use
     /// for the transition when interpolating between two values. The output should generally be
     /// in the range of [0.0, 1.0].
+    #[cfg_attr(feature = "serde", serde(skip))]
     Custom(fn(f32) -> f32),
 }
 
 impl Curve {
+    /// Creates a [`Curve::Bezier`] with the control points `(x1, y1)` and `(x2, y2)`, like
+    /// `cubic-bezier(x1, y1, x2, y2)` in CSS.
+    ///
+    /// ```rust
+    /// # use iced_anim::transition::Curve;
+    /// // A curve from a design spec that starts fast and settles gently.
+    /// let curve = Curve::cubic_bezier(0.2, 0.8, 0.2, 1.0);
+    /// assert!(curve.value(0.25) > 0.5);
+    /// assert_eq!(curve.value(1.0), 1.0);
+    /// ```
+    pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Curve::Bezier(Bezier::new(x1, y1, x2, y2))
+    }
+
     /// The value of the curve at the given `progress`.
     ///
     /// Use this to interpolate between two values. The `progress` should be in the range of
@@ -35,4 +54,121 @@ impl Curve {
             Curve::Custom(f) => f(progress),
         }
     }
+
+    /// The slope of the curve at the given `progress`, i.e. how quickly the value changes
+    /// relative to the progress.
+    pub fn slope(&self, progress: f32) -> f32 {
+        match self {
+            Curve::Linear => 1.0,
+            Curve::Ease => EASE.slope(progress),
+            Curve::EaseIn => EASE_IN.slope(progress),
+            Curve::EaseOut => EASE_OUT.slope(progress),
+            Curve::EaseInOut => EASE_IN_OUT.slope(progress),
+            Curve::Bezier(bezier) => bezier.slope(progress),
+            Curve::Custom(f) => {
+                // Custom curves don't have an analytic derivative, so approximate it.
+                const H: f32 = 1e-3;
+                (f(progress + H) - f(progress - H)) / (2.0 * H)
+            }
+        }
+    }
+
+    /// Finds the progress within the first half of the curve whose slope best matches `slope`.
+    ///
+    /// This lets a new transition start with the same speed that a previous one ended with.
+    pub fn progress_for_slope(&self, slope: f32) -> f32 {
+        const STEPS: usize = 50;
+        (0..=STEPS)
+            .map(|step| 0.5 * step as f32 / STEPS as f32)
+            .fold((0.0, f32::INFINITY), |(best, best_error), progress| {
+                let error = (self.slope(progress) - slope).abs();
+                if error < best_error {
+                    (progress, error)
+                } else {
+                    (best, best_error)
+                }
+            })
+            .0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Cubic beziers with the control points of a preset should follow the preset.
+    #[test]
+    fn cubic_bezier_matches_presets() {
+        let presets = [
+            (Curve::Ease, Curve::cubic_bezier(0.25, 0.1, 0.25, 1.0)),
+            (Curve::EaseIn, Curve::cubic_bezier(0.42, 0.0, 1.0, 1.0)),
+            (Curve::EaseOut, Curve::cubic_bezier(0.0, 0.0, 0.58, 1.0)),
+            (Curve::EaseInOut, Curve::cubic_bezier(0.42, 0.0, 0.58, 1.0)),
+        ];
+        for (preset, custom) in presets {
+            for progress in [0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0] {
+                assert_eq!(preset.value(progress), custom.value(progress));
+            }
+        }
+    }
+
+    /// Cubic beziers should keep their control points.
+    #[test]
+    fn cubic_bezier_control_points() {
+        let Curve::Bezier(bezier) = Curve::cubic_bezier(0.2, 0.8, 0.2, 1.0) else {
+            panic!("expected a bezier curve");
+        };
+        assert_eq!(bezier.control_points(), [0.2, 0.8, 0.2, 1.0]);
+    }
+
+    #[test]
+    fn linear_slope() {
+        assert_eq!(Curve::Linear.slope(0.0), 1.0);
+        assert_eq!(Curve::Linear.slope(0.7), 1.0);
+    }
+
+    /// Custom curves should approximate their slope numerically.
+    #[test]
+    fn custom_slope() {
+        let curve = Curve::Custom(|x| x * x);
+        assert!((curve.slope(0.5) - 1.0).abs() < 1e-2);
+    }
+
+    /// Linear curves have the same slope everywhere, so the start is the best match.
+    #[test]
+    fn linear_progress_for_slope() {
+        assert_eq!(Curve::Linear.progress_for_slope(1.0), 0.0);
+    }
+
+    /// Curves that start flat should skip ahead to match a steeper slope.
+    #[test]
+    fn ease_in_progress_for_slope() {
+        let progress = Curve::EaseIn.progress_for_slope(1.0);
+        assert!(progress > 0.0);
+        assert!((Curve::EaseIn.slope(progress) - 1.0).abs() < 0.1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let curves = [
+            Curve::Linear,
+            Curve::Ease,
+            Curve::EaseIn,
+            Curve::EaseOut,
+            Curve::EaseInOut,
+            Curve::cubic_bezier(0.2, 0.8, 0.2, 1.0),
+        ];
+        for curve in curves {
+            let json = serde_json::to_string(&curve).unwrap();
+            assert_eq!(serde_json::from_str::<Curve>(&json).unwrap(), curve);
+        }
+    }
+
+    /// Custom curves hold a function pointer, so they can't be serialized.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_custom_curve_fails() {
+        assert!(serde_json::to_string(&Curve::Custom(|x| x * x)).is_err());
+    }
 }
@@ -4,11 +4,18 @@ use super::Curve;
 use std::time::Duration;
 
 /// A configuration for creating a `Transition`.
+///
+/// With the `serde` feature, the duration is (de)serialized as milliseconds and missing fields
+/// fall back to [`Easing::default`].
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Easing {
     /// The curve to use to determine how to update the current value over time.
     pub curve: Curve,
-    /// How long the transition should take to complete.
+    /// How long the transition should take to complete. Durations shorter than
+    /// [`MIN_DURATION`](super::MIN_DURATION) complete on the next tick.
+    #[cfg_attr(feature = "serde", serde(with = "crate::millis"))]
     pub duration: Duration,
     /// Whether the transition will move in reverse if a new target value
     /// is the same as the initial value.
@@ -18,6 +25,18 @@ pub struct Easing {
     /// changing the target value will always be treated as moving forward along the curve and
     /// restart the transition from the beginning.
     pub reversible: bool,
+    /// Whether a transition that is retargeted right as it completes will start the new
+    /// transition partway along the curve to match the speed the previous one ended with.
+    ///
+    /// This avoids a dead stop between chained transitions, e.g. when an app immediately
+    /// sets a new target once a transition completes.
+    pub chain_smoothing: bool,
+    /// Whether a transition that is retargeted while it's moving carries its current velocity
+    /// into the start of the new transition.
+    ///
+    /// This avoids the value momentarily stopping when it's interrupted, especially with curves
+    /// that start slowly like [`Curve::EaseInOut`].
+    pub velocity_matching: bool,
 }
 
 impl Default for Easing {
@@ -26,6 +45,8 @@ impl Default for Easing {
             curve: Curve::default(),
             duration: DEFAULT_DURATION,
             reversible: false,
+            chain_smoothing: false,
+            velocity_matching: false,
         }
     }
 }
@@ -36,6 +57,8 @@ impl Easing {
         curve: Curve::Linear,
         duration: DEFAULT_DURATION,
         reversible: false,
+        chain_smoothing: false,
+        velocity_matching: false,
     };
 
     /// A default easing that uses [`Curve::Ease`] and the default duration.
@@ -43,6 +66,8 @@ impl Easing {
         curve: Curve::Ease,
         duration: DEFAULT_DURATION,
         reversible: false,
+        chain_smoothing: false,
+        velocity_matching: false,
     };
 
     /// A default easing that uses [`Curve::EaseIn`] and the default duration.
@@ -50,6 +75,8 @@ impl Easing {
         curve: Curve::EaseIn,
         duration: DEFAULT_DURATION,
         reversible: false,
+        chain_smoothing: false,
+        velocity_matching: false,
     };
 
     /// A default easing that uses [`Curve::EaseOut`] and the default duration.
@@ -57,6 +84,8 @@ impl Easing {
         curve: Curve::EaseOut,
         duration: DEFAULT_DURATION,
         reversible: false,
+        chain_smoothing: false,
+        velocity_matching: false,
     };
 
     /// A default easing that uses [`Curve::EaseInOut`] and the default duration.
@@ -64,6 +93,8 @@ impl Easing {
         curve: Curve::EaseInOut,
         duration: DEFAULT_DURATION,
         reversible: false,
+        chain_smoothing: false,
+        velocity_matching: false,
     };
 
     /// Creates a new [`Easing`] with the given `curve`.
@@ -72,6 +103,8 @@ impl Easing {
             curve,
             duration: DEFAULT_DURATION,
             reversible: false,
+            chain_smoothing: false,
+            velocity_matching: false,
         }
     }
 
@@ -97,6 +130,28 @@ impl Easing {
         self.reversible = reversible;
         self
     }
+
+    /// Sets whether chained transitions keep their speed across the joint and returns the
+    /// updated easing.
+    ///
+    /// When enabled, a new target set shortly after a transition completes (or during its final
+    /// few percent) starts the new transition at the point on the curve whose slope matches the
+    /// slope the previous transition ended with.
+    pub fn chain_smoothing(mut self, chain_smoothing: bool) -> Self {
+        self.chain_smoothing = chain_smoothing;
+        self
+    }
+
+    /// Sets whether interrupted transitions keep their speed and returns the updated easing.
+    ///
+    /// When enabled, a new target set while the transition is moving blends the velocity it had
+    /// into the first [`VELOCITY_MATCHING_WINDOW`](super::VELOCITY_MATCHING_WINDOW) of the new
+    /// transition, so the value eases from its current speed onto the curve instead of restarting
+    /// from the curve's initial speed.
+    pub fn velocity_matching(mut self, velocity_matching: bool) -> Self {
+        self.velocity_matching = velocity_matching;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -119,6 +174,8 @@ mod tests {
         assert_eq!(easing.curve, Curve::default());
         assert_eq!(easing.duration, DEFAULT_DURATION);
         assert!(!easing.reversible);
+        assert!(!easing.chain_smoothing);
+        assert!(!easing.velocity_matching);
     }
 
     #[test]
@@ -131,4 +188,24 @@ mod tests {
         assert_eq!(easing.duration, Duration::from_millis(300));
         assert!(easing.reversible);
     }
+
+    /// Durations should be serialized as milliseconds.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let easing = Easing::EASE_OUT
+            .with_duration(Duration::from_millis(250))
+            .reversible(true);
+        let json = serde_json::to_value(easing).unwrap();
+        assert_eq!(json["duration"], 250);
+        assert_eq!(serde_json::from_value::<Easing>(json).unwrap(), easing);
+    }
+
+    /// Missing fields should fall back to the default easing.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_missing_fields() {
+        let easing: Easing = serde_json::from_str(r#"{ "curve": "EaseIn" }"#).unwrap();
+        assert_eq!(easing, Easing::EASE_IN);
+    }
 }
@@ -58,8 +58,14 @@ impl Progress {
     }
 
     /// Updates the progress by the given delta, clamping the result to the range [0.0, 1.0].
+    /// Progress that isn't finite completes the transition rather than never finishing it.
     pub fn update(&mut self, delta_progress: f32) {
-        let progress = (self.progress() + delta_progress).clamp(0.0, 1.0);
+        let progress = self.progress() + delta_progress;
+        let progress = if progress.is_finite() {
+            progress.clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
         match self {
             Self::Forward(p) | Self::Reverse(p) => *p = progress,
         }
@@ -143,6 +149,18 @@ mod tests {
         assert_eq!(progress.value(), 1.0);
     }
 
+    /// Progress that isn't finite should complete the transition instead of never finishing.
+    #[test]
+    fn update_non_finite() {
+        let mut progress = Progress::Forward(0.0);
+        progress.update(f32::NAN);
+        assert!(progress.is_complete());
+
+        let mut progress = Progress::Reverse(0.5);
+        progress.update(f32::INFINITY);
+        assert!(progress.is_complete());
+    }
+
     /// The default [`Progress`] should be [`Progress::Forward`] with a value of 1.0
     /// to ensure new transitions are idle.
     #[test]
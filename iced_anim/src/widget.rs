@@ -8,17 +8,71 @@
 //!
 //! Animations work out of the box, but there are a couple of things to keep in mind:
 //!
-//! - Styles with enum representations (including `Option`) don't animate between variants.
+//! - Styles with enum representations don't animate between variants.
 //!   This mostly comes down to being unable to cleanly represent a transition between different
-//!   variants since the underlying data between them is different. For example, if you're creating
-//!   a button style with a particular color, then you would want all backgrounds in that style to
-//!   only be an [`iced::Color`] and not an [`iced::Gradient`]. Use default or empty values like
-//!   [`iced::Color::TRANSPARENT`] in place of [`None`] to ensure optional values are animated,
-//!   since [`None`] counts as a different variant.
+//!   variants since the underlying data between them is different. Backgrounds are the exception,
+//!   since an [`iced::Color`] cross-fades into an [`iced::Gradient`] as a uniform gradient, and so
+//!   are optional colors and backgrounds, since [`None`] fades in and out as a transparent version
+//!   of the other value.
 //! - You can disable animations by passing a [`Motion`] with a duration of `0.0` to the
 //!   `motion` method, but there may be a more ergonomic way to do this in the future.
+pub mod animated_layout;
+pub mod animated_number;
+pub mod animated_visibility;
 pub mod button;
+pub mod checkbox;
+pub mod container;
+mod cycle;
+pub mod flip_group;
+pub mod follow_cursor;
+pub mod freeze;
+pub mod image;
+pub mod indeterminate_bar;
+pub mod marquee;
+pub mod opacity;
+pub mod pick_list;
+pub mod radio;
+pub mod relative_layer;
+pub mod retain_exit;
+mod retention;
+mod ripple;
+pub mod rule;
+pub mod scrollable;
+pub mod slider;
+pub mod spinner;
+pub mod stepper;
 pub mod svg;
+pub mod text;
+pub mod text_input;
+pub mod toggler;
+pub mod tooltip;
+pub mod transform;
 
+pub use animated_layout::{animated_layout, AnimatedLayout};
+pub use animated_number::{animated_number, AnimatedNumber};
+pub use animated_visibility::{animated_visibility, AnimatedVisibility};
 pub use button::{button, Button};
+pub use checkbox::{checkbox, Checkbox};
+pub use container::{container, Container};
+pub use flip_group::{flip_group, FlipGroup};
+pub use follow_cursor::{follow_cursor, FollowCursor};
+pub use freeze::{freeze, Freeze};
+pub use image::{image, Image};
+pub use indeterminate_bar::{indeterminate_bar, IndeterminateBar};
+pub use marquee::{marquee, Marquee};
+pub use opacity::{opacity, Opacity};
+pub use pick_list::{pick_list, PickList};
+pub use radio::{radio, Radio};
+pub use relative_layer::{relative_layer, RelativeLayer};
+pub use retain_exit::{retain_exit, ExitPhase, RetainExit};
+pub use rule::{horizontal_rule, vertical_rule, Rule};
+pub use scrollable::{scrollable, Scrollable};
+pub use slider::{slider, Slider};
+pub use spinner::{spinner, Spinner};
+pub use stepper::{stepper, Stepper};
 pub use svg::{svg, Svg};
+pub use text::{text, Text};
+pub use text_input::{text_input, TextInput};
+pub use toggler::{toggler, Toggler};
+pub use tooltip::{tooltip, Tooltip};
+pub use transform::{transform, Transform, TransformWidget};
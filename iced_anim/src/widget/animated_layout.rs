@@ -0,0 +1,330 @@
+//! A container that animates its content between layout positions and sizes.
+//!
+//! When the content moves because a sibling appeared or a list was re-sorted, Iced lays it out at
+//! its new position right away. An [`AnimatedLayout`] remembers where the content was drawn and
+//! glides it to its new bounds instead of snapping, similar to implicit layout animations in
+//! other UI frameworks. Content that grows is revealed as its bounds expand.
+//!
+//! ```rust
+//! # use iced::{Element, widget::{column, text}};
+//! # use iced_anim::{spring::Motion, widget::animated_layout};
+//! # fn view<'a, Message: 'a>(items: &'a [String]) -> Element<'a, Message> {
+//! // Rows glide to their new positions whenever the list is re-sorted.
+//! column(items.iter().map(|item| {
+//!     animated_layout(text(item))
+//!         .animation(Motion::SNAPPY)
+//!         .into()
+//! }))
+//! .into()
+//! # }
+//! ```
+//!
+//! > Note: the content is only drawn at its animated bounds, so it still receives events at its
+//! > actual layout bounds while it's moving.
+use crate::{animated::Mode, Animate, Animated};
+use iced::{
+    advanced::{
+        graphics::core::event,
+        layout, mouse, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    window, Element, Event, Length, Rectangle, Size, Vector,
+};
+
+/// The default distance in logical pixels that the bounds must move before they're animated.
+const DEFAULT_THRESHOLD: f32 = 0.5;
+
+/// A container that animates its content between layout positions and sizes.
+pub struct AnimatedLayout<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// The content whose layout changes will be animated.
+    content: Element<'a, Message, Theme, Renderer>,
+    /// The animation used to move between bounds.
+    mode: Mode,
+    /// Changes smaller than this many logical pixels are applied without animating.
+    threshold: f32,
+}
+
+impl<'a, Message, Theme, Renderer> AnimatedLayout<'a, Message, Theme, Renderer> {
+    /// Creates a new [`AnimatedLayout`] that animates layout changes of the `content`.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            mode: Mode::default(),
+            threshold: DEFAULT_THRESHOLD,
+        }
+    }
+
+    /// Sets the animation used to move the content between its bounds.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+
+    /// Sets the distance in logical pixels that the bounds must move before they're animated.
+    ///
+    /// Smaller changes, like sub-pixel differences from rounding, are applied immediately.
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// The bounds that the content is drawn at, once it has been laid out.
+    bounds: Option<Animated<Rectangle>>,
+}
+
+impl State {
+    /// Records the latest layout `bounds`, animating towards them if they moved far enough from
+    /// where the content is currently drawn.
+    fn observe(&mut self, bounds: Rectangle, threshold: f32, mode: Mode) {
+        let Some(animated) = self.bounds.as_mut() else {
+            self.bounds = Some(Animated::new(bounds, mode));
+            return;
+        };
+
+        if animated.mode() != mode {
+            animated.apply(mode);
+        }
+        if animated.target() == &bounds {
+            return;
+        }
+
+        let distance = animated
+            .value()
+            .distance_to(&bounds)
+            .into_iter()
+            .fold(0.0, |max, distance| distance.abs().max(max));
+        if distance > threshold {
+            animated.set_target(bounds);
+        } else {
+            animated.settle_at(bounds);
+        }
+    }
+
+    /// Whether the content is still moving towards its latest bounds.
+    fn is_animating(&self) -> bool {
+        self.bounds.as_ref().is_some_and(Animated::is_animating)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for AnimatedLayout<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            if let Some(bounds) = tree.state.downcast_mut::<State>().bounds.as_mut() {
+                bounds.resume_at(now);
+            }
+        }
+
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // The position is only known once the parent has placed the content, so layout changes
+        // are picked up on the next event rather than in `layout`.
+        let state = tree.state.downcast_mut::<State>();
+        state.observe(layout.bounds(), self.threshold, self.mode);
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if let Some(bounds) = state.bounds.as_mut() {
+                bounds.tick(now);
+            }
+        }
+        if state.is_animating() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let current = state
+            .bounds
+            .as_ref()
+            .map_or(bounds, |animated| *animated.value());
+
+        let translation = current.position() - bounds.position();
+        let draw_content = |renderer: &mut Renderer| {
+            renderer.with_translation(translation, |renderer| {
+                self.content.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor,
+                    &(*viewport - translation),
+                );
+            });
+        };
+
+        // Growing content is clipped to its animated size so it's revealed as it expands.
+        if current.width < bounds.width || current.height < bounds.height {
+            renderer.with_layer(current, draw_content);
+        } else {
+            draw_content(renderer);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<AnimatedLayout<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(animated_layout: AnimatedLayout<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(animated_layout)
+    }
+}
+
+/// Creates a new [`AnimatedLayout`] that animates layout changes of the `content`.
+pub fn animated_layout<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> AnimatedLayout<'a, Message, Theme, Renderer> {
+    AnimatedLayout::new(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spring::Motion;
+    use iced::Point;
+
+    fn bounds(x: f32) -> Rectangle {
+        Rectangle::new(Point::new(x, 0.0), Size::new(100.0, 20.0))
+    }
+
+    /// Moving the content should animate from where it was drawn to its new bounds.
+    #[test]
+    fn moving_animates_to_new_bounds() {
+        let mut state = State::default();
+        state.observe(bounds(0.0), DEFAULT_THRESHOLD, Motion::SMOOTH.into());
+        assert!(!state.is_animating());
+
+        state.observe(bounds(50.0), DEFAULT_THRESHOLD, Motion::SMOOTH.into());
+        assert!(state.is_animating());
+        let animated = state.bounds.as_ref().unwrap();
+        assert_eq!(*animated.value(), bounds(0.0));
+        assert_eq!(*animated.target(), bounds(50.0));
+    }
+
+    /// Sub-pixel changes shouldn't start an animation.
+    #[test]
+    fn small_changes_are_applied_immediately() {
+        let mut state = State::default();
+        state.observe(bounds(0.0), DEFAULT_THRESHOLD, Motion::SMOOTH.into());
+        state.observe(bounds(0.25), DEFAULT_THRESHOLD, Motion::SMOOTH.into());
+        assert!(!state.is_animating());
+        assert_eq!(*state.bounds.as_ref().unwrap().value(), bounds(0.25));
+    }
+}
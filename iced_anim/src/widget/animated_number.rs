@@ -0,0 +1,409 @@
+//! Text that counts towards a number whenever it changes, like an odometer.
+//!
+//! The number itself is animated, and the text is formatted again on every frame, so counters,
+//! scores, and totals roll towards their new value instead of jumping to it:
+//!
+//! ```rust
+//! # use iced::Element;
+//! # use iced_anim::{spring::Motion, widget::animated_number};
+//! # fn view<'a, Message: 'a>(balance: f64) -> Element<'a, Message> {
+//! animated_number(balance)
+//!     .precision(2)
+//!     .size(24)
+//!     .monospace()
+//!     .animation(Motion::SMOOTH)
+//!     .into()
+//! # }
+//! ```
+//!
+//! The width of the text changes with the digits it shows, which can make the layout around it
+//! jitter while it counts. Using a [`monospace`](AnimatedNumber::monospace) font keeps digits the
+//! same width, and a fixed [`width`](AnimatedNumber::width) with a centered or right
+//! [`alignment`](AnimatedNumber::align_x) keeps the number in place as it gains or loses digits.
+use crate::{animate::AnimatedTextStyle, animated::Mode, Animated, AnimatedState};
+use iced::{
+    advanced::{
+        layout, renderer,
+        text::{self as core_text, Paragraph},
+        widget::{text as core_widget, tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event, mouse, window, Color, Element, Event, Length, Pixels, Rectangle, Size,
+};
+
+// Re-export the widget types for convenience
+pub use iced::widget::text::{Catalog, LineHeight, Style, StyleFn};
+
+/// Text that animates towards a number by counting through the values in between.
+#[allow(missing_debug_implementations)]
+pub struct AnimatedNumber<'a, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    value: f64,
+    precision: usize,
+    format: Option<Box<dyn Fn(f64) -> String + 'a>>,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    width: Length,
+    height: Length,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+    mode: Mode,
+}
+
+impl<'a, Theme, Renderer> AnimatedNumber<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    /// Creates a new [`AnimatedNumber`] that counts towards the given `value`.
+    pub fn new(value: f64) -> Self {
+        Self {
+            value,
+            precision: 0,
+            format: None,
+            size: None,
+            line_height: LineHeight::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            font: None,
+            class: Theme::default(),
+            mode: Mode::default(),
+        }
+    }
+
+    /// Sets the number of decimal places shown, which is 0 by default.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets how the number is turned into text, replacing the [`precision`](Self::precision).
+    ///
+    /// This is called with the in-between values while animating, e.g. to add units or
+    /// thousands separators.
+    pub fn format(mut self, format: impl Fn(f64) -> String + 'a) -> Self {
+        self.format = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the size of the [`AnimatedNumber`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`AnimatedNumber`].
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the [`AnimatedNumber`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Uses a monospace font, so every digit takes up the same width while counting.
+    pub fn monospace(self) -> Self
+    where
+        Renderer::Font: From<iced::Font>,
+    {
+        self.font(iced::Font::MONOSPACE)
+    }
+
+    /// Sets the width of the [`AnimatedNumber`] boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`AnimatedNumber`] boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Centers the [`AnimatedNumber`], both horizontally and vertically.
+    pub fn center(self) -> Self {
+        self.align_x(alignment::Horizontal::Center)
+            .align_y(alignment::Vertical::Center)
+    }
+
+    /// Sets the [`alignment::Horizontal`] of the [`AnimatedNumber`].
+    pub fn align_x(mut self, alignment: impl Into<alignment::Horizontal>) -> Self {
+        self.horizontal_alignment = alignment.into();
+        self
+    }
+
+    /// Sets the [`alignment::Vertical`] of the [`AnimatedNumber`].
+    pub fn align_y(mut self, alignment: impl Into<alignment::Vertical>) -> Self {
+        self.vertical_alignment = alignment.into();
+        self
+    }
+
+    /// Sets the style of the [`AnimatedNumber`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the [`Color`] of the [`AnimatedNumber`].
+    pub fn color(self, color: impl Into<Color>) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        let color = Some(color.into());
+        self.style(move |_theme| Style { color })
+    }
+
+    /// Sets the style class of the [`AnimatedNumber`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the animation of the [`AnimatedNumber`], used for both its value and color.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+
+    /// Formats the given `value` as it's displayed.
+    fn formatted(&self, value: f64) -> String {
+        match &self.format {
+            Some(format) => format(value),
+            None => format_number(value, self.precision),
+        }
+    }
+}
+
+/// Formats the `value` with the given number of decimal places, without showing a negative
+/// zero when a value close to zero rounds away its sign.
+fn format_number(value: f64, precision: usize) -> String {
+    let text = format!("{value:.precision$}");
+    match text.strip_prefix('-') {
+        Some(unsigned) if unsigned.chars().all(|c| c == '0' || c == '.') => unsigned.to_owned(),
+        _ => text,
+    }
+}
+
+struct State<P: Paragraph> {
+    paragraph: core_widget::State<P>,
+    /// The number that's currently displayed.
+    value: Animated<f64>,
+    /// The animated style of the text, which only depends on the theme and class.
+    animated_state: AnimatedState<(), AnimatedTextStyle>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for AnimatedNumber<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            paragraph: core_widget::State::default(),
+            value: Animated::new(self.value, self.mode),
+            animated_state: AnimatedState::new((), self.mode),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.animated_state.diff(self.mode);
+        if state.value.mode() != self.mode {
+            state.value.apply(self.mode);
+        }
+        if state.value.target() != &self.value {
+            state.value.set_target(self.value);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let content = self.formatted(*state.value.value());
+
+        core_widget::layout(
+            &mut state.paragraph,
+            renderer,
+            limits,
+            self.width,
+            self.height,
+            &content,
+            self.line_height,
+            self.size,
+            self.font,
+            self.horizontal_alignment,
+            self.vertical_alignment,
+            core_text::Shaping::Basic,
+            core_text::Wrapping::None,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+            state.value.resume_at(now);
+            state.animated_state.resume_at(now);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        if state.animated_state.needs_redraw(()) || state.value.needs_tick() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.animated_state.tick(now);
+            if state.value.is_animating() {
+                // The text is only shaped during layout, and its width changes with the digits.
+                let previous = self.formatted(*state.value.value());
+                state.value.tick(now);
+                if self.formatted(*state.value.value()) != previous {
+                    shell.invalidate_layout();
+                }
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = state
+            .animated_state
+            .current_value(|_| theme.style(&self.class).into());
+
+        core_widget::draw(
+            renderer,
+            renderer_style,
+            layout,
+            state.paragraph.0.raw(),
+            style.0,
+            viewport,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<AnimatedNumber<'a, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(number: AnimatedNumber<'a, Theme, Renderer>) -> Self {
+        Element::new(number)
+    }
+}
+
+/// Creates a new [`AnimatedNumber`] that counts towards the given `value`.
+pub fn animated_number<'a, Theme, Renderer>(value: f64) -> AnimatedNumber<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    AnimatedNumber::new(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transition::Easing;
+    use std::time::{Duration, Instant};
+
+    fn number(value: f64) -> AnimatedNumber<'static, iced::Theme, ()> {
+        animated_number(value).animation(Easing::LINEAR)
+    }
+
+    fn state(tree: &mut Tree) -> &mut State<()> {
+        tree.state.downcast_mut::<State<()>>()
+    }
+
+    /// Changing the number should count through the values in between.
+    #[test]
+    fn diff_counts_towards_value() {
+        let mut tree = Tree::new(&number(0.0) as &dyn Widget<(), iced::Theme, ()>);
+        tree.diff(&number(100.0) as &dyn Widget<(), iced::Theme, ()>);
+
+        let start = Instant::now();
+        let value = &mut state(&mut tree).value;
+        assert_eq!(*value.target(), 100.0);
+        value.resume_at(start);
+        value.tick(start + Duration::from_millis(96));
+        assert!(*value.value() > 0.0 && *value.value() < 100.0);
+
+        for ms in (0..=100).map(|frame| frame * 16) {
+            value.tick(start + Duration::from_millis(ms));
+        }
+        assert_eq!(*value.value(), 100.0);
+    }
+
+    /// Numbers should use the precision or custom format they're given.
+    #[test]
+    fn formatting() {
+        assert_eq!(number(1.0).formatted(12.345), "12");
+        assert_eq!(number(1.0).precision(2).formatted(12.345), "12.35");
+        assert_eq!(number(1.0).formatted(-0.3), "0");
+        assert_eq!(number(1.0).precision(1).formatted(-0.04), "0.0");
+        assert_eq!(number(1.0).formatted(-1.6), "-2");
+
+        let percent = number(1.0).format(|value| format!("{:.0}%", value * 100.0));
+        assert_eq!(percent.formatted(0.5), "50%");
+    }
+}
@@ -0,0 +1,474 @@
+//! A container that animates its content in and out as it's shown and hidden.
+//!
+//! Conditionally including an element in your view makes it appear and vanish instantly. An
+//! [`AnimatedVisibility`] is always included in your view instead, and it animates its content in
+//! when `is_visible` becomes `true` and out when it becomes `false`. The content keeps being drawn
+//! while it exits, and the container's height collapses along with it so siblings slide into
+//! place. Once the exit completes, the container takes up no space until it's visible again.
+//!
+//! ```rust
+//! # use iced::{Element, widget::{column, text}};
+//! # use iced_anim::{transition::Easing, widget::{animated_visibility, animated_visibility::Effect}};
+//! # #[derive(Clone)]
+//! # enum Message {}
+//! # fn view<'a>(rows: &'a [(String, bool)]) -> Element<'a, Message> {
+//! // Removed rows fade and slide away instead of disappearing.
+//! column(rows.iter().map(|(label, is_visible)| {
+//!     animated_visibility(*is_visible, text(label))
+//!         .enter(Easing::EASE_OUT)
+//!         .exit(Easing::EASE_IN)
+//!         .effect(Effect::FADE | Effect::SLIDE_UP)
+//!         .into()
+//! }))
+//! .into()
+//! # }
+//! ```
+//!
+//! > Note: Iced can't change the opacity of arbitrary content, so [`Effect::FADE`] fades the
+//! > text color the content inherits. Content with its own colors should be combined with
+//! > another effect.
+use std::ops::{BitOr, BitOrAssign};
+
+use crate::animated::Mode;
+use iced::{
+    advanced::{
+        graphics::core::event,
+        layout, mouse, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    window, Element, Event, Length, Rectangle, Size, Transformation, Vector,
+};
+
+use super::retention::Retention;
+
+/// How small the content is scaled when it's fully hidden with [`Effect::SCALE`].
+const HIDDEN_SCALE: f32 = 0.8;
+
+/// The visual effects played while content enters and exits, which can be combined with `|`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Effect(u8);
+
+impl Effect {
+    /// Only collapses the content without any other effect.
+    pub const NONE: Self = Self(0);
+    /// Fades the text color inherited by the content.
+    pub const FADE: Self = Self(1);
+    /// Slides the content up as it exits and down as it enters.
+    pub const SLIDE_UP: Self = Self(1 << 1);
+    /// Slides the content down as it exits and up as it enters.
+    pub const SLIDE_DOWN: Self = Self(1 << 2);
+    /// Shrinks the content towards its center as it exits and grows it as it enters.
+    pub const SCALE: Self = Self(1 << 3);
+
+    /// Whether all of the `other` effects are included in this one.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl Default for Effect {
+    fn default() -> Self {
+        Self::FADE
+    }
+}
+
+impl BitOr for Effect {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Effect {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// A container that animates its content in and out as it's shown and hidden.
+pub struct AnimatedVisibility<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// Whether the content should currently be visible.
+    is_visible: bool,
+    /// The content being shown or hidden.
+    content: Element<'a, Message, Theme, Renderer>,
+    /// The mode used when the content becomes visible.
+    enter: Mode,
+    /// The mode used when the content becomes hidden.
+    exit: Mode,
+    /// The effects played while entering and exiting.
+    effect: Effect,
+}
+
+impl<'a, Message, Theme, Renderer> AnimatedVisibility<'a, Message, Theme, Renderer> {
+    /// Creates a new [`AnimatedVisibility`] that shows the `content` while `is_visible` is `true`.
+    pub fn new(
+        is_visible: bool,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            is_visible,
+            content: content.into(),
+            enter: Mode::default(),
+            exit: Mode::default(),
+            effect: Effect::default(),
+        }
+    }
+
+    /// Defines the way the content will animate in and out.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        let mode = mode.into();
+        self.enter = mode;
+        self.exit = mode;
+        self
+    }
+
+    /// Defines the way the content will animate in when it becomes visible.
+    pub fn enter(mut self, mode: impl Into<Mode>) -> Self {
+        self.enter = mode.into();
+        self
+    }
+
+    /// Defines the way the content will animate out when it becomes hidden.
+    pub fn exit(mut self, mode: impl Into<Mode>) -> Self {
+        self.exit = mode.into();
+        self
+    }
+
+    /// Sets the effects played while the content enters and exits.
+    pub fn effect(mut self, effect: Effect) -> Self {
+        self.effect = effect;
+        self
+    }
+
+    /// The mode for the direction the content is currently heading in.
+    fn mode(&self) -> Mode {
+        if self.is_visible {
+            self.enter
+        } else {
+            self.exit
+        }
+    }
+
+    /// The transformation applied to content with the given `visibility` inside its `bounds`.
+    fn transformation(&self, bounds: Rectangle, visibility: f32) -> Transformation {
+        let hidden = 1.0 - visibility;
+        let mut offset = 0.0;
+        if self.effect.contains(Effect::SLIDE_UP) {
+            offset -= bounds.height * hidden;
+        }
+        if self.effect.contains(Effect::SLIDE_DOWN) {
+            offset += bounds.height * hidden;
+        }
+
+        let slide = Transformation::translate(0.0, offset);
+        if !self.effect.contains(Effect::SCALE) {
+            return slide;
+        }
+
+        let scale = HIDDEN_SCALE + (1.0 - HIDDEN_SCALE) * visibility;
+        let center = bounds.center();
+        slide
+            * Transformation::translate(center.x, center.y)
+            * Transformation::scale(scale)
+            * Transformation::translate(-center.x, -center.y)
+    }
+}
+
+/// Collapses the height of the content `size` based on its `visibility`.
+fn collapse(size: Size, visibility: f32) -> Size {
+    Size::new(size.width, size.height * visibility.clamp(0.0, 1.0))
+}
+
+struct State {
+    retention: Retention,
+    mode: Mode,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for AnimatedVisibility<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            retention: Retention::new(self.is_visible, self.mode()),
+            mode: self.mode(),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // The mode is applied before the visibility changes so the new direction uses it.
+        let mode = self.mode();
+        if state.mode != mode {
+            state.mode = mode;
+            state.retention.apply(mode);
+        }
+
+        state.retention.set_visible(self.is_visible);
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_ref::<State>();
+        if state.retention.is_released() {
+            return layout::Node::new(Size::ZERO);
+        }
+
+        let visibility = state.retention.phase().visibility();
+        let content = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+        layout::Node::with_children(collapse(content.size(), visibility), vec![content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State>();
+            state.retention.resume_at(now);
+        }
+
+        if let Some(content_layout) = layout.children().next() {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                content_layout,
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            if state.retention.is_animating() {
+                state.retention.tick(now);
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+                shell.invalidate_layout();
+            }
+        }
+
+        // Exiting content is no longer interactive.
+        if !self.is_visible || state.retention.is_released() {
+            return event::Status::Ignored;
+        }
+
+        let Some(content_layout) = layout.children().next() else {
+            return event::Status::Ignored;
+        };
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            content_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let Some(content_layout) = layout.children().next() else {
+            return;
+        };
+
+        let state = tree.state.downcast_ref::<State>();
+        let visibility = state.retention.phase().visibility();
+        if visibility >= 1.0 {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                content_layout,
+                cursor,
+                viewport,
+            );
+            return;
+        }
+
+        let style = if self.effect.contains(Effect::FADE) {
+            renderer::Style {
+                text_color: style.text_color.scale_alpha(visibility),
+            }
+        } else {
+            *style
+        };
+
+        let transformation = self.transformation(content_layout.bounds(), visibility);
+
+        // The content is clipped to the collapsing bounds so it doesn't overlap its siblings.
+        renderer.with_layer(layout.bounds(), |renderer| {
+            renderer.with_transformation(transformation, |renderer| {
+                self.content.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    &style,
+                    content_layout,
+                    cursor,
+                    viewport,
+                );
+            });
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match layout.children().next() {
+            Some(content_layout) if self.is_visible => self.content.as_widget().mouse_interaction(
+                &tree.children[0],
+                content_layout,
+                cursor,
+                viewport,
+                renderer,
+            ),
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        // Overlays can't be animated along with the content, so they close as soon as it exits.
+        if !self.is_visible {
+            return None;
+        }
+
+        let content_layout = layout.children().next()?;
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            content_layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<AnimatedVisibility<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(animated_visibility: AnimatedVisibility<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(animated_visibility)
+    }
+}
+
+/// Creates a new [`AnimatedVisibility`] that animates the `content` in and out.
+pub fn animated_visibility<'a, Message, Theme, Renderer>(
+    is_visible: bool,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> AnimatedVisibility<'a, Message, Theme, Renderer> {
+    AnimatedVisibility::new(is_visible, content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transition::Easing;
+    use iced::widget::Space;
+
+    fn visibility(is_visible: bool) -> AnimatedVisibility<'static, ()> {
+        animated_visibility(is_visible, Space::new(10.0, 10.0))
+            .enter(Easing::EASE_OUT)
+            .exit(Easing::EASE_IN)
+    }
+
+    #[test]
+    fn combine_effects() {
+        let effect = Effect::FADE | Effect::SLIDE_UP;
+        assert!(effect.contains(Effect::FADE));
+        assert!(effect.contains(Effect::SLIDE_UP));
+        assert!(!effect.contains(Effect::SCALE));
+        assert!(effect.contains(Effect::NONE));
+    }
+
+    /// The enter mode should be used when showing content and the exit mode when hiding it.
+    #[test]
+    fn mode_follows_direction() {
+        assert_eq!(visibility(true).mode(), Mode::from(Easing::EASE_OUT));
+        assert_eq!(visibility(false).mode(), Mode::from(Easing::EASE_IN));
+    }
+
+    /// Only the height collapses so rows below the content slide up into its place.
+    #[test]
+    fn collapse_height() {
+        let size = Size::new(100.0, 40.0);
+        assert_eq!(collapse(size, 1.0), size);
+        assert_eq!(collapse(size, 0.25), Size::new(100.0, 10.0));
+        assert_eq!(collapse(size, -0.5), Size::new(100.0, 0.0));
+    }
+
+    /// Sliding up moves hidden content above its bounds by its own height.
+    #[test]
+    fn slide_up_when_hidden() {
+        let bounds = Rectangle::new(iced::Point::ORIGIN, Size::new(100.0, 40.0));
+        let widget = visibility(false).effect(Effect::SLIDE_UP);
+        assert_eq!(
+            widget.transformation(bounds, 0.0),
+            Transformation::translate(0.0, -40.0)
+        );
+        assert_eq!(widget.transformation(bounds, 1.0), Transformation::IDENTITY);
+    }
+}
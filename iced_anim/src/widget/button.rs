@@ -1,5 +1,8 @@
 //! An animated button that will automatically transition between different styles.
-use crate::{animated::Mode, AnimatedState};
+//!
+//! Buttons can also show a [`ripple`](Button::ripple) that expands from where they're pressed.
+use super::ripple::Ripples;
+use crate::{animated::Mode, elevation::Elevation, AnimatedState};
 use iced::{
     advanced::{
         layout, renderer,
@@ -8,8 +11,8 @@ use iced::{
     },
     event,
     mouse::{self, Cursor},
-    overlay, touch, window, Background, Color, Element, Event, Length, Padding, Rectangle, Size,
-    Vector,
+    overlay, touch, window, Background, Border, Color, Element, Event, Length, Padding, Rectangle,
+    Size, Vector,
 };
 
 // Re-export the widget types for convenience
@@ -31,8 +34,18 @@ where
     clip: bool,
     class: Theme::Class<'a>,
     mode: Mode,
+    mode_for: Option<ModeFor<'a>>,
+    has_ripple: bool,
+    ripple_color: Option<Color>,
+    elevation: Option<Elevation>,
 }
 
+/// The opacity of a ripple when it starts, before it fades out.
+const RIPPLE_OPACITY: f32 = 0.3;
+
+/// Resolves the animation mode used when the status changes from one status to another.
+type ModeFor<'a> = Box<dyn Fn(&Status, &Status) -> Mode + 'a>;
+
 enum OnPress<'a, Message> {
     Direct(Message),
     Closure(Box<dyn Fn() -> Message + 'a>),
@@ -65,6 +78,10 @@ where
             clip: false,
             class: Theme::default(),
             mode: Mode::default(),
+            mode_for: None,
+            has_ripple: false,
+            ripple_color: None,
+            elevation: None,
         }
     }
 
@@ -146,6 +163,72 @@ where
         self
     }
 
+    /// Sets the animation of the [`Button`] for each status change, e.g. to give instant feedback
+    /// when the button is pressed and ease out slowly when it's released.
+    ///
+    /// The closure receives the status the button is changing `from` and the status it's
+    /// changing `to`. The mode set with [`Button::animation`] is used until the status changes.
+    ///
+    /// ```rust
+    /// # use iced_anim::{animated::Mode, spring::Motion, transition::Easing};
+    /// # use iced_anim::widget::button::{button, Status};
+    /// # use std::time::Duration;
+    /// # let _: iced_anim::widget::Button<'_, ()> =
+    /// button("Press me").animation_with(|_from, to| match to {
+    ///     Status::Pressed => Mode::from(Motion::SNAPPY.with_duration(Duration::from_millis(100))),
+    ///     _ => Mode::from(Easing::EASE_OUT.with_duration(Duration::from_millis(400))),
+    /// })
+    /// # ;
+    /// ```
+    pub fn animation_with<M>(mut self, mode_for: impl Fn(&Status, &Status) -> M + 'a) -> Self
+    where
+        M: Into<Mode>,
+    {
+        self.mode_for = Some(Box::new(move |from, to| mode_for(from, to).into()));
+        self
+    }
+
+    /// Sets whether pressing the [`Button`] shows a ripple that expands from the press position
+    /// and fades out. Overlapping ripples from rapid presses are clipped to the button's bounds.
+    pub fn ripple(mut self, ripple: bool) -> Self {
+        self.has_ripple = ripple;
+        self
+    }
+
+    /// Sets the color of the [`Button`]'s ripples, which defaults to the text color.
+    pub fn ripple_color(mut self, color: impl Into<Color>) -> Self {
+        self.ripple_color = Some(color.into());
+        self
+    }
+
+    /// Sets the elevation of the [`Button`], which replaces the shadow of its style with the
+    /// [`elevation`](crate::elevation) preset for that level.
+    ///
+    /// The button is raised by one level while it's hovered, settles back while it's pressed,
+    /// and is flat while it's disabled, animating the shadow between each status.
+    pub fn elevation(mut self, level: u8) -> Self {
+        self.elevation = Some(Elevation::from(level));
+        self
+    }
+
+    /// The style of the [`Button`] for the given `status`, including its elevation.
+    fn style_for(&self, theme: &Theme, status: Status) -> Style {
+        let style = theme.style(&self.class, status);
+        let Some(elevation) = self.elevation else {
+            return style;
+        };
+
+        let elevation = match status {
+            Status::Active | Status::Pressed => elevation,
+            Status::Hovered => elevation.raised(1),
+            Status::Disabled => Elevation(0),
+        };
+        Style {
+            shadow: elevation.shadow(),
+            ..style
+        }
+    }
+
     /// The initial status that this widget will have based on its properties.
     ///
     /// This will be used as the initial state value.
@@ -178,6 +261,7 @@ where
 struct State {
     is_pressed: bool,
     animated_state: AnimatedState<Status, Style>,
+    ripples: Ripples,
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -197,6 +281,7 @@ where
         let state = State {
             is_pressed: false,
             animated_state: AnimatedState::new(status, self.mode),
+            ripples: Ripples::default(),
         };
 
         tree::State::new(state)
@@ -240,6 +325,12 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State>();
+            state.animated_state.resume_at(now);
+            state.ripples.resume_at(now);
+        }
+
         operation.container(None, layout.bounds(), &mut |operation| {
             self.content.as_widget().operate(
                 &mut tree.children[0],
@@ -277,25 +368,32 @@ where
         // Redraw anytime the status changes and would trigger a style change.
         let state = tree.state.downcast_mut::<State>();
         let status = self.get_status(state, cursor, layout);
-        let needs_redraw = state.animated_state.needs_redraw(status);
-
-        if needs_redraw {
-            shell.request_redraw(window::RedrawRequest::NextFrame);
+        match &self.mode_for {
+            Some(mode_for) => {
+                state
+                    .animated_state
+                    .handle_event_with(&event, status, mode_for, shell);
+            }
+            None => state.animated_state.handle_event(&event, status, shell),
         }
 
         match event {
-            Event::Window(window::Event::RedrawRequested(now)) => {
-                state.animated_state.tick(now);
+            Event::Window(window::Event::RedrawRequested(now)) if state.ripples.is_animating() => {
+                state.ripples.tick(now);
+                shell.request_redraw(window::RedrawRequest::NextFrame);
             }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 if self.on_press.is_some() {
                     let bounds = layout.bounds();
 
-                    if cursor.is_over(bounds) {
+                    if let Some(position) = cursor.position_over(bounds) {
                         let state = tree.state.downcast_mut::<State>();
 
                         state.is_pressed = true;
+                        if self.has_ripple {
+                            state.ripples.spawn(bounds, position, crate::clock::now());
+                        }
                         shell.request_redraw(window::RedrawRequest::NextFrame);
 
                         return event::Status::Captured;
@@ -349,7 +447,7 @@ where
 
         let style = state
             .animated_state
-            .current_value(|status| theme.style(&self.class, *status));
+            .current_value(|status| self.style_for(theme, *status));
 
         if style.background.is_some() || style.border.width > 0.0 || style.shadow.color.a > 0.0 {
             renderer.fill_quad(
@@ -364,6 +462,27 @@ where
             );
         }
 
+        if state.ripples.is_animating() {
+            let color = self.ripple_color.unwrap_or(style.text_color);
+            // Ripples can be much larger than the button, so clip them to its bounds.
+            renderer.with_layer(bounds, |renderer| {
+                for ripple in state.ripples.iter() {
+                    let circle = ripple.circle(bounds);
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: circle,
+                            border: Border {
+                                radius: ripple.radius().into(),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        },
+                        color.scale_alpha(RIPPLE_OPACITY * ripple.alpha()),
+                    );
+                }
+            });
+        }
+
         let viewport = if self.clip {
             bounds.intersection(viewport).unwrap_or(*viewport)
         } else {
@@ -0,0 +1,508 @@
+//! An animated checkbox whose check mark scales in when it's toggled.
+//!
+//! The box animates between its checked and unchecked styles like other animated widgets, while
+//! the check mark grows and fades in from the center of the box. The animation follows the
+//! `is_checked` value passed to the [`Checkbox`], so toggling it from anywhere - a click, a
+//! keyboard shortcut handled by the app, or another widget - animates the check mark the same way.
+//!
+//! ```no_run
+//! # use iced::Element;
+//! # use iced_anim::{spring::Motion, widget::checkbox};
+//! # #[derive(Clone)] enum Message { Toggle(bool) }
+//! # fn view<'a>(is_checked: bool) -> Element<'a, Message> {
+//! checkbox("Enable notifications", is_checked)
+//!     .on_toggle(Message::Toggle)
+//!     .animation(Motion::BOUNCY)
+//!     .into()
+//! # }
+//! ```
+use crate::{animate::AnimatedCheckboxStyle, animated::Mode, Animated, AnimatedState};
+use iced::{
+    advanced::{
+        layout, renderer,
+        text::{self as core_text, Paragraph},
+        widget::{text as core_widget, tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event,
+    mouse::{self, Cursor},
+    touch, window, Element, Event, Length, Pixels, Rectangle, Size,
+};
+
+// Re-export the widget types for convenience
+pub use iced::widget::checkbox::{
+    danger, primary, secondary, success, Catalog, Icon, Status, Style, StyleFn,
+};
+pub use iced::widget::text::{LineHeight, Shaping, Wrapping};
+
+/// The default size of the box, in logical pixels.
+const DEFAULT_SIZE: f32 = 16.0;
+
+/// The default spacing between the box and the label, in logical pixels.
+const DEFAULT_SPACING: f32 = 8.0;
+
+/// The check mark isn't drawn once it has shrunk below this much of its full size.
+const MIN_ICON_SCALE: f32 = 0.01;
+
+/// A box that can be checked, whose check mark scales in and out as it's toggled.
+#[allow(missing_debug_implementations)]
+pub struct Checkbox<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: core_text::Renderer,
+    Theme: Catalog,
+{
+    is_checked: bool,
+    on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    label: String,
+    width: Length,
+    size: f32,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    text_line_height: LineHeight,
+    text_shaping: Shaping,
+    text_wrapping: Wrapping,
+    font: Option<Renderer::Font>,
+    icon: Icon<Renderer::Font>,
+    class: Theme::Class<'a>,
+    mode: Mode,
+}
+
+impl<'a, Message, Theme, Renderer> Checkbox<'a, Message, Theme, Renderer>
+where
+    Renderer: core_text::Renderer,
+    Theme: Catalog,
+{
+    /// Creates a new [`Checkbox`] with the given label and checked state.
+    ///
+    /// The [`Checkbox`] is disabled until [`Checkbox::on_toggle`] is called.
+    pub fn new(label: impl Into<String>, is_checked: bool) -> Self {
+        Checkbox {
+            is_checked,
+            on_toggle: None,
+            label: label.into(),
+            width: Length::Shrink,
+            size: DEFAULT_SIZE,
+            spacing: DEFAULT_SPACING,
+            text_size: None,
+            text_line_height: LineHeight::default(),
+            text_shaping: Shaping::default(),
+            text_wrapping: Wrapping::default(),
+            font: None,
+            icon: Icon {
+                font: Renderer::ICON_FONT,
+                code_point: Renderer::CHECKMARK_ICON,
+                size: None,
+                line_height: LineHeight::default(),
+                shaping: Shaping::Basic,
+            },
+            class: Theme::default(),
+            mode: Mode::default(),
+        }
+    }
+
+    /// Sets the function that will be called when the [`Checkbox`] is toggled, receiving the
+    /// new checked state.
+    ///
+    /// Unless `on_toggle` is called, the [`Checkbox`] will be disabled.
+    pub fn on_toggle(mut self, on_toggle: impl Fn(bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the function that will be called when the [`Checkbox`] is toggled, if `Some`.
+    ///
+    /// If `None`, the [`Checkbox`] will be disabled.
+    pub fn on_toggle_maybe(mut self, on_toggle: Option<impl Fn(bool) -> Message + 'a>) -> Self {
+        self.on_toggle = on_toggle.map(|on_toggle| Box::new(on_toggle) as _);
+        self
+    }
+
+    /// Sets the size of the box of the [`Checkbox`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the width of the [`Checkbox`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the spacing between the box and the label of the [`Checkbox`].
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the label of the [`Checkbox`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the label of the [`Checkbox`].
+    pub fn text_line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`Shaping`] strategy of the label of the [`Checkbox`].
+    pub fn text_shaping(mut self, shaping: Shaping) -> Self {
+        self.text_shaping = shaping;
+        self
+    }
+
+    /// Sets the [`Wrapping`] strategy of the label of the [`Checkbox`].
+    pub fn text_wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.text_wrapping = wrapping;
+        self
+    }
+
+    /// Sets the font of the label of the [`Checkbox`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the [`Icon`] drawn inside the box when the [`Checkbox`] is checked.
+    pub fn icon(mut self, icon: Icon<Renderer::Font>) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    /// Sets the style of the [`Checkbox`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Checkbox`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the animation of the [`Checkbox`], used for both its style and its check mark.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+
+    /// The progress of the check mark when the [`Checkbox`] is settled.
+    fn progress(&self) -> f32 {
+        if self.is_checked {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Gets the status of the [`Checkbox`] based on the cursor position.
+    fn get_status(&self, cursor: Cursor, layout: Layout<'_>) -> Status {
+        let is_checked = self.is_checked;
+        if self.on_toggle.is_none() {
+            Status::Disabled { is_checked }
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered { is_checked }
+        } else {
+            Status::Active { is_checked }
+        }
+    }
+}
+
+struct State<P: Paragraph> {
+    paragraph: core_widget::State<P>,
+    animated_state: AnimatedState<Status, AnimatedCheckboxStyle>,
+    /// How far the check mark has scaled in, from `0.0` when unchecked to `1.0` when checked.
+    progress: Animated<f32>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Checkbox<'a, Message, Theme, Renderer>
+where
+    Renderer: core_text::Renderer,
+    Theme: Catalog,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        let is_checked = self.is_checked;
+        let status = if self.on_toggle.is_some() {
+            Status::Active { is_checked }
+        } else {
+            Status::Disabled { is_checked }
+        };
+
+        tree::State::new(State::<Renderer::Paragraph> {
+            paragraph: core_widget::State::default(),
+            animated_state: AnimatedState::new(status, self.mode),
+            progress: Animated::new(self.progress(), self.mode),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.animated_state.diff(self.mode);
+
+        if state.progress.mode() != self.mode {
+            state.progress.apply(self.mode);
+        }
+        // Toggling retargets from the current progress, so rapid toggles reverse smoothly.
+        if state.progress.target() != &self.progress() {
+            state.progress.set_target(self.progress());
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::next_to_each_other(
+            &limits.width(self.width),
+            self.spacing,
+            |_| layout::Node::new(Size::new(self.size, self.size)),
+            |limits| {
+                let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+                core_widget::layout(
+                    &mut state.paragraph,
+                    renderer,
+                    limits,
+                    self.width,
+                    Length::Shrink,
+                    &self.label,
+                    self.text_line_height,
+                    self.text_size,
+                    self.font,
+                    alignment::Horizontal::Left,
+                    alignment::Vertical::Top,
+                    self.text_shaping,
+                    self.text_wrapping,
+                )
+            },
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+            state.animated_state.resume_at(now);
+            state.progress.resume_at(now);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        // Redraw anytime the status changes or the check mark is still animating.
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let status = self.get_status(cursor, layout);
+        if state.animated_state.needs_redraw(status) || state.progress.needs_tick() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.animated_state.tick(now);
+                state.progress.tick(now);
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(on_toggle) = &self.on_toggle {
+                    if cursor.is_over(layout.bounds()) {
+                        shell.publish(on_toggle(!self.is_checked));
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) && self.on_toggle.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = state
+            .animated_state
+            .current_value(|status| theme.style(&self.class, *status).into());
+        let mut children = layout.children();
+
+        let box_bounds = children.next().unwrap().bounds();
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: box_bounds,
+                border: style.border,
+                ..Default::default()
+            },
+            style.background,
+        );
+
+        // Springy animations can overshoot, letting the check mark pop past its full size.
+        let scale = *state.progress.value();
+        if scale > MIN_ICON_SCALE {
+            let Icon {
+                font,
+                code_point,
+                size,
+                line_height,
+                shaping,
+            } = &self.icon;
+            let size = size.unwrap_or(Pixels(box_bounds.height * 0.7));
+
+            renderer.fill_text(
+                core_text::Text {
+                    content: code_point.to_string(),
+                    font: *font,
+                    size: Pixels(size.0 * scale),
+                    line_height: *line_height,
+                    bounds: box_bounds.size(),
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: *shaping,
+                    wrapping: Wrapping::default(),
+                },
+                box_bounds.center(),
+                style.icon_color.scale_alpha(scale.min(1.0)),
+                *viewport,
+            );
+        }
+
+        core_widget::draw(
+            renderer,
+            renderer_style,
+            children.next().unwrap(),
+            state.paragraph.0.raw(),
+            iced::widget::text::Style {
+                color: style.text_color,
+            },
+            viewport,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Checkbox<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(checkbox: Checkbox<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(checkbox)
+    }
+}
+
+/// Creates a new [`Checkbox`] with the given label and checked state.
+pub fn checkbox<'a, Message, Theme, Renderer>(
+    label: impl Into<String>,
+    is_checked: bool,
+) -> Checkbox<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    Checkbox::new(label, is_checked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spring::Motion;
+    use std::time::{Duration, Instant};
+
+    fn checkbox(is_checked: bool) -> Checkbox<'static, bool, iced::Theme, ()> {
+        Checkbox::new("Label", is_checked)
+            .on_toggle(|is_checked| is_checked)
+            .animation(Motion::SMOOTH)
+    }
+
+    fn progress(tree: &Tree) -> f32 {
+        *tree.state.downcast_ref::<State<()>>().progress.value()
+    }
+
+    fn tick(tree: &mut Tree, now: Instant) {
+        tree.state.downcast_mut::<State<()>>().progress.tick(now);
+    }
+
+    /// Checking the box should scale the check mark in, and unchecking it mid-animation should
+    /// reverse from wherever the check mark currently is.
+    #[test]
+    fn toggling_animates_check_mark() {
+        let unchecked = checkbox(false);
+        let mut tree = Tree::new(&unchecked as &dyn Widget<bool, iced::Theme, ()>);
+        assert_eq!(progress(&tree), 0.0);
+
+        let start = Instant::now();
+        tree.diff(&checkbox(true) as &dyn Widget<bool, iced::Theme, ()>);
+        tick(&mut tree, start);
+        tick(&mut tree, start + Duration::from_millis(100));
+        let partial = progress(&tree);
+        assert!(partial > 0.0 && partial < 1.0);
+
+        tree.diff(&unchecked as &dyn Widget<bool, iced::Theme, ()>);
+        assert_eq!(progress(&tree), partial);
+        tick(&mut tree, start + Duration::from_millis(116));
+        assert!(progress(&tree) > 0.0);
+
+        for frame in 8..200 {
+            tick(&mut tree, start + Duration::from_millis(frame * 16));
+        }
+        assert_eq!(progress(&tree), 0.0);
+    }
+}
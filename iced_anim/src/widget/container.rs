@@ -0,0 +1,383 @@
+//! An animated container that will automatically transition between different styles.
+//!
+//! Containers in Iced don't have a status, so the style animates whenever the theme or the
+//! style of the container changes, e.g. when switching between light and dark themes.
+use crate::{animate::AnimatedContainerStyle, animated::Mode, elevation::Elevation, AnimatedState};
+use iced::{
+    advanced::{
+        layout, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event, mouse, overlay, window, Element, Event, Length, Padding, Pixels, Rectangle,
+    Size, Vector,
+};
+
+// Re-export the widget types for convenience
+pub use iced::widget::container::{
+    bordered_box, dark, rounded_box, transparent, Catalog, Style, StyleFn,
+};
+
+/// An animated container that will automatically transition between different styles.
+pub struct Container<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    width: Length,
+    height: Length,
+    max_width: f32,
+    max_height: f32,
+    padding: Padding,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    clip: bool,
+    class: Theme::Class<'a>,
+    mode: Mode,
+    elevation: Option<Elevation>,
+}
+
+impl<'a, Message, Theme, Renderer> Container<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+    Theme: Catalog,
+{
+    /// Creates a new [`Container`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        let content = content.into();
+        let size = content.as_widget().size_hint();
+        Container {
+            content,
+            width: size.width.fluid(),
+            height: size.height.fluid(),
+            max_width: f32::INFINITY,
+            max_height: f32::INFINITY,
+            padding: Padding::ZERO,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            clip: false,
+            class: Theme::default(),
+            mode: Mode::default(),
+            elevation: None,
+        }
+    }
+
+    /// Sets the [`Padding`] of the [`Container`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Container`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Container`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the maximum width of the [`Container`].
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = max_width.into().0;
+        self
+    }
+
+    /// Sets the maximum height of the [`Container`].
+    pub fn max_height(mut self, max_height: impl Into<Pixels>) -> Self {
+        self.max_height = max_height.into().0;
+        self
+    }
+
+    /// Sets the width of the [`Container`] and centers its contents horizontally.
+    pub fn center_x(self, width: impl Into<Length>) -> Self {
+        self.width(width).align_x(alignment::Horizontal::Center)
+    }
+
+    /// Sets the height of the [`Container`] and centers its contents vertically.
+    pub fn center_y(self, height: impl Into<Length>) -> Self {
+        self.height(height).align_y(alignment::Vertical::Center)
+    }
+
+    /// Centers the contents in both the horizontal and vertical axes of the [`Container`].
+    ///
+    /// This is equivalent to chaining [`center_x`](Self::center_x) and
+    /// [`center_y`](Self::center_y).
+    pub fn center(self, length: impl Into<Length>) -> Self {
+        let length = length.into();
+        self.center_x(length).center_y(length)
+    }
+
+    /// Sets the content alignment for the horizontal axis of the [`Container`].
+    pub fn align_x(mut self, alignment: impl Into<alignment::Horizontal>) -> Self {
+        self.horizontal_alignment = alignment.into();
+        self
+    }
+
+    /// Sets the content alignment for the vertical axis of the [`Container`].
+    pub fn align_y(mut self, alignment: impl Into<alignment::Vertical>) -> Self {
+        self.vertical_alignment = alignment.into();
+        self
+    }
+
+    /// Sets whether the contents of the [`Container`] should be clipped on overflow.
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// Sets the style of the [`Container`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Container`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the animation of the [`Container`].
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+
+    /// Sets the elevation of the [`Container`], which replaces the shadow of its style with the
+    /// [`elevation`](crate::elevation) preset for that level.
+    ///
+    /// Changing the level between views animates the shadow to the new level.
+    pub fn elevation(mut self, level: u8) -> Self {
+        self.elevation = Some(Elevation::from(level));
+        self
+    }
+
+    /// The style of the [`Container`], including its elevation.
+    fn current_style(&self, theme: &Theme) -> Style {
+        let style = theme.style(&self.class);
+        match self.elevation {
+            Some(elevation) => style.shadow(elevation.shadow()),
+            None => style,
+        }
+    }
+}
+
+/// Containers don't have a status, so the animated style only depends on the theme and class.
+#[derive(Debug)]
+struct State {
+    animated_state: AnimatedState<(), AnimatedContainerStyle>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Container<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+    Theme: Catalog,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            animated_state: AnimatedState::new((), self.mode),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.animated_state.diff(self.mode);
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        iced::widget::container::layout(
+            limits,
+            self.width,
+            self.height,
+            self.max_width,
+            self.max_height,
+            self.padding,
+            self.horizontal_alignment,
+            self.vertical_alignment,
+            |limits| {
+                self.content
+                    .as_widget()
+                    .layout(&mut tree.children[0], renderer, limits)
+            },
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State>();
+            state.animated_state.resume_at(now);
+        }
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                layout.children().next().unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // Keep redrawing while the style is animating towards a new theme or class.
+        let state = tree.state.downcast_mut::<State>();
+        if state.animated_state.needs_redraw(()) {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.animated_state.tick(now);
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let style = state
+            .animated_state
+            .current_value(|_| self.current_style(theme).into());
+
+        if let Some(clipped_viewport) = bounds.intersection(viewport) {
+            iced::widget::container::draw_background(renderer, &style, bounds);
+
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style.text_color.unwrap_or(renderer_style.text_color),
+                },
+                layout.children().next().unwrap(),
+                cursor,
+                if self.clip {
+                    &clipped_viewport
+                } else {
+                    viewport
+                },
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Container<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(container: Container<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(container)
+    }
+}
+
+/// Creates a new [`Container`] with the given content.
+pub fn container<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Container<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    Container::new(content)
+}
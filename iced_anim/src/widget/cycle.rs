@@ -0,0 +1,160 @@
+//! A looping progress that repeatedly animates from `0.0` to `1.0`.
+//!
+//! This drives indeterminate indicators from the timestamps of `RedrawRequested` events, so they
+//! don't need a subscription or any messages to keep moving.
+use std::time::{Duration, Instant};
+
+use crate::{
+    animated::Mode,
+    spring::MAX_DURATION,
+    stall::{FrameClock, Gap, StallPolicy},
+    Animated,
+};
+
+/// A progress that loops from `0.0` to `1.0` once per period.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Cycle {
+    /// The eased progress through the current cycle.
+    progress: Animated<f32>,
+    /// How long one cycle takes.
+    period: Duration,
+    /// How far into the current cycle the loop is.
+    elapsed: Duration,
+    /// Measures the time between ticks of the loop.
+    clock: FrameClock,
+}
+
+impl Cycle {
+    /// Creates a new cycle that eases through each loop with `mode` and lasts `period`.
+    pub fn new(mode: Mode, period: Duration) -> Self {
+        Self {
+            progress: Animated::new(0.0, mode).with_duration(period).to(1.0),
+            period,
+            elapsed: Duration::ZERO,
+            clock: FrameClock::default(),
+        }
+    }
+
+    /// The eased progress through the current cycle.
+    pub fn progress(&self) -> f32 {
+        *self.progress.value()
+    }
+
+    /// Applies a potentially new easing `mode` and `period`, keeping the current progress.
+    pub fn diff(&mut self, mode: Mode, period: Duration) {
+        self.progress.apply(mode);
+        if self.period != period {
+            self.period = period;
+            self.elapsed = self.elapsed.min(period);
+        }
+        self.progress = self.progress.clone().with_duration(period);
+    }
+
+    /// Resumes the loop at `now` without advancing it by the time since it was last ticked.
+    pub fn resume_at(&mut self, now: Instant) {
+        self.clock.resume_at(now);
+        self.progress.resume_at(now);
+    }
+
+    /// Advances the loop to `now`, starting a new cycle whenever the current one completes.
+    ///
+    /// Stalls in the event loop either shift the phase of the loop forward or skip ahead to
+    /// where it would have been, depending on the [`StallPolicy`].
+    pub fn tick(&mut self, now: Instant) {
+        let dt = match self.clock.tick(now) {
+            Gap::Frame(dt) => dt.min(MAX_DURATION),
+            stall => stall.elapsed(StallPolicy::global()),
+        };
+        self.elapsed += dt;
+
+        // Keep the eased progress in step with the clamped time, even after long frames.
+        self.progress.resume_at(now.checked_sub(dt).unwrap_or(now));
+
+        if self.period.is_zero() {
+            self.elapsed = Duration::ZERO;
+            return;
+        }
+
+        if self.elapsed >= self.period {
+            self.elapsed =
+                Duration::from_secs_f32(self.elapsed.as_secs_f32() % self.period.as_secs_f32());
+            let cycle_start = now.checked_sub(self.elapsed).unwrap_or(now);
+
+            // Finish the previous cycle, then start the next one from where it ended so the
+            // loop stays continuous.
+            self.progress.tick_with(cycle_start, None);
+            let value = self.progress() - 1.0;
+            self.progress.settle_at(value.min(0.0));
+            self.progress.set_target(1.0);
+            self.progress.resume_at(cycle_start);
+        }
+
+        // The loop has already handled any stall, so the progress follows its clock exactly.
+        self.progress.tick_with(now, None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transition::Easing;
+
+    const PERIOD: Duration = Duration::from_millis(1000);
+    const FRAME: Duration = Duration::from_millis(10);
+
+    /// Ticks the `cycle` every frame for the given `duration`, returning the last instant.
+    fn run(cycle: &mut Cycle, start: Instant, duration: Duration) -> Instant {
+        let mut now = start;
+        cycle.tick(now);
+        while now - start < duration {
+            now += FRAME;
+            cycle.tick(now);
+        }
+        now
+    }
+
+    /// A linear cycle should progress proportionally to the elapsed time.
+    #[test]
+    fn linear_progress() {
+        let mut cycle = Cycle::new(Easing::LINEAR.into(), PERIOD);
+        run(&mut cycle, Instant::now(), PERIOD / 4);
+        assert!(
+            (cycle.progress() - 0.25).abs() < 0.02,
+            "{}",
+            cycle.progress()
+        );
+    }
+
+    /// The progress should wrap back around to the start after each period.
+    #[test]
+    fn wraps_each_period() {
+        let mut cycle = Cycle::new(Easing::LINEAR.into(), PERIOD);
+        run(&mut cycle, Instant::now(), PERIOD * 2 + PERIOD / 2);
+        assert!(
+            (cycle.progress() - 0.5).abs() < 0.02,
+            "{}",
+            cycle.progress()
+        );
+    }
+
+    /// Eased cycles should follow the shape of the curve within each period.
+    #[test]
+    fn eased_progress() {
+        let mut cycle = Cycle::new(Easing::EASE_IN.into(), PERIOD);
+        run(&mut cycle, Instant::now(), PERIOD + PERIOD / 4);
+        assert!(cycle.progress() < 0.2, "{}", cycle.progress());
+    }
+
+    /// Resuming should skip the time that passed since the last tick.
+    #[test]
+    fn resume_skips_gap() {
+        let mut cycle = Cycle::new(Easing::LINEAR.into(), PERIOD);
+        let now = run(&mut cycle, Instant::now(), PERIOD / 4);
+        let progress = cycle.progress();
+
+        let later = now + PERIOD * 10;
+        cycle.resume_at(later);
+        cycle.tick(later);
+        assert_eq!(cycle.progress(), progress);
+    }
+}
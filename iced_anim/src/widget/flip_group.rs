@@ -0,0 +1,612 @@
+//! A column of keyed children that glide to their new positions when they're re-ordered.
+//!
+//! Iced places re-sorted children at their new rows right away. A [`FlipGroup`] remembers where
+//! each child was drawn by its key and, when a later layout moves that key, draws the child
+//! translated from its old position and animates it into place. This is the "FLIP" technique
+//! (first, last, invert, play) from web animations.
+//!
+//! ```rust
+//! # use iced::{Element, widget::text};
+//! # use iced_anim::{spring::Motion, widget::flip_group};
+//! # struct Item { id: u64, name: String }
+//! # fn view<'a, Message: 'a>(items: &'a [Item]) -> Element<'a, Message> {
+//! // Rows slide to their new positions whenever the items are re-sorted.
+//! flip_group(items.iter().map(|item| (item.id, text(&item.name).into())))
+//!     .spacing(8)
+//!     .animation(Motion::SNAPPY)
+//!     .into()
+//! # }
+//! ```
+//!
+//! Children added after the group is first shown fade in where they're placed, and removed
+//! children disappear immediately. Only the text color of new children is faded, for the same
+//! reasons described in [`opacity`](super::opacity).
+//!
+//! > Note: children are only drawn at their animated positions, so they still receive events at
+//! > their actual layout bounds while they're moving.
+use crate::{animated::Mode, Animate, Animated};
+use iced::{
+    advanced::{
+        graphics::core::event,
+        layout::{self, flex},
+        mouse, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, window, Alignment, Element, Event, Length, Padding, Pixels, Point, Rectangle, Size,
+    Vector,
+};
+use std::{collections::HashMap, hash::Hash};
+
+/// The default distance in logical pixels that a child must move before it's animated.
+const DEFAULT_THRESHOLD: f32 = 0.5;
+
+/// A column of keyed children that glide to their new positions when they're re-ordered.
+pub struct FlipGroup<'a, Key, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// The keys identifying each of the children, in the same order.
+    keys: Vec<Key>,
+    /// The children laid out in a column.
+    children: Vec<Element<'a, Message, Theme, Renderer>>,
+    /// The vertical spacing between the children.
+    spacing: f32,
+    /// The padding around the children.
+    padding: Padding,
+    /// The width of the group.
+    width: Length,
+    /// The height of the group.
+    height: Length,
+    /// The maximum width of the group.
+    max_width: f32,
+    /// The horizontal alignment of the children.
+    align: Alignment,
+    /// The animation used to move children and fade in new ones.
+    mode: Mode,
+    /// Moves smaller than this many logical pixels are applied without animating.
+    threshold: f32,
+    /// Whether children added after the group was first shown fade in.
+    fade_in: bool,
+}
+
+impl<'a, Key, Message, Theme, Renderer> FlipGroup<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + Eq + Hash + 'static,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates an empty [`FlipGroup`].
+    pub fn new() -> Self {
+        Self {
+            keys: Vec::new(),
+            children: Vec::new(),
+            spacing: 0.0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: f32::INFINITY,
+            align: Alignment::Start,
+            mode: Mode::default(),
+            threshold: DEFAULT_THRESHOLD,
+            fade_in: true,
+        }
+    }
+
+    /// Creates a [`FlipGroup`] with the given keyed children.
+    pub fn with_children(
+        children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
+    ) -> Self {
+        children
+            .into_iter()
+            .fold(Self::new(), |group, (key, child)| {
+                group.push_keyed(key, child)
+            })
+    }
+
+    /// Adds a child identified by the given `key`, which should be unique within the group.
+    ///
+    /// The child is animated from wherever the same key was drawn before.
+    pub fn push_keyed(
+        mut self,
+        key: Key,
+        child: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        let child = child.into();
+        let child_size = child.as_widget().size_hint();
+        self.width = self.width.enclose(child_size.width);
+        self.height = self.height.enclose(child_size.height);
+
+        self.keys.push(key);
+        self.children.push(child);
+        self
+    }
+
+    /// Sets the vertical spacing between the children.
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] around the children.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`FlipGroup`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`FlipGroup`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the maximum width of the [`FlipGroup`].
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = max_width.into().0;
+        self
+    }
+
+    /// Sets the horizontal alignment of the children.
+    pub fn align_x(mut self, align: impl Into<alignment::Horizontal>) -> Self {
+        self.align = Alignment::from(align.into());
+        self
+    }
+
+    /// Sets the animation used to move the children and fade in new ones.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+
+    /// Sets the distance in logical pixels that a child must move before it's animated.
+    ///
+    /// Smaller moves, like sub-pixel differences from rounding, are applied immediately.
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Sets whether children added after the group was first shown fade in, which is the default.
+    pub fn fade_in(mut self, fade_in: bool) -> Self {
+        self.fade_in = fade_in;
+        self
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Default for FlipGroup<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + Eq + Hash + 'static,
+    Renderer: iced::advanced::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The animated placement of a single child.
+#[derive(Debug)]
+struct Item {
+    /// Where the child is drawn, relative to the group.
+    position: Animated<Point>,
+    /// The opacity of the child, which only animates while a new child fades in.
+    opacity: Animated<f32>,
+}
+
+impl Item {
+    /// Creates an item placed at `position`, fading in from transparent if `fade_in` is set.
+    fn new(position: Point, mode: Mode, fade_in: bool) -> Self {
+        let mut opacity = Animated::new(if fade_in { 0.0 } else { 1.0 }, mode);
+        opacity.set_target(1.0);
+        Self {
+            position: Animated::new(position, mode),
+            opacity,
+        }
+    }
+
+    /// Records the latest layout `position`, animating towards it if it moved far enough from
+    /// where the child is currently drawn.
+    fn observe(&mut self, position: Point, threshold: f32, mode: Mode) {
+        if self.position.mode() != mode {
+            self.position.apply(mode);
+            self.opacity.apply(mode);
+        }
+        if self.position.target() == &position {
+            return;
+        }
+
+        let distance = self
+            .position
+            .value()
+            .distance_to(&position)
+            .into_iter()
+            .fold(0.0, |max, distance| distance.abs().max(max));
+        if distance > threshold {
+            self.position.set_target(position);
+        } else {
+            self.position.settle_at(position);
+        }
+    }
+
+    /// Advances the animations of the item to `now`.
+    fn tick(&mut self, now: std::time::Instant) {
+        self.position.tick(now);
+        self.opacity.tick(now);
+    }
+
+    /// Whether the item is still moving or fading in.
+    fn is_animating(&self) -> bool {
+        self.position.is_animating() || self.opacity.is_animating()
+    }
+}
+
+struct State<Key> {
+    /// The keys of the children in the tree, used to match them up when diffing.
+    keys: Vec<Key>,
+    /// The placement of each child by its key, once the group has been laid out.
+    items: HashMap<Key, Item>,
+    /// Whether the group has been laid out, after which new children fade in.
+    is_initialized: bool,
+}
+
+impl<Key: Copy + Eq + Hash> State<Key> {
+    /// Records the latest `positions` of the children with the given `keys`, relative to the
+    /// group, and forgets any children that were removed.
+    fn observe(
+        &mut self,
+        keys: &[Key],
+        positions: impl IntoIterator<Item = Point>,
+        threshold: f32,
+        mode: Mode,
+        fade_in: bool,
+    ) {
+        for (key, position) in keys.iter().zip(positions) {
+            match self.items.get_mut(key) {
+                Some(item) => item.observe(position, threshold, mode),
+                None => {
+                    let item = Item::new(position, mode, fade_in && self.is_initialized);
+                    self.items.insert(*key, item);
+                }
+            }
+        }
+
+        // Keys are unique, so any extra items belong to children that were removed.
+        if self.items.len() > keys.len() {
+            self.items.retain(|key, _| keys.contains(key));
+        }
+        self.is_initialized = true;
+    }
+
+    /// Advances the animations of every child to `now`.
+    fn tick(&mut self, now: std::time::Instant) {
+        self.items.values_mut().for_each(|item| item.tick(now));
+    }
+
+    /// Whether any child is still moving or fading in.
+    fn is_animating(&self) -> bool {
+        self.items.values().any(Item::is_animating)
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for FlipGroup<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + Eq + Hash + 'static,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            keys: self.keys.clone(),
+            items: HashMap::new(),
+            is_initialized: false,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let Tree {
+            state, children, ..
+        } = tree;
+        let state = state.downcast_mut::<State<Key>>();
+
+        // Match the trees up by key so each child keeps its own state when it moves.
+        tree::diff_children_custom_with_search(
+            children,
+            &self.children,
+            |tree, child| child.as_widget().diff(tree),
+            |index| {
+                self.keys.get(index).or_else(|| self.keys.last()).copied()
+                    != Some(state.keys[index])
+            },
+            |child| Tree::new(child.as_widget()),
+        );
+
+        if state.keys != self.keys {
+            state.keys.clone_from(&self.keys);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .max_width(self.max_width)
+            .width(self.width)
+            .height(self.height);
+
+        flex::resolve(
+            flex::Axis::Vertical,
+            renderer,
+            &limits,
+            self.width,
+            self.height,
+            self.padding,
+            self.spacing,
+            self.align,
+            &self.children,
+            &mut tree.children,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            for item in tree.state.downcast_mut::<State<Key>>().items.values_mut() {
+                item.position.resume_at(now);
+                item.opacity.resume_at(now);
+            }
+        }
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, tree), layout)| {
+                    child.as_widget().operate(tree, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // Positions are relative to the group so moving the whole group doesn't animate its
+        // children, and they're only known once the parent has placed the group.
+        let origin = layout.position();
+        let state = tree.state.downcast_mut::<State<Key>>();
+        state.observe(
+            &self.keys,
+            layout
+                .children()
+                .map(|child| Point::ORIGIN + (child.position() - origin)),
+            self.threshold,
+            self.mode,
+            self.fade_in,
+        );
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.tick(now);
+        }
+        if state.is_animating() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Key>>();
+        let origin = layout.position();
+
+        for (((key, child), tree), layout) in self
+            .keys
+            .iter()
+            .zip(&self.children)
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            let Some(item) = state.items.get(key) else {
+                child
+                    .as_widget()
+                    .draw(tree, renderer, theme, style, layout, cursor, viewport);
+                continue;
+            };
+
+            let opacity = *item.opacity.value();
+            if opacity <= 0.0 {
+                continue;
+            }
+
+            let style = renderer::Style {
+                text_color: style.text_color.scale_alpha(opacity),
+            };
+            let translation =
+                (origin + (*item.position.value() - Point::ORIGIN)) - layout.position();
+            renderer.with_translation(translation, |renderer| {
+                child.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    &style,
+                    layout,
+                    cursor,
+                    &(*viewport - translation),
+                );
+            });
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, tree), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer, translation)
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> From<FlipGroup<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Copy + Eq + Hash + 'static,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(flip_group: FlipGroup<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(flip_group)
+    }
+}
+
+/// Creates a new [`FlipGroup`] with the given keyed children.
+pub fn flip_group<'a, Key, Message, Theme, Renderer>(
+    children: impl IntoIterator<Item = (Key, Element<'a, Message, Theme, Renderer>)>,
+) -> FlipGroup<'a, Key, Message, Theme, Renderer>
+where
+    Key: Copy + Eq + Hash + 'static,
+    Renderer: iced::advanced::Renderer,
+{
+    FlipGroup::with_children(children)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spring::Motion;
+
+    fn rows(count: usize) -> Vec<Point> {
+        (0..count)
+            .map(|row| Point::new(0.0, row as f32 * 20.0))
+            .collect()
+    }
+
+    fn observe(state: &mut State<u32>, keys: &[u32]) {
+        let mode = Motion::SMOOTH.into();
+        state.observe(keys, rows(keys.len()), DEFAULT_THRESHOLD, mode, true);
+    }
+
+    fn new_state() -> State<u32> {
+        State {
+            keys: Vec::new(),
+            items: HashMap::new(),
+            is_initialized: false,
+        }
+    }
+
+    /// Re-sorting the children should animate each key from where it was drawn.
+    #[test]
+    fn reordering_animates_from_old_positions() {
+        let mut state = new_state();
+        observe(&mut state, &[1, 2, 3]);
+        assert!(!state.is_animating());
+
+        observe(&mut state, &[3, 2, 1]);
+        assert!(state.is_animating());
+        let first = &state.items[&1].position;
+        assert_eq!(*first.value(), Point::new(0.0, 0.0));
+        assert_eq!(*first.target(), Point::new(0.0, 40.0));
+        assert!(!state.items[&2].is_animating());
+    }
+
+    /// Only children added after the first layout should fade in.
+    #[test]
+    fn new_children_fade_in() {
+        let mut state = new_state();
+        observe(&mut state, &[1, 2]);
+        assert_eq!(*state.items[&1].opacity.value(), 1.0);
+
+        observe(&mut state, &[1, 2, 3]);
+        let added = &state.items[&3];
+        assert_eq!(*added.opacity.value(), 0.0);
+        assert_eq!(*added.opacity.target(), 1.0);
+        assert_eq!(*added.position.value(), Point::new(0.0, 40.0));
+    }
+
+    /// Removed children should be forgotten, so they fade in again if they're re-added.
+    #[test]
+    fn removed_children_are_forgotten() {
+        let mut state = new_state();
+        observe(&mut state, &[1, 2, 3]);
+        observe(&mut state, &[1, 3]);
+        assert_eq!(state.items.len(), 2);
+        assert!(!state.items.contains_key(&2));
+        assert_eq!(*state.items[&3].position.target(), Point::new(0.0, 20.0));
+    }
+}
@@ -0,0 +1,361 @@
+//! A wrapper that draws its content following the cursor while it's hovered.
+//!
+//! The content is laid out as usual, but while the cursor is over its layout bounds, it's drawn
+//! with its center springing towards the cursor. Once the cursor leaves, the content springs
+//! back to where it was laid out.
+//!
+//! ```rust
+//! # use iced::{Element, Vector, widget::container};
+//! # use iced_anim::{spring::Motion, widget::follow_cursor};
+//! # fn view<'a, Message: 'a>(is_enabled: bool) -> Element<'a, Message> {
+//! follow_cursor(container("Catch me").padding(16))
+//!     .animation(Motion::BOUNCY)
+//!     .offset(Vector::new(0.0, -8.0))
+//!     .enabled(is_enabled)
+//!     .into()
+//! # }
+//! ```
+//!
+//! The cursor is mapped through the translation, so the content receives events where it's
+//! drawn rather than where it was laid out.
+use crate::{animated::Mode, Animated};
+use iced::{
+    advanced::{
+        graphics::core::event,
+        layout, mouse, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    window, Element, Event, Length, Rectangle, Size, Vector,
+};
+
+/// A wrapper that draws its content following the cursor while it's hovered.
+pub struct FollowCursor<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// The content that follows the cursor.
+    content: Element<'a, Message, Theme, Renderer>,
+    /// The animation used to follow the cursor and return to the layout position.
+    mode: Mode,
+    /// Where the center of the content is drawn relative to the cursor.
+    offset: Vector,
+    /// Whether the content follows the cursor, or stays where it was laid out.
+    is_enabled: bool,
+}
+
+impl<'a, Message, Theme, Renderer> FollowCursor<'a, Message, Theme, Renderer> {
+    /// Creates a new [`FollowCursor`] whose `content` follows the cursor while it's hovered.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            mode: Mode::default(),
+            offset: Vector::ZERO,
+            is_enabled: true,
+        }
+    }
+
+    /// Sets the animation used to follow the cursor and return to the layout position.
+    ///
+    /// Springs work best, since they keep their velocity as the cursor keeps moving.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+
+    /// Sets where the center of the content is drawn relative to the cursor, e.g. to keep it
+    /// just above the cursor instead of underneath it.
+    pub fn offset(mut self, offset: Vector) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets whether the content follows the cursor. Disabled content springs back to where it
+    /// was laid out.
+    pub fn enabled(mut self, is_enabled: bool) -> Self {
+        self.is_enabled = is_enabled;
+        self
+    }
+
+    /// The translation the content should be drawn with for the `cursor` over `bounds`.
+    fn target(&self, bounds: Rectangle, cursor: mouse::Cursor) -> Vector {
+        match cursor.position_over(bounds) {
+            Some(position) if self.is_enabled => position - bounds.center() + self.offset,
+            _ => Vector::ZERO,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    /// How far the content is drawn from where it was laid out.
+    translation: Animated<Vector>,
+}
+
+impl State {
+    /// Moves the content towards the `target` translation with the given `mode`.
+    fn follow(&mut self, target: Vector, mode: Mode) {
+        if self.translation.mode() != mode {
+            self.translation.apply(mode);
+        }
+        if self.translation.target() != &target {
+            self.translation.set_target(target);
+        }
+    }
+
+    /// Maps the `cursor` to where the content was laid out.
+    fn content_cursor(&self, cursor: mouse::Cursor) -> mouse::Cursor {
+        match cursor {
+            mouse::Cursor::Available(position) => {
+                mouse::Cursor::Available(position - *self.translation.value())
+            }
+            mouse::Cursor::Unavailable => mouse::Cursor::Unavailable,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for FollowCursor<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            translation: Animated::new(Vector::ZERO, self.mode),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            tree.state
+                .downcast_mut::<State>()
+                .translation
+                .resume_at(now);
+        }
+
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // Every event has the latest cursor, so the target also catches up with layout changes
+        // and with the content being disabled, not just with cursor movements.
+        let state = tree.state.downcast_mut::<State>();
+        state.follow(self.target(layout.bounds(), cursor), self.mode);
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.translation.tick(now);
+        }
+        if state.translation.needs_tick() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        let cursor = state.content_cursor(cursor);
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let translation = *state.translation.value();
+        let cursor = state.content_cursor(cursor);
+
+        renderer.with_translation(translation, |renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                &(*viewport - translation),
+            );
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let cursor = tree.state.downcast_ref::<State>().content_cursor(cursor);
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let offset = *tree.state.downcast_ref::<State>().translation.value();
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation + offset,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<FollowCursor<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(follow_cursor: FollowCursor<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(follow_cursor)
+    }
+}
+
+/// Creates a new [`FollowCursor`] whose `content` follows the cursor while it's hovered.
+pub fn follow_cursor<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> FollowCursor<'a, Message, Theme, Renderer> {
+    FollowCursor::new(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spring::Motion;
+    use iced::Point;
+
+    fn bounds() -> Rectangle {
+        Rectangle::new(Point::new(100.0, 100.0), Size::new(100.0, 50.0))
+    }
+
+    fn follower<'a>() -> FollowCursor<'a, ()> {
+        follow_cursor(iced::widget::text("Follow"))
+    }
+
+    /// The center of the content should be drawn at the cursor, plus the offset.
+    #[test]
+    fn targets_cursor_while_hovered() {
+        let cursor = mouse::Cursor::Available(Point::new(160.0, 110.0));
+        assert_eq!(
+            follower().target(bounds(), cursor),
+            Vector::new(10.0, -15.0)
+        );
+
+        let offset = follower().offset(Vector::new(0.0, -8.0));
+        assert_eq!(offset.target(bounds(), cursor), Vector::new(10.0, -23.0));
+    }
+
+    /// Leaving the bounds or disabling the follower should return the content to its layout.
+    #[test]
+    fn returns_when_left_or_disabled() {
+        let outside = mouse::Cursor::Available(Point::new(20.0, 20.0));
+        assert_eq!(follower().target(bounds(), outside), Vector::ZERO);
+        assert_eq!(
+            follower().target(bounds(), mouse::Cursor::Unavailable),
+            Vector::ZERO
+        );
+
+        let inside = mouse::Cursor::Available(Point::new(160.0, 110.0));
+        let disabled = follower().enabled(false);
+        assert_eq!(disabled.target(bounds(), inside), Vector::ZERO);
+    }
+
+    /// Retargeting while the cursor keeps moving should keep the spring's velocity, and the
+    /// cursor should be mapped to where the content was laid out.
+    #[test]
+    fn continuous_retargeting() {
+        let mode = Mode::from(Motion::SNAPPY);
+        let mut state = State {
+            translation: Animated::new(Vector::ZERO, mode),
+        };
+
+        state.follow(Vector::new(40.0, 0.0), mode);
+        let start = crate::clock::now();
+        state
+            .translation
+            .tick(start + std::time::Duration::from_millis(16));
+        let moved = *state.translation.value();
+        assert!(moved.x > 0.0);
+
+        state.follow(Vector::new(60.0, 0.0), mode);
+        assert_eq!(*state.translation.target(), Vector::new(60.0, 0.0));
+        assert_eq!(*state.translation.value(), moved);
+
+        let cursor = state.content_cursor(mouse::Cursor::Available(Point::new(50.0, 50.0)));
+        assert_eq!(
+            cursor,
+            mouse::Cursor::Available(Point::new(50.0 - moved.x, 50.0))
+        );
+    }
+}
@@ -0,0 +1,298 @@
+//! A container that freezes the animations of everything inside it.
+//!
+//! While frozen, a [`Freeze`] stops redraw events from reaching its content and drops any redraw
+//! requests the content makes, so animations hold their current value without settling or
+//! consuming frames. This is useful for pausing the background behind a modal while the modal's
+//! own animations keep running.
+//!
+//! When the container unfreezes, the animations inside it resume from where they were instead of
+//! jumping forward by the time they spent frozen.
+//!
+//! > Note: animated values stored in your app state are ticked by your own messages, so they
+//! > aren't affected by this container.
+//!
+//! ```rust
+//! # use iced::{Element, widget::text};
+//! # use iced_anim::widget::{button, freeze};
+//! # #[derive(Clone)]
+//! # enum Message { Open }
+//! # fn view<'a>(is_modal_open: bool) -> Element<'a, Message> {
+//! // The button's hover animation holds still while the modal is open.
+//! freeze(is_modal_open, button(text("Open")).on_press(Message::Open)).into()
+//! # }
+//! ```
+use crate::resume::Resume;
+use iced::{
+    advanced::{
+        graphics::core::event,
+        layout, mouse, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    window, Element, Event, Length, Rectangle, Size, Vector,
+};
+
+/// A container that freezes the animations of its content.
+pub struct Freeze<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// Whether the content's animations are currently frozen.
+    is_frozen: bool,
+    /// The content whose animations may be frozen.
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> Freeze<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Freeze`] that freezes the animations in `content` while `is_frozen`.
+    pub fn new(is_frozen: bool, content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            is_frozen,
+            content: content.into(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// Whether the content was frozen as of the last diff.
+    is_frozen: bool,
+    /// Whether the content was unfrozen and needs to resume on the next frame.
+    needs_resume: bool,
+}
+
+impl State {
+    /// Updates the state with whether the content `is_frozen` now.
+    fn set_frozen(&mut self, is_frozen: bool) {
+        if self.is_frozen && !is_frozen {
+            self.needs_resume = true;
+        } else if is_frozen {
+            // Refreezing before the next frame doesn't need to resume anything.
+            self.needs_resume = false;
+        }
+
+        self.is_frozen = is_frozen;
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Freeze<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            is_frozen: self.is_frozen,
+            needs_resume: false,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.state
+            .downcast_mut::<State>()
+            .set_frozen(self.is_frozen);
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            // Frozen animations never see the frame, so they hold their current value.
+            if self.is_frozen {
+                return event::Status::Ignored;
+            }
+
+            // Resume before the first frame after unfreezing so the frozen time is skipped.
+            let state = tree.state.downcast_mut::<State>();
+            if state.needs_resume {
+                state.needs_resume = false;
+                self.content.as_widget().operate(
+                    &mut tree.children[0],
+                    layout,
+                    renderer,
+                    &mut Resume { now },
+                );
+            }
+        }
+
+        if !self.is_frozen {
+            return self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+
+        // Forward everything except the content's redraw requests while frozen.
+        let mut messages = Vec::new();
+        let mut content_shell = Shell::new(&mut messages);
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut content_shell,
+            viewport,
+        );
+
+        if content_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+        if content_shell.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+        for message in messages {
+            shell.publish(message);
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Freeze<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(freeze: Freeze<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(freeze)
+    }
+}
+
+/// Creates a new [`Freeze`] that freezes the animations in `content` while `is_frozen`.
+pub fn freeze<'a, Message, Theme, Renderer>(
+    is_frozen: bool,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Freeze<'a, Message, Theme, Renderer> {
+    Freeze::new(is_frozen, content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Unfreezing should resume the content on the next frame.
+    #[test]
+    fn unfreezing_needs_resume() {
+        let mut state = State::default();
+        state.set_frozen(true);
+        assert!(!state.needs_resume);
+
+        state.set_frozen(false);
+        assert!(state.needs_resume);
+    }
+
+    /// Refreezing before the next frame shouldn't resume anything.
+    #[test]
+    fn refreezing_cancels_resume() {
+        let mut state = State::default();
+        state.set_frozen(true);
+        state.set_frozen(false);
+        state.set_frozen(true);
+        assert!(!state.needs_resume);
+    }
+}
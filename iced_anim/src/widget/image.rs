@@ -0,0 +1,468 @@
+//! Images display raster graphics, cross-fading whenever their handle changes.
+//!
+//! ```rust
+//! # use iced::{advanced::image::Renderer, Element, Theme};
+//! # use iced_anim::{transition::Easing, widget::image};
+//! # fn view<'a, Message: 'a, R>(thumbnail: image::Handle) -> Element<'a, Message, Theme, R>
+//! # where R: Renderer<Handle = image::Handle> + 'a {
+//! image(thumbnail)
+//!     .animation(Easing::EASE.with_duration(std::time::Duration::from_millis(200)))
+//!     .width(128)
+//!     .height(128)
+//!     .into()
+//! # }
+//! ```
+//!
+//! The old image fades out while the new one fades in over it. If the handle changes again
+//! before the cross-fade finishes, whatever mix of images is visible at that point fades out
+//! as a whole, so swapping images quickly never makes the image jump.
+//!
+//! Iced's renderers only draw raster images with its `image` feature enabled, which also lets
+//! them decode PNG, JPG, and other formats.
+//!
+//! Iced can't draw the mix into a single texture, so the old frame is kept as the handles it's
+//! made of along with their opacities. Only the last few are kept, and faded ones are dropped.
+use crate::{animated::Mode, Animated};
+use iced::{
+    advanced::{
+        graphics::core::event,
+        image, layout, mouse, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    window, ContentFit, Element, Event, Length, Point, Rectangle, Rotation, Size, Vector,
+};
+
+// Re-export the image types for convenience
+pub use image::{FilterMethod, Handle};
+
+/// The most images kept in the old frame while cross-fading quickly between many handles.
+const MAX_FRAMES: usize = 4;
+
+/// Images in the old frame that are more transparent than this are dropped.
+const MIN_OPACITY: f32 = 0.01;
+
+/// A frame that displays an image while keeping aspect ratio, cross-fading when its handle
+/// changes.
+#[allow(missing_debug_implementations)]
+pub struct Image<Handle = image::Handle> {
+    handle: Handle,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    filter_method: FilterMethod,
+    rotation: Rotation,
+    opacity: f32,
+    mode: Mode,
+}
+
+impl<Handle> Image<Handle> {
+    /// Creates a new [`Image`] with the given handle.
+    pub fn new(handle: impl Into<Handle>) -> Self {
+        Self {
+            handle: handle.into(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::default(),
+            filter_method: FilterMethod::default(),
+            rotation: Rotation::default(),
+            opacity: 1.0,
+            mode: Mode::default(),
+        }
+    }
+
+    /// Sets the width of the [`Image`] boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Image`] boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Image`].
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the [`Image`].
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Applies the given [`Rotation`] to the [`Image`].
+    pub fn rotation(mut self, rotation: impl Into<Rotation>) -> Self {
+        self.rotation = rotation.into();
+        self
+    }
+
+    /// Sets the opacity of the [`Image`].
+    ///
+    /// It should be in the [0.0, 1.0] range—`0.0` meaning completely transparent,
+    /// and `1.0` meaning completely opaque.
+    pub fn opacity(mut self, opacity: impl Into<f32>) -> Self {
+        self.opacity = opacity.into();
+        self
+    }
+
+    /// Sets the animation used to cross-fade between images when the handle changes.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+
+    /// Draws the image of the given `handle` in the `bounds` with the given `opacity`.
+    fn draw_handle<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        bounds: Rectangle,
+        handle: &Handle,
+        opacity: f32,
+    ) where
+        Renderer: image::Renderer<Handle = Handle>,
+        Handle: Clone,
+    {
+        let Size { width, height } = renderer.measure_image(handle);
+        let image_size = Size::new(width as f32, height as f32);
+        let rotated_size = self.rotation.apply(image_size);
+
+        let adjusted_fit = self.content_fit.fit(rotated_size, bounds.size());
+        let scale = Vector::new(
+            adjusted_fit.width / rotated_size.width,
+            adjusted_fit.height / rotated_size.height,
+        );
+
+        let final_size = image_size * scale;
+
+        let position = match self.content_fit {
+            ContentFit::None => Point::new(
+                bounds.x + (rotated_size.width - adjusted_fit.width) / 2.0,
+                bounds.y + (rotated_size.height - adjusted_fit.height) / 2.0,
+            ),
+            _ => Point::new(
+                bounds.center_x() - final_size.width / 2.0,
+                bounds.center_y() - final_size.height / 2.0,
+            ),
+        };
+
+        let drawing_bounds = Rectangle::new(position, final_size);
+
+        let render = |renderer: &mut Renderer| {
+            renderer.draw_image(
+                image::Image {
+                    handle: handle.clone(),
+                    filter_method: self.filter_method,
+                    rotation: self.rotation.radians(),
+                    opacity,
+                    snap: true,
+                },
+                drawing_bounds,
+            );
+        };
+
+        if adjusted_fit.width > bounds.width || adjusted_fit.height > bounds.height {
+            renderer.with_layer(bounds, render);
+        } else {
+            render(renderer);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct State<Handle> {
+    /// The handle being faded in, or shown once the cross-fade finishes.
+    current: Handle,
+    /// The images making up the old frame being faded out, from bottom to top, along with the
+    /// opacity each had in that frame.
+    previous: Vec<(Handle, f32)>,
+    /// How far the current image has faded in over the old frame, from 0 to 1.
+    blend: Animated<f32>,
+}
+
+impl<Handle: Clone + PartialEq> State<Handle> {
+    fn new(handle: Handle, mode: Mode) -> Self {
+        Self {
+            current: handle,
+            previous: Vec::new(),
+            blend: Animated::new(1.0, mode),
+        }
+    }
+
+    /// The current blend factor, which springs may push slightly outside of 0 to 1.
+    fn blend(&self) -> f32 {
+        self.blend.value().clamp(0.0, 1.0)
+    }
+
+    /// Cross-fades to the given `handle`, promoting whatever is visible now to the old frame.
+    fn change(&mut self, handle: Handle) {
+        if handle == self.current {
+            return;
+        }
+
+        let blend = self.blend();
+        for (_, opacity) in &mut self.previous {
+            *opacity *= 1.0 - blend;
+        }
+        let previous = std::mem::replace(&mut self.current, handle);
+        self.previous.push((previous, blend));
+        self.previous.retain(|(_, opacity)| *opacity > MIN_OPACITY);
+        let overflow = self.previous.len().saturating_sub(MAX_FRAMES);
+        self.previous.drain(..overflow);
+
+        self.blend.settle_at(0.0);
+        self.blend.set_target(1.0);
+    }
+
+    /// Advances the cross-fade, dropping the old frame once it's finished.
+    fn tick(&mut self, now: std::time::Instant) {
+        self.blend.tick(now);
+        if !self.blend.is_animating() {
+            self.previous.clear();
+        }
+    }
+
+    /// The images to draw from bottom to top, along with their opacity.
+    fn layers(&self) -> impl Iterator<Item = (&Handle, f32)> {
+        let blend = self.blend();
+        self.previous
+            .iter()
+            .map(move |(handle, opacity)| (handle, opacity * (1.0 - blend)))
+            .chain(std::iter::once((&self.current, blend)))
+    }
+}
+
+impl<Message, Theme, Renderer, Handle> Widget<Message, Theme, Renderer> for Image<Handle>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone + PartialEq + 'static,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Handle>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.handle.clone(), self.mode))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Handle>>();
+        if state.blend.mode() != self.mode {
+            state.blend.apply(self.mode);
+        }
+        state.change(self.handle.clone());
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        // The raw w/h of the underlying image
+        let Size { width, height } = renderer.measure_image(&self.handle);
+        let image_size = Size::new(width as f32, height as f32);
+
+        // The rotated size of the image
+        let rotated_size = self.rotation.apply(image_size);
+
+        // The size to be available to the widget prior to `Shrink`ing
+        let raw_size = limits.resolve(self.width, self.height, rotated_size);
+
+        // The uncropped size of the image when fit to the bounds above
+        let full_size = self.content_fit.fit(rotated_size, raw_size);
+
+        // Shrink the widget to fit the resized image, if requested
+        let final_size = Size {
+            width: match self.width {
+                Length::Shrink => f32::min(raw_size.width, full_size.width),
+                _ => raw_size.width,
+            },
+            height: match self.height {
+                Length::Shrink => f32::min(raw_size.height, full_size.height),
+                _ => raw_size.height,
+            },
+        };
+
+        layout::Node::new(final_size)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State<Handle>>();
+            state.blend.resume_at(now);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Handle>>();
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.tick(now);
+        }
+        if state.blend.needs_tick() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Handle>>();
+        for (handle, opacity) in state.layers() {
+            if opacity > 0.0 {
+                self.draw_handle(renderer, layout.bounds(), handle, opacity * self.opacity);
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer, Handle> From<Image<Handle>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone + PartialEq + 'static,
+{
+    fn from(image: Image<Handle>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(image)
+    }
+}
+
+/// Creates a new [`Image`] from the given handle, which cross-fades when the handle changes.
+///
+/// Images display raster graphics in different formats (PNG, JPG, etc.).
+pub fn image<Handle>(handle: impl Into<Handle>) -> Image<Handle> {
+    Image::new(handle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transition::Easing;
+    use std::time::{Duration, Instant};
+
+    fn thumbnail(handle: &Handle) -> Image {
+        image(handle.clone()).animation(Easing::LINEAR)
+    }
+
+    fn state(tree: &mut Tree) -> &mut State<Handle> {
+        tree.state.downcast_mut::<State<Handle>>()
+    }
+
+    fn layers(state: &State<Handle>) -> Vec<(Handle, f32)> {
+        state
+            .layers()
+            .map(|(handle, opacity)| (handle.clone(), opacity))
+            .collect()
+    }
+
+    /// Changing the handle should fade the old image out while the new one fades in, and drop
+    /// the old image once the cross-fade finishes.
+    #[test]
+    fn handle_change_cross_fades() {
+        let first = Handle::from_path("first.png");
+        let second = Handle::from_path("second.png");
+        let mut tree = Tree::new(&thumbnail(&first) as &dyn Widget<(), iced::Theme, ()>);
+        assert_eq!(layers(state(&mut tree)), vec![(first.clone(), 1.0)]);
+
+        tree.diff(&thumbnail(&second) as &dyn Widget<(), iced::Theme, ()>);
+        let start = Instant::now();
+        let state = state(&mut tree);
+        assert!(state.blend.is_animating());
+        assert_eq!(
+            layers(state),
+            vec![(first.clone(), 1.0), (second.clone(), 0.0)]
+        );
+
+        state.tick(start + Duration::from_millis(16));
+        let [(_, old), (_, new)] = layers(state)[..] else {
+            panic!("expected both images while cross-fading");
+        };
+        assert!(old < 1.0 && new > 0.0);
+        assert!((old + new - 1.0).abs() < 1e-6);
+
+        for ms in (2..=100).map(|frame| frame * 16) {
+            state.tick(start + Duration::from_millis(ms));
+        }
+        assert_eq!(layers(state), vec![(second, 1.0)]);
+    }
+
+    /// Rebuilding the widget with the same handle shouldn't restart the cross-fade.
+    #[test]
+    fn same_handle_doesnt_fade() {
+        let first = Handle::from_path("first.png");
+        let mut tree = Tree::new(&thumbnail(&first) as &dyn Widget<(), iced::Theme, ()>);
+        tree.diff(&thumbnail(&first) as &dyn Widget<(), iced::Theme, ()>);
+        assert!(!state(&mut tree).blend.is_animating());
+    }
+
+    /// Changing the handle mid-blend should fade out the mix that's visible at that point as the
+    /// old frame rather than jumping back to either image.
+    #[test]
+    fn change_mid_blend_promotes_visible_mix() {
+        let [first, second, third] =
+            ["first.png", "second.png", "third.png"].map(Handle::from_path);
+        let mut state = State::new(first.clone(), Easing::LINEAR.into());
+        state.change(second.clone());
+        state.blend.settle_at(0.25);
+
+        state.change(third.clone());
+        assert_eq!(
+            layers(&state),
+            vec![(first, 0.75), (second, 0.25), (third.clone(), 0.0)]
+        );
+
+        state.blend.settle_at(0.5);
+        assert_eq!(
+            layers(&state)
+                .iter()
+                .map(|(_, opacity)| *opacity)
+                .collect::<Vec<_>>(),
+            vec![0.375, 0.125, 0.5]
+        );
+
+        // Swapping handles every frame keeps only the most recent images.
+        for index in 0..10 {
+            state.blend.settle_at(0.5);
+            state.change(Handle::from_path(format!("{index}.png")));
+        }
+        assert_eq!(state.previous.len(), MAX_FRAMES);
+    }
+}
@@ -0,0 +1,297 @@
+//! A progress bar that indicates indeterminate progress with a sliding gradient.
+//!
+//! Like the [`Spinner`](super::Spinner), the bar drives itself from the timestamps of
+//! `RedrawRequested` events, so it doesn't need a subscription or any messages to keep moving.
+//! It only requests redraws while it's visible, and shows a static track instead when it's
+//! disabled, e.g. for reduced motion preferences.
+//!
+//! ```rust
+//! # use std::time::Duration;
+//! # use iced::Element;
+//! # use iced_anim::widget::indeterminate_bar;
+//! # #[derive(Clone)]
+//! # enum Message {}
+//! # fn view<'a>() -> Element<'a, Message> {
+//! indeterminate_bar()
+//!     .height(6.0)
+//!     .speed(Duration::from_millis(1200))
+//!     .into()
+//! # }
+//! ```
+use std::{f32::consts::FRAC_PI_2, time::Duration};
+
+use super::cycle::Cycle;
+use crate::{animated::Mode, transition::Easing};
+use iced::{
+    advanced::{
+        graphics::core::event,
+        layout, mouse, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    gradient, window, Background, Border, Color, Element, Event, Length, Radians, Rectangle, Size,
+};
+
+/// The default time it takes for the gradient to slide across the bar.
+pub const DEFAULT_SPEED: Duration = Duration::from_millis(1500);
+
+/// The default height of the bar.
+pub const DEFAULT_HEIGHT: f32 = 4.0;
+
+/// The width of the sliding gradient as a fraction of the bar's width.
+const SEGMENT: f32 = 0.4;
+
+/// The opacity of the track behind the gradient.
+const TRACK_OPACITY: f32 = 0.2;
+
+/// The opacity of the track when the bar is disabled.
+const STATIC_OPACITY: f32 = 0.4;
+
+/// A progress bar that indicates indeterminate progress with a sliding gradient.
+pub struct IndeterminateBar {
+    /// The width of the bar.
+    width: Length,
+    /// The height of the bar.
+    height: f32,
+    /// The color of the gradient, or the text color if `None`.
+    color: Option<Color>,
+    /// How long it takes the gradient to slide across the bar.
+    speed: Duration,
+    /// How each slide is eased.
+    mode: Mode,
+    /// Whether the bar is static instead of animating.
+    is_disabled: bool,
+}
+
+impl IndeterminateBar {
+    /// Creates a new [`IndeterminateBar`].
+    pub fn new() -> Self {
+        Self {
+            width: Length::Fill,
+            height: DEFAULT_HEIGHT,
+            color: None,
+            speed: DEFAULT_SPEED,
+            mode: Easing::EASE_IN_OUT.into(),
+            is_disabled: false,
+        }
+    }
+
+    /// Sets the width of the [`IndeterminateBar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`IndeterminateBar`].
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the color of the [`IndeterminateBar`], which defaults to the text color.
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets how long it takes the gradient to slide across the [`IndeterminateBar`].
+    pub fn speed(mut self, speed: Duration) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Sets how each slide of the gradient is eased.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+
+    /// Whether to show a static track instead of animating.
+    /// Useful for reduced motion preferences.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.is_disabled = disabled;
+        self
+    }
+}
+
+impl Default for IndeterminateBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct State {
+    cycle: Cycle,
+}
+
+/// The horizontal span of the gradient within a bar of the given `width` at `progress`.
+///
+/// The gradient starts fully to the left of the bar and ends fully to the right of it.
+fn segment(progress: f32, width: f32) -> (f32, f32) {
+    let segment_width = width * SEGMENT;
+    let x = progress * (width + segment_width) - segment_width;
+    (x, segment_width)
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for IndeterminateBar
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            cycle: Cycle::new(self.mode, self.speed),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.cycle.diff(self.mode, self.speed);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Fixed(self.height))
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO))
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State>();
+            state.cycle.resume_at(now);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // Offscreen bars stop requesting frames until they're redrawn again.
+        if self.is_disabled || !layout.bounds().intersects(viewport) {
+            return event::Status::Ignored;
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+            state.cycle.tick(now);
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let color = self.color.unwrap_or(style.text_color);
+        let border = Border {
+            radius: (bounds.height / 2.0).into(),
+            ..Default::default()
+        };
+
+        let track_opacity = if self.is_disabled {
+            STATIC_OPACITY
+        } else {
+            TRACK_OPACITY
+        };
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border,
+                ..Default::default()
+            },
+            color.scale_alpha(track_opacity),
+        );
+
+        if self.is_disabled {
+            return;
+        }
+
+        let (x, width) = segment(state.cycle.progress(), bounds.width);
+        let gradient = gradient::Linear::new(Radians(FRAC_PI_2))
+            .add_stop(0.0, Color::TRANSPARENT)
+            .add_stop(0.5, color)
+            .add_stop(1.0, Color::TRANSPARENT);
+
+        // The gradient slides in and out of view, so clip it to the track.
+        renderer.with_layer(bounds, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + x,
+                        width,
+                        ..bounds
+                    },
+                    border,
+                    ..Default::default()
+                },
+                Background::Gradient(gradient.into()),
+            );
+        });
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<IndeterminateBar> for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(bar: IndeterminateBar) -> Self {
+        Self::new(bar)
+    }
+}
+
+/// Creates a new [`IndeterminateBar`].
+pub fn indeterminate_bar() -> IndeterminateBar {
+    IndeterminateBar::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The gradient should start just out of view on the left.
+    #[test]
+    fn segment_starts_left_of_bar() {
+        let (x, width) = segment(0.0, 100.0);
+        assert_eq!(x + width, 0.0);
+    }
+
+    /// The gradient should end just out of view on the right.
+    #[test]
+    fn segment_ends_right_of_bar() {
+        let (x, _) = segment(1.0, 100.0);
+        assert_eq!(x, 100.0);
+    }
+}
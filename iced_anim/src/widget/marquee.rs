@@ -0,0 +1,571 @@
+//! A marquee that automatically scrolls content that's too wide to fit.
+//!
+//! Long labels in constrained spaces, like a track title in a music player, can be wrapped in a
+//! [`Marquee`] to scroll them horizontally when they overflow. The scrolling loops through a few
+//! phases: hold at the start, scroll to the end, hold at the end, and then rewind back to the
+//! start. Scrolling pauses while the marquee is hovered, and the content springs back to the
+//! start if it fits again or its width changes.
+//!
+//! ```rust
+//! # use iced::{Element, widget::text};
+//! # use iced_anim::widget::marquee;
+//! # #[derive(Clone)]
+//! # enum Message {}
+//! # fn view<'a>() -> Element<'a, Message> {
+//! marquee(text("A very long track title that won't fit"))
+//!     .width(120)
+//!     .into()
+//! # }
+//! ```
+use std::time::{Duration, Instant};
+
+use crate::{
+    spring::MAX_DURATION,
+    stall::{FrameClock, Gap, StallPolicy},
+    Animated,
+};
+use iced::{
+    advanced::{
+        graphics::core::event,
+        layout, mouse, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    window, Element, Event, Length, Rectangle, Size, Vector,
+};
+
+/// The default amount of time to hold at either end of the scroll.
+pub const DEFAULT_HOLD: Duration = Duration::from_millis(1500);
+
+/// The default scrolling speed, in pixels per second.
+pub const DEFAULT_SPEED: f32 = 40.0;
+
+/// How the marquee gets back to the start after scrolling to the end.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Rewind {
+    /// Jump straight back to the start.
+    Snap,
+    /// Scroll back to the start at the same speed.
+    #[default]
+    Scroll,
+}
+
+/// A marquee that automatically scrolls content that's too wide to fit.
+pub struct Marquee<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    width: Length,
+    config: Config,
+}
+
+/// Configures the timing of the marquee loop.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Config {
+    /// How long to hold at either end.
+    hold: Duration,
+    /// The scrolling speed, in pixels per second.
+    speed: f32,
+    /// How to get back to the start.
+    rewind: Rewind,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            hold: DEFAULT_HOLD,
+            speed: DEFAULT_SPEED,
+            rewind: Rewind::default(),
+        }
+    }
+}
+
+impl Config {
+    /// How long it takes to scroll across the given `overflow`.
+    fn scroll_duration(&self, overflow: f32) -> Duration {
+        Duration::from_secs_f32(overflow / self.speed.max(f32::EPSILON))
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Marquee<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Marquee`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            width: Length::Fill,
+            config: Config::default(),
+        }
+    }
+
+    /// Sets the width of the [`Marquee`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets how long the [`Marquee`] holds at either end before scrolling.
+    pub fn hold(mut self, hold: Duration) -> Self {
+        self.config.hold = hold;
+        self
+    }
+
+    /// Sets the scrolling speed of the [`Marquee`] in pixels per second.
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.config.speed = speed;
+        self
+    }
+
+    /// Sets how the [`Marquee`] gets back to the start after scrolling to the end.
+    pub fn rewind(mut self, rewind: Rewind) -> Self {
+        self.config.rewind = rewind;
+        self
+    }
+}
+
+/// The phases of the marquee loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    /// Holding at the start of the content.
+    HoldStart,
+    /// Scrolling towards the end of the content.
+    Forward,
+    /// Holding at the end of the content.
+    HoldEnd,
+    /// Scrolling back towards the start of the content.
+    Back,
+}
+
+/// The phase state machine that drives the marquee's horizontal offset.
+#[derive(Debug)]
+struct Looper {
+    /// The current phase of the loop.
+    phase: Phase,
+    /// How long the loop has spent in the current phase, excluding paused time.
+    elapsed: Duration,
+    /// Measures the time between ticks of the loop.
+    clock: FrameClock,
+    /// The horizontal offset of the content.
+    offset: Animated<f32>,
+}
+
+impl Looper {
+    fn new() -> Self {
+        Self {
+            phase: Phase::HoldStart,
+            elapsed: Duration::ZERO,
+            clock: FrameClock::default(),
+            offset: Animated::spring(0.0, crate::spring::Motion::SMOOTH),
+        }
+    }
+
+    /// The current horizontal offset of the content.
+    fn offset(&self) -> f32 {
+        *self.offset.value()
+    }
+
+    /// Restarts the loop, springing the content back to the start.
+    fn reset(&mut self) {
+        self.phase = Phase::HoldStart;
+        self.elapsed = Duration::ZERO;
+        self.offset.set_target(0.0);
+    }
+
+    /// Whether the loop needs to keep being ticked.
+    fn is_animating(&self, overflow: f32, is_paused: bool) -> bool {
+        (overflow > 0.0 && !is_paused) || self.offset.is_animating()
+    }
+
+    /// Advances the loop to `now` for content that overflows by `overflow` pixels.
+    fn tick(&mut self, now: Instant, overflow: f32, config: &Config, is_paused: bool) {
+        // Holds and scrolls are shifted forward by stalls in the event loop unless they settle.
+        let dt = match self.clock.tick(now) {
+            Gap::Frame(dt) => dt.min(MAX_DURATION),
+            stall => stall.elapsed(StallPolicy::global()),
+        };
+
+        if overflow <= 0.0 {
+            // The content fits, so spring back to the start.
+            if self.phase != Phase::HoldStart || self.elapsed > Duration::ZERO {
+                self.reset();
+            }
+        } else if !is_paused {
+            self.advance(dt, overflow, config);
+        }
+
+        self.offset.tick(now);
+    }
+
+    /// Resumes the loop at `now` without advancing it by the paused time.
+    fn resume_at(&mut self, now: Instant) {
+        self.clock.resume_at(now);
+        self.offset.resume_at(now);
+    }
+
+    /// Moves through the phases of the loop by the given amount of time.
+    fn advance(&mut self, dt: Duration, overflow: f32, config: &Config) {
+        self.elapsed += dt;
+        let scroll = config.scroll_duration(overflow);
+
+        // Multiple phases may be completed in a single tick if they're short.
+        for _ in 0..4 {
+            let phase_duration = match self.phase {
+                Phase::HoldStart | Phase::HoldEnd => config.hold,
+                Phase::Forward | Phase::Back => scroll,
+            };
+
+            if self.elapsed < phase_duration {
+                break;
+            }
+
+            self.elapsed -= phase_duration;
+            self.phase = match (self.phase, config.rewind) {
+                (Phase::HoldStart, _) => Phase::Forward,
+                (Phase::Forward, _) => Phase::HoldEnd,
+                (Phase::HoldEnd, Rewind::Scroll) => Phase::Back,
+                (Phase::HoldEnd, Rewind::Snap) | (Phase::Back, _) => Phase::HoldStart,
+            };
+
+            if self.phase == Phase::HoldStart {
+                self.offset.settle_at(0.0);
+            }
+        }
+
+        let fraction = if scroll.is_zero() {
+            1.0
+        } else {
+            (self.elapsed.as_secs_f32() / scroll.as_secs_f32()).min(1.0)
+        };
+
+        match self.phase {
+            // The offset may still be springing back to the start.
+            Phase::HoldStart => self.offset.set_target(0.0),
+            Phase::Forward => self.offset.settle_at(overflow * fraction),
+            Phase::HoldEnd => self.offset.settle_at(overflow),
+            Phase::Back => self.offset.settle_at(overflow * (1.0 - fraction)),
+        }
+    }
+}
+
+struct State {
+    looper: Looper,
+    /// How far the content overflows the marquee, as of the last layout.
+    overflow: f32,
+    /// The natural width of the content, used to detect content changes.
+    content_width: f32,
+    /// Whether the cursor is over the marquee.
+    is_hovered: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Marquee<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            looper: Looper::new(),
+            overflow: 0.0,
+            content_width: 0.0,
+            is_hovered: false,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        // Measure the content without any width constraint to see whether it overflows.
+        let content_limits =
+            layout::Limits::new(Size::ZERO, Size::new(f32::INFINITY, limits.max().height));
+        let content =
+            self.content
+                .as_widget()
+                .layout(&mut tree.children[0], renderer, &content_limits);
+        let content_size = content.size();
+        let size = limits.resolve(self.width, Length::Shrink, content_size);
+
+        let state = tree.state.downcast_mut::<State>();
+        if state.content_width != content_size.width {
+            state.content_width = content_size.width;
+            state.looper.reset();
+        }
+        state.overflow = (content_size.width - size.width).max(0.0);
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State>();
+            state.looper.resume_at(now);
+        }
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        state.is_hovered = cursor.is_over(layout.bounds());
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state
+                .looper
+                .tick(now, state.overflow, &self.config, state.is_hovered);
+        }
+
+        if state.looper.is_animating(state.overflow, state.is_hovered) {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let Some(viewport) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        let state = tree.state.downcast_ref::<State>();
+        let translation = Vector::new(-state.looper.offset(), 0.0);
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(translation, |renderer| {
+                self.content.as_widget().draw(
+                    &tree.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    layout.children().next().unwrap(),
+                    cursor,
+                    &viewport,
+                );
+            });
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Marquee<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(marquee: Marquee<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(marquee)
+    }
+}
+
+/// Creates a new [`Marquee`] that scrolls its content when it's too wide to fit.
+pub fn marquee<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Marquee<'a, Message, Theme, Renderer> {
+    Marquee::new(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: Duration = Duration::from_millis(16);
+
+    /// Ticks the looper at 60fps for the given `duration`, returning the final instant.
+    fn run(looper: &mut Looper, start: Instant, duration: Duration, config: &Config) -> Instant {
+        let mut now = start;
+        while now < start + duration {
+            now += FRAME;
+            looper.tick(now, 100.0, config, false);
+        }
+        now
+    }
+
+    fn config() -> Config {
+        Config {
+            hold: Duration::from_millis(500),
+            speed: 100.0,
+            rewind: Rewind::Scroll,
+        }
+    }
+
+    /// The loop should hold, scroll forward, hold, and scroll back in order.
+    #[test]
+    fn phase_timing() {
+        let config = config();
+        let mut looper = Looper::new();
+        let start = Instant::now();
+        looper.tick(start, 100.0, &config, false);
+
+        let now = run(&mut looper, start, Duration::from_millis(400), &config);
+        assert_eq!(looper.phase, Phase::HoldStart);
+        assert_eq!(looper.offset(), 0.0);
+
+        let now = run(&mut looper, now, Duration::from_millis(600), &config);
+        assert_eq!(looper.phase, Phase::Forward);
+        assert!(looper.offset() > 0.0 && looper.offset() < 100.0);
+
+        let now = run(&mut looper, now, Duration::from_millis(700), &config);
+        assert_eq!(looper.phase, Phase::HoldEnd);
+        assert_eq!(looper.offset(), 100.0);
+
+        run(&mut looper, now, Duration::from_millis(700), &config);
+        assert_eq!(looper.phase, Phase::Back);
+        assert!(looper.offset() < 100.0);
+    }
+
+    /// Snapping rewinds should jump from the end hold straight to the start.
+    #[test]
+    fn snap_rewind() {
+        let config = Config {
+            rewind: Rewind::Snap,
+            ..config()
+        };
+        let mut looper = Looper::new();
+        let start = Instant::now();
+        looper.tick(start, 100.0, &config, false);
+
+        // Hold (500ms) + scroll (1000ms) + hold (500ms) and a bit more.
+        run(&mut looper, start, Duration::from_millis(2100), &config);
+        assert_eq!(looper.phase, Phase::HoldStart);
+        assert_eq!(looper.offset(), 0.0);
+    }
+
+    /// Pausing should stop the loop from progressing.
+    #[test]
+    fn pause_stops_progress() {
+        let config = config();
+        let mut looper = Looper::new();
+        let start = Instant::now();
+        looper.tick(start, 100.0, &config, false);
+        let now = run(&mut looper, start, Duration::from_millis(800), &config);
+        let offset = looper.offset();
+
+        looper.tick(now + FRAME, 100.0, &config, true);
+        looper.tick(now + 2 * FRAME, 100.0, &config, true);
+        assert_eq!(looper.offset(), offset);
+        assert!(!looper.is_animating(100.0, true));
+    }
+
+    /// Content that fits again should spring back to the start.
+    #[test]
+    fn fits_again_resets() {
+        let config = config();
+        let mut looper = Looper::new();
+        let start = Instant::now();
+        looper.tick(start, 100.0, &config, false);
+        let now = run(&mut looper, start, Duration::from_millis(800), &config);
+        assert_eq!(looper.phase, Phase::Forward);
+
+        looper.tick(now + FRAME, 0.0, &config, false);
+        assert_eq!(looper.phase, Phase::HoldStart);
+        assert_eq!(*looper.offset.target(), 0.0);
+    }
+
+    /// Stalls in the event loop shouldn't count towards holds under the default policy.
+    #[test]
+    fn stall_shifts_hold() {
+        let config = config();
+        let mut looper = Looper::new();
+        let start = Instant::now();
+        looper.tick(start, 100.0, &config, false);
+        let now = run(&mut looper, start, Duration::from_millis(200), &config);
+
+        let now = now + Duration::from_secs(2);
+        looper.tick(now, 100.0, &config, false);
+        assert_eq!(looper.phase, Phase::HoldStart);
+        assert_eq!(looper.offset(), 0.0);
+
+        // The rest of the hold still needs to pass before scrolling.
+        let now = run(&mut looper, now, Duration::from_millis(200), &config);
+        assert_eq!(looper.phase, Phase::HoldStart);
+        run(&mut looper, now, Duration::from_millis(200), &config);
+        assert_eq!(looper.phase, Phase::Forward);
+    }
+}
@@ -0,0 +1,316 @@
+//! A wrapper that fades its content in and out by animating its opacity.
+//!
+//! ```rust
+//! # use iced::{Element, widget::text};
+//! # use iced_anim::{transition::Easing, widget::opacity};
+//! # fn view<'a, Message: 'a>(is_visible: bool) -> Element<'a, Message> {
+//! opacity(if is_visible { 1.0 } else { 0.0 }, text("Now you see me"))
+//!     .animation(Easing::EASE_OUT)
+//!     .into()
+//! # }
+//! ```
+//!
+//! Iced's renderers can't draw arbitrary content with a global alpha, so the opacity is applied
+//! in two ways:
+//!
+//! - The text color the content inherits is faded, which covers plain text and icons.
+//! - If a [`backdrop`](Opacity::backdrop) color is set, it's drawn over the content with the
+//!   inverse opacity. This fades everything the content draws, including backgrounds and
+//!   borders, into the backdrop, so it looks right as long as the backdrop matches whatever is
+//!   behind the content, like the background of the window.
+//!
+//! Fully transparent content isn't drawn at all, although it still takes up space and receives
+//! events. Wrap it in [`animated_visibility`](super::animated_visibility) to remove it instead.
+use crate::{animated::Mode, Animated};
+use iced::{
+    advanced::{
+        graphics::core::event,
+        layout, mouse, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    window, Color, Element, Event, Length, Rectangle, Size, Vector,
+};
+
+/// A wrapper that fades its content in and out by animating its opacity.
+pub struct Opacity<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// The content whose opacity is animated.
+    content: Element<'a, Message, Theme, Renderer>,
+    /// The opacity the content animates towards, from 0 to 1.
+    opacity: f32,
+    /// The color drawn over the content to fade everything it draws.
+    backdrop: Option<Color>,
+    /// The animation used to change the opacity.
+    mode: Mode,
+}
+
+impl<'a, Message, Theme, Renderer> Opacity<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Opacity`] that draws the `content` with the given `opacity`, from 0 to 1.
+    pub fn new(opacity: f32, content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            opacity: opacity.clamp(0.0, 1.0),
+            backdrop: None,
+            mode: Mode::default(),
+        }
+    }
+
+    /// Sets the color that the content fades into, which should match whatever is behind it.
+    ///
+    /// Without a backdrop, only the text color inherited by the content is faded.
+    pub fn backdrop(mut self, backdrop: impl Into<Color>) -> Self {
+        self.backdrop = Some(backdrop.into());
+        self
+    }
+
+    /// Sets the animation used to change the opacity.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    /// The opacity the content is currently drawn with.
+    opacity: Animated<f32>,
+}
+
+impl State {
+    /// The current opacity, kept within 0 and 1 for springs that overshoot.
+    fn opacity(&self) -> f32 {
+        self.opacity.value().clamp(0.0, 1.0)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Opacity<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            opacity: Animated::new(self.opacity, self.mode),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        if state.opacity.mode() != self.mode {
+            state.opacity.apply(self.mode);
+        }
+        if state.opacity.target() != &self.opacity {
+            state.opacity.set_target(self.opacity);
+        }
+
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            tree.state.downcast_mut::<State>().opacity.resume_at(now);
+        }
+
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.opacity.tick(now);
+        }
+        if state.opacity.needs_tick() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let opacity = tree.state.downcast_ref::<State>().opacity();
+        if opacity <= 0.0 {
+            return;
+        }
+
+        let style = renderer::Style {
+            text_color: style.text_color.scale_alpha(opacity),
+        };
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            &style,
+            layout,
+            cursor,
+            viewport,
+        );
+
+        // The backdrop is drawn in its own layer so it also covers the text of the content,
+        // which is drawn after quads within the same layer.
+        if let Some(backdrop) = self.backdrop.filter(|_| opacity < 1.0) {
+            let bounds = layout.bounds();
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..renderer::Quad::default()
+                    },
+                    backdrop.scale_alpha(1.0 - opacity),
+                );
+            });
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Opacity<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(opacity: Opacity<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(opacity)
+    }
+}
+
+/// Creates a new [`Opacity`] that draws the `content` with the given `opacity`, from 0 to 1.
+pub fn opacity<'a, Message, Theme, Renderer>(
+    opacity: f32,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Opacity<'a, Message, Theme, Renderer> {
+    Opacity::new(opacity, content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transition::Easing;
+    use iced::widget::Space;
+    use std::time::{Duration, Instant};
+
+    fn faded(value: f32) -> Opacity<'static, (), iced::Theme, ()> {
+        opacity(value, Space::new(Length::Fill, Length::Fill)).animation(Easing::LINEAR)
+    }
+
+    fn state(tree: &mut Tree) -> &mut State {
+        tree.state.downcast_mut::<State>()
+    }
+
+    /// Changing the opacity should animate from the current opacity to the new one.
+    #[test]
+    fn diff_retargets_opacity() {
+        let mut tree = Tree::new(&faded(0.0) as &dyn Widget<(), iced::Theme, ()>);
+        assert_eq!(state(&mut tree).opacity(), 0.0);
+
+        tree.diff(&faded(1.0) as &dyn Widget<(), iced::Theme, ()>);
+        let start = Instant::now();
+        let state = state(&mut tree);
+        assert!(state.opacity.is_animating());
+        assert_eq!(state.opacity(), 0.0);
+
+        for ms in (0..=100).map(|frame| frame * 16) {
+            state.opacity.tick(start + Duration::from_millis(ms));
+        }
+        assert_eq!(state.opacity(), 1.0);
+    }
+
+    /// Opacities outside of 0 and 1 should be clamped.
+    #[test]
+    fn opacity_is_clamped() {
+        let tree = Tree::new(&faded(2.0) as &dyn Widget<(), iced::Theme, ()>);
+        assert_eq!(*tree.state.downcast_ref::<State>().opacity.value(), 1.0);
+    }
+}
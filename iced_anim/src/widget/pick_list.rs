@@ -0,0 +1,1215 @@
+//! An animated pick list whose menu grows and fades in from the field when it's opened.
+//!
+//! The field animates between its styles like other animated widgets, while the menu scales up
+//! from the edge of the field it's anchored to as it opens, and shrinks back before it's removed
+//! when it closes. The highlight in the menu glides between options as they're hovered or picked
+//! with the arrow keys instead of jumping:
+//!
+//! ```no_run
+//! # use iced::Element;
+//! # use iced_anim::{spring::Motion, widget::pick_list};
+//! # #[derive(Clone)] enum Message { Pick(&'static str) }
+//! # fn view<'a>(fruit: Option<&'static str>) -> Element<'a, Message> {
+//! pick_list(["Apple", "Orange", "Strawberry"], fruit, Message::Pick)
+//!     .placeholder("Pick a fruit...")
+//!     .animation(Motion::SNAPPY)
+//!     .into()
+//! # }
+//! ```
+//!
+//! While the menu is open, the arrow keys move the highlight, enter picks the highlighted option,
+//! and escape closes the menu.
+use std::borrow::Borrow;
+
+use crate::{animate::AnimatedPickListStyle, animated::Mode, Animate, Animated, AnimatedState};
+use iced::{
+    advanced::{
+        layout, overlay, renderer,
+        text::{self as core_text, paragraph, Paragraph, Text},
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event, keyboard, mouse, touch,
+    widget::Scrollable,
+    window, Border, Element, Event, Length, Padding, Pixels, Point, Rectangle, Size,
+    Transformation, Vector,
+};
+
+// Re-export the widget types for convenience
+pub use iced::widget::overlay::menu;
+pub use iced::widget::pick_list::{default, Catalog, Handle, Icon, Status, Style, StyleFn};
+
+/// How large the menu is relative to its full size when it starts opening.
+const CLOSED_SCALE: f32 = 0.9;
+
+/// A widget for selecting a single value from a list of options in an animated menu.
+#[allow(missing_debug_implementations)]
+pub struct PickList<'a, T, L, V, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    L: Borrow<[T]> + 'a,
+    V: Borrow<T> + 'a,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    on_open: Option<Message>,
+    on_close: Option<Message>,
+    options: L,
+    placeholder: Option<String>,
+    selected: Option<V>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: core_text::LineHeight,
+    text_shaping: core_text::Shaping,
+    font: Option<Renderer::Font>,
+    handle: Handle<Renderer::Font>,
+    class: <Theme as Catalog>::Class<'a>,
+    menu_class: <Theme as menu::Catalog>::Class<'a>,
+    mode: Mode,
+}
+
+impl<'a, T, L, V, Message, Theme, Renderer> PickList<'a, T, L, V, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone,
+    L: Borrow<[T]> + 'a,
+    V: Borrow<T> + 'a,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    /// Creates a new [`PickList`] with the given list of options, the current selected value,
+    /// and the message to produce when an option is selected.
+    pub fn new(options: L, selected: Option<V>, on_select: impl Fn(T) -> Message + 'a) -> Self {
+        Self {
+            on_select: Box::new(on_select),
+            on_open: None,
+            on_close: None,
+            options,
+            placeholder: None,
+            selected,
+            width: Length::Shrink,
+            padding: super::button::DEFAULT_PADDING,
+            text_size: None,
+            text_line_height: core_text::LineHeight::default(),
+            text_shaping: core_text::Shaping::default(),
+            font: None,
+            handle: Handle::default(),
+            class: <Theme as Catalog>::default(),
+            menu_class: <Theme as Catalog>::default_menu(),
+            mode: Mode::default(),
+        }
+    }
+
+    /// Sets the placeholder of the [`PickList`].
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the width of the [`PickList`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`PickList`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`PickList`].
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the text [`LineHeight`](core_text::LineHeight) of the [`PickList`].
+    pub fn text_line_height(mut self, line_height: impl Into<core_text::LineHeight>) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`Shaping`](core_text::Shaping) strategy of the [`PickList`].
+    pub fn text_shaping(mut self, shaping: core_text::Shaping) -> Self {
+        self.text_shaping = shaping;
+        self
+    }
+
+    /// Sets the font of the [`PickList`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the [`Handle`] of the [`PickList`].
+    pub fn handle(mut self, handle: Handle<Renderer::Font>) -> Self {
+        self.handle = handle;
+        self
+    }
+
+    /// Sets the message that will be produced when the [`PickList`] is opened.
+    pub fn on_open(mut self, on_open: Message) -> Self {
+        self.on_open = Some(on_open);
+        self
+    }
+
+    /// Sets the message that will be produced when the [`PickList`] is closed.
+    pub fn on_close(mut self, on_close: Message) -> Self {
+        self.on_close = Some(on_close);
+        self
+    }
+
+    /// Sets the style of the [`PickList`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style of the menu of the [`PickList`].
+    #[must_use]
+    pub fn menu_style(mut self, style: impl Fn(&Theme) -> menu::Style + 'a) -> Self
+    where
+        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.menu_class = (Box::new(style) as menu::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`PickList`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style class of the menu of the [`PickList`].
+    #[must_use]
+    pub fn menu_class(mut self, class: impl Into<<Theme as menu::Catalog>::Class<'a>>) -> Self {
+        self.menu_class = class.into();
+        self
+    }
+
+    /// Sets the animation of the [`PickList`], used for its style, its menu opening and
+    /// closing, and the highlight moving between options.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+
+    /// Gets the status of the [`PickList`] based on the cursor position.
+    fn get_status<P: Paragraph>(
+        &self,
+        state: &State<P>,
+        cursor: mouse::Cursor,
+        layout: Layout<'_>,
+    ) -> Status {
+        if state.is_open {
+            Status::Opened
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+}
+
+struct State<P: Paragraph> {
+    /// The tree of the scrollable list inside the menu.
+    menu: Tree,
+    keyboard_modifiers: keyboard::Modifiers,
+    is_open: bool,
+    hovered_option: Option<usize>,
+    options: Vec<paragraph::Plain<P>>,
+    placeholder: paragraph::Plain<P>,
+    animated_state: AnimatedState<Status, AnimatedPickListStyle>,
+    /// How far the menu is open, from `0.0` when closed to `1.0` when open.
+    progress: Animated<f32>,
+    /// The position of the highlight in the menu, measured in options from the top.
+    highlight: Animated<f32>,
+}
+
+impl<P: Paragraph> State<P> {
+    fn new(mode: Mode) -> Self {
+        Self {
+            menu: Tree::empty(),
+            keyboard_modifiers: keyboard::Modifiers::default(),
+            is_open: false,
+            hovered_option: None,
+            options: Vec::new(),
+            placeholder: paragraph::Plain::default(),
+            animated_state: AnimatedState::new(Status::Active, mode),
+            progress: Animated::new(0.0, mode),
+            highlight: Animated::new(0.0, mode),
+        }
+    }
+
+    /// Opens the menu with the highlight resting on the option at `index`, if any.
+    fn open(&mut self, index: Option<usize>) {
+        self.is_open = true;
+        self.hovered_option = index;
+        self.highlight.settle_at(index.unwrap_or_default() as f32);
+    }
+
+    /// Retargets the menu and its highlight after the menu was opened, closed, or hovered,
+    /// returning whether either of them is animating.
+    fn sync(&mut self) -> bool {
+        let progress = if self.is_open { 1.0 } else { 0.0 };
+        if self.progress.target() != &progress {
+            self.progress.set_target(progress);
+        }
+
+        if let Some(index) = self.hovered_option {
+            if self.highlight.target() != &(index as f32) {
+                self.highlight.set_target(index as f32);
+            }
+        }
+
+        self.progress.is_animating() || self.highlight.is_animating()
+    }
+
+    /// Whether the menu should be shown, which includes while it's closing.
+    fn is_menu_visible(&self) -> bool {
+        self.is_open || self.progress.is_animating()
+    }
+}
+
+impl<'a, T, L, V, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for PickList<'a, T, L, V, Message, Theme, Renderer>
+where
+    T: Clone + ToString + PartialEq + 'a,
+    L: Borrow<[T]>,
+    V: Borrow<T>,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::new(self.mode))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.animated_state.diff(self.mode);
+
+        if state.progress.mode() != self.mode {
+            state.progress.apply(self.mode);
+            state.highlight.apply(self.mode);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let options = self.options.borrow();
+
+        state.options.resize_with(options.len(), Default::default);
+
+        let option_text = Text {
+            content: "",
+            bounds: Size::new(
+                f32::INFINITY,
+                self.text_line_height.to_absolute(text_size).into(),
+            ),
+            size: text_size,
+            line_height: self.text_line_height,
+            font,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: self.text_shaping,
+            wrapping: core_text::Wrapping::default(),
+        };
+
+        for (option, paragraph) in options.iter().zip(state.options.iter_mut()) {
+            let label = option.to_string();
+            paragraph.update(Text {
+                content: &label,
+                ..option_text
+            });
+        }
+
+        if let Some(placeholder) = &self.placeholder {
+            state.placeholder.update(Text {
+                content: placeholder,
+                ..option_text
+            });
+        }
+
+        let max_width = match self.width {
+            Length::Shrink => {
+                let labels_width = state.options.iter().fold(0.0, |width, paragraph| {
+                    f32::max(width, paragraph.min_width())
+                });
+
+                labels_width.max(
+                    self.placeholder
+                        .as_ref()
+                        .map(|_| state.placeholder.min_width())
+                        .unwrap_or(0.0),
+                )
+            }
+            _ => 0.0,
+        };
+
+        let size = {
+            let intrinsic = Size::new(
+                max_width + text_size.0 + self.padding.left,
+                f32::from(self.text_line_height.to_absolute(text_size)),
+            );
+
+            limits
+                .width(self.width)
+                .shrink(self.padding)
+                .resolve(self.width, Length::Shrink, intrinsic)
+                .expand(self.padding)
+        };
+
+        layout::Node::new(size)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+            state.animated_state.resume_at(now);
+            state.progress.resume_at(now);
+            state.highlight.resume_at(now);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let event_status = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.animated_state.tick(now);
+                state.progress.tick(now);
+                state.highlight.tick(now);
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if state.is_open {
+                    // The menu didn't capture the press, so it was either outside the menu or
+                    // on the field, and either way the menu closes.
+                    state.is_open = false;
+
+                    if let Some(on_close) = &self.on_close {
+                        shell.publish(on_close.clone());
+                    }
+
+                    event::Status::Captured
+                } else if cursor.is_over(layout.bounds()) {
+                    let selected = self.selected.as_ref().map(Borrow::borrow);
+                    let index = self
+                        .options
+                        .borrow()
+                        .iter()
+                        .position(|option| Some(option) == selected);
+                    state.open(index);
+
+                    if let Some(on_open) = &self.on_open {
+                        shell.publish(on_open.clone());
+                    }
+
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { y, .. },
+            }) => {
+                if state.keyboard_modifiers.command()
+                    && cursor.is_over(layout.bounds())
+                    && !state.is_open
+                {
+                    fn find_next<'a, T: PartialEq>(
+                        selected: &'a T,
+                        mut options: impl Iterator<Item = &'a T>,
+                    ) -> Option<&'a T> {
+                        let _ = options.find(|&option| option == selected);
+                        options.next()
+                    }
+
+                    let options = self.options.borrow();
+                    let selected = self.selected.as_ref().map(Borrow::borrow);
+
+                    let next_option = if y < 0.0 {
+                        match selected {
+                            Some(selected) => find_next(selected, options.iter()),
+                            None => options.first(),
+                        }
+                    } else if y > 0.0 {
+                        match selected {
+                            Some(selected) => find_next(selected, options.iter().rev()),
+                            None => options.last(),
+                        }
+                    } else {
+                        None
+                    };
+
+                    if let Some(next_option) = next_option {
+                        shell.publish((self.on_select)(next_option.clone()));
+                    }
+
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = modifiers;
+                event::Status::Ignored
+            }
+            _ => event::Status::Ignored,
+        };
+
+        // The menu may have been opened, closed, or hovered by this event or by the menu itself,
+        // so redraw anytime the status changes or the menu is still moving.
+        let is_animating = state.sync();
+        let status = self.get_status(state, cursor, layout);
+        if state.animated_state.needs_redraw(status) || is_animating {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        event_status
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let selected = self.selected.as_ref().map(Borrow::borrow);
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = state
+            .animated_state
+            .current_value(|status| Catalog::style(theme, &self.class, *status).into());
+
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let handle = match &self.handle {
+            Handle::Arrow { size } => Some((
+                Renderer::ICON_FONT,
+                Renderer::ARROW_DOWN_ICON,
+                *size,
+                core_text::LineHeight::default(),
+                core_text::Shaping::Basic,
+            )),
+            Handle::Static(icon) => Some((
+                icon.font,
+                icon.code_point,
+                icon.size,
+                icon.line_height,
+                icon.shaping,
+            )),
+            Handle::Dynamic { open, closed } => {
+                let icon = if state.is_open { open } else { closed };
+                Some((
+                    icon.font,
+                    icon.code_point,
+                    icon.size,
+                    icon.line_height,
+                    icon.shaping,
+                ))
+            }
+            Handle::None => None,
+        };
+
+        if let Some((font, code_point, size, line_height, shaping)) = handle {
+            let size = size.unwrap_or_else(|| renderer.default_size());
+
+            renderer.fill_text(
+                Text {
+                    content: code_point.to_string(),
+                    size,
+                    line_height,
+                    font,
+                    bounds: Size::new(bounds.width, f32::from(line_height.to_absolute(size))),
+                    horizontal_alignment: alignment::Horizontal::Right,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping,
+                    wrapping: core_text::Wrapping::default(),
+                },
+                Point::new(
+                    bounds.x + bounds.width - self.padding.right,
+                    bounds.center_y(),
+                ),
+                style.handle_color,
+                *viewport,
+            );
+        }
+
+        let label = selected.map(ToString::to_string);
+        if let Some(label) = label.or_else(|| self.placeholder.clone()) {
+            let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+            renderer.fill_text(
+                Text {
+                    content: label,
+                    size: text_size,
+                    line_height: self.text_line_height,
+                    font,
+                    bounds: Size::new(
+                        bounds.width - self.padding.horizontal(),
+                        f32::from(self.text_line_height.to_absolute(text_size)),
+                    ),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: self.text_shaping,
+                    wrapping: core_text::Wrapping::default(),
+                },
+                Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                if selected.is_some() {
+                    style.text_color
+                } else {
+                    style.placeholder_color
+                },
+                *viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        if !state.is_menu_visible() {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+        let list = List {
+            options: self.options.borrow(),
+            hovered_option: &mut state.hovered_option,
+            is_open: &mut state.is_open,
+            on_select: &*self.on_select,
+            on_close: self.on_close.as_ref(),
+            highlight: *state.highlight.value(),
+            opacity: state.progress.value().clamp(0.0, 1.0),
+            padding: self.padding,
+            text_size: self.text_size,
+            text_line_height: self.text_line_height,
+            text_shaping: self.text_shaping,
+            font: Some(self.font.unwrap_or_else(|| renderer.default_font())),
+            class: &self.menu_class,
+        };
+
+        Some(overlay::Element::new(Box::new(Menu::new(
+            list,
+            &mut state.menu,
+            layout.position() + translation,
+            bounds.width,
+            bounds.height,
+        ))))
+    }
+}
+
+impl<'a, T, L, V, Message, Theme, Renderer> From<PickList<'a, T, L, V, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Clone + ToString + PartialEq + 'a,
+    L: Borrow<[T]> + 'a,
+    V: Borrow<T> + 'a,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(pick_list: PickList<'a, T, L, V, Message, Theme, Renderer>) -> Self {
+        Self::new(pick_list)
+    }
+}
+
+/// Creates a new [`PickList`] with the given list of options, the current selected value, and
+/// the message to produce when an option is selected.
+pub fn pick_list<'a, T, L, V, Message, Theme, Renderer>(
+    options: L,
+    selected: Option<V>,
+    on_select: impl Fn(T) -> Message + 'a,
+) -> PickList<'a, T, L, V, Message, Theme, Renderer>
+where
+    T: ToString + PartialEq + Clone + 'a,
+    L: Borrow<[T]> + 'a,
+    V: Borrow<T> + 'a,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    PickList::new(options, selected, on_select)
+}
+
+/// Fades every color of a menu `style` by the given `opacity`.
+fn fade(style: menu::Style, opacity: f32) -> menu::Style {
+    menu::Style {
+        background: style.background.scale_alpha(opacity),
+        border: Border {
+            color: style.border.color.scale_alpha(opacity),
+            ..style.border
+        },
+        text_color: style.text_color.scale_alpha(opacity),
+        selected_text_color: style.selected_text_color.scale_alpha(opacity),
+        selected_background: style.selected_background.scale_alpha(opacity),
+    }
+}
+
+/// The menu overlay of a [`PickList`], which is drawn while it opens and closes.
+struct Menu<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    /// The position of the field the menu is anchored to.
+    position: Point,
+    tree: &'a mut Tree,
+    list: Scrollable<'a, Message, Theme, Renderer>,
+    width: f32,
+    target_height: f32,
+    class: &'a <Theme as menu::Catalog>::Class<'b>,
+    /// Whether the menu is open rather than closing.
+    is_open: bool,
+    /// How far the menu is open, from `0.0` when closed to `1.0` when open.
+    progress: f32,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> Menu<'a, 'b, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+    'b: 'a,
+{
+    fn new<T>(
+        list: List<'a, 'b, T, Message, Theme, Renderer>,
+        tree: &'a mut Tree,
+        position: Point,
+        width: f32,
+        target_height: f32,
+    ) -> Self
+    where
+        T: Clone + ToString,
+    {
+        let class = list.class;
+        let is_open = *list.is_open;
+        let progress = list.opacity;
+        let list = Scrollable::new(list);
+        tree.diff(&list as &dyn Widget<_, _, _>);
+
+        Self {
+            position,
+            tree,
+            list,
+            width,
+            target_height,
+            class,
+            is_open,
+            progress,
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Menu<'_, '_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let space_below = bounds.height - (self.position.y + self.target_height);
+        let space_above = self.position.y;
+
+        let limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(bounds.width - self.position.x, space_below.max(space_above)),
+        )
+        .width(self.width);
+
+        let node = self.list.layout(self.tree, renderer, &limits);
+        let size = node.size();
+
+        node.move_to(if space_below > space_above {
+            self.position + Vector::new(0.0, self.target_height)
+        } else {
+            self.position - Vector::new(0.0, size.height)
+        })
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        // A closing menu is only drawn until it's gone.
+        if !self.is_open {
+            return event::Status::Ignored;
+        }
+
+        let bounds = layout.bounds();
+        self.list.on_event(
+            self.tree, event, layout, cursor, renderer, clipboard, shell, &bounds,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !self.is_open {
+            return mouse::Interaction::default();
+        }
+
+        self.list
+            .mouse_interaction(self.tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let bounds = layout.bounds();
+        let style = fade(menu::Catalog::style(theme, self.class), self.progress);
+
+        let draw_menu = |renderer: &mut Renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    ..renderer::Quad::default()
+                },
+                style.background,
+            );
+
+            self.list.draw(
+                self.tree, renderer, theme, defaults, layout, cursor, &bounds,
+            );
+        };
+
+        if self.progress >= 1.0 {
+            draw_menu(renderer);
+            return;
+        }
+
+        // The menu grows from the edge touching the field, whether it's placed below or above.
+        let anchor = if bounds.y >= self.position.y {
+            Point::new(bounds.center_x(), bounds.y)
+        } else {
+            Point::new(bounds.center_x(), bounds.y + bounds.height)
+        };
+        let scale = CLOSED_SCALE + (1.0 - CLOSED_SCALE) * self.progress;
+        let transformation = Transformation::translate(anchor.x, anchor.y)
+            * Transformation::scale(scale)
+            * Transformation::translate(-anchor.x, -anchor.y);
+
+        renderer.with_transformation(transformation, draw_menu);
+    }
+}
+
+/// The list of options inside a [`Menu`].
+struct List<'a, 'b, T, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    options: &'a [T],
+    hovered_option: &'a mut Option<usize>,
+    is_open: &'a mut bool,
+    on_select: &'a dyn Fn(T) -> Message,
+    on_close: Option<&'a Message>,
+    /// The animated position of the highlight, measured in options from the top.
+    highlight: f32,
+    /// The opacity of the list as the menu opens and closes.
+    opacity: f32,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    text_line_height: core_text::LineHeight,
+    text_shaping: core_text::Shaping,
+    font: Option<Renderer::Font>,
+    class: &'a <Theme as menu::Catalog>::Class<'b>,
+}
+
+impl<T, Message, Theme, Renderer> List<'_, '_, T, Message, Theme, Renderer>
+where
+    T: Clone,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    /// The height of each option in the list.
+    fn option_height(&self, renderer: &Renderer) -> f32 {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        f32::from(self.text_line_height.to_absolute(text_size)) + self.padding.vertical()
+    }
+
+    /// Closes the menu by picking the option at `index`, if it exists.
+    fn select(&mut self, index: usize, shell: &mut Shell<'_, Message>) -> event::Status {
+        let Some(option) = self.options.get(index) else {
+            return event::Status::Ignored;
+        };
+
+        *self.is_open = false;
+        shell.publish((self.on_select)(option.clone()));
+        event::Status::Captured
+    }
+
+    /// Handles keyboard navigation within the open menu.
+    fn on_key_pressed(
+        &mut self,
+        key: keyboard::Key,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let last = self.options.len().checked_sub(1);
+        match key.as_ref() {
+            keyboard::Key::Named(keyboard::key::Named::ArrowDown) => {
+                *self.hovered_option = match *self.hovered_option {
+                    Some(index) => last.map(|last| (index + 1).min(last)),
+                    None => last.map(|_| 0),
+                };
+                event::Status::Captured
+            }
+            keyboard::Key::Named(keyboard::key::Named::ArrowUp) => {
+                *self.hovered_option = match *self.hovered_option {
+                    Some(index) => Some(index.saturating_sub(1)),
+                    None => last,
+                };
+                event::Status::Captured
+            }
+            keyboard::Key::Named(keyboard::key::Named::Enter) => match *self.hovered_option {
+                Some(index) => self.select(index, shell),
+                None => event::Status::Ignored,
+            },
+            keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                *self.is_open = false;
+                if let Some(on_close) = self.on_close {
+                    shell.publish(on_close.clone());
+                }
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for List<'_, '_, T, Message, Theme, Renderer>
+where
+    T: Clone + ToString,
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Fill,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let intrinsic = Size::new(
+            0.0,
+            self.option_height(renderer) * self.options.len() as f32,
+        );
+
+        layout::Node::new(limits.resolve(Length::Fill, Length::Shrink, intrinsic))
+    }
+
+    fn on_event(
+        &mut self,
+        _tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if cursor.is_over(layout.bounds()) =>
+            {
+                if let Some(index) = *self.hovered_option {
+                    return self.select(index, shell);
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(position) = cursor.position_in(layout.bounds()) {
+                    let index = (position.y / self.option_height(renderer)) as usize;
+                    *self.hovered_option = Some(index);
+                }
+            }
+            Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_in(layout.bounds()) {
+                    let index = (position.y / self.option_height(renderer)) as usize;
+                    *self.hovered_option = Some(index);
+                    return self.select(index, shell);
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => {
+                return self.on_key_pressed(key, shell);
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let style = fade(menu::Catalog::style(theme, self.class), self.opacity);
+        let bounds = layout.bounds();
+
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let option_height = self.option_height(renderer);
+
+        if self.hovered_option.is_some() {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + style.border.width,
+                        y: bounds.y + option_height * self.highlight,
+                        width: bounds.width - style.border.width * 2.0,
+                        height: option_height,
+                    },
+                    border: iced::border::rounded(style.border.radius),
+                    ..renderer::Quad::default()
+                },
+                style.selected_background,
+            );
+        }
+
+        let offset = viewport.y - bounds.y;
+        let start = (offset / option_height) as usize;
+        let end = ((offset + viewport.height) / option_height).ceil() as usize;
+        let visible_options =
+            &self.options[start.min(self.options.len())..end.min(self.options.len())];
+
+        for (i, option) in visible_options.iter().enumerate() {
+            let i = start + i;
+            let bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + (option_height * i as f32),
+                width: bounds.width,
+                height: option_height,
+            };
+
+            // Options take on the selected text color as the highlight passes over them.
+            let coverage = match self.hovered_option {
+                Some(_) => (1.0 - (i as f32 - self.highlight).abs()).clamp(0.0, 1.0),
+                None => 0.0,
+            };
+            let mut color = style.text_color;
+            color.lerp(&style.text_color, &style.selected_text_color, coverage);
+
+            renderer.fill_text(
+                Text {
+                    content: option.to_string(),
+                    bounds: Size::new(f32::INFINITY, bounds.height),
+                    size: text_size,
+                    line_height: self.text_line_height,
+                    font: self.font.unwrap_or_else(|| renderer.default_font()),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: self.text_shaping,
+                    wrapping: core_text::Wrapping::default(),
+                },
+                Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                color,
+                *viewport,
+            );
+        }
+    }
+}
+
+impl<'a, 'b, T, Message, Theme, Renderer> From<List<'a, 'b, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: Clone + ToString,
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+    'b: 'a,
+{
+    fn from(list: List<'a, 'b, T, Message, Theme, Renderer>) -> Self {
+        Element::new(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transition::Easing;
+    use std::time::{Duration, Instant};
+
+    fn state() -> State<()> {
+        State::new(Easing::LINEAR.into())
+    }
+
+    fn done() -> Instant {
+        Instant::now() + crate::animated::DEFAULT_DURATION + Duration::from_millis(1)
+    }
+
+    /// The menu should stay visible while it closes, then be removed once it's gone.
+    #[test]
+    fn menu_closes_before_removal() {
+        let mut state = state();
+        assert!(!state.is_menu_visible());
+
+        state.open(Some(1));
+        assert!(state.sync());
+        state.progress.tick(done());
+        assert_eq!(*state.progress.value(), 1.0);
+
+        state.is_open = false;
+        assert!(state.sync());
+        assert!(state.is_menu_visible());
+
+        state
+            .progress
+            .tick(done() + crate::animated::DEFAULT_DURATION);
+        assert!(!state.is_menu_visible());
+    }
+
+    /// Opening the menu places the highlight on the selected option without animating, while
+    /// hovering another option glides the highlight there.
+    #[test]
+    fn highlight_glides_between_options() {
+        let mut state = state();
+        state.open(Some(2));
+        state.sync();
+        assert_eq!(*state.highlight.value(), 2.0);
+        assert!(!state.highlight.is_animating());
+
+        state.hovered_option = Some(0);
+        state.sync();
+        state
+            .highlight
+            .tick(Instant::now() + crate::animated::DEFAULT_DURATION / 2);
+        let highlight = *state.highlight.value();
+        assert!(highlight > 0.0 && highlight < 2.0);
+
+        state.highlight.tick(done());
+        assert_eq!(*state.highlight.value(), 0.0);
+    }
+
+    #[test]
+    fn fade_menu_style() {
+        let style = fade(menu::default(&iced::Theme::Light), 0.5);
+        let full = menu::default(&iced::Theme::Light);
+        assert_eq!(style.text_color.a, full.text_color.a * 0.5);
+        assert_eq!(style.border.color.a, full.border.color.a * 0.5);
+        assert_eq!(style.border.width, full.border.width);
+    }
+}
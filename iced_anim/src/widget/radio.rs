@@ -0,0 +1,469 @@
+//! An animated radio button whose dot scales in when it's selected.
+//!
+//! The circle animates between its selected and unselected styles like other animated widgets,
+//! while the dot grows from the center when the option is picked. Since every radio button in a
+//! group is rebuilt with the new selection, the previously selected option shrinks its dot back
+//! down at the same time.
+//!
+//! ```no_run
+//! # use iced::{widget::column, Element};
+//! # use iced_anim::{spring::Motion, widget::radio};
+//! # #[derive(Clone, Copy, PartialEq, Eq)] enum Size { Small, Large }
+//! # #[derive(Clone)] enum Message { Select(Size) }
+//! # fn view<'a>(selected: Option<Size>) -> Element<'a, Message> {
+//! column![
+//!     radio("Small", Size::Small, selected, Message::Select).animation(Motion::BOUNCY),
+//!     radio("Large", Size::Large, selected, Message::Select).animation(Motion::BOUNCY),
+//! ]
+//! .into()
+//! # }
+//! ```
+use crate::{animate::AnimatedRadioStyle, animated::Mode, Animated, AnimatedState};
+use iced::{
+    advanced::{
+        layout, renderer,
+        text::{self as core_text, Paragraph},
+        widget::{text as core_widget, tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event,
+    mouse::{self, Cursor},
+    touch, window, Border, Element, Event, Length, Pixels, Rectangle, Size,
+};
+
+// Re-export the widget types for convenience
+pub use iced::widget::radio::{default, Catalog, Status, Style, StyleFn};
+pub use iced::widget::text::{LineHeight, Shaping, Wrapping};
+
+/// The default size of the circle, in logical pixels.
+const DEFAULT_SIZE: f32 = 16.0;
+
+/// The default spacing between the circle and the label, in logical pixels.
+const DEFAULT_SPACING: f32 = 8.0;
+
+/// The dot isn't drawn once it has shrunk below this much of its full size.
+const MIN_DOT_SCALE: f32 = 0.01;
+
+/// A circular button representing one choice out of a group of options, whose dot scales in
+/// when it's selected.
+#[allow(missing_debug_implementations)]
+pub struct Radio<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: core_text::Renderer,
+    Theme: Catalog,
+{
+    is_selected: bool,
+    on_click: Message,
+    label: String,
+    width: Length,
+    size: f32,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    text_line_height: LineHeight,
+    text_shaping: Shaping,
+    text_wrapping: Wrapping,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+    mode: Mode,
+}
+
+impl<'a, Message, Theme, Renderer> Radio<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: core_text::Renderer,
+    Theme: Catalog,
+{
+    /// Creates a new [`Radio`] button for the given `value`, which is selected when it's equal
+    /// to the `selected` value. Clicking it produces the message returned by `f`.
+    pub fn new<F, V>(label: impl Into<String>, value: V, selected: Option<V>, f: F) -> Self
+    where
+        V: Eq + Copy,
+        F: FnOnce(V) -> Message,
+    {
+        Radio {
+            is_selected: Some(value) == selected,
+            on_click: f(value),
+            label: label.into(),
+            width: Length::Shrink,
+            size: DEFAULT_SIZE,
+            spacing: DEFAULT_SPACING,
+            text_size: None,
+            text_line_height: LineHeight::default(),
+            text_shaping: Shaping::default(),
+            text_wrapping: Wrapping::default(),
+            font: None,
+            class: Theme::default(),
+            mode: Mode::default(),
+        }
+    }
+
+    /// Sets the size of the circle of the [`Radio`] button.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the width of the [`Radio`] button.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the spacing between the circle and the label of the [`Radio`] button.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the label of the [`Radio`] button.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the label of the [`Radio`] button.
+    pub fn text_line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`Shaping`] strategy of the label of the [`Radio`] button.
+    pub fn text_shaping(mut self, shaping: Shaping) -> Self {
+        self.text_shaping = shaping;
+        self
+    }
+
+    /// Sets the [`Wrapping`] strategy of the label of the [`Radio`] button.
+    pub fn text_wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.text_wrapping = wrapping;
+        self
+    }
+
+    /// Sets the font of the label of the [`Radio`] button.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Radio`] button.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Radio`] button.
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the animation of the [`Radio`] button, used for both its style and its dot.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+
+    /// The scale of the dot when the [`Radio`] button is settled.
+    fn dot_scale(&self) -> f32 {
+        if self.is_selected {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Gets the status of the [`Radio`] button based on the cursor position.
+    fn get_status(&self, cursor: Cursor, layout: Layout<'_>) -> Status {
+        let is_selected = self.is_selected;
+        if cursor.is_over(layout.bounds()) {
+            Status::Hovered { is_selected }
+        } else {
+            Status::Active { is_selected }
+        }
+    }
+}
+
+struct State<P: Paragraph> {
+    paragraph: core_widget::State<P>,
+    animated_state: AnimatedState<Status, AnimatedRadioStyle>,
+    /// How far the dot has scaled in, from `0.0` when unselected to `1.0` when selected.
+    dot_scale: Animated<f32>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Radio<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: core_text::Renderer,
+    Theme: Catalog,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        let status = Status::Active {
+            is_selected: self.is_selected,
+        };
+
+        tree::State::new(State::<Renderer::Paragraph> {
+            paragraph: core_widget::State::default(),
+            animated_state: AnimatedState::new(status, self.mode),
+            dot_scale: Animated::new(self.dot_scale(), self.mode),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.animated_state.diff(self.mode);
+
+        if state.dot_scale.mode() != self.mode {
+            state.dot_scale.apply(self.mode);
+        }
+        // The selection can change from any radio button in the group, so the dot follows the
+        // selected flag rather than this button's clicks.
+        if state.dot_scale.target() != &self.dot_scale() {
+            state.dot_scale.set_target(self.dot_scale());
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::next_to_each_other(
+            &limits.width(self.width),
+            self.spacing,
+            |_| layout::Node::new(Size::new(self.size, self.size)),
+            |limits| {
+                let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+                core_widget::layout(
+                    &mut state.paragraph,
+                    renderer,
+                    limits,
+                    self.width,
+                    Length::Shrink,
+                    &self.label,
+                    self.text_line_height,
+                    self.text_size,
+                    self.font,
+                    alignment::Horizontal::Left,
+                    alignment::Vertical::Top,
+                    self.text_shaping,
+                    self.text_wrapping,
+                )
+            },
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+            state.animated_state.resume_at(now);
+            state.dot_scale.resume_at(now);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        // Redraw anytime the status changes or the dot is still scaling.
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let status = self.get_status(cursor, layout);
+        if state.animated_state.needs_redraw(status) || state.dot_scale.needs_tick() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.animated_state.tick(now);
+                state.dot_scale.tick(now);
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if cursor.is_over(layout.bounds()) =>
+            {
+                shell.publish(self.on_click.clone());
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = state
+            .animated_state
+            .current_value(|status| theme.style(&self.class, *status).into());
+        let mut children = layout.children();
+
+        let bounds = children.next().unwrap().bounds();
+        let size = bounds.width;
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    radius: (size / 2.0).into(),
+                    width: style.border_width,
+                    color: style.border_color,
+                },
+                ..Default::default()
+            },
+            style.background,
+        );
+
+        // The dot grows from the center, and springy animations can let it overshoot slightly.
+        let scale = *state.dot_scale.value();
+        if scale > MIN_DOT_SCALE {
+            let dot_size = size / 2.0 * scale;
+            let center = bounds.center();
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: center.x - dot_size / 2.0,
+                        y: center.y - dot_size / 2.0,
+                        width: dot_size,
+                        height: dot_size,
+                    },
+                    border: Border {
+                        radius: (dot_size / 2.0).into(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                style.dot_color,
+            );
+        }
+
+        core_widget::draw(
+            renderer,
+            renderer_style,
+            children.next().unwrap(),
+            state.paragraph.0.raw(),
+            iced::widget::text::Style {
+                color: style.text_color,
+            },
+            viewport,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Radio<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a + Clone,
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(radio: Radio<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(radio)
+    }
+}
+
+/// Creates a new [`Radio`] button for the given `value`, which is selected when it's equal to
+/// the `selected` value.
+pub fn radio<'a, Message, Theme, Renderer, V>(
+    label: impl Into<String>,
+    value: V,
+    selected: Option<V>,
+    on_click: impl FnOnce(V) -> Message,
+) -> Radio<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+    V: Eq + Copy,
+{
+    Radio::new(label, value, selected, on_click)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spring::Motion;
+    use std::time::{Duration, Instant};
+
+    fn option(value: u8, selected: u8) -> Radio<'static, u8, iced::Theme, ()> {
+        Radio::new("Option", value, Some(selected), |value| value).animation(Motion::SMOOTH)
+    }
+
+    fn dot_scale(tree: &Tree) -> f32 {
+        *tree.state.downcast_ref::<State<()>>().dot_scale.value()
+    }
+
+    /// Picking another option should scale its dot in while the old option's dot scales out.
+    #[test]
+    fn selection_moves_between_options() {
+        let mut first = Tree::new(&option(1, 1) as &dyn Widget<u8, iced::Theme, ()>);
+        let mut second = Tree::new(&option(2, 1) as &dyn Widget<u8, iced::Theme, ()>);
+        assert_eq!((dot_scale(&first), dot_scale(&second)), (1.0, 0.0));
+
+        first.diff(&option(1, 2) as &dyn Widget<u8, iced::Theme, ()>);
+        second.diff(&option(2, 2) as &dyn Widget<u8, iced::Theme, ()>);
+
+        let start = Instant::now();
+        for ms in [0, 100] {
+            for tree in [&mut first, &mut second] {
+                let state = tree.state.downcast_mut::<State<()>>();
+                state.dot_scale.tick(start + Duration::from_millis(ms));
+            }
+        }
+        assert!(dot_scale(&first) < 1.0 && dot_scale(&first) > 0.0);
+        assert!(dot_scale(&second) > 0.0 && dot_scale(&second) < 1.0);
+    }
+}
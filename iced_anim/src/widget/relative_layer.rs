@@ -0,0 +1,219 @@
+//! A layer that positions its content using relative coordinates.
+//!
+//! A [`RelativeLayer`] fills the space available to it and resolves a [`RelativePoint`] against
+//! its own bounds at layout time. Animating the relative position instead of an absolute offset
+//! keeps the content proportionally correct when the layer is resized mid-animation.
+//!
+//! The content can also be shifted by an `anchor` relative to its own size, e.g. an anchor of
+//! `(1.0, 0.0)` aligns the content's right edge with the position.
+//!
+//! ```rust
+//! # use iced::{Element, widget::text};
+//! # use iced_anim::{AnimationBuilder, RelativePoint, widget::relative_layer};
+//! # #[derive(Clone)]
+//! # enum Message {}
+//! # fn view<'a>(is_open: bool) -> Element<'a, Message> {
+//! let anchor = if is_open { RelativePoint::new(1.0, 0.0) } else { RelativePoint::ORIGIN };
+//! AnimationBuilder::new(anchor, |anchor| {
+//!     relative_layer(RelativePoint::new(1.0, 0.0), text("Panel"))
+//!         .anchor(anchor)
+//!         .into()
+//! })
+//! .animates_layout(true)
+//! .into()
+//! # }
+//! ```
+use crate::RelativePoint;
+use iced::{
+    advanced::{
+        graphics::core::event,
+        layout, mouse, overlay, renderer,
+        widget::{Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    Element, Event, Length, Rectangle, Size, Vector,
+};
+
+/// A layer that positions its content using relative coordinates.
+pub struct RelativeLayer<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// Where the content is positioned, relative to the size of this layer.
+    position: RelativePoint,
+    /// Which point of the content is placed at the `position`, relative to the content's size.
+    anchor: RelativePoint,
+    /// The content to position.
+    content: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> RelativeLayer<'a, Message, Theme, Renderer> {
+    /// Creates a new [`RelativeLayer`] that places its `content` at the given `position`.
+    pub fn new(
+        position: RelativePoint,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            position,
+            anchor: RelativePoint::ORIGIN,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the point of the content, relative to its own size, that is placed at the position.
+    pub fn anchor(mut self, anchor: RelativePoint) -> Self {
+        self.anchor = anchor;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RelativeLayer<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Fill)
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits.resolve(Length::Fill, Length::Fill, Size::ZERO);
+        let content = self.content.as_widget().layout(
+            &mut tree.children[0],
+            renderer,
+            &layout::Limits::new(Size::ZERO, size),
+        );
+
+        let position = self.position.resolve(size) - self.anchor.resolve_vector(content.size());
+        layout::Node::with_children(size, vec![content.move_to(position)])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        // Content positioned outside the layer is clipped to its bounds.
+        let bounds = layout.bounds();
+        let Some(viewport) = bounds.intersection(viewport) else {
+            return;
+        };
+
+        renderer.with_layer(bounds, |renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout.children().next().unwrap(),
+                cursor,
+                &viewport,
+            );
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<RelativeLayer<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(layer: RelativeLayer<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(layer)
+    }
+}
+
+/// Creates a new [`RelativeLayer`] that places its `content` at the given relative `position`.
+pub fn relative_layer<'a, Message, Theme, Renderer>(
+    position: RelativePoint,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> RelativeLayer<'a, Message, Theme, Renderer> {
+    RelativeLayer::new(position, content)
+}
@@ -0,0 +1,319 @@
+//! A container that keeps its content around long enough to play an exit animation.
+//!
+//! Conditionally including an element in your view makes it vanish instantly when it's removed.
+//! A [`RetainExit`] is always included in your view instead, and it keeps building its content
+//! with an [`ExitPhase`] after `visible` becomes `false` so the content can render its own exit
+//! styling. Once the exit completes, the container takes up no space until it's visible again.
+//!
+//! ```rust
+//! # use iced::{Element, widget::text};
+//! # use iced_anim::widget::retain_exit;
+//! # #[derive(Clone)]
+//! # enum Message {}
+//! # fn view<'a>(is_visible: bool) -> Element<'a, Message> {
+//! retain_exit("greeting", is_visible, |phase| {
+//!     text("Hello!")
+//!         .size(24.0 * phase.visibility())
+//!         .into()
+//! })
+//! .into()
+//! # }
+//! ```
+use crate::animated::Mode;
+use iced::{
+    advanced::{
+        graphics::core::event,
+        layout, mouse, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    window, Element, Event, Length, Rectangle, Size, Vector,
+};
+
+pub use super::retention::ExitPhase;
+use super::retention::Retention;
+
+/// A container that keeps building its content while it plays an exit animation.
+pub struct RetainExit<'a, Key, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// Identifies the retained content, resetting the retention when it changes.
+    key: Key,
+    /// Whether the content should currently be visible.
+    is_visible: bool,
+    /// Builds the content for the given phase.
+    builder: Box<dyn Fn(ExitPhase) -> Element<'a, Message, Theme, Renderer> + 'a>,
+    /// The phase used to build the cached content.
+    built_phase: ExitPhase,
+    /// The content built for the most recent phase.
+    content: Element<'a, Message, Theme, Renderer>,
+    /// The mode defining how to animate the exit.
+    mode: Mode,
+}
+
+impl<'a, Key, Message, Theme, Renderer> RetainExit<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq + 'static,
+    Renderer: iced::advanced::Renderer,
+{
+    /// Creates a new [`RetainExit`] for the given `key` and visibility.
+    pub fn new(
+        key: Key,
+        is_visible: bool,
+        builder: impl Fn(ExitPhase) -> Element<'a, Message, Theme, Renderer> + 'a,
+    ) -> Self {
+        // Hidden content may be mid-exit, in which case it'll be rebuilt when ticked.
+        let built_phase = if is_visible {
+            ExitPhase::Visible
+        } else {
+            ExitPhase::Exiting(0.0)
+        };
+
+        Self {
+            key,
+            is_visible,
+            content: builder(built_phase),
+            builder: Box::new(builder),
+            built_phase,
+            mode: Mode::default(),
+        }
+    }
+
+    /// Defines the way the exit will animate.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+
+    /// Rebuilds the content if the `phase` differs from the one it was built with.
+    fn rebuild(&mut self, phase: ExitPhase, tree: &mut Tree) {
+        if self.built_phase != phase {
+            self.built_phase = phase;
+            self.content = (self.builder)(phase);
+            tree.diff_children(std::slice::from_ref(&self.content));
+        }
+    }
+}
+
+struct State<Key> {
+    key: Key,
+    retention: Retention,
+    mode: Mode,
+}
+
+impl<'a, Key, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RetainExit<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq + 'static,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            key: self.key.clone(),
+            retention: Retention::new(self.is_visible, self.mode),
+            mode: self.mode,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Key>>();
+        if state.key != self.key {
+            // Different content is being retained, so start over without an exit.
+            *state = State {
+                key: self.key.clone(),
+                retention: Retention::new(self.is_visible, self.mode),
+                mode: self.mode,
+            };
+            tree.children = self.children();
+            return;
+        }
+
+        if state.mode != self.mode {
+            state.mode = self.mode;
+            state.retention.apply(self.mode);
+        }
+
+        state.retention.set_visible(self.is_visible);
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_ref::<State<Key>>();
+        if state.retention.is_released() {
+            return layout::Node::new(Size::ZERO);
+        }
+
+        let content = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State<Key>>();
+            state.retention.resume_at(now);
+        }
+
+        if let Some(content_layout) = layout.children().next() {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                content_layout,
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State<Key>>();
+            if state.retention.is_animating() {
+                state.retention.tick(now);
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+                shell.invalidate_layout();
+            }
+
+            let phase = state.retention.phase();
+            self.rebuild(phase, tree);
+        }
+
+        // Exiting content is no longer interactive.
+        let state = tree.state.downcast_ref::<State<Key>>();
+        if !self.is_visible || state.retention.is_released() {
+            return event::Status::Ignored;
+        }
+
+        let Some(content_layout) = layout.children().next() else {
+            return event::Status::Ignored;
+        };
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            content_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if let Some(content_layout) = layout.children().next() {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                content_layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match layout.children().next() {
+            Some(content_layout) if self.is_visible => self.content.as_widget().mouse_interaction(
+                &tree.children[0],
+                content_layout,
+                cursor,
+                viewport,
+                renderer,
+            ),
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let content_layout = layout.children().next()?;
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            content_layout,
+            renderer,
+            translation,
+        )
+    }
+}
+
+impl<'a, Key, Message, Theme, Renderer> From<RetainExit<'a, Key, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq + 'static,
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(retain_exit: RetainExit<'a, Key, Message, Theme, Renderer>) -> Self {
+        Self::new(retain_exit)
+    }
+}
+
+/// Creates a new [`RetainExit`] that keeps building its content while it exits.
+pub fn retain_exit<'a, Key, Message, Theme, Renderer>(
+    key: Key,
+    is_visible: bool,
+    builder: impl Fn(ExitPhase) -> Element<'a, Message, Theme, Renderer> + 'a,
+) -> RetainExit<'a, Key, Message, Theme, Renderer>
+where
+    Key: Clone + PartialEq + 'static,
+    Renderer: iced::advanced::Renderer,
+{
+    RetainExit::new(key, is_visible, builder)
+}
@@ -0,0 +1,161 @@
+//! Tracks whether some content should still be retained while it plays an exit animation.
+//!
+//! This is the shared mechanism behind widgets that keep building content after it's been hidden
+//! so it can animate out before being removed from the layout.
+use std::time::Instant;
+
+use crate::{animated::Mode, Animated};
+
+/// The phase of some retained content as it enters or exits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExitPhase {
+    /// The content is fully visible.
+    Visible,
+    /// The content is exiting, where the progress goes from `0.0` when the exit starts
+    /// to `1.0` when the exit completes.
+    ///
+    /// Reappearing content will move this progress back towards `0.0`.
+    Exiting(f32),
+}
+
+impl ExitPhase {
+    /// The exit progress of this phase, where `0.0` is fully visible and `1.0` is fully exited.
+    pub fn progress(&self) -> f32 {
+        match self {
+            ExitPhase::Visible => 0.0,
+            ExitPhase::Exiting(progress) => *progress,
+        }
+    }
+
+    /// A convenience for the remaining visibility, e.g. to use as an opacity.
+    pub fn visibility(&self) -> f32 {
+        1.0 - self.progress()
+    }
+}
+
+/// The retain → release lifecycle of some content with an exit animation.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Retention {
+    /// Whether the content has been requested to be visible.
+    is_visible: bool,
+    /// The exit progress, where `0.0` is fully visible and `1.0` is fully exited.
+    progress: Animated<f32>,
+}
+
+impl Retention {
+    /// Creates a new retention that is either visible or already released.
+    pub fn new(is_visible: bool, mode: impl Into<Mode>) -> Self {
+        let initial = if is_visible { 0.0 } else { 1.0 };
+        Self {
+            is_visible,
+            progress: Animated::new(initial, mode),
+        }
+    }
+
+    /// Changes whether the content should be visible, starting or interrupting the exit.
+    pub fn set_visible(&mut self, is_visible: bool) {
+        if self.is_visible != is_visible {
+            self.is_visible = is_visible;
+            self.progress.set_target(if is_visible { 0.0 } else { 1.0 });
+        }
+    }
+
+    /// Applies a potentially new animation mode to the exit progress.
+    pub fn apply(&mut self, mode: Mode) {
+        self.progress.apply(mode);
+    }
+
+    /// Updates the exit progress with the current time.
+    pub fn tick(&mut self, now: Instant) {
+        self.progress.tick(now);
+    }
+
+    /// Resumes the exit progress at `now` without advancing it by the paused time.
+    pub fn resume_at(&mut self, now: Instant) {
+        self.progress.resume_at(now);
+    }
+
+    /// Whether the exit progress is still animating.
+    pub fn is_animating(&self) -> bool {
+        self.progress.is_animating()
+    }
+
+    /// The current phase of the retained content.
+    pub fn phase(&self) -> ExitPhase {
+        let progress = *self.progress.value();
+        if self.is_visible && !self.is_animating() {
+            ExitPhase::Visible
+        } else {
+            ExitPhase::Exiting(progress.clamp(0.0, 1.0))
+        }
+    }
+
+    /// Whether the exit has completed and the content no longer needs to be retained.
+    pub fn is_released(&self) -> bool {
+        !self.is_visible && !self.is_animating()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{animated::DEFAULT_DURATION, transition::Easing};
+    use std::time::Duration;
+
+    fn done() -> Instant {
+        Instant::now() + DEFAULT_DURATION + Duration::from_millis(1)
+    }
+
+    /// Visible content starts fully visible and isn't released.
+    #[test]
+    fn new_visible() {
+        let retention = Retention::new(true, Easing::LINEAR);
+        assert_eq!(retention.phase(), ExitPhase::Visible);
+        assert!(!retention.is_released());
+    }
+
+    /// Hidden content starts released since there's nothing to exit.
+    #[test]
+    fn new_hidden_is_released() {
+        let retention = Retention::new(false, Easing::LINEAR);
+        assert!(retention.is_released());
+    }
+
+    /// Hiding content retains it while exiting, then releases it once the exit completes.
+    #[test]
+    fn retain_then_release() {
+        let mut retention = Retention::new(true, Easing::LINEAR);
+        retention.set_visible(false);
+        assert_eq!(retention.phase(), ExitPhase::Exiting(0.0));
+        assert!(!retention.is_released());
+
+        retention.tick(Instant::now() + DEFAULT_DURATION / 2);
+        let progress = retention.phase().progress();
+        assert!(progress > 0.0 && progress < 1.0);
+        assert!(!retention.is_released());
+
+        retention.tick(done());
+        assert!(retention.is_released());
+    }
+
+    /// Showing content mid-exit interrupts the exit and keeps it retained.
+    #[test]
+    fn interrupt_exit() {
+        let mut retention = Retention::new(true, Easing::LINEAR);
+        retention.set_visible(false);
+        retention.tick(Instant::now() + DEFAULT_DURATION / 2);
+
+        retention.set_visible(true);
+        assert!(!retention.is_released());
+        assert!(matches!(retention.phase(), ExitPhase::Exiting(_)));
+
+        retention.tick(done());
+        assert_eq!(retention.phase(), ExitPhase::Visible);
+    }
+
+    #[test]
+    fn phase_visibility() {
+        assert_eq!(ExitPhase::Visible.visibility(), 1.0);
+        assert_eq!(ExitPhase::Exiting(0.25).visibility(), 0.75);
+    }
+}
@@ -0,0 +1,191 @@
+//! Ripples that expand from a press and fade out, like the ink effect in Material design.
+//!
+//! Each ripple animates its radius until it covers the farthest corner of the pressed bounds
+//! while its opacity fades out, and is removed once it's no longer visible.
+use std::time::{Duration, Instant};
+
+use crate::{transition::Easing, Animated};
+use iced::{Point, Rectangle, Size, Vector};
+
+/// The most ripples that can be visible at once. Older ripples are removed first.
+pub(crate) const MAX_RIPPLES: usize = 8;
+
+/// How long it takes a ripple to expand to its full size.
+const EXPAND_DURATION: Duration = Duration::from_millis(400);
+
+/// How long it takes a ripple to fade out completely.
+const FADE_DURATION: Duration = Duration::from_millis(600);
+
+/// A single ripple expanding from the point where it was pressed.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Ripple {
+    /// Where the ripple started, relative to the top left of the pressed bounds.
+    offset: Vector,
+    /// The radius of the ripple.
+    radius: Animated<f32>,
+    /// The opacity of the ripple, from `1.0` when it's pressed to `0.0` when it's gone.
+    alpha: Animated<f32>,
+}
+
+impl Ripple {
+    /// Creates a new ripple pressed at `position` within `bounds` at the time `now`.
+    fn new(bounds: Rectangle, position: Point, now: Instant) -> Self {
+        let offset = position - bounds.position();
+        let mut radius = Animated::new(0.0, Easing::EASE_OUT.with_duration(EXPAND_DURATION))
+            .to(max_radius(bounds.size(), offset));
+        let mut alpha = Animated::new(1.0, Easing::EASE_IN.with_duration(FADE_DURATION)).to(0.0);
+        radius.resume_at(now);
+        alpha.resume_at(now);
+
+        Self {
+            offset,
+            radius,
+            alpha,
+        }
+    }
+
+    /// The circle covered by the ripple within the given `bounds`.
+    pub fn circle(&self, bounds: Rectangle) -> Rectangle {
+        let radius = *self.radius.value();
+        let center = bounds.position() + self.offset;
+        Rectangle::new(
+            Point::new(center.x - radius, center.y - radius),
+            Size::new(radius * 2.0, radius * 2.0),
+        )
+    }
+
+    /// The radius of the ripple.
+    pub fn radius(&self) -> f32 {
+        *self.radius.value()
+    }
+
+    /// The opacity of the ripple.
+    pub fn alpha(&self) -> f32 {
+        *self.alpha.value()
+    }
+}
+
+/// The radius a ripple needs to cover bounds of the given `size` from the `offset` it
+/// started at, which is the distance to the farthest corner.
+fn max_radius(size: Size, offset: Vector) -> f32 {
+    let dx = offset.x.max(size.width - offset.x);
+    let dy = offset.y.max(size.height - offset.y);
+    dx.hypot(dy)
+}
+
+/// The ripples of a single widget, which can overlap when pressed in quick succession.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Ripples {
+    ripples: Vec<Ripple>,
+}
+
+impl Ripples {
+    /// Starts a new ripple pressed at `position` within `bounds` at the time `now`.
+    pub fn spawn(&mut self, bounds: Rectangle, position: Point, now: Instant) {
+        if self.ripples.len() >= MAX_RIPPLES {
+            self.ripples.remove(0);
+        }
+        self.ripples.push(Ripple::new(bounds, position, now));
+    }
+
+    /// Whether there are any visible ripples that still need to be ticked.
+    pub fn is_animating(&self) -> bool {
+        !self.ripples.is_empty()
+    }
+
+    /// Returns the visible ripples, from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = &Ripple> {
+        self.ripples.iter()
+    }
+
+    /// Resumes each ripple at `now` without advancing it by the time since it was last ticked.
+    pub fn resume_at(&mut self, now: Instant) {
+        for ripple in &mut self.ripples {
+            ripple.radius.resume_at(now);
+            ripple.alpha.resume_at(now);
+        }
+    }
+
+    /// Advances each ripple to `now` and removes the ones that have faded out.
+    pub fn tick(&mut self, now: Instant) {
+        for ripple in &mut self.ripples {
+            ripple.radius.tick(now);
+            ripple.alpha.tick(now);
+        }
+        self.ripples.retain(|ripple| ripple.alpha.is_animating());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 100.0,
+        y: 50.0,
+        width: 80.0,
+        height: 30.0,
+    };
+
+    /// The ripple should reach the corner farthest from where it was pressed.
+    #[test]
+    fn radius_reaches_farthest_corner() {
+        let size = BOUNDS.size();
+        assert_eq!(
+            max_radius(size, Vector::new(0.0, 0.0)),
+            80.0_f32.hypot(30.0)
+        );
+        assert_eq!(
+            max_radius(size, Vector::new(60.0, 10.0)),
+            60.0_f32.hypot(20.0)
+        );
+        assert_eq!(
+            max_radius(size, Vector::new(40.0, 15.0)),
+            40.0_f32.hypot(15.0)
+        );
+    }
+
+    /// Ripples should expand from the press position and be removed once they fade out.
+    #[test]
+    fn ripple_lifecycle() {
+        let mut ripples = Ripples::default();
+        let now = Instant::now();
+        ripples.spawn(BOUNDS, Point::new(110.0, 60.0), now);
+        assert!(ripples.is_animating());
+
+        let ripple = ripples.iter().next().unwrap();
+        assert_eq!(ripple.radius(), 0.0);
+        assert_eq!(ripple.circle(BOUNDS).center(), Point::new(110.0, 60.0));
+
+        ripples.tick(now + EXPAND_DURATION / 2);
+        let ripple = ripples.iter().next().unwrap();
+        assert!(ripple.radius() > 0.0);
+        assert!(ripple.alpha() > 0.0 && ripple.alpha() < 1.0);
+
+        ripples.tick(now + FADE_DURATION + Duration::from_millis(1));
+        assert!(!ripples.is_animating());
+    }
+
+    /// Rapid presses should overlap, keeping only the newest ripples.
+    #[test]
+    fn overlapping_ripples_are_bounded() {
+        let mut ripples = Ripples::default();
+        let now = Instant::now();
+        for index in 0..MAX_RIPPLES + 2 {
+            let position = Point::new(BOUNDS.x + index as f32, BOUNDS.y);
+            ripples.spawn(
+                BOUNDS,
+                position,
+                now + Duration::from_millis(index as u64 * 10),
+            );
+        }
+
+        assert_eq!(ripples.iter().count(), MAX_RIPPLES);
+        let oldest = ripples.iter().next().unwrap();
+        assert_eq!(oldest.circle(BOUNDS).center().x, BOUNDS.x + 2.0);
+
+        // Older ripples finish first while the newer ones keep going.
+        ripples.tick(now + FADE_DURATION + Duration::from_millis(35));
+        assert_eq!(ripples.iter().count(), MAX_RIPPLES - 2);
+    }
+}
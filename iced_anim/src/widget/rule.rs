@@ -0,0 +1,361 @@
+//! Rules divide space horizontally or vertically, growing in when they appear.
+//!
+//! The line of a [`Rule`] grows from nothing to its full length when it's first created, and
+//! grows in or shrinks away whenever its [`visible`](Rule::visible) flag flips. Its color,
+//! thickness, and corner radius animate with theme changes.
+//!
+//! ```rust
+//! # use iced::{Alignment, Element, widget::{column, text}};
+//! # use iced_anim::{transition::Easing, widget::horizontal_rule};
+//! # fn view<'a, Message: 'a>(show_advanced: bool) -> Element<'a, Message> {
+//! column![
+//!     text("General"),
+//!     horizontal_rule(1)
+//!         .visible(show_advanced)
+//!         .grow_from(Alignment::Center)
+//!         .animation(Easing::EASE_OUT),
+//! ]
+//! .into()
+//! # }
+//! ```
+//!
+//! > Note: Iced's rule style can't be compared, so only its color, width, and radius animate.
+//! > Changes to the [`FillMode`] apply immediately.
+use crate::{animated::Mode, Animated, AnimatedState};
+use iced::{
+    advanced::{
+        graphics::core::event,
+        layout, mouse, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    border, window, Alignment, Color, Element, Event, Length, Pixels, Rectangle, Size,
+};
+
+// Re-export the widget types for convenience
+pub use iced::widget::rule::{Catalog, FillMode, Style, StyleFn};
+
+/// The animated parts of a rule's [`Style`]: its color, width, and corner radius.
+type Line = (Color, f32, border::Radius);
+
+/// Display a horizontal or vertical rule for dividing content, whose line grows in.
+#[allow(missing_debug_implementations)]
+pub struct Rule<'a, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    width: Length,
+    height: Length,
+    is_horizontal: bool,
+    is_visible: bool,
+    grow_from: Alignment,
+    class: Theme::Class<'a>,
+    mode: Mode,
+}
+
+#[derive(Debug)]
+struct State {
+    /// How much of the line is drawn, from 0 to 1.
+    growth: Animated<f32>,
+    /// The animated color, width, and radius of the line.
+    animated_state: AnimatedState<(), Line>,
+}
+
+impl<'a, Theme> Rule<'a, Theme>
+where
+    Theme: Catalog,
+{
+    /// Creates a horizontal [`Rule`] with the given height.
+    pub fn horizontal(height: impl Into<Pixels>) -> Self {
+        Self::new(Length::Fill, Length::Fixed(height.into().0), true)
+    }
+
+    /// Creates a vertical [`Rule`] with the given width.
+    pub fn vertical(width: impl Into<Pixels>) -> Self {
+        Self::new(Length::Fixed(width.into().0), Length::Fill, false)
+    }
+
+    fn new(width: Length, height: Length, is_horizontal: bool) -> Self {
+        Self {
+            width,
+            height,
+            is_horizontal,
+            is_visible: true,
+            grow_from: Alignment::Start,
+            class: Theme::default(),
+            mode: Mode::default(),
+        }
+    }
+
+    /// Sets whether the line of the [`Rule`] is visible. Changing it grows the line in or
+    /// shrinks it away, while the rule keeps taking up the same space.
+    pub fn visible(mut self, is_visible: bool) -> Self {
+        self.is_visible = is_visible;
+        self
+    }
+
+    /// Sets where the line grows from: the start, center, or end of the [`Rule`].
+    ///
+    /// Defaults to [`Alignment::Start`], which is the left of horizontal rules and the top of
+    /// vertical ones.
+    pub fn grow_from(mut self, alignment: impl Into<Alignment>) -> Self {
+        self.grow_from = alignment.into();
+        self
+    }
+
+    /// Sets the style of the [`Rule`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Rule`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the animation used to grow the line and change its style.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+
+    /// The growth the line animates towards.
+    fn target_growth(&self) -> f32 {
+        if self.is_visible {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Returns the offset and length of the part of a line at `offset` with the given `length` that's
+/// drawn at the given `growth`, when growing from the `alignment`.
+fn segment(offset: f32, length: f32, growth: f32, alignment: Alignment) -> (f32, f32) {
+    let grown = length * growth.clamp(0.0, 1.0);
+    let offset = match alignment {
+        Alignment::Start => offset,
+        Alignment::Center => offset + (length - grown) / 2.0,
+        Alignment::End => offset + length - grown,
+    };
+    (offset, grown)
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Rule<'a, Theme>
+where
+    Renderer: iced::advanced::Renderer,
+    Theme: Catalog,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        // New rules grow in from nothing.
+        let mut growth = Animated::new(0.0, self.mode);
+        growth.set_target(self.target_growth());
+
+        tree::State::new(State {
+            growth,
+            animated_state: AnimatedState::new((), self.mode),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.animated_state.diff(self.mode);
+        if state.growth.mode() != self.mode {
+            state.growth.apply(self.mode);
+        }
+        if state.growth.target() != &self.target_growth() {
+            state.growth.set_target(self.target_growth());
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State>();
+            state.growth.resume_at(now);
+            state.animated_state.resume_at(now);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.growth.tick(now);
+            state.animated_state.tick(now);
+        }
+
+        if state.animated_state.needs_redraw(()) || state.growth.needs_tick() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class);
+        let (color, width, radius) = *state
+            .animated_state
+            .current_value(|_| (style.color, f32::from(style.width), style.radius));
+
+        let bounds = layout.bounds();
+        let growth = *state.growth.value();
+        let bounds = if self.is_horizontal {
+            let (offset, length) = style.fill_mode.fill(bounds.width);
+            let (x, width_drawn) = segment(offset, length, growth, self.grow_from);
+            Rectangle {
+                x: bounds.x + x,
+                y: (bounds.center_y() - width / 2.0).round(),
+                width: width_drawn,
+                height: width,
+            }
+        } else {
+            let (offset, length) = style.fill_mode.fill(bounds.height);
+            let (y, height_drawn) = segment(offset, length, growth, self.grow_from);
+            Rectangle {
+                x: (bounds.center_x() - width / 2.0).round(),
+                y: bounds.y + y,
+                width,
+                height: height_drawn,
+            }
+        };
+
+        if bounds.width <= 0.0 || bounds.height <= 0.0 {
+            return;
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: border::rounded(radius),
+                ..renderer::Quad::default()
+            },
+            color,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Rule<'a, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(rule: Rule<'a, Theme>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(rule)
+    }
+}
+
+/// Creates a new horizontal [`Rule`] with the given height, whose line grows in.
+pub fn horizontal_rule<'a, Theme>(height: impl Into<Pixels>) -> Rule<'a, Theme>
+where
+    Theme: Catalog,
+{
+    Rule::horizontal(height)
+}
+
+/// Creates a new vertical [`Rule`] with the given width, whose line grows in.
+pub fn vertical_rule<'a, Theme>(width: impl Into<Pixels>) -> Rule<'a, Theme>
+where
+    Theme: Catalog,
+{
+    Rule::vertical(width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transition::Easing;
+    use std::time::{Duration, Instant};
+
+    fn rule(is_visible: bool) -> Rule<'static> {
+        horizontal_rule(2)
+            .visible(is_visible)
+            .animation(Easing::LINEAR)
+    }
+
+    fn state(tree: &mut Tree) -> &mut State {
+        tree.state.downcast_mut::<State>()
+    }
+
+    /// New rules should grow in, and hiding them should shrink the line back to nothing.
+    #[test]
+    fn grows_in_and_out() {
+        let mut tree = Tree::new(&rule(true) as &dyn Widget<(), iced::Theme, ()>);
+        let start = Instant::now();
+        let growth = &mut state(&mut tree).growth;
+        assert_eq!(*growth.value(), 0.0);
+        assert!(growth.is_animating());
+
+        for ms in (0..=100).map(|frame| frame * 16) {
+            growth.tick(start + Duration::from_millis(ms));
+        }
+        assert_eq!(*growth.value(), 1.0);
+
+        tree.diff(&rule(false) as &dyn Widget<(), iced::Theme, ()>);
+        let growth = &mut state(&mut tree).growth;
+        assert_eq!(growth.target(), &0.0);
+        growth.settle();
+        assert_eq!(*growth.value(), 0.0);
+    }
+
+    /// The drawn part of the line should stay anchored to where it grows from.
+    #[test]
+    fn segment_alignment() {
+        assert_eq!(segment(10.0, 100.0, 0.5, Alignment::Start), (10.0, 50.0));
+        assert_eq!(segment(10.0, 100.0, 0.5, Alignment::Center), (35.0, 50.0));
+        assert_eq!(segment(10.0, 100.0, 0.5, Alignment::End), (60.0, 50.0));
+        assert_eq!(segment(10.0, 100.0, 1.2, Alignment::End), (10.0, 100.0));
+    }
+}
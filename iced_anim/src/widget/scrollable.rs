@@ -0,0 +1,535 @@
+//! A wrapper around a scrollable that glides to offsets instead of jumping there.
+//!
+//! Iced's `scroll_to` operation moves a scrollable to its new offset instantly. Wrapping a
+//! scrollable in a [`Scrollable`] lets you return [`scroll_to`] from your `update` function
+//! instead, which animates the offset from wherever the scrollable currently is:
+//!
+//! ```no_run
+//! # use iced::{Element, Task, widget::{button, column, scrollable::{AbsoluteOffset, Id}}};
+//! # use iced_anim::{spring::Motion, widget::scrollable};
+//! # #[derive(Clone)] enum Message { JumpToBottom }
+//! # fn id() -> Id { Id::new("log") }
+//! fn view<'a>() -> Element<'a, Message> {
+//!     column![
+//!         button("Jump to bottom").on_press(Message::JumpToBottom),
+//!         scrollable(id(), iced::widget::scrollable(column![/* ... */]))
+//!             .animation(Motion::SMOOTH),
+//!     ]
+//!     .into()
+//! }
+//!
+//! fn update(message: Message) -> Task<Message> {
+//!     match message {
+//!         // Offsets past the end are clamped, so this glides all the way to the bottom.
+//!         Message::JumpToBottom => {
+//!             scrollable::scroll_to(id(), AbsoluteOffset { x: 0.0, y: f32::INFINITY })
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Scrolling with the mouse wheel or touch while the offset is animating cancels the animation,
+//! so the user is never fighting the scrollable for control.
+use std::any::Any;
+
+use crate::{animated::Mode, Animated};
+use iced::{
+    advanced::{
+        graphics::core::event,
+        layout, mouse, overlay, renderer,
+        widget::{self, operation, tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    touch, window, Element, Event, Length, Rectangle, Size, Task, Vector,
+};
+
+// Re-export the scrollable types for convenience
+pub use iced::widget::scrollable::{AbsoluteOffset, Catalog, Id};
+
+/// A wrapper around a scrollable that animates programmatic scrolling.
+pub struct Scrollable<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// The id shared by this wrapper and the scrollable inside it.
+    id: Id,
+    /// The scrollable whose offset is animated.
+    content: Element<'a, Message, Theme, Renderer>,
+    /// The animation used to move between offsets.
+    mode: Mode,
+}
+
+impl<'a, Message, Theme, Renderer> Scrollable<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    /// Creates a new [`Scrollable`] that animates the offset of the given `scrollable`.
+    ///
+    /// The `id` is given to the `scrollable`, so it's the same one passed to [`scroll_to`].
+    pub fn new(id: Id, scrollable: iced::widget::Scrollable<'a, Message, Theme, Renderer>) -> Self {
+        Self {
+            content: scrollable.id(id.clone()).into(),
+            id,
+            mode: Mode::default(),
+        }
+    }
+
+    /// Sets the animation used to move between offsets.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+}
+
+impl<Message, Theme, Renderer> Scrollable<'_, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    /// Reads the current offset and its bounds from the scrollable inside this wrapper.
+    fn read(&self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer) -> Option<Viewport> {
+        let mut read = Read {
+            id: self.id.clone().into(),
+            viewport: None,
+        };
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, &mut read);
+        read.viewport
+    }
+
+    /// Moves the scrollable inside this wrapper to the given `offset`.
+    fn apply(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        offset: AbsoluteOffset,
+    ) {
+        let mut scroll_to = operation::scrollable::scroll_to(self.id.clone().into(), offset);
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, &mut scroll_to);
+    }
+}
+
+/// The offset of a scrollable along with the bounds that limit it.
+#[derive(Debug, Clone, Copy)]
+struct Viewport {
+    offset: AbsoluteOffset,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+}
+
+impl Viewport {
+    /// Limits the `offset` to the range the scrollable can actually reach.
+    fn clamp(&self, offset: AbsoluteOffset) -> AbsoluteOffset {
+        let max_x = (self.content_bounds.width - self.bounds.width).max(0.0);
+        let max_y = (self.content_bounds.height - self.bounds.height).max(0.0);
+        AbsoluteOffset {
+            x: offset.x.clamp(0.0, max_x),
+            y: offset.y.clamp(0.0, max_y),
+        }
+    }
+}
+
+/// An operation that reads the [`Viewport`] of the scrollable with the given id.
+struct Read {
+    id: widget::Id,
+    viewport: Option<Viewport>,
+}
+
+impl Operation for Read {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn operation::Scrollable,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        translation: Vector,
+    ) {
+        if id == Some(&self.id) {
+            self.viewport = Some(Viewport {
+                offset: AbsoluteOffset {
+                    x: translation.x,
+                    y: translation.y,
+                },
+                bounds,
+                content_bounds,
+            });
+        }
+    }
+}
+
+/// The state a [`Scrollable`] exposes to find out whether it's being asked to scroll.
+#[derive(Debug, Default)]
+struct Request(Option<AbsoluteOffset>);
+
+/// An operation that asks the [`Scrollable`] with the given id to glide to an offset.
+struct ScrollTo {
+    id: widget::Id,
+    offset: AbsoluteOffset,
+}
+
+impl<T> Operation<T> for ScrollTo {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+        if id == Some(&self.id) {
+            if let Some(request) = state.downcast_mut::<Request>() {
+                request.0 = Some(self.offset);
+            }
+        }
+    }
+}
+
+/// Produces a [`Task`] that glides the [`Scrollable`] with the given [`Id`] to the `offset`.
+///
+/// The offset is clamped to the scrollable's content, so [`f32::INFINITY`] scrolls to the end.
+pub fn scroll_to<T>(id: Id, offset: AbsoluteOffset) -> Task<T>
+where
+    T: Send + 'static,
+{
+    iced::advanced::widget::operate(ScrollTo {
+        id: id.into(),
+        offset,
+    })
+}
+
+struct State {
+    /// The offset being animated towards, if the scrollable is being scrolled programmatically.
+    offset: Option<Animated<AbsoluteOffset>>,
+    mode: Mode,
+}
+
+impl State {
+    /// Glides from the current `viewport` towards the `target`, continuing any animation that's
+    /// already in progress.
+    fn scroll_to(&mut self, viewport: Viewport, target: AbsoluteOffset) {
+        let target = viewport.clamp(target);
+        match &mut self.offset {
+            Some(offset) => offset.set_target(target),
+            None => self.offset = Some(Animated::new(viewport.offset, self.mode).to(target)),
+        }
+    }
+
+    /// Stops any programmatic scrolling, leaving the scrollable where it currently is.
+    fn cancel(&mut self) {
+        self.offset = None;
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Scrollable<'_, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            offset: None,
+            mode: self.mode,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        if state.mode != self.mode {
+            state.mode = self.mode;
+            if let Some(offset) = &mut state.offset {
+                offset.apply(self.mode);
+            }
+        }
+
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            if let Some(offset) = &mut tree.state.downcast_mut::<State>().offset {
+                offset.resume_at(now);
+            }
+        }
+
+        let mut request = Request::default();
+        operation.custom(&mut request, Some(&self.id.clone().into()));
+        if let Some(target) = request.0 {
+            if let Some(viewport) = self.read(tree, layout, renderer) {
+                tree.state
+                    .downcast_mut::<State>()
+                    .scroll_to(viewport, target);
+            }
+        }
+
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(offset) = &mut state.offset {
+                    offset.tick(now);
+                    let value = *offset.value();
+                    if !offset.is_animating() {
+                        state.offset = None;
+                    }
+                    self.apply(tree, layout, renderer, value);
+                }
+            }
+            // Scrolling by hand takes over from any programmatic scrolling.
+            Event::Mouse(mouse::Event::WheelScrolled { .. })
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if cursor.is_over(layout.bounds()) =>
+            {
+                state.cancel();
+            }
+            _ => {}
+        }
+
+        if tree.state.downcast_ref::<State>().offset.is_some() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Scrollable<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(scrollable: Scrollable<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(scrollable)
+    }
+}
+
+/// Creates a new [`Scrollable`] that animates the offset of the given `scrollable`.
+pub fn scrollable<'a, Message, Theme, Renderer>(
+    id: Id,
+    scrollable: iced::widget::Scrollable<'a, Message, Theme, Renderer>,
+) -> Scrollable<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    Scrollable::new(id, scrollable)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{animated::DEFAULT_DURATION, transition::Easing};
+    use iced::{Point, Size};
+    use std::time::{Duration, Instant};
+
+    const VIEWPORT: Viewport = Viewport {
+        offset: AbsoluteOffset { x: 0.0, y: 100.0 },
+        bounds: Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 200.0,
+            height: 300.0,
+        },
+        content_bounds: Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 200.0,
+            height: 1000.0,
+        },
+    };
+
+    fn state() -> State {
+        State {
+            offset: None,
+            mode: Easing::LINEAR.into(),
+        }
+    }
+
+    /// Offsets past the content should be clamped so the end of the content can be targeted.
+    #[test]
+    fn clamp_to_content() {
+        let end = VIEWPORT.clamp(AbsoluteOffset {
+            x: 50.0,
+            y: f32::INFINITY,
+        });
+        assert_eq!(end, AbsoluteOffset { x: 0.0, y: 700.0 });
+
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(200.0, 300.0));
+        let short = Viewport {
+            content_bounds: bounds,
+            bounds,
+            ..VIEWPORT
+        };
+        assert_eq!(short.clamp(AbsoluteOffset { x: 0.0, y: 40.0 }).y, 0.0);
+    }
+
+    /// Scrolling should start from the current offset rather than jumping.
+    #[test]
+    fn glide_from_current_offset() {
+        let mut state = state();
+        state.scroll_to(VIEWPORT, AbsoluteOffset { x: 0.0, y: 500.0 });
+
+        let offset = state.offset.as_mut().unwrap();
+        assert_eq!(offset.value().y, 100.0);
+        offset.tick(Instant::now() + DEFAULT_DURATION / 2);
+        assert!(offset.value().y > 100.0 && offset.value().y < 500.0);
+
+        offset.tick(Instant::now() + DEFAULT_DURATION + Duration::from_millis(1));
+        assert_eq!(offset.value().y, 500.0);
+    }
+
+    /// Asking to scroll again mid-animation should retarget the same animation.
+    #[test]
+    fn retarget_mid_animation() {
+        let mut state = state();
+        state.scroll_to(VIEWPORT, AbsoluteOffset { x: 0.0, y: 500.0 });
+        state
+            .offset
+            .as_mut()
+            .unwrap()
+            .tick(Instant::now() + DEFAULT_DURATION / 2);
+        let halfway = *state.offset.as_ref().unwrap().value();
+
+        state.scroll_to(VIEWPORT, AbsoluteOffset { x: 0.0, y: 0.0 });
+        let offset = state.offset.as_ref().unwrap();
+        assert_eq!(*offset.value(), halfway);
+        assert_eq!(offset.target().y, 0.0);
+
+        state.cancel();
+        assert!(state.offset.is_none());
+    }
+
+    #[test]
+    fn scroll_to_request() {
+        let id = Id::new("list");
+        let mut scroll_to = ScrollTo {
+            id: id.clone().into(),
+            offset: AbsoluteOffset { x: 0.0, y: 20.0 },
+        };
+
+        let mut request = Request::default();
+        Operation::<()>::custom(&mut scroll_to, &mut request, Some(&Id::new("other").into()));
+        assert_eq!(request.0, None);
+
+        Operation::<()>::custom(&mut scroll_to, &mut request, Some(&id.into()));
+        assert_eq!(request.0, Some(AbsoluteOffset { x: 0.0, y: 20.0 }));
+    }
+}
@@ -0,0 +1,580 @@
+//! An animated slider whose handle glides to values that change without dragging.
+//!
+//! The rail and handle animate between their styles as the slider is hovered and dragged, while
+//! the handle glides to new values from clicks on the rail, the keyboard, the scroll wheel, or the
+//! app itself. Dragging the handle always follows the cursor exactly, without any animation:
+//!
+//! ```no_run
+//! # use iced::Element;
+//! # use iced_anim::{spring::Motion, widget::slider};
+//! # #[derive(Clone)] enum Message { Volume(f32) }
+//! # fn view<'a>(volume: f32) -> Element<'a, Message> {
+//! slider(0.0..=100.0, volume, Message::Volume)
+//!     .animation(Motion::SNAPPY)
+//!     .into()
+//! # }
+//! ```
+use std::ops::RangeInclusive;
+
+use crate::{animate::AnimatedSliderStyle, animated::Mode, Animated, AnimatedState};
+use iced::{
+    advanced::{
+        layout, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    keyboard::{self, key},
+    mouse::{self, Cursor},
+    touch, window, Border, Element, Event, Length, Pixels, Point, Rectangle, Size,
+};
+
+// Re-export the widget types for convenience
+pub use iced::widget::slider::{
+    default, Catalog, Handle, HandleShape, Rail, Status, Style, StyleFn,
+};
+
+/// The default height of the slider, in logical pixels.
+const DEFAULT_HEIGHT: f32 = 16.0;
+
+/// A horizontal bar with a handle that selects a value from a range, whose handle glides to
+/// values that change without dragging.
+#[allow(missing_debug_implementations)]
+pub struct Slider<'a, Message, Theme = iced::Theme>
+where
+    Theme: Catalog,
+{
+    range: RangeInclusive<f32>,
+    step: f32,
+    shift_step: Option<f32>,
+    value: f32,
+    default: Option<f32>,
+    on_change: Box<dyn Fn(f32) -> Message + 'a>,
+    on_release: Option<Message>,
+    width: Length,
+    height: f32,
+    class: Theme::Class<'a>,
+    mode: Mode,
+    is_gliding: bool,
+}
+
+impl<'a, Message, Theme> Slider<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+{
+    /// Creates a new [`Slider`] for the given `value` within the `range`, which produces the
+    /// message returned by `on_change` whenever the value changes.
+    ///
+    /// The value is clamped to the range, and the step defaults to `1.0`.
+    pub fn new(
+        range: RangeInclusive<f32>,
+        value: f32,
+        on_change: impl Fn(f32) -> Message + 'a,
+    ) -> Self {
+        let value = value.clamp(*range.start(), *range.end());
+
+        Slider {
+            range,
+            step: 1.0,
+            shift_step: None,
+            value,
+            default: None,
+            on_change: Box::new(on_change),
+            on_release: None,
+            width: Length::Fill,
+            height: DEFAULT_HEIGHT,
+            class: Theme::default(),
+            mode: Mode::default(),
+            is_gliding: true,
+        }
+    }
+
+    /// Sets the value that the [`Slider`] resets to when it's clicked while holding the
+    /// command key.
+    pub fn default(mut self, default: impl Into<f32>) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+
+    /// Sets the message produced when the mouse or finger is released after dragging.
+    ///
+    /// This is useful to apply a change only once the user has settled on a value.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the width of the [`Slider`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Slider`].
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = height.into().0;
+        self
+    }
+
+    /// Sets the step size of the [`Slider`].
+    pub fn step(mut self, step: impl Into<f32>) -> Self {
+        self.step = step.into();
+        self
+    }
+
+    /// Sets the step size of the [`Slider`] while the shift key is held.
+    pub fn shift_step(mut self, shift_step: impl Into<f32>) -> Self {
+        self.shift_step = Some(shift_step.into());
+        self
+    }
+
+    /// Sets the style of the [`Slider`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Slider`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the animation of the [`Slider`], used for both its style and its handle.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+
+    /// Sets whether the handle glides to values that change without dragging, like clicking
+    /// on the rail. This is enabled by default, and the handle jumps to new values otherwise.
+    pub fn glide(mut self, glide: bool) -> Self {
+        self.is_gliding = glide;
+        self
+    }
+
+    /// The step to use for the given keyboard modifiers.
+    fn current_step(&self, modifiers: keyboard::Modifiers) -> f32 {
+        match self.shift_step {
+            Some(shift_step) if modifiers.shift() => shift_step,
+            _ => self.step,
+        }
+    }
+
+    /// Finds the value under the cursor, snapped to the nearest step.
+    fn locate(&self, position: Point, bounds: Rectangle, modifiers: keyboard::Modifiers) -> f32 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        if position.x <= bounds.x {
+            start
+        } else if position.x >= bounds.x + bounds.width {
+            end
+        } else {
+            let step = self.current_step(modifiers);
+            let percent = (position.x - bounds.x) / bounds.width;
+            let steps = (percent * (end - start) / step).round();
+            (steps * step + start).min(end)
+        }
+    }
+
+    /// Moves the value by a number of steps, snapped to the step grid and clamped to the range.
+    fn offset(&self, steps: f32, modifiers: keyboard::Modifiers) -> f32 {
+        let step = self.current_step(modifiers);
+        let value = step * ((self.value / step).round() + steps);
+        value.clamp(*self.range.start(), *self.range.end())
+    }
+
+    /// Publishes a change if the value is different from the current one.
+    fn change(&mut self, value: f32, shell: &mut Shell<'_, Message>) {
+        if (self.value - value).abs() > f32::EPSILON {
+            shell.publish((self.on_change)(value));
+            self.value = value;
+        }
+    }
+
+    /// Gets the status of the [`Slider`] based on the current [`State`].
+    fn get_status(&self, state: &State, cursor: Cursor, layout: Layout<'_>) -> Status {
+        if state.drag.is_some() {
+            Status::Dragged
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+}
+
+/// How the user is currently interacting with the handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Drag {
+    /// The rail was pressed, so the handle glides to the pressed value.
+    Pressed,
+    /// The handle is being dragged, so it follows the cursor exactly.
+    Moving,
+}
+
+struct State {
+    drag: Option<Drag>,
+    modifiers: keyboard::Modifiers,
+    animated_state: AnimatedState<Status, AnimatedSliderStyle>,
+    /// The value shown by the handle, which glides towards the actual value.
+    handle: Animated<f32>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Slider<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            drag: None,
+            modifiers: keyboard::Modifiers::default(),
+            animated_state: AnimatedState::new(Status::Active, self.mode),
+            handle: Animated::new(self.value, self.mode),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.animated_state.diff(self.mode);
+
+        if state.handle.mode() != self.mode {
+            state.handle.apply(self.mode);
+        }
+        if state.handle.target() != &self.value {
+            // Dragging needs to follow the cursor without any lag.
+            if self.is_gliding && state.drag != Some(Drag::Moving) {
+                state.handle.set_target(self.value);
+            } else {
+                state.handle.settle_at(self.value);
+            }
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State>();
+            state.animated_state.resume_at(now);
+            state.handle.resume_at(now);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        // Redraw anytime the status changes or the handle is still gliding.
+        let state = tree.state.downcast_mut::<State>();
+        let status = self.get_status(state, cursor, layout);
+        if state.animated_state.needs_redraw(status) || state.handle.needs_tick() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        let bounds = layout.bounds();
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.animated_state.tick(now);
+                state.handle.tick(now);
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    let modifiers = state.modifiers;
+                    match self.default {
+                        Some(default) if modifiers.command() => {
+                            state.drag = None;
+                            self.change(default, shell);
+                        }
+                        _ => {
+                            state.drag = Some(Drag::Pressed);
+                            let value = self.locate(position, bounds, modifiers);
+                            self.change(value, shell);
+                        }
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if state.drag.take().is_some() =>
+            {
+                if let Some(on_release) = self.on_release.clone() {
+                    shell.publish(on_release);
+                }
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+                if state.drag.is_some() =>
+            {
+                if let Some(position) = cursor.position() {
+                    state.drag = Some(Drag::Moving);
+                    let value = self.locate(position, bounds, state.modifiers);
+                    self.change(value, shell);
+                }
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if state.modifiers.control() && cursor.is_over(bounds) =>
+            {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+                let value = self.offset(y.signum(), state.modifiers);
+                self.change(value, shell);
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) if cursor.is_over(bounds) => {
+                let steps = match key {
+                    keyboard::Key::Named(key::Named::ArrowUp | key::Named::ArrowRight) => 1.0,
+                    keyboard::Key::Named(key::Named::ArrowDown | key::Named::ArrowLeft) => -1.0,
+                    _ => return event::Status::Ignored,
+                };
+                let value = self.offset(steps, state.modifiers);
+                self.change(value, shell);
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        if state.drag.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let style = Style::from(
+            *state
+                .animated_state
+                .current_value(|status| theme.style(&self.class, *status).into()),
+        );
+        let bounds = layout.bounds();
+
+        let (handle_width, handle_height, handle_radius) = match style.handle.shape {
+            HandleShape::Circle { radius } => (radius * 2.0, radius * 2.0, radius.into()),
+            HandleShape::Rectangle {
+                width,
+                border_radius,
+            } => (f32::from(width), bounds.height, border_radius),
+        };
+
+        // Springy animations can overshoot the value, so keep the handle on the rail.
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let offset = if start >= end {
+            0.0
+        } else {
+            let progress = (state.handle.value() - start) / (end - start);
+            (bounds.width - handle_width) * progress.clamp(0.0, 1.0)
+        };
+
+        let rail_y = bounds.y + bounds.height / 2.0;
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x,
+                    y: rail_y - style.rail.width / 2.0,
+                    width: offset + handle_width / 2.0,
+                    height: style.rail.width,
+                },
+                border: style.rail.border,
+                ..Default::default()
+            },
+            style.rail.backgrounds.0,
+        );
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + offset + handle_width / 2.0,
+                    y: rail_y - style.rail.width / 2.0,
+                    width: bounds.width - offset - handle_width / 2.0,
+                    height: style.rail.width,
+                },
+                border: style.rail.border,
+                ..Default::default()
+            },
+            style.rail.backgrounds.1,
+        );
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + offset,
+                    y: rail_y - handle_height / 2.0,
+                    width: handle_width,
+                    height: handle_height,
+                },
+                border: Border {
+                    radius: handle_radius,
+                    width: style.handle.border_width,
+                    color: style.handle.border_color,
+                },
+                ..Default::default()
+            },
+            style.handle.background,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Slider<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(slider: Slider<'a, Message, Theme>) -> Self {
+        Element::new(slider)
+    }
+}
+
+/// Creates a new [`Slider`] for the given `value` within the `range`, which produces the
+/// message returned by `on_change` whenever the value changes.
+pub fn slider<'a, Message, Theme>(
+    range: RangeInclusive<f32>,
+    value: f32,
+    on_change: impl Fn(f32) -> Message + 'a,
+) -> Slider<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+{
+    Slider::new(range, value, on_change)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spring::Motion;
+    use iced::advanced::{clipboard, layout::Limits};
+
+    /// Sends an event with the cursor at `x` to a slider for `value`, returning the messages
+    /// that it published.
+    fn send(tree: &mut Tree, value: f32, event: Event, x: f32) -> Vec<f32> {
+        let mut element: Element<'_, f32, iced::Theme, ()> =
+            slider(0.0..=100.0, value, |value| value)
+                .animation(Motion::SMOOTH)
+                .into();
+        tree.diff(&element);
+        let node = element.as_widget().layout(
+            tree,
+            &(),
+            &Limits::new(Size::ZERO, Size::new(200.0, DEFAULT_HEIGHT)),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        element.as_widget_mut().on_event(
+            tree,
+            event,
+            Layout::new(&node),
+            Cursor::Available(Point::new(x, DEFAULT_HEIGHT / 2.0)),
+            &(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::with_size(Size::INFINITY),
+        );
+        messages
+    }
+
+    fn handle(tree: &Tree) -> &Animated<f32> {
+        &tree.state.downcast_ref::<State>().handle
+    }
+
+    /// Clicking the rail should glide the handle to the new value, but dragging should follow
+    /// the cursor exactly.
+    #[test]
+    fn handle_glides_on_click_but_not_drag() {
+        let press = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        let moved = Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::ORIGIN,
+        });
+        let release = Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left));
+        let mut tree = Tree::new(Element::<'_, f32, iced::Theme, ()>::from(slider(
+            0.0..=100.0,
+            0.0,
+            |value| value,
+        )));
+
+        assert_eq!(send(&mut tree, 0.0, press, 150.0), vec![75.0]);
+        assert_eq!(
+            send(&mut tree, 75.0, moved.clone(), 150.0),
+            Vec::<f32>::new()
+        );
+        assert_eq!(*handle(&tree).value(), 0.0);
+        assert_eq!(*handle(&tree).target(), 75.0);
+
+        assert_eq!(send(&mut tree, 75.0, moved, 50.0), vec![25.0]);
+        assert_eq!(send(&mut tree, 25.0, release, 50.0), Vec::<f32>::new());
+        assert_eq!(*handle(&tree).value(), 25.0);
+        assert!(!handle(&tree).is_animating());
+    }
+}
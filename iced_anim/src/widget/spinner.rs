@@ -0,0 +1,285 @@
+//! A spinner that indicates indeterminate progress with a rotating arc.
+//!
+//! The spinner drives itself from the timestamps of `RedrawRequested` events, so it doesn't need
+//! a subscription or any messages to keep spinning. It only requests redraws while it's visible,
+//! and shows a static ring instead when it's disabled, e.g. for reduced motion preferences.
+//!
+//! ```rust
+//! # use iced::Element;
+//! # use iced_anim::{transition::Easing, widget::spinner};
+//! # #[derive(Clone)]
+//! # enum Message {}
+//! # fn view<'a>() -> Element<'a, Message> {
+//! spinner()
+//!     .size(48.0)
+//!     .animation(Easing::EASE_IN_OUT)
+//!     .into()
+//! # }
+//! ```
+use std::{f32::consts::TAU, time::Duration};
+
+use super::cycle::Cycle;
+use crate::{animated::Mode, transition::Easing};
+use iced::{
+    advanced::{
+        graphics::core::event,
+        layout, mouse, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    window, Border, Color, Element, Event, Length, Point, Rectangle, Size,
+};
+
+/// The default time it takes for the spinner to complete one revolution.
+pub const DEFAULT_SPEED: Duration = Duration::from_millis(1000);
+
+/// The default diameter of the spinner.
+pub const DEFAULT_SIZE: f32 = 32.0;
+
+/// The number of dots that make up the spinner's ring.
+const DOTS: usize = 12;
+
+/// The fraction of the ring covered by the arc.
+const ARC: f32 = 0.75;
+
+/// The opacity of the dots when the spinner is disabled.
+const STATIC_OPACITY: f32 = 0.4;
+
+/// A spinner that indicates indeterminate progress with a rotating arc.
+pub struct Spinner {
+    /// The diameter of the spinner.
+    size: f32,
+    /// The color of the arc, or the text color if `None`.
+    color: Option<Color>,
+    /// How long one revolution takes.
+    speed: Duration,
+    /// How each revolution is eased.
+    mode: Mode,
+    /// Whether the spinner is static instead of animating.
+    is_disabled: bool,
+}
+
+impl Spinner {
+    /// Creates a new [`Spinner`].
+    pub fn new() -> Self {
+        Self {
+            size: DEFAULT_SIZE,
+            color: None,
+            speed: DEFAULT_SPEED,
+            mode: Easing::LINEAR.into(),
+            is_disabled: false,
+        }
+    }
+
+    /// Sets the diameter of the [`Spinner`].
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the color of the [`Spinner`], which defaults to the text color.
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets how long it takes the [`Spinner`] to complete one revolution.
+    pub fn speed(mut self, speed: Duration) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Sets how each revolution of the [`Spinner`] is eased.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+
+    /// Whether to show a static ring instead of animating.
+    /// Useful for reduced motion preferences.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.is_disabled = disabled;
+        self
+    }
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct State {
+    cycle: Cycle,
+}
+
+/// The opacity of the dot at `index` when the head of the arc is at `progress`.
+fn dot_opacity(index: usize, progress: f32) -> f32 {
+    let position = index as f32 / DOTS as f32;
+    // How far the dot trails behind the head of the arc, as a fraction of the ring.
+    let behind = (progress - position).rem_euclid(1.0);
+    if behind < ARC {
+        1.0 - behind / ARC
+    } else {
+        0.0
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Spinner
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            cycle: Cycle::new(self.mode, self.speed),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.cycle.diff(self.mode, self.speed);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(self.size), Length::Fixed(self.size))
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(limits.resolve(self.size, self.size, Size::ZERO))
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State>();
+            state.cycle.resume_at(now);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // Offscreen spinners stop requesting frames until they're redrawn again.
+        if self.is_disabled || !layout.bounds().intersects(viewport) {
+            return event::Status::Ignored;
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+            state.cycle.tick(now);
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let color = self.color.unwrap_or(style.text_color);
+
+        let diameter = bounds.width.min(bounds.height);
+        let dot_radius = diameter * 0.08;
+        let ring_radius = diameter / 2.0 - dot_radius;
+        let center = bounds.center();
+
+        for index in 0..DOTS {
+            let opacity = if self.is_disabled {
+                STATIC_OPACITY
+            } else {
+                dot_opacity(index, state.cycle.progress())
+            };
+            if opacity <= 0.0 {
+                continue;
+            }
+
+            // Start at the top and go clockwise.
+            let angle = index as f32 / DOTS as f32 * TAU - TAU / 4.0;
+            let dot_center = Point::new(
+                center.x + ring_radius * angle.cos(),
+                center.y + ring_radius * angle.sin(),
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle::new(
+                        Point::new(dot_center.x - dot_radius, dot_center.y - dot_radius),
+                        Size::new(dot_radius * 2.0, dot_radius * 2.0),
+                    ),
+                    border: Border {
+                        radius: dot_radius.into(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                color.scale_alpha(opacity),
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Spinner> for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(spinner: Spinner) -> Self {
+        Self::new(spinner)
+    }
+}
+
+/// Creates a new [`Spinner`].
+pub fn spinner() -> Spinner {
+    Spinner::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The dot at the head of the arc should be fully opaque.
+    #[test]
+    fn head_is_opaque() {
+        assert_eq!(dot_opacity(0, 0.0), 1.0);
+        assert_eq!(dot_opacity(3, 0.25), 1.0);
+    }
+
+    /// Dots fade out behind the head, and dots ahead of it aren't drawn.
+    #[test]
+    fn tail_fades_out() {
+        let trailing = dot_opacity(11, 0.0);
+        assert!(trailing > 0.0 && trailing < 1.0);
+        assert!(dot_opacity(10, 0.0) < trailing);
+        assert_eq!(dot_opacity(1, 0.0), 0.0);
+    }
+}
@@ -0,0 +1,809 @@
+//! A numeric stepper with buttons to decrement and increment a value.
+//!
+//! The displayed value animates towards the target, while [`Stepper::on_change`] is always
+//! called with the target itself, so the application never sees an in-flight value. Holding
+//! either button repeats the step, getting faster the longer it's held, and stepping past the
+//! bounds of the stepper briefly pushes the value past its edge and springs it back without
+//! publishing anything.
+//!
+//! ```no_run
+//! # use iced::Element;
+//! # use iced_anim::{spring::Motion, widget::stepper};
+//! # #[derive(Clone)]
+//! # enum Message { VolumeChanged(f32) }
+//! # fn view<'a>(volume: f32) -> Element<'a, Message> {
+//! stepper(volume)
+//!     .range(0.0..=10.0)
+//!     .step(0.5)
+//!     .on_change(Message::VolumeChanged)
+//!     .animation(Motion::SNAPPY)
+//!     .into()
+//! # }
+//! ```
+use std::{
+    ops::RangeInclusive,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use super::button::{self, Button};
+use crate::{
+    animated::Mode,
+    spring::Motion,
+    stall::{FrameClock, StallPolicy},
+    Animated,
+};
+use iced::{
+    advanced::{
+        layout, renderer,
+        text::{self as core_text, LineHeight, Shaping, Wrapping},
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event,
+    mouse::{self, Cursor},
+    touch, window, Element, Event, Length, Pixels, Point, Rectangle, Size,
+};
+
+/// How long a button needs to be held before the step starts repeating.
+pub const REPEAT_DELAY: Duration = Duration::from_millis(400);
+
+/// The interval between the first two repeats, which shrinks with every repeat after it.
+const FIRST_INTERVAL: Duration = Duration::from_millis(150);
+
+/// The shortest interval between repeats, no matter how long the button is held.
+const MIN_INTERVAL: Duration = Duration::from_millis(30);
+
+/// How far the value is pushed past the edge of the display when stepping past a bound.
+const OVERSHOOT: f32 = 8.0;
+
+/// The default width of the value between the buttons.
+const DEFAULT_DISPLAY_WIDTH: f32 = 64.0;
+
+/// A numeric stepper whose value animates between steps.
+#[allow(missing_debug_implementations)]
+pub struct Stepper<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: button::Catalog,
+    Renderer: core_text::Renderer,
+{
+    value: f32,
+    range: RangeInclusive<f32>,
+    step: f32,
+    on_change: Option<Rc<dyn Fn(f32) -> Message + 'a>>,
+    decrement: Button<'a, Message, Theme, Renderer>,
+    increment: Button<'a, Message, Theme, Renderer>,
+    format: Option<Box<dyn Fn(f32) -> String + 'a>>,
+    size: Option<Pixels>,
+    display_width: f32,
+    mode: Mode,
+}
+
+impl<'a, Message, Theme, Renderer> Stepper<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: button::Catalog + iced::widget::text::Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    /// Creates a new [`Stepper`] showing the given `value`, which steps by `1.0` without bounds.
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            range: f32::MIN..=f32::MAX,
+            step: 1.0,
+            on_change: None,
+            decrement: Button::new(iced::widget::text("-")),
+            increment: Button::new(iced::widget::text("+")),
+            format: None,
+            size: None,
+            display_width: DEFAULT_DISPLAY_WIDTH,
+            mode: Mode::default(),
+        }
+    }
+
+    /// Sets the inclusive bounds of the [`Stepper`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end.
+    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
+        assert!(
+            range.start() <= range.end(),
+            "the range of a stepper can't be empty"
+        );
+        self.range = range;
+        self
+    }
+
+    /// Sets how much each press of a button changes the value of the [`Stepper`].
+    ///
+    /// The value is shown with as many decimals as the step needs unless it's changed with
+    /// [`Stepper::format`].
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the message produced with the target value when the [`Stepper`] changes.
+    ///
+    /// Unless `on_change` is called, the [`Stepper`] will be disabled.
+    pub fn on_change(mut self, on_change: impl Fn(f32) -> Message + 'a) -> Self {
+        let on_change: Rc<dyn Fn(f32) -> Message + 'a> = Rc::new(on_change);
+        let value = self.value;
+
+        // The stepper changes the value as soon as a button is pressed, so the messages from the
+        // buttons themselves are never published. They only need one to show as enabled.
+        let decrement = Rc::clone(&on_change);
+        self.decrement = self.decrement.on_press_with(move || decrement(value));
+        let increment = Rc::clone(&on_change);
+        self.increment = self.increment.on_press_with(move || increment(value));
+        self.on_change = Some(on_change);
+        self
+    }
+
+    /// Sets how the value of the [`Stepper`] is shown, including while it animates.
+    pub fn format(mut self, format: impl Fn(f32) -> String + 'a) -> Self {
+        self.format = Some(Box::new(format));
+        self
+    }
+
+    /// Sets the text size of the value.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the width of the value between the buttons.
+    pub fn display_width(mut self, width: f32) -> Self {
+        self.display_width = width;
+        self
+    }
+
+    /// Sets the style of both buttons of the [`Stepper`].
+    #[must_use]
+    pub fn button_style(
+        mut self,
+        style: impl Fn(&Theme, button::Status) -> button::Style + Clone + 'a,
+    ) -> Self
+    where
+        <Theme as button::Catalog>::Class<'a>: From<button::StyleFn<'a, Theme>>,
+    {
+        self.decrement = self.decrement.style(style.clone());
+        self.increment = self.increment.style(style);
+        self
+    }
+
+    /// Sets the animation of the [`Stepper`], used for both its value and its buttons.
+    ///
+    /// Stepping past a bound always springs back with [`Motion::BOUNCY`].
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self.decrement = self.decrement.animation(self.mode);
+        self.increment = self.increment.animation(self.mode);
+        self
+    }
+}
+
+/// The number of decimals needed to show values that change by `step`.
+fn decimals(step: f32) -> usize {
+    (0..6)
+        .find(|&decimals| {
+            let scaled = step.abs() * 10_f32.powi(decimals as i32);
+            (scaled - scaled.round()).abs() < 1e-3
+        })
+        .unwrap_or(6)
+}
+
+/// The button of a [`Stepper`], which is the direction it changes the value in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Decrement,
+    Increment,
+}
+
+impl Direction {
+    fn sign(self) -> f32 {
+        match self {
+            Direction::Decrement => -1.0,
+            Direction::Increment => 1.0,
+        }
+    }
+}
+
+/// Schedules the repeated steps of a held button, which get closer together the longer it's
+/// held.
+///
+/// Time is measured between the redraws the stepper receives, so stalls in the event loop are
+/// handled by the crate-wide [`StallPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Repeat {
+    direction: Direction,
+    /// How long the button has been held, excluding stalls that were paused.
+    held: Duration,
+    /// How long the button needs to be held for the next repeat.
+    next: Duration,
+    /// The number of repeats so far.
+    repeats: u32,
+    clock: FrameClock,
+}
+
+impl Repeat {
+    /// Starts repeating in the given `direction` from the press at `now`.
+    fn start(direction: Direction, now: Instant) -> Self {
+        let mut clock = FrameClock::default();
+        clock.tick(now);
+
+        Self {
+            direction,
+            held: Duration::ZERO,
+            next: REPEAT_DELAY,
+            repeats: 0,
+            clock,
+        }
+    }
+
+    /// The interval between the given repeat and the one after it, which shrinks to four fifths
+    /// of the previous interval with every repeat.
+    fn interval(repeat: u32) -> Duration {
+        (0..repeat)
+            .try_fold(FIRST_INTERVAL, |interval, _| {
+                let next = interval * 4 / 5;
+                (next > MIN_INTERVAL).then_some(next)
+            })
+            .unwrap_or(MIN_INTERVAL)
+    }
+
+    /// Advances the held time to `now`, returning how many repeats are due.
+    fn tick(&mut self, now: Instant) -> u32 {
+        self.held += self.clock.tick(now).elapsed(StallPolicy::global());
+
+        let mut due = 0;
+        while self.held >= self.next {
+            self.next += Self::interval(self.repeats);
+            self.repeats += 1;
+            due += 1;
+        }
+        due
+    }
+
+    /// Rebases the held time to `now`, so the time since the last tick doesn't count.
+    fn resume_at(&mut self, now: Instant) {
+        self.clock.resume_at(now);
+    }
+}
+
+/// The value of a [`Stepper`], separated into the target that's published and the value that's
+/// displayed while animating towards it.
+#[derive(Debug)]
+struct Value {
+    /// The value that was last published or given to the stepper.
+    target: f32,
+    /// The value shown between the buttons.
+    display: Animated<f32>,
+    /// The horizontal offset of the displayed value, which rubber-bands at the bounds.
+    band: Animated<f32>,
+}
+
+impl Value {
+    fn new(value: f32, mode: Mode) -> Self {
+        Self {
+            target: value,
+            display: Animated::new(value, mode),
+            band: Animated::spring(0.0, Motion::BOUNCY),
+        }
+    }
+
+    /// Animates towards the value from the view if it changed, e.g. because the application
+    /// rejected or adjusted the last published value.
+    fn sync(&mut self, value: f32, mode: Mode) {
+        if self.display.mode() != mode {
+            self.display.apply(mode);
+        }
+        if self.target != value {
+            self.target = value;
+            self.display.set_target(value);
+        }
+    }
+
+    /// Steps the target in the given `direction`, returning the new target to publish.
+    ///
+    /// Stepping past a bound clamps to it, and stepping while already at the bound rubber-bands
+    /// the displayed value instead of returning anything.
+    fn step(
+        &mut self,
+        direction: Direction,
+        step: f32,
+        range: &RangeInclusive<f32>,
+    ) -> Option<f32> {
+        let scale = 10_f32.powi(decimals(step) as i32);
+        let next = ((self.target + direction.sign() * step) * scale).round() / scale;
+        let next = next.clamp(*range.start(), *range.end());
+
+        if next == self.target {
+            self.band.settle_at(direction.sign() * OVERSHOOT);
+            self.band.set_target(0.0);
+            None
+        } else {
+            self.target = next;
+            self.display.set_target(next);
+            Some(next)
+        }
+    }
+
+    fn is_animating(&self) -> bool {
+        self.display.is_animating() || self.band.is_animating()
+    }
+
+    fn tick(&mut self, now: Instant) {
+        self.display.tick(now);
+        self.band.tick(now);
+    }
+
+    fn resume_at(&mut self, now: Instant) {
+        self.display.resume_at(now);
+        self.band.resume_at(now);
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    value: Value,
+    /// The repeats of the held button, if any.
+    repeat: Option<Repeat>,
+}
+
+impl<'a, Message, Theme, Renderer> Stepper<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: button::Catalog,
+    Renderer: core_text::Renderer + 'a,
+{
+    /// Steps the value in the given `direction`, publishing the new target if it changed.
+    fn step_value(&self, state: &mut State, direction: Direction, shell: &mut Shell<'_, Message>) {
+        let Some(on_change) = self.on_change.as_ref() else {
+            return;
+        };
+
+        if let Some(value) = state.value.step(direction, self.step, &self.range) {
+            shell.publish(on_change(value));
+        }
+    }
+
+    /// Formats the displayed value with the custom format, or with as many decimals as the step
+    /// needs.
+    fn display_text(&self, value: f32) -> String {
+        match &self.format {
+            Some(format) => format(value),
+            None => format!("{:.*}", decimals(self.step), value),
+        }
+    }
+}
+
+/// The layout of the button with the given `direction`, which are on either side of the value.
+fn button_layout(layout: Layout<'_>, direction: Direction) -> Layout<'_> {
+    let mut children = layout.children();
+    match direction {
+        Direction::Decrement => children.next(),
+        Direction::Increment => children.nth(2),
+    }
+    .expect("a stepper has two buttons and a value")
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Stepper<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: button::Catalog,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            value: Value::new(self.value, self.mode),
+            repeat: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![
+            Tree::new(&self.decrement as &dyn Widget<Message, Theme, Renderer>),
+            Tree::new(&self.increment as &dyn Widget<Message, Theme, Renderer>),
+        ]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.value.sync(self.value, self.mode);
+        if self.on_change.is_none() {
+            state.repeat = None;
+        }
+
+        tree.children[0].diff(&self.decrement as &dyn Widget<Message, Theme, Renderer>);
+        tree.children[1].diff(&self.increment as &dyn Widget<Message, Theme, Renderer>);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let loose = limits.loose();
+        let decrement = Widget::layout(&self.decrement, &mut tree.children[0], renderer, &loose);
+        let increment = Widget::layout(&self.increment, &mut tree.children[1], renderer, &loose);
+
+        // Lay out the value between the buttons, which are centered vertically.
+        let height = decrement.size().height.max(increment.size().height);
+        let display_x = decrement.size().width;
+        let increment_x = display_x + self.display_width;
+        let width = increment_x + increment.size().width;
+
+        let decrement_y = (height - decrement.size().height) / 2.0;
+        let increment_y = (height - increment.size().height) / 2.0;
+        let size = limits.resolve(Length::Shrink, Length::Shrink, Size::new(width, height));
+
+        layout::Node::with_children(
+            size,
+            vec![
+                decrement.move_to(Point::new(0.0, decrement_y)),
+                layout::Node::new(Size::new(self.display_width, height))
+                    .move_to(Point::new(display_x, 0.0)),
+                increment.move_to(Point::new(increment_x, increment_y)),
+            ],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State>();
+            state.value.resume_at(now);
+            if let Some(repeat) = state.repeat.as_mut() {
+                repeat.resume_at(now);
+            }
+        }
+
+        Widget::operate(
+            &self.decrement,
+            &mut tree.children[0],
+            button_layout(layout, Direction::Decrement),
+            renderer,
+            operation,
+        );
+        Widget::operate(
+            &self.increment,
+            &mut tree.children[1],
+            button_layout(layout, Direction::Increment),
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // The buttons still receive every event so their styles animate as usual, but their
+        // messages are dropped since the stepper publishes its own.
+        let mut messages = Vec::new();
+        let mut buttons = Shell::new(&mut messages);
+        let buttons_with_directions = [
+            (&mut self.decrement, Direction::Decrement),
+            (&mut self.increment, Direction::Increment),
+        ];
+        for (index, (button, direction)) in buttons_with_directions.into_iter().enumerate() {
+            Widget::on_event(
+                button,
+                &mut tree.children[index],
+                event.clone(),
+                button_layout(layout, direction),
+                cursor,
+                renderer,
+                clipboard,
+                &mut buttons,
+                viewport,
+            );
+        }
+        if let Some(request) = buttons.redraw_request() {
+            shell.request_redraw(request);
+        }
+        if buttons.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+        if buttons.are_widgets_invalid() {
+            shell.invalidate_widgets();
+        }
+
+        let is_over = |direction| cursor.is_over(button_layout(layout, direction).bounds());
+
+        let state = tree.state.downcast_mut::<State>();
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.value.tick(now);
+
+                // Keep repeating while the held button is under the cursor.
+                if let Some(repeat) = state.repeat.as_mut() {
+                    if is_over(repeat.direction) {
+                        let (direction, due) = (repeat.direction, repeat.tick(now));
+                        for _ in 0..due {
+                            self.step_value(state, direction, shell);
+                        }
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    } else {
+                        state.repeat = None;
+                    }
+                }
+
+                if state.value.is_animating() {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if self.on_change.is_none() {
+                    return event::Status::Ignored;
+                }
+
+                let pressed = [Direction::Decrement, Direction::Increment]
+                    .into_iter()
+                    .find(|&direction| is_over(direction));
+
+                if let Some(direction) = pressed {
+                    self.step_value(state, direction, shell);
+                    state.repeat = Some(Repeat::start(direction, crate::clock::now()));
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                state.repeat = None;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        Widget::mouse_interaction(
+            &self.decrement,
+            &tree.children[0],
+            button_layout(layout, Direction::Decrement),
+            cursor,
+            viewport,
+            renderer,
+        )
+        .max(Widget::mouse_interaction(
+            &self.increment,
+            &tree.children[1],
+            button_layout(layout, Direction::Increment),
+            cursor,
+            viewport,
+            renderer,
+        ))
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let mut children = layout.children();
+        let decrement_layout = children.next().unwrap();
+        let display_layout = children.next().unwrap();
+        let increment_layout = children.next().unwrap();
+
+        Widget::draw(
+            &self.decrement,
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            decrement_layout,
+            cursor,
+            viewport,
+        );
+        Widget::draw(
+            &self.increment,
+            &tree.children[1],
+            renderer,
+            theme,
+            style,
+            increment_layout,
+            cursor,
+            viewport,
+        );
+
+        let bounds = display_layout.bounds();
+        let position = Point::new(
+            bounds.center_x() + *state.value.band.value(),
+            bounds.center_y(),
+        );
+
+        renderer.fill_text(
+            core_text::Text {
+                content: self.display_text(*state.value.display.value()),
+                bounds: bounds.size(),
+                size: self.size.unwrap_or_else(|| renderer.default_size()),
+                line_height: LineHeight::default(),
+                font: renderer.default_font(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                shaping: Shaping::Basic,
+                wrapping: Wrapping::None,
+            },
+            position,
+            style.text_color,
+            bounds,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Stepper<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: button::Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(stepper: Stepper<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(stepper)
+    }
+}
+
+/// Creates a new [`Stepper`] showing the given `value`.
+pub fn stepper<'a, Message, Theme, Renderer>(value: f32) -> Stepper<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: button::Catalog + iced::widget::text::Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    Stepper::new(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Holds a button from `start` at 1ms frames for the given `duration`, returning the held
+    /// times in milliseconds when each repeat happened.
+    fn repeat_times(duration: Duration) -> Vec<u64> {
+        let start = Instant::now();
+        let mut repeat = Repeat::start(Direction::Increment, start);
+        let mut times = Vec::new();
+        for ms in 1..=duration.as_millis() as u64 {
+            for _ in 0..repeat.tick(start + Duration::from_millis(ms)) {
+                times.push(ms);
+            }
+        }
+        times
+    }
+
+    /// Repeats should start after a delay and get faster the longer the button is held.
+    #[test]
+    fn repeat_timetable() {
+        let times = repeat_times(Duration::from_millis(1000));
+        assert_eq!(times[..5], [400, 550, 670, 766, 843]);
+
+        let intervals: Vec<u64> = times.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        assert!(intervals.windows(2).all(|pair| pair[1] <= pair[0]));
+    }
+
+    /// Repeats should never get closer together than the minimum interval.
+    #[test]
+    fn repeat_interval_floor() {
+        assert_eq!(Repeat::interval(0), FIRST_INTERVAL);
+        assert!(Repeat::interval(1) < FIRST_INTERVAL);
+        assert_eq!(Repeat::interval(100), MIN_INTERVAL);
+
+        let times = repeat_times(Duration::from_secs(3));
+        let last = &times[times.len() - 2..];
+        assert_eq!(last[1] - last[0], MIN_INTERVAL.as_millis() as u64);
+    }
+
+    /// Slow frames should catch up on every repeat that was due.
+    #[test]
+    fn repeat_catches_up_on_slow_frames() {
+        let start = Instant::now();
+        let mut repeat = Repeat::start(Direction::Decrement, start);
+        assert_eq!(repeat.tick(start + Duration::from_millis(300)), 0);
+        assert_eq!(repeat.tick(start + Duration::from_millis(680)), 3);
+    }
+
+    /// Steps should publish the target immediately while the displayed value animates.
+    #[test]
+    fn target_is_published_before_display() {
+        let mut value = Value::new(1.0, Motion::SMOOTH.into());
+        let range = 0.0..=10.0;
+
+        assert_eq!(value.step(Direction::Increment, 1.0, &range), Some(2.0));
+        assert_eq!(value.step(Direction::Increment, 1.0, &range), Some(3.0));
+        assert_eq!(*value.display.value(), 1.0);
+        assert_eq!(*value.display.target(), 3.0);
+    }
+
+    /// Steps past a bound should clamp to it, then rubber-band without publishing anything.
+    #[test]
+    fn bounds_rubber_band() {
+        let mut value = Value::new(9.5, Motion::SMOOTH.into());
+        let range = 0.0..=10.0;
+        assert_eq!(value.step(Direction::Increment, 1.0, &range), Some(10.0));
+        assert!(!value.band.is_animating());
+
+        assert_eq!(value.step(Direction::Increment, 1.0, &range), None);
+        assert_eq!(value.target, 10.0);
+        assert_eq!(*value.band.value(), OVERSHOOT);
+
+        // The band springs back to rest on its own.
+        let mut now = Instant::now();
+        value.resume_at(now);
+        for _ in 0..180 {
+            now += Duration::from_millis(16);
+            value.tick(now);
+        }
+        assert!(!value.band.is_animating());
+        assert_eq!(*value.band.value(), 0.0);
+        assert_eq!(*value.display.value(), 10.0);
+    }
+
+    /// Repeated fractional steps shouldn't drift from the step's precision.
+    #[test]
+    fn fractional_steps_stay_rounded() {
+        let mut value = Value::new(0.0, Mode::default());
+        let range = f32::MIN..=f32::MAX;
+        let mut last = None;
+        for _ in 0..3 {
+            last = value.step(Direction::Increment, 0.1, &range);
+        }
+        assert_eq!(last, Some(0.3));
+        assert_eq!(decimals(0.1), 1);
+        assert_eq!(decimals(0.25), 2);
+        assert_eq!(decimals(5.0), 0);
+    }
+
+    /// A value from the view that differs from the target should replace it.
+    #[test]
+    fn sync_replaces_rejected_targets() {
+        let mut value = Value::new(1.0, Mode::default());
+        value.step(Direction::Increment, 1.0, &(0.0..=10.0));
+        value.sync(1.0, Mode::default());
+        assert_eq!(value.target, 1.0);
+        assert_eq!(*value.display.target(), 1.0);
+    }
+}
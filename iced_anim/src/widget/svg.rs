@@ -3,11 +3,11 @@ use crate::{animated::Mode, AnimatedState};
 use iced::{
     advanced::{
         layout, renderer, svg,
-        widget::{tree, Tree},
+        widget::{tree, Operation, Tree},
         Layout, Widget,
     },
     mouse::{self, Cursor},
-    window, ContentFit, Element, Event, Length, Point, Rectangle, Rotation, Size, Vector,
+    ContentFit, Element, Length, Point, Rectangle, Rotation, Size, Vector,
 };
 use std::path::PathBuf;
 
@@ -211,6 +211,19 @@ where
         layout::Node::new(final_size)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State>();
+            state.animated_state.resume_at(now);
+        }
+    }
+
     fn draw(
         &self,
         tree: &Tree,
@@ -284,15 +297,7 @@ where
         // Redraw anytime the status changes and would trigger a style change.
         let state = tree.state.downcast_mut::<State>();
         let status = self.get_status(cursor, layout);
-        let needs_redraw = state.animated_state.needs_redraw(status);
-
-        if needs_redraw {
-            shell.request_redraw(window::RedrawRequest::NextFrame);
-        }
-
-        if let Event::Window(window::Event::RedrawRequested(now)) = event {
-            state.animated_state.tick(now);
-        }
+        state.animated_state.handle_event(&event, status, shell);
 
         iced::event::Status::Ignored
     }
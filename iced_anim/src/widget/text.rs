@@ -0,0 +1,361 @@
+//! An animated text widget that will automatically transition between colors and sizes.
+//!
+//! Text in Iced doesn't have a status, so the color animates whenever the theme or the style of
+//! the text changes. For example, a label can be highlighted when a value changes by returning a
+//! different color from its style until the highlight is removed:
+//!
+//! ```no_run
+//! # use iced::{Color, Element};
+//! # use iced_anim::{spring::Motion, widget::text};
+//! # fn view<'a, Message: 'a>(count: u32, is_highlighted: bool) -> Element<'a, Message> {
+//! text(count)
+//!     .style(move |theme: &iced::Theme| text::Style {
+//!         color: Some(if is_highlighted {
+//!             Color::from_rgb(1.0, 0.8, 0.0)
+//!         } else {
+//!             theme.palette().text
+//!         }),
+//!     })
+//!     .size(if is_highlighted { 24 } else { 16 })
+//!     .animation(Motion::BOUNCY)
+//!     .into()
+//! # }
+//! ```
+//!
+//! Changing the size animates the layout of the text as well, so widgets around it will move to
+//! make room for the text as it grows.
+use crate::{animate::AnimatedTextStyle, animated::Mode, Animated, AnimatedState};
+use iced::{
+    advanced::{
+        layout, renderer,
+        text::{self as core_text, Paragraph},
+        widget::{text as core_widget, tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event, mouse, window, Color, Element, Event, Length, Pixels, Rectangle, Size,
+};
+
+// Re-export the widget types for convenience
+pub use iced::widget::text::{
+    base, danger, default, primary, secondary, success, Catalog, IntoFragment, LineHeight, Shaping,
+    Style, StyleFn, Wrapping,
+};
+
+/// A paragraph of text whose color and size animate when they change.
+#[allow(missing_debug_implementations)]
+pub struct Text<'a, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    fragment: core_text::Fragment<'a>,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    width: Length,
+    height: Length,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    font: Option<Renderer::Font>,
+    shaping: Shaping,
+    wrapping: Wrapping,
+    class: Theme::Class<'a>,
+    mode: Mode,
+}
+
+impl<'a, Theme, Renderer> Text<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    /// Creates a new [`Text`] with the given contents.
+    pub fn new(fragment: impl IntoFragment<'a>) -> Self {
+        Text {
+            fragment: fragment.into_fragment(),
+            size: None,
+            line_height: LineHeight::default(),
+            font: None,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: Shaping::default(),
+            wrapping: Wrapping::default(),
+            class: Theme::default(),
+            mode: Mode::default(),
+        }
+    }
+
+    /// Sets the size of the [`Text`], which animates when it changes.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`Text`].
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the [`Text`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the width of the [`Text`] boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Text`] boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Centers the [`Text`], both horizontally and vertically.
+    pub fn center(self) -> Self {
+        self.align_x(alignment::Horizontal::Center)
+            .align_y(alignment::Vertical::Center)
+    }
+
+    /// Sets the [`alignment::Horizontal`] of the [`Text`].
+    pub fn align_x(mut self, alignment: impl Into<alignment::Horizontal>) -> Self {
+        self.horizontal_alignment = alignment.into();
+        self
+    }
+
+    /// Sets the [`alignment::Vertical`] of the [`Text`].
+    pub fn align_y(mut self, alignment: impl Into<alignment::Vertical>) -> Self {
+        self.vertical_alignment = alignment.into();
+        self
+    }
+
+    /// Sets the [`Shaping`] strategy of the [`Text`].
+    pub fn shaping(mut self, shaping: Shaping) -> Self {
+        self.shaping = shaping;
+        self
+    }
+
+    /// Sets the [`Wrapping`] strategy of the [`Text`].
+    pub fn wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.wrapping = wrapping;
+        self
+    }
+
+    /// Sets the style of the [`Text`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Text`].
+    pub fn color(self, color: impl Into<Color>) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.color_maybe(Some(color))
+    }
+
+    /// Sets the [`Color`] of the [`Text`], if `Some`.
+    pub fn color_maybe(self, color: Option<impl Into<Color>>) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        let color = color.map(Into::into);
+        self.style(move |_theme| Style { color })
+    }
+
+    /// Sets the style class of the [`Text`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the animation of the [`Text`], used for both its color and size.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+}
+
+/// Text doesn't have a status, so the animated style only depends on the theme and class.
+struct State<P: Paragraph> {
+    paragraph: core_widget::State<P>,
+    animated_state: AnimatedState<(), AnimatedTextStyle>,
+    /// The animated size of the text, if it has an explicit size.
+    size: Option<Animated<f32>>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Text<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            paragraph: core_widget::State::default(),
+            animated_state: AnimatedState::new((), self.mode),
+            size: self.size.map(|size| Animated::new(size.0, self.mode)),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.animated_state.diff(self.mode);
+
+        // Animate towards a new size, or snap to the default size if it was removed.
+        match (&mut state.size, self.size) {
+            (Some(animated), Some(size)) => {
+                if animated.mode() != self.mode {
+                    animated.apply(self.mode);
+                }
+                if animated.target() != &size.0 {
+                    animated.set_target(size.0);
+                }
+            }
+            (None, Some(size)) => state.size = Some(Animated::new(size.0, self.mode)),
+            (_, None) => state.size = None,
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let size = state
+            .size
+            .as_ref()
+            .map(|size| Pixels(*size.value()))
+            .or(self.size);
+
+        core_widget::layout(
+            &mut state.paragraph,
+            renderer,
+            limits,
+            self.width,
+            self.height,
+            &self.fragment,
+            self.line_height,
+            size,
+            self.font,
+            self.horizontal_alignment,
+            self.vertical_alignment,
+            self.shaping,
+            self.wrapping,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+            state.animated_state.resume_at(now);
+            if let Some(size) = state.size.as_mut() {
+                size.resume_at(now);
+            }
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let is_resizing = state.size.as_ref().is_some_and(Animated::needs_tick);
+
+        // Keep redrawing while the color or size is animating.
+        if state.animated_state.needs_redraw(()) || is_resizing {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.animated_state.tick(now);
+            if let Some(size) = state.size.as_mut().filter(|size| size.is_animating()) {
+                // Changing the size of the text changes its bounds, so it needs a new layout.
+                size.tick(now);
+                shell.invalidate_layout();
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = state
+            .animated_state
+            .current_value(|_| theme.style(&self.class).into());
+
+        core_widget::draw(
+            renderer,
+            renderer_style,
+            layout,
+            state.paragraph.0.raw(),
+            style.0,
+            viewport,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Text<'a, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(text: Text<'a, Theme, Renderer>) -> Self {
+        Element::new(text)
+    }
+}
+
+/// Creates a new [`Text`] with the given contents.
+pub fn text<'a, Theme, Renderer>(text: impl IntoFragment<'a>) -> Text<'a, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    Text::new(text)
+}
@@ -0,0 +1,394 @@
+//! An animated text input that will automatically transition between different styles.
+//!
+//! The text input wraps the one from Iced, so editing, selection, and focus behave exactly the
+//! same. Only the style is animated, e.g. so the border of a focused input fades in instead of
+//! snapping into place.
+use std::{cell::Cell, rc::Rc};
+
+use crate::{animate::AnimatedTextInputStyle, animated::Mode, AnimatedState};
+use iced::{
+    advanced::{
+        layout, renderer, text,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event,
+    mouse::{self, Cursor},
+    window, Element, Event, Length, Padding, Pixels, Rectangle, Size,
+};
+
+// Re-export the widget types for convenience
+pub use iced::widget::text_input::{default, Catalog, Icon, Id, Status, Style, StyleFn};
+
+/// An animated text input that will automatically transition between different styles.
+#[allow(missing_debug_implementations)]
+pub struct TextInput<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The wrapped text input, which draws with the animated style.
+    input: iced::widget::TextInput<'a, Message, Theme, Renderer>,
+    /// The animated style for the wrapped input to use in its next draw.
+    animated_style: Rc<Cell<Option<Style>>>,
+    is_enabled: bool,
+    class: Theme::Class<'a>,
+    mode: Mode,
+}
+
+impl<'a, Message, Theme, Renderer> TextInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer,
+    Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+{
+    /// Creates a new [`TextInput`] with the given placeholder and value.
+    pub fn new(placeholder: &str, value: &str) -> Self {
+        let animated_style = Rc::new(Cell::new(None));
+        let input = iced::widget::TextInput::new(placeholder, value).style({
+            let animated_style = Rc::clone(&animated_style);
+            move |theme: &Theme, status| {
+                animated_style
+                    .get()
+                    .unwrap_or_else(|| theme.style(&<Theme as Catalog>::default(), status))
+            }
+        });
+
+        Self {
+            input,
+            animated_style,
+            is_enabled: false,
+            class: <Theme as Catalog>::default(),
+            mode: Mode::default(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`TextInput`].
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.input = self.input.id(id.into());
+        self
+    }
+
+    /// Converts the [`TextInput`] into a secure password input.
+    pub fn secure(mut self, is_secure: bool) -> Self {
+        self.input = self.input.secure(is_secure);
+        self
+    }
+
+    /// Sets the message that should be produced when some text is typed into the [`TextInput`].
+    ///
+    /// If this method is not called, the [`TextInput`] will be disabled.
+    pub fn on_input(mut self, on_input: impl Fn(String) -> Message + 'a) -> Self {
+        self.input = self.input.on_input(on_input);
+        self.is_enabled = true;
+        self
+    }
+
+    /// Sets the message that should be produced when some text is typed into the [`TextInput`],
+    /// if `Some`.
+    ///
+    /// If `None`, the [`TextInput`] will be disabled.
+    pub fn on_input_maybe(mut self, on_input: Option<impl Fn(String) -> Message + 'a>) -> Self {
+        self.is_enabled = on_input.is_some();
+        self.input = self.input.on_input_maybe(on_input);
+        self
+    }
+
+    /// Sets the message that should be produced when the [`TextInput`] is focused and the enter
+    /// key is pressed.
+    pub fn on_submit(mut self, message: Message) -> Self {
+        self.input = self.input.on_submit(message);
+        self
+    }
+
+    /// Sets the message that should be produced when the [`TextInput`] is focused and the enter
+    /// key is pressed, if `Some`.
+    pub fn on_submit_maybe(mut self, on_submit: Option<Message>) -> Self {
+        self.input = self.input.on_submit_maybe(on_submit);
+        self
+    }
+
+    /// Sets the message that should be produced when some text is pasted into the [`TextInput`].
+    pub fn on_paste(mut self, on_paste: impl Fn(String) -> Message + 'a) -> Self {
+        self.input = self.input.on_paste(on_paste);
+        self
+    }
+
+    /// Sets the font of the [`TextInput`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.input = self.input.font(font);
+        self
+    }
+
+    /// Sets the [`Icon`] of the [`TextInput`].
+    pub fn icon(mut self, icon: Icon<Renderer::Font>) -> Self {
+        self.input = self.input.icon(icon);
+        self
+    }
+
+    /// Sets the width of the [`TextInput`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.input = self.input.width(width);
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`TextInput`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.input = self.input.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the [`TextInput`].
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.input = self.input.size(size);
+        self
+    }
+
+    /// Sets the line height of the [`TextInput`].
+    pub fn line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.input = self.input.line_height(line_height);
+        self
+    }
+
+    /// Sets the horizontal alignment of the [`TextInput`].
+    pub fn align_x(mut self, alignment: impl Into<alignment::Horizontal>) -> Self {
+        self.input = self.input.align_x(alignment);
+        self
+    }
+
+    /// Sets the style of the [`TextInput`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`TextInput`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the animation of the [`TextInput`].
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> TextInput<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The initial status that this widget will have based on its properties.
+    ///
+    /// This will be used as the initial state value.
+    fn get_initial_status(&self) -> Status {
+        if self.is_enabled {
+            Status::Active
+        } else {
+            Status::Disabled
+        }
+    }
+
+    /// Gets the status of the [`TextInput`] based on the state of the wrapped input.
+    fn get_status(&self, input: &Tree, cursor: Cursor, layout: Layout<'_>) -> Status {
+        let input = input
+            .state
+            .downcast_ref::<iced::widget::text_input::State<Renderer::Paragraph>>();
+
+        if !self.is_enabled {
+            Status::Disabled
+        } else if input.is_focused() {
+            Status::Focused
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    animated_state: AnimatedState<Status, AnimatedTextInputStyle>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TextInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        let status = self.get_initial_status();
+        tree::State::new(State {
+            animated_state: AnimatedState::new(status, self.mode),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(
+            &self.input as &dyn Widget<Message, Theme, Renderer>,
+        )]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        state.animated_state.diff(self.mode);
+        tree.children[0].diff(&self.input as &dyn Widget<Message, Theme, Renderer>);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Widget::size(&self.input)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        Widget::layout(&self.input, &mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State>();
+            state.animated_state.resume_at(now);
+        }
+
+        Widget::operate(
+            &self.input,
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let status = Widget::on_event(
+            &mut self.input,
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        // Redraw anytime the status changes and would trigger a style change, which includes
+        // the input gaining or losing focus from the event it just handled.
+        let input_status = self.get_status(&tree.children[0], cursor, layout);
+        let state = tree.state.downcast_mut::<State>();
+        if state.animated_state.needs_redraw(input_status) {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.animated_state.tick(now);
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        Widget::mouse_interaction(
+            &self.input,
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let animated_style = state
+            .animated_state
+            .current_value(|status| theme.style(&self.class, *status).into());
+
+        // The wrapped input picks up the animated style from its style function.
+        self.animated_style.set(Some(animated_style.0));
+        Widget::draw(
+            &self.input,
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TextInput<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(text_input: TextInput<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(text_input)
+    }
+}
+
+/// Creates a new [`TextInput`] with the given placeholder and value.
+pub fn text_input<'a, Message, Theme, Renderer>(
+    placeholder: &str,
+    value: &str,
+) -> TextInput<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer,
+    Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+{
+    TextInput::new(placeholder, value)
+}
@@ -0,0 +1,522 @@
+//! An animated toggler whose knob slides between its off and on positions.
+//!
+//! The track animates between its toggled styles like other animated widgets, while the knob
+//! slides from wherever it currently is. Toggling again mid-animation reverses the knob smoothly
+//! instead of jumping, and a bouncy [`Motion`](crate::spring::Motion) lets it overshoot playfully:
+//!
+//! ```no_run
+//! # use iced::Element;
+//! # use iced_anim::{spring::Motion, widget::toggler};
+//! # #[derive(Clone)] enum Message { Toggle(bool) }
+//! # fn view<'a>(is_toggled: bool) -> Element<'a, Message> {
+//! toggler(is_toggled)
+//!     .label("Dark mode")
+//!     .on_toggle(Message::Toggle)
+//!     .animation(Motion::BOUNCY)
+//!     .into()
+//! # }
+//! ```
+use crate::{animate::AnimatedTogglerStyle, animated::Mode, Animated, AnimatedState};
+use iced::{
+    advanced::{
+        layout, renderer,
+        text::{self as core_text, Paragraph},
+        widget::{text as core_widget, tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    alignment, event,
+    mouse::{self, Cursor},
+    touch, window, Border, Element, Event, Length, Pixels, Rectangle, Size,
+};
+
+// Re-export the widget types for convenience
+pub use iced::widget::text::{IntoFragment, LineHeight, Shaping, Wrapping};
+pub use iced::widget::toggler::{default, Catalog, Status, Style, StyleFn};
+
+/// The default height of the toggler, in logical pixels.
+const DEFAULT_SIZE: f32 = 16.0;
+
+/// The radius of the track relative to its height.
+const BORDER_RADIUS_RATIO: f32 = 32.0 / 13.0;
+
+/// The space between the bounds, the track, and the knob relative to the height.
+const SPACE_RATIO: f32 = 0.05;
+
+/// A switch that can be toggled, whose knob slides between its off and on positions.
+#[allow(missing_debug_implementations)]
+pub struct Toggler<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Renderer: core_text::Renderer,
+    Theme: Catalog,
+{
+    is_toggled: bool,
+    on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    label: Option<core_text::Fragment<'a>>,
+    width: Length,
+    size: f32,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    text_line_height: LineHeight,
+    text_alignment: alignment::Horizontal,
+    text_shaping: Shaping,
+    text_wrapping: Wrapping,
+    font: Option<Renderer::Font>,
+    class: Theme::Class<'a>,
+    mode: Mode,
+}
+
+impl<'a, Message, Theme, Renderer> Toggler<'a, Message, Theme, Renderer>
+where
+    Renderer: core_text::Renderer,
+    Theme: Catalog,
+{
+    /// Creates a new [`Toggler`] with the given toggled state.
+    ///
+    /// The [`Toggler`] is disabled until [`Toggler::on_toggle`] is called.
+    pub fn new(is_toggled: bool) -> Self {
+        Toggler {
+            is_toggled,
+            on_toggle: None,
+            label: None,
+            width: Length::Shrink,
+            size: DEFAULT_SIZE,
+            spacing: DEFAULT_SIZE / 2.0,
+            text_size: None,
+            text_line_height: LineHeight::default(),
+            text_alignment: alignment::Horizontal::Left,
+            text_shaping: Shaping::default(),
+            text_wrapping: Wrapping::default(),
+            font: None,
+            class: Theme::default(),
+            mode: Mode::default(),
+        }
+    }
+
+    /// Sets the label of the [`Toggler`].
+    pub fn label(mut self, label: impl IntoFragment<'a>) -> Self {
+        self.label = Some(label.into_fragment());
+        self
+    }
+
+    /// Sets the function that will be called when the [`Toggler`] is toggled, receiving the
+    /// new toggled state.
+    ///
+    /// Unless `on_toggle` is called, the [`Toggler`] will be disabled.
+    pub fn on_toggle(mut self, on_toggle: impl Fn(bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(on_toggle));
+        self
+    }
+
+    /// Sets the function that will be called when the [`Toggler`] is toggled, if `Some`.
+    ///
+    /// If `None`, the [`Toggler`] will be disabled.
+    pub fn on_toggle_maybe(mut self, on_toggle: Option<impl Fn(bool) -> Message + 'a>) -> Self {
+        self.on_toggle = on_toggle.map(|on_toggle| Box::new(on_toggle) as _);
+        self
+    }
+
+    /// Sets the height of the [`Toggler`], which is half of its width.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into().0;
+        self
+    }
+
+    /// Sets the width of the [`Toggler`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the spacing between the [`Toggler`] and its label.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the label of the [`Toggler`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the label of the [`Toggler`].
+    pub fn text_line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the horizontal alignment of the label of the [`Toggler`].
+    pub fn text_alignment(mut self, alignment: impl Into<alignment::Horizontal>) -> Self {
+        self.text_alignment = alignment.into();
+        self
+    }
+
+    /// Sets the [`Shaping`] strategy of the label of the [`Toggler`].
+    pub fn text_shaping(mut self, shaping: Shaping) -> Self {
+        self.text_shaping = shaping;
+        self
+    }
+
+    /// Sets the [`Wrapping`] strategy of the label of the [`Toggler`].
+    pub fn text_wrapping(mut self, wrapping: Wrapping) -> Self {
+        self.text_wrapping = wrapping;
+        self
+    }
+
+    /// Sets the font of the label of the [`Toggler`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Toggler`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Toggler`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the animation of the [`Toggler`], used for both its style and its knob.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+
+    /// The position of the knob when the [`Toggler`] is settled.
+    fn position(&self) -> f32 {
+        if self.is_toggled {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Gets the status of the [`Toggler`] based on the cursor position.
+    fn get_status(&self, cursor: Cursor, layout: Layout<'_>) -> Status {
+        let is_toggled = self.is_toggled;
+        if self.on_toggle.is_none() {
+            Status::Disabled
+        } else if cursor.is_over(layout.bounds()) {
+            Status::Hovered { is_toggled }
+        } else {
+            Status::Active { is_toggled }
+        }
+    }
+}
+
+struct State<P: Paragraph> {
+    paragraph: core_widget::State<P>,
+    animated_state: AnimatedState<Status, AnimatedTogglerStyle>,
+    /// The position of the knob, from `0.0` when off to `1.0` when on.
+    position: Animated<f32>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Toggler<'a, Message, Theme, Renderer>
+where
+    Renderer: core_text::Renderer,
+    Theme: Catalog,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        let status = if self.on_toggle.is_some() {
+            Status::Active {
+                is_toggled: self.is_toggled,
+            }
+        } else {
+            Status::Disabled
+        };
+
+        tree::State::new(State::<Renderer::Paragraph> {
+            paragraph: core_widget::State::default(),
+            animated_state: AnimatedState::new(status, self.mode),
+            position: Animated::new(self.position(), self.mode),
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        state.animated_state.diff(self.mode);
+
+        if state.position.mode() != self.mode {
+            state.position.apply(self.mode);
+        }
+        // Toggling retargets from the current position and velocity, so the knob reverses
+        // smoothly when it's toggled mid-animation.
+        if state.position.target() != &self.position() {
+            state.position.set_target(self.position());
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width);
+
+        layout::next_to_each_other(
+            &limits,
+            self.spacing,
+            |_| layout::Node::new(Size::new(2.0 * self.size, self.size)),
+            |limits| {
+                let Some(label) = self.label.as_deref() else {
+                    return layout::Node::new(Size::ZERO);
+                };
+                let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+                core_widget::layout(
+                    &mut state.paragraph,
+                    renderer,
+                    limits,
+                    self.width,
+                    Length::Shrink,
+                    label,
+                    self.text_line_height,
+                    self.text_size,
+                    self.font,
+                    self.text_alignment,
+                    alignment::Vertical::Top,
+                    self.text_shaping,
+                    self.text_wrapping,
+                )
+            },
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+            state.animated_state.resume_at(now);
+            state.position.resume_at(now);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        // Redraw anytime the status changes or the knob is still sliding.
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let status = self.get_status(cursor, layout);
+        if state.animated_state.needs_redraw(status) || state.position.needs_tick() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.animated_state.tick(now);
+                state.position.tick(now);
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(on_toggle) = &self.on_toggle {
+                    if cursor.is_over(layout.bounds()) {
+                        shell.publish(on_toggle(!self.is_toggled));
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) && self.on_toggle.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let style = state
+            .animated_state
+            .current_value(|status| theme.style(&self.class, *status).into());
+        let mut children = layout.children();
+
+        let bounds = children.next().unwrap().bounds();
+        let border_radius = bounds.height / BORDER_RADIUS_RATIO;
+        let space = SPACE_RATIO * bounds.height;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + space,
+                    y: bounds.y + space,
+                    width: bounds.width - 2.0 * space,
+                    height: bounds.height - 2.0 * space,
+                },
+                border: Border {
+                    radius: border_radius.into(),
+                    width: style.background_border_width,
+                    color: style.background_border_color,
+                },
+                ..Default::default()
+            },
+            style.background,
+        );
+
+        // The knob travels between the insets at either end of the track.
+        let knob_size = bounds.height - 4.0 * space;
+        let travel = bounds.width - 4.0 * space - knob_size;
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + 2.0 * space + travel * state.position.value(),
+                    y: bounds.y + 2.0 * space,
+                    width: knob_size,
+                    height: knob_size,
+                },
+                border: Border {
+                    radius: border_radius.into(),
+                    width: style.foreground_border_width,
+                    color: style.foreground_border_color,
+                },
+                ..Default::default()
+            },
+            style.foreground,
+        );
+
+        if self.label.is_some() {
+            core_widget::draw(
+                renderer,
+                renderer_style,
+                children.next().unwrap(),
+                state.paragraph.0.raw(),
+                iced::widget::text::Style::default(),
+                viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Toggler<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: core_text::Renderer + 'a,
+{
+    fn from(toggler: Toggler<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(toggler)
+    }
+}
+
+/// Creates a new [`Toggler`] with the given toggled state.
+pub fn toggler<'a, Message, Theme, Renderer>(
+    is_toggled: bool,
+) -> Toggler<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: core_text::Renderer,
+{
+    Toggler::new(is_toggled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spring::Motion;
+    use std::time::{Duration, Instant};
+
+    fn toggler(is_toggled: bool) -> Toggler<'static, bool, iced::Theme, ()> {
+        Toggler::new(is_toggled)
+            .on_toggle(|is_toggled| is_toggled)
+            .animation(Motion::BOUNCY)
+    }
+
+    fn position(tree: &Tree) -> f32 {
+        *tree.state.downcast_ref::<State<()>>().position.value()
+    }
+
+    fn tick(tree: &mut Tree, now: Instant) {
+        tree.state.downcast_mut::<State<()>>().position.tick(now);
+    }
+
+    /// Toggling rapidly should reverse the knob from its current position without jumping.
+    #[test]
+    fn knob_reverses_smoothly_mid_animation() {
+        let off = toggler(false);
+        let on = toggler(true);
+        let mut tree = Tree::new(&off as &dyn Widget<bool, iced::Theme, ()>);
+        let start = Instant::now();
+        let mut positions = vec![position(&tree)];
+
+        // Toggle on, then back off and on again before the knob ever settles.
+        for (frame, widget) in [(0, &on), (6, &off), (12, &on)] {
+            tree.diff(widget as &dyn Widget<bool, iced::Theme, ()>);
+            assert_eq!(position(&tree), *positions.last().unwrap());
+
+            for ms in (frame..frame + 6).map(|frame| frame * 16) {
+                tick(&mut tree, start + Duration::from_millis(ms));
+                positions.push(position(&tree));
+            }
+        }
+
+        let largest_step = positions
+            .windows(2)
+            .map(|pair| (pair[1] - pair[0]).abs())
+            .fold(0.0, f32::max);
+        assert!(positions.iter().any(|&position| position > 0.0));
+        assert!(largest_step < 0.25, "the knob jumped by {largest_step}");
+
+        for ms in (18..200).map(|frame| frame * 16) {
+            tick(&mut tree, start + Duration::from_millis(ms));
+        }
+        assert_eq!(position(&tree), 1.0);
+    }
+}
@@ -0,0 +1,702 @@
+//! An animated tooltip that fades and slides in after hovering its content for a moment.
+//!
+//! Unlike the tooltips in Iced, which appear as soon as their content is hovered, these wait for
+//! a configurable [`show_delay`](Tooltip::show_delay) before fading in, and a
+//! [`hide_delay`](Tooltip::hide_delay) after the cursor leaves before fading out. Moving the
+//! cursor away while the tooltip is still fading in reverses it right away:
+//!
+//! ```no_run
+//! # use iced::{Element, widget::text};
+//! # use iced_anim::{transition::Easing, widget::tooltip::{self, tooltip}};
+//! # use std::time::Duration;
+//! # #[derive(Clone)] enum Message {}
+//! # fn view<'a>() -> Element<'a, Message> {
+//! tooltip(text("Hover me"), text("Tooltip contents"), tooltip::Position::Bottom)
+//!     .show_delay(Duration::from_millis(300))
+//!     .animation(Easing::EASE_OUT.with_duration(Duration::from_millis(150)))
+//!     .into()
+//! # }
+//! ```
+//!
+//! > Note: Iced can't change the opacity of arbitrary content, so the tooltip fades its
+//! > [`style`](Tooltip::style) and the text color its contents inherit. Contents with their own
+//! > colors won't fade with it.
+use std::time::{Duration, Instant};
+
+use crate::{animated::Mode, Animated};
+use iced::{
+    advanced::{
+        layout, mouse, overlay, renderer, text,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    widget::container,
+    window, Border, Element, Event, Length, Padding, Pixels, Point, Rectangle, Size, Vector,
+};
+
+// Re-export the widget types for convenience
+pub use iced::widget::container::{Catalog, Style, StyleFn};
+pub use iced::widget::tooltip::Position;
+
+/// How far a hidden tooltip is shifted towards its content, in pixels.
+const SLIDE_DISTANCE: f32 = 4.0;
+
+/// An element that fades and slides in a tooltip over some content when it's hovered.
+#[allow(missing_debug_implementations)]
+pub struct Tooltip<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    tooltip: Element<'a, Message, Theme, Renderer>,
+    position: Position,
+    gap: f32,
+    padding: f32,
+    snap_within_viewport: bool,
+    class: Theme::Class<'a>,
+    show_delay: Duration,
+    hide_delay: Duration,
+    mode: Mode,
+}
+
+impl<'a, Message, Theme, Renderer> Tooltip<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default padding of a [`Tooltip`].
+    const DEFAULT_PADDING: f32 = 5.0;
+
+    /// Creates a new [`Tooltip`] that shows the `tooltip` at the given `position` relative to
+    /// the `content`.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        tooltip: impl Into<Element<'a, Message, Theme, Renderer>>,
+        position: Position,
+    ) -> Self {
+        Tooltip {
+            content: content.into(),
+            tooltip: tooltip.into(),
+            position,
+            gap: 0.0,
+            padding: Self::DEFAULT_PADDING,
+            snap_within_viewport: true,
+            class: Theme::default(),
+            show_delay: Duration::ZERO,
+            hide_delay: Duration::ZERO,
+            mode: Mode::default(),
+        }
+    }
+
+    /// Sets the gap between the content and its [`Tooltip`].
+    pub fn gap(mut self, gap: impl Into<Pixels>) -> Self {
+        self.gap = gap.into().0;
+        self
+    }
+
+    /// Sets the padding of the [`Tooltip`].
+    pub fn padding(mut self, padding: impl Into<Pixels>) -> Self {
+        self.padding = padding.into().0;
+        self
+    }
+
+    /// Sets whether the [`Tooltip`] is snapped within the viewport.
+    pub fn snap_within_viewport(mut self, snap: bool) -> Self {
+        self.snap_within_viewport = snap;
+        self
+    }
+
+    /// Sets how long the content needs to be hovered before the [`Tooltip`] fades in.
+    pub fn show_delay(mut self, delay: Duration) -> Self {
+        self.show_delay = delay;
+        self
+    }
+
+    /// Sets how long the [`Tooltip`] stays after the cursor leaves the content before it
+    /// fades out.
+    pub fn hide_delay(mut self, delay: Duration) -> Self {
+        self.hide_delay = delay;
+        self
+    }
+
+    /// Sets the animation of the [`Tooltip`] as it fades in and out.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+
+    /// Sets the style of the [`Tooltip`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Tooltip`].
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+}
+
+/// The state of a [`Tooltip`], which tracks the hover delay and how visible the tooltip is.
+#[derive(Debug)]
+struct State {
+    /// Whether the cursor is over the content.
+    is_hovered: bool,
+    /// When the cursor last entered or left the content.
+    changed_at: Instant,
+    /// Where the cursor was last seen over the content, for [`Position::FollowCursor`].
+    cursor_position: Point,
+    /// How visible the tooltip is, from 0 when hidden to 1 when shown.
+    opacity: Animated<f32>,
+    /// The animation mode of the opacity.
+    mode: Mode,
+}
+
+impl State {
+    fn new(mode: Mode, now: Instant) -> Self {
+        Self {
+            is_hovered: false,
+            changed_at: now,
+            cursor_position: Point::ORIGIN,
+            opacity: Animated::new_at(0.0, mode, now),
+            mode,
+        }
+    }
+
+    /// Records whether the content `is_hovered` as of `now`, restarting the delay if it changed.
+    fn hover(&mut self, is_hovered: bool, now: Instant) {
+        if self.is_hovered != is_hovered {
+            self.is_hovered = is_hovered;
+            self.changed_at = now;
+        }
+    }
+
+    /// Fades the tooltip in or out once the delay since the hover last changed has passed,
+    /// returning when the delay passes if it hasn't yet.
+    fn sync(
+        &mut self,
+        now: Instant,
+        show_delay: Duration,
+        hide_delay: Duration,
+    ) -> Option<Instant> {
+        let target = if self.is_hovered { 1.0 } else { 0.0 };
+        if *self.opacity.target() == target {
+            return None;
+        }
+
+        // A tooltip that's still fading reverses right away instead of waiting again.
+        let delay = if self.opacity.is_animating() {
+            Duration::ZERO
+        } else if self.is_hovered {
+            show_delay
+        } else {
+            hide_delay
+        };
+
+        let due = self.changed_at + delay;
+        if now >= due {
+            self.opacity.schedule_target(target, now);
+            self.opacity.tick(now);
+            None
+        } else {
+            Some(due)
+        }
+    }
+
+    /// Whether any part of the tooltip is visible.
+    fn is_visible(&self) -> bool {
+        self.opacity.is_animating() || *self.opacity.value() > 0.0
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tooltip<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self.mode, crate::clock::now()))
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content), Tree::new(&self.tooltip)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        if state.mode != self.mode {
+            state.mode = self.mode;
+            state.opacity.apply(self.mode);
+        }
+        tree.diff_children(&[self.content.as_widget(), self.tooltip.as_widget()]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            let state = tree.state.downcast_mut::<State>();
+            state.opacity.resume_at(now);
+        }
+
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let was_visible = state.is_visible();
+
+        let now = match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                state.opacity.tick(now);
+                now
+            }
+            _ => crate::clock::now(),
+        };
+
+        if let Some(position) = cursor.position_over(layout.bounds()) {
+            state.cursor_position = position;
+        }
+        state.hover(cursor.is_over(layout.bounds()), now);
+
+        if let Some(due) = state.sync(now, self.show_delay, self.hide_delay) {
+            shell.request_redraw(window::RedrawRequest::At(due));
+        }
+        if state.opacity.needs_tick() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        // The tooltip overlay is only laid out while it's visible.
+        if was_visible != state.is_visible() {
+            shell.invalidate_layout();
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_ref::<State>();
+        let is_visible = state.is_visible();
+        let opacity = state.opacity.value().clamp(0.0, 1.0);
+        let cursor_position = state.cursor_position;
+
+        let mut children = tree.children.iter_mut();
+
+        let content = self.content.as_widget_mut().overlay(
+            children.next().unwrap(),
+            layout,
+            renderer,
+            translation,
+        );
+
+        let tooltip = is_visible.then(|| {
+            overlay::Element::new(Box::new(Overlay {
+                position: layout.position() + translation,
+                tooltip: &self.tooltip,
+                state: children.next().unwrap(),
+                cursor_position,
+                content_bounds: layout.bounds(),
+                snap_within_viewport: self.snap_within_viewport,
+                positioning: self.position,
+                gap: self.gap,
+                padding: self.padding,
+                class: &self.class,
+                opacity,
+            }))
+        });
+
+        if content.is_some() || tooltip.is_some() {
+            Some(
+                overlay::Group::with_children(content.into_iter().chain(tooltip).collect())
+                    .overlay(),
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Tooltip<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(tooltip: Tooltip<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(tooltip)
+    }
+}
+
+/// Creates a new [`Tooltip`] that shows the `tooltip` at the given `position` relative to the
+/// `content` after it's hovered.
+pub fn tooltip<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    tooltip: impl Into<Element<'a, Message, Theme, Renderer>>,
+    position: Position,
+) -> Tooltip<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    Tooltip::new(content, tooltip, position)
+}
+
+/// Fades every color of a tooltip `style` by the given `opacity`.
+fn fade(style: Style, opacity: f32) -> Style {
+    Style {
+        text_color: style.text_color.map(|color| color.scale_alpha(opacity)),
+        background: style
+            .background
+            .map(|background| background.scale_alpha(opacity)),
+        border: Border {
+            color: style.border.color.scale_alpha(opacity),
+            ..style.border
+        },
+        shadow: iced::Shadow {
+            color: style.shadow.color.scale_alpha(opacity),
+            ..style.shadow
+        },
+    }
+}
+
+/// The direction a hidden tooltip is shifted in, towards its content.
+fn slide_direction(position: Position) -> Vector {
+    match position {
+        Position::Top | Position::FollowCursor => Vector::new(0.0, 1.0),
+        Position::Bottom => Vector::new(0.0, -1.0),
+        Position::Left => Vector::new(1.0, 0.0),
+        Position::Right => Vector::new(-1.0, 0.0),
+    }
+}
+
+/// The overlay that draws the tooltip with its current opacity.
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    position: Point,
+    tooltip: &'b Element<'a, Message, Theme, Renderer>,
+    state: &'b mut Tree,
+    cursor_position: Point,
+    content_bounds: Rectangle,
+    snap_within_viewport: bool,
+    positioning: Position,
+    gap: f32,
+    padding: f32,
+    class: &'b Theme::Class<'a>,
+    opacity: f32,
+}
+
+impl<'a, 'b, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        let viewport = Rectangle::with_size(bounds);
+
+        let tooltip_layout = self.tooltip.as_widget().layout(
+            self.state,
+            renderer,
+            &layout::Limits::new(
+                Size::ZERO,
+                if self.snap_within_viewport {
+                    viewport.size()
+                } else {
+                    Size::INFINITY
+                },
+            )
+            .shrink(Padding::new(self.padding)),
+        );
+
+        let text_bounds = tooltip_layout.bounds();
+        let x_center = self.position.x + (self.content_bounds.width - text_bounds.width) / 2.0;
+        let y_center = self.position.y + (self.content_bounds.height - text_bounds.height) / 2.0;
+
+        let offset = match self.positioning {
+            Position::Top => Vector::new(
+                x_center,
+                self.position.y - text_bounds.height - self.gap - self.padding,
+            ),
+            Position::Bottom => Vector::new(
+                x_center,
+                self.position.y + self.content_bounds.height + self.gap + self.padding,
+            ),
+            Position::Left => Vector::new(
+                self.position.x - text_bounds.width - self.gap - self.padding,
+                y_center,
+            ),
+            Position::Right => Vector::new(
+                self.position.x + self.content_bounds.width + self.gap + self.padding,
+                y_center,
+            ),
+            Position::FollowCursor => {
+                let translation = self.position - self.content_bounds.position();
+                Vector::new(
+                    self.cursor_position.x,
+                    self.cursor_position.y - text_bounds.height,
+                ) + translation
+            }
+        };
+
+        let mut tooltip_bounds = Rectangle {
+            x: offset.x - self.padding,
+            y: offset.y - self.padding,
+            width: text_bounds.width + self.padding * 2.0,
+            height: text_bounds.height + self.padding * 2.0,
+        };
+
+        if self.snap_within_viewport {
+            tooltip_bounds.x = tooltip_bounds
+                .x
+                .min(viewport.x + viewport.width - tooltip_bounds.width)
+                .max(viewport.x);
+            tooltip_bounds.y = tooltip_bounds
+                .y
+                .min(viewport.y + viewport.height - tooltip_bounds.height)
+                .max(viewport.y);
+        }
+
+        layout::Node::with_children(
+            tooltip_bounds.size(),
+            vec![tooltip_layout.translate(Vector::new(self.padding, self.padding))],
+        )
+        .translate(Vector::new(tooltip_bounds.x, tooltip_bounds.y))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        inherited_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let style = fade(theme.style(self.class), self.opacity);
+        let defaults = renderer::Style {
+            text_color: style
+                .text_color
+                .unwrap_or(inherited_style.text_color.scale_alpha(self.opacity)),
+        };
+        let slide = slide_direction(self.positioning) * (SLIDE_DISTANCE * (1.0 - self.opacity));
+
+        renderer.with_translation(slide, |renderer| {
+            container::draw_background(renderer, &style, layout.bounds());
+            self.tooltip.as_widget().draw(
+                self.state,
+                renderer,
+                theme,
+                &defaults,
+                layout.children().next().unwrap(),
+                cursor,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+        });
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _renderer: &Renderer, _cursor_position: Point) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transition::Easing;
+
+    const DELAY: Duration = Duration::from_millis(300);
+
+    fn after(start: Instant, millis: u64) -> Instant {
+        start + Duration::from_millis(millis)
+    }
+
+    /// The tooltip should only fade in once the content has been hovered for the show delay.
+    #[test]
+    fn shows_after_delay() {
+        let start = Instant::now();
+        let mut state = State::new(Easing::LINEAR.into(), start);
+
+        state.hover(true, start);
+        assert_eq!(
+            state.sync(after(start, 100), DELAY, DELAY),
+            Some(after(start, 300))
+        );
+        assert!(!state.is_visible());
+
+        assert_eq!(state.sync(after(start, 300), DELAY, DELAY), None);
+        assert!(state.is_visible());
+        assert_eq!(*state.opacity.target(), 1.0);
+    }
+
+    /// Leaving before the show delay passes should never show the tooltip.
+    #[test]
+    fn leaving_cancels_delay() {
+        let start = Instant::now();
+        let mut state = State::new(Easing::LINEAR.into(), start);
+
+        state.hover(true, start);
+        state.sync(after(start, 100), DELAY, DELAY);
+        state.hover(false, after(start, 200));
+        assert_eq!(state.sync(after(start, 400), DELAY, DELAY), None);
+        assert!(!state.is_visible());
+    }
+
+    /// Moving away while the tooltip fades in should reverse it from its current opacity
+    /// without waiting for the hide delay.
+    #[test]
+    fn reverses_while_fading_in() {
+        let start = Instant::now();
+        let mut state = State::new(Easing::LINEAR.into(), start);
+
+        state.hover(true, start);
+        state.sync(start, Duration::ZERO, DELAY);
+        state.opacity.tick(after(start, 50));
+        let opacity = *state.opacity.value();
+        assert!(opacity > 0.0 && opacity < 1.0, "{opacity}");
+
+        state.hover(false, after(start, 50));
+        assert_eq!(state.sync(after(start, 50), Duration::ZERO, DELAY), None);
+        assert_eq!(*state.opacity.target(), 0.0);
+
+        state.opacity.tick(after(start, 60));
+        assert!(*state.opacity.value() < opacity);
+    }
+
+    /// A fully shown tooltip should wait for the hide delay before fading out.
+    #[test]
+    fn hides_after_delay() {
+        let start = Instant::now();
+        let mut state = State::new(Easing::LINEAR.into(), start);
+
+        state.hover(true, start);
+        state.sync(start, Duration::ZERO, DELAY);
+        state.opacity.settle();
+
+        state.hover(false, after(start, 1000));
+        assert_eq!(
+            state.sync(after(start, 1100), Duration::ZERO, DELAY),
+            Some(after(start, 1300))
+        );
+        assert_eq!(*state.opacity.target(), 1.0);
+        assert_eq!(state.sync(after(start, 1300), Duration::ZERO, DELAY), None);
+        assert_eq!(*state.opacity.target(), 0.0);
+    }
+
+    /// Fading a style should scale the alpha of every color.
+    #[test]
+    fn fade_style() {
+        let style = Style::default()
+            .background(iced::Color::BLACK)
+            .color(iced::Color::WHITE);
+        let faded = fade(style, 0.25);
+        assert_eq!(faded.text_color.unwrap().a, 0.25);
+        assert_eq!(
+            faded.background,
+            Some(iced::Color::BLACK.scale_alpha(0.25).into())
+        );
+    }
+}
@@ -0,0 +1,438 @@
+//! A wrapper that animates the scale and translation of its content.
+//!
+//! The content is laid out as usual and only drawn with its animated [`Transform`], so scaling
+//! it up on hover or sliding it around doesn't move the widgets around it:
+//!
+//! ```rust
+//! # use iced::{Element, widget::button};
+//! # use iced_anim::{spring::Motion, widget::transform::{transform, Transform}};
+//! # #[derive(Clone)] enum Message { Pressed }
+//! # fn view<'a>(is_hovered: bool) -> Element<'a, Message> {
+//! let scale = if is_hovered { 1.1 } else { 1.0 };
+//! transform(button("Hover me").on_press(Message::Pressed))
+//!     .transform(Transform::scale(scale))
+//!     .animation(Motion::BOUNCY)
+//!     .into()
+//! # }
+//! ```
+//!
+//! The cursor is mapped through the transform, so the content receives events where it's drawn
+//! rather than where it was laid out.
+//!
+//! > Note: Iced's renderer can only scale and translate content, so there's no rotation yet.
+//! > Overlays of the content, like menus, follow its translation but not its scale.
+use crate::{animated::Mode, Animate, Animated};
+use iced::{
+    advanced::{
+        graphics::core::event,
+        layout, mouse, overlay, renderer,
+        widget::{tree, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    window, Element, Event, Length, Point, Rectangle, Size, Transformation, Vector,
+};
+
+/// Scales smaller than this can't be inverted, so the content can't be interacted with.
+const MIN_SCALE: f32 = 1e-3;
+
+/// A uniform scale and translation applied to some content when it's drawn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    /// How much the content is scaled around its origin, where `1.0` is its original size.
+    pub scale: f32,
+    /// How far the content is moved from its layout position, in logical pixels.
+    pub translation: Vector,
+}
+
+impl Transform {
+    /// A [`Transform`] that draws the content exactly where it was laid out.
+    pub const IDENTITY: Self = Self {
+        scale: 1.0,
+        translation: Vector::ZERO,
+    };
+
+    /// Creates a [`Transform`] that scales the content by the given factor.
+    pub fn scale(scale: f32) -> Self {
+        Self {
+            scale,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Creates a [`Transform`] that moves the content by the given `translation`.
+    pub fn translate(translation: Vector) -> Self {
+        Self {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Returns the [`Transformation`] that draws content with this transform around `origin`.
+    fn around(self, origin: Point) -> Transformation {
+        let origin = Vector::new(origin.x, origin.y);
+        let center = origin + self.translation;
+        Transformation::translate(center.x, center.y)
+            * Transformation::scale(self.scale)
+            * Transformation::translate(-origin.x, -origin.y)
+    }
+
+    /// Maps a `point` where the content is drawn back to where it was laid out, or `None` if the
+    /// content is scaled too small to be interacted with.
+    fn invert(self, point: Point, origin: Point) -> Option<Point> {
+        if self.scale.abs() < MIN_SCALE {
+            return None;
+        }
+        let offset = (point - origin - self.translation) * (1.0 / self.scale);
+        Some(origin + offset)
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Animate for Transform {
+    fn components() -> usize {
+        f32::components() + Vector::<f32>::components()
+    }
+
+    fn distance_to(&self, end: &Self) -> Vec<f32> {
+        [
+            self.scale.distance_to(&end.scale),
+            self.translation.distance_to(&end.translation),
+        ]
+        .concat()
+    }
+
+    fn update(&mut self, components: &mut impl Iterator<Item = f32>) {
+        self.scale.update(components);
+        self.translation.update(components);
+    }
+
+    fn lerp(&mut self, start: &Self, end: &Self, progress: f32) {
+        self.scale.lerp(&start.scale, &end.scale, progress);
+        self.translation
+            .lerp(&start.translation, &end.translation, progress);
+    }
+}
+
+/// A wrapper that animates the scale and translation of its content.
+pub struct TransformWidget<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer> {
+    /// The content that's drawn with the animated transform.
+    content: Element<'a, Message, Theme, Renderer>,
+    /// The transform that the content animates towards.
+    transform: Transform,
+    /// The point the content is scaled around, relative to its size.
+    origin: Point,
+    /// The animation used to move between transforms.
+    mode: Mode,
+}
+
+impl<'a, Message, Theme, Renderer> TransformWidget<'a, Message, Theme, Renderer> {
+    /// Creates a new [`TransformWidget`] that draws the `content` without any transform.
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            transform: Transform::IDENTITY,
+            origin: Point::new(0.5, 0.5),
+            mode: Mode::default(),
+        }
+    }
+
+    /// Sets the [`Transform`] of the content, which is animated to whenever it changes.
+    pub fn transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// Sets the point the content is scaled around, relative to its size.
+    ///
+    /// `Point::new(0.0, 0.0)` is the top left corner of the content and `Point::new(1.0, 1.0)`
+    /// is its bottom right corner. Content is scaled around its center by default.
+    pub fn origin(mut self, origin: Point) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Sets the animation used to move between transforms.
+    pub fn animation(mut self, mode: impl Into<Mode>) -> Self {
+        self.mode = mode.into();
+        self
+    }
+
+    /// The absolute origin of the transform for content laid out within `bounds`.
+    fn origin_in(&self, bounds: Rectangle) -> Point {
+        Point::new(
+            bounds.x + bounds.width * self.origin.x,
+            bounds.y + bounds.height * self.origin.y,
+        )
+    }
+
+    /// Maps the `cursor` to where the content was laid out.
+    fn content_cursor(
+        &self,
+        tree: &Tree,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Cursor {
+        let transform = *tree.state.downcast_ref::<State>().transform.value();
+        cursor
+            .position()
+            .and_then(|position| transform.invert(position, self.origin_in(bounds)))
+            .map_or(mouse::Cursor::Unavailable, mouse::Cursor::Available)
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    /// The transform the content is currently drawn with.
+    transform: Animated<Transform>,
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for TransformWidget<'a, Message, Theme, Renderer>
+where
+    Renderer: iced::advanced::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            transform: Animated::new(self.transform, self.mode),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        if state.transform.mode() != self.mode {
+            state.transform.apply(self.mode);
+        }
+        if state.transform.target() != &self.transform {
+            state.transform.set_target(self.transform);
+        }
+
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(now) = crate::resume::requested(operation) {
+            tree.state.downcast_mut::<State>().transform.resume_at(now);
+        }
+
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.transform.tick(now);
+        }
+        if state.transform.needs_tick() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        let cursor = self.content_cursor(tree, layout.bounds(), cursor);
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let transform = *tree.state.downcast_ref::<State>().transform.value();
+        let origin = self.origin_in(bounds);
+        let cursor = self.content_cursor(tree, bounds, cursor);
+
+        // Content that's scaled up or moved may be drawn outside of its layout bounds, so it's
+        // drawn within the whole viewport instead of being culled.
+        renderer.with_transformation(transform.around(origin), |renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                &Rectangle::with_size(Size::INFINITY),
+            );
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let cursor = self.content_cursor(tree, layout.bounds(), cursor);
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let offset = tree
+            .state
+            .downcast_ref::<State>()
+            .transform
+            .value()
+            .translation;
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            translation + offset,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<TransformWidget<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: iced::advanced::Renderer + 'a,
+{
+    fn from(transform: TransformWidget<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(transform)
+    }
+}
+
+/// Creates a new [`TransformWidget`] that animates the scale and translation of the `content`.
+pub fn transform<'a, Message, Theme, Renderer>(
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> TransformWidget<'a, Message, Theme, Renderer> {
+    TransformWidget::new(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transition::Easing;
+    use std::time::{Duration, Instant};
+
+    const ORIGIN: Point = Point::new(50.0, 50.0);
+
+    /// Points should map back to where they were laid out, undoing both scale and translation.
+    #[test]
+    fn invert_round_trip() {
+        let transform = Transform {
+            scale: 2.0,
+            translation: Vector::new(10.0, -5.0),
+        };
+        let point = Point::new(60.0, 40.0);
+        let drawn = point * transform.around(ORIGIN);
+        assert_eq!(drawn, Point::new(80.0, 25.0));
+        assert_eq!(transform.invert(drawn, ORIGIN), Some(point));
+    }
+
+    /// The origin should stay in place while the content is scaled around it.
+    #[test]
+    fn scales_around_origin() {
+        let transform = Transform::scale(1.1);
+        assert_eq!(ORIGIN * transform.around(ORIGIN), ORIGIN);
+    }
+
+    /// Content that's scaled down to nothing can't be interacted with.
+    #[test]
+    fn zero_scale_has_no_cursor() {
+        assert_eq!(Transform::scale(0.0).invert(ORIGIN, ORIGIN), None);
+    }
+
+    /// Transforms should animate their scale and translation together.
+    #[test]
+    fn animates_scale_and_translation() {
+        let now = Instant::now();
+        let mut transform = Animated::new_at(Transform::IDENTITY, Easing::LINEAR, now);
+        transform.schedule_target(
+            Transform {
+                scale: 2.0,
+                translation: Vector::new(10.0, 20.0),
+            },
+            now,
+        );
+        transform.tick(now);
+        transform.tick(now + Easing::LINEAR.duration / 2);
+
+        let value = transform.value();
+        assert!((value.scale - 1.5).abs() < 1e-3, "{value:?}");
+        assert!((value.translation.x - 5.0).abs() < 1e-3, "{value:?}");
+        assert!((value.translation.y - 10.0).abs() < 1e-3, "{value:?}");
+
+        transform.tick(now + Easing::LINEAR.duration + Duration::from_millis(1));
+        assert!(!transform.is_animating());
+    }
+}
@@ -0,0 +1,98 @@
+//! Helpers for animating the size of a window, e.g. when switching between a compact and an
+//! expanded layout.
+//!
+//! Keep the window size in an [`Animated<Size<u32>>`](crate::Animated), tick it every frame while
+//! it's animating, and return a [`resize`] task afterwards:
+//!
+//! ```rust,no_run
+//! # use iced::{window, Size, Task};
+//! # use iced_anim::{spring::Motion, Animated};
+//! # use std::time::Instant;
+//! # enum Message { Tick(Instant) }
+//! # struct App { id: window::Id, size: Animated<Size<u32>> }
+//! # impl App {
+//! fn update(&mut self, message: Message) -> Task<Message> {
+//!     match message {
+//!         Message::Tick(now) => {
+//!             self.size.tick(now);
+//!             iced_anim::window::resize(self.id, &self.size)
+//!         }
+//!     }
+//! }
+//! # }
+//! ```
+//!
+//! Iced can't query the size of the monitor a window is on, so use [`resize_within`] with bounds
+//! you already know to keep bouncy springs from overshooting the screen.
+use iced::{window, Size, Task};
+
+use crate::Animated;
+
+/// Returns a task that resizes the window with the given `id` to the current value of `size`.
+///
+/// Once `size` has stopped animating, the window is resized to its exact target.
+pub fn resize<T>(id: window::Id, size: &Animated<Size<u32>>) -> Task<T> {
+    window::resize(id, logical(current(size)))
+}
+
+/// Returns a task that resizes the window with the given `id` to the current value of `size`,
+/// clamped so it never grows larger than `bounds`, e.g. the size of the monitor.
+pub fn resize_within<T>(id: window::Id, size: &Animated<Size<u32>>, bounds: Size<u32>) -> Task<T> {
+    window::resize(id, logical(clamp(current(size), bounds)))
+}
+
+/// The size the window should have for the current frame of the animation.
+fn current(size: &Animated<Size<u32>>) -> Size<u32> {
+    if size.is_animating() {
+        *size.value()
+    } else {
+        *size.target()
+    }
+}
+
+/// Limits each dimension of `size` to `bounds`.
+fn clamp(size: Size<u32>, bounds: Size<u32>) -> Size<u32> {
+    Size::new(size.width.min(bounds.width), size.height.min(bounds.height))
+}
+
+fn logical(size: Size<u32>) -> Size {
+    Size::new(size.width as f32, size.height as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{spring::Motion, transition::Easing};
+    use std::time::{Duration, Instant};
+
+    /// A settled animation should resize the window to its exact target.
+    #[test]
+    fn settles_at_target() {
+        let mut size = Animated::spring(Size::new(400, 300), Motion::BOUNCY);
+        size.set_target(Size::new(800, 600));
+        size.settle();
+        assert_eq!(current(&size), Size::new(800, 600));
+    }
+
+    /// Sizes between two whole-pixel sizes should be rounded to whole pixels.
+    #[test]
+    fn animates_whole_pixels() {
+        let now = Instant::now();
+        let mut size = Animated::new_at(Size::new(400, 300), Easing::LINEAR, now);
+        size.schedule_target(Size::new(801, 601), now);
+        size.tick(now + Easing::LINEAR.duration / 2);
+        assert!(size.is_animating());
+        assert_eq!(current(&size), Size::new(601, 451));
+
+        size.tick(now + Easing::LINEAR.duration + Duration::from_millis(1));
+        assert_eq!(current(&size), Size::new(801, 601));
+    }
+
+    /// Springs that overshoot their target shouldn't grow the window past its bounds.
+    #[test]
+    fn clamps_to_bounds() {
+        let bounds = Size::new(1920, 1080);
+        assert_eq!(clamp(Size::new(2000, 900), bounds), Size::new(1920, 900));
+        assert_eq!(clamp(Size::new(800, 1200), bounds), Size::new(800, 1080));
+    }
+}
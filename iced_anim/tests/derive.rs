@@ -0,0 +1,455 @@
+//! Compile-time coverage for `#[derive(Animate)]` on less common struct and enum shapes.
+#![cfg(feature = "derive")]
+
+use std::marker::PhantomData;
+
+use iced_anim::Animate;
+
+/// A marker type that doesn't implement `Animate` itself.
+#[derive(Debug, Clone, PartialEq)]
+struct Tag;
+
+#[derive(Animate, Debug, Clone, PartialEq)]
+struct Styled<const N: usize> {
+    values: [f32; N],
+    _marker: PhantomData<Tag>,
+}
+
+#[derive(Animate, Debug, Clone, PartialEq)]
+struct Tagged {
+    value: f32,
+    _tag: PhantomData<Tag>,
+}
+
+#[derive(Animate, Debug, Clone, PartialEq)]
+enum Shape {
+    Circle { radius: f32 },
+    Rect { size: iced::Size },
+}
+
+#[derive(Animate, Debug, Clone, PartialEq)]
+enum Fill {
+    None,
+    Solid(iced::Color),
+}
+
+#[derive(Animate, Debug, Clone, PartialEq)]
+struct Card {
+    #[animate(skip)]
+    id: usize,
+    #[animate(skip)]
+    label: String,
+    width: f32,
+    color: iced::Color,
+}
+
+#[derive(Animate, Debug, Clone, PartialEq)]
+enum Item {
+    Labeled(#[animate(skip)] String, f32),
+    Sized {
+        #[animate(skip)]
+        id: usize,
+        size: iced::Size,
+    },
+}
+
+#[derive(Animate, Debug, Clone, PartialEq)]
+struct Wrapper<T> {
+    inner: T,
+    opacity: f32,
+}
+
+#[derive(Animate, Debug, Clone, PartialEq)]
+struct Bounded<T, U>
+where
+    T: Copy,
+{
+    values: [T; 2],
+    #[animate(skip)]
+    label: U,
+}
+
+/// Const generics should be forwarded to the impl, and markers add no components.
+#[test]
+fn const_generic_array_components() {
+    assert_eq!(Styled::<3>::components(), <[f32; 3]>::components());
+    assert_eq!(Styled::<5>::components(), 5);
+}
+
+#[test]
+fn tagged_newtype_components() {
+    assert_eq!(Tagged::components(), f32::components());
+}
+
+#[test]
+fn const_generic_lerp() {
+    let start = Styled {
+        values: [0.0, 0.0],
+        _marker: PhantomData,
+    };
+    let end = Styled {
+        values: [2.0, 4.0],
+        _marker: PhantomData,
+    };
+
+    let mut value = start.clone();
+    value.lerp(&start, &end, 0.5);
+    assert_eq!(value.values, [1.0, 2.0]);
+}
+
+/// Enums have as many components as their largest variant.
+#[test]
+fn enum_components() {
+    assert_eq!(Shape::components(), iced::Size::<f32>::components());
+    assert_eq!(Fill::components(), iced::Color::components());
+}
+
+#[test]
+fn enum_same_variant_lerp() {
+    let start = Shape::Rect {
+        size: iced::Size::new(0.0, 10.0),
+    };
+    let end = Shape::Rect {
+        size: iced::Size::new(10.0, 20.0),
+    };
+
+    let mut value = start.clone();
+    value.lerp(&start, &end, 0.5);
+    assert_eq!(
+        value,
+        Shape::Rect {
+            size: iced::Size::new(5.0, 15.0)
+        }
+    );
+    assert_eq!(start.distance_to(&end), vec![-10.0, -10.0]);
+}
+
+/// Different variants have no distance between them and don't interpolate.
+#[test]
+fn enum_different_variants() {
+    let start = Shape::Circle { radius: 1.0 };
+    let end = Shape::Rect {
+        size: iced::Size::new(10.0, 20.0),
+    };
+    assert_eq!(start.distance_to(&end), vec![0.0; Shape::components()]);
+
+    let mut value = start.clone();
+    value.lerp(&start, &end, 0.5);
+    assert_eq!(value, start);
+}
+
+/// Smaller variants should be padded so they line up with the larger ones.
+#[test]
+fn enum_distance_is_padded() {
+    let distance = Shape::Circle { radius: 1.0 }.distance_to(&Shape::Circle { radius: 4.0 });
+    assert_eq!(distance, vec![-3.0, 0.0]);
+    assert_eq!(Fill::None.distance_to(&Fill::None), vec![0.0; 4]);
+    assert_eq!(
+        Fill::Solid(iced::Color::BLACK).distance_to(&Fill::Solid(iced::Color::WHITE)),
+        vec![-1.0, -1.0, -1.0, 0.0]
+    );
+}
+
+/// Updating a smaller variant should consume all of the enum's components so the
+/// values that follow it in a larger type stay aligned.
+#[test]
+fn enum_update_consumes_all_components() {
+    let mut value = (Shape::Circle { radius: 1.0 }, 0.0_f32);
+    let mut components = [2.0, 100.0, 3.0].into_iter();
+    value.update(&mut components);
+    assert_eq!(value, (Shape::Circle { radius: 3.0 }, 3.0));
+    assert_eq!(components.next(), None);
+}
+
+/// Enums should animate through `Animated` when staying on the same variant.
+#[test]
+fn enum_animates() {
+    use iced_anim::{transition::Easing, Animated};
+    use std::time::{Duration, Instant};
+
+    let mut shape = Animated::new(Shape::Circle { radius: 0.0 }, Easing::LINEAR);
+    shape.set_target(Shape::Circle { radius: 10.0 });
+    let start = Instant::now();
+    shape.resume_at(start);
+    shape.tick(start + Duration::from_millis(100));
+    let Shape::Circle { radius } = *shape.value() else {
+        panic!("The shape should still be a circle");
+    };
+    assert!(radius > 0.0 && radius < 10.0);
+}
+
+#[derive(Animate, Debug, Clone, PartialEq)]
+struct Chart {
+    bars: Vec<f32>,
+    opacity: f32,
+}
+
+/// Derived types should reshape their fields, so a spring can animate a field whose length
+/// changes without mixing up the fields after it.
+#[test]
+fn reshape_forwards_to_fields() {
+    use iced_anim::{spring::Motion, Animated};
+    use std::time::{Duration, Instant};
+
+    let mut chart = Animated::new(
+        Chart {
+            bars: vec![0.0],
+            opacity: 0.0,
+        },
+        Motion::SMOOTH,
+    );
+    chart.set_target(Chart {
+        bars: vec![1.0, 2.0, 3.0],
+        opacity: 1.0,
+    });
+
+    let start = Instant::now();
+    chart.resume_at(start);
+    chart.tick(start + Duration::from_millis(100));
+    assert_eq!(chart.value().bars.len(), 3);
+    assert!(chart.value().opacity > 0.0 && chart.value().opacity < 1.0);
+}
+
+fn card(id: usize, label: &str, width: f32) -> Card {
+    Card {
+        id,
+        label: label.to_owned(),
+        width,
+        color: iced::Color::BLACK,
+    }
+}
+
+/// Skipped fields don't contribute any components.
+#[test]
+fn skipped_field_components() {
+    assert_eq!(Card::components(), 1 + iced::Color::components());
+    assert_eq!(Item::components(), iced::Size::<f32>::components());
+    assert_eq!(
+        card(1, "a", 0.0).distance_to(&card(2, "b", 10.0)),
+        vec![-10.0, 0.0, 0.0, 0.0, 0.0]
+    );
+}
+
+/// Skipped fields keep their current value while the rest of the value animates.
+#[test]
+fn skipped_fields_are_untouched() {
+    let start = card(1, "start", 0.0);
+    let end = card(2, "end", 10.0);
+
+    let mut value = card(3, "value", 0.0);
+    value.lerp(&start, &end, 0.5);
+    assert_eq!(value, card(3, "value", 5.0));
+
+    let mut components = [1.0, 0.0, 0.0, 0.0, 0.0].into_iter();
+    value.update(&mut components);
+    assert_eq!(value, card(3, "value", 6.0));
+    assert_eq!(components.next(), None);
+}
+
+#[test]
+fn skipped_enum_fields_are_untouched() {
+    let start = Item::Labeled("start".to_owned(), 0.0);
+    let end = Item::Labeled("end".to_owned(), 10.0);
+    assert_eq!(start.distance_to(&end), vec![-10.0, 0.0]);
+
+    let mut value = Item::Labeled("value".to_owned(), 0.0);
+    value.lerp(&start, &end, 0.5);
+    assert_eq!(value, Item::Labeled("value".to_owned(), 5.0));
+
+    let sized = |id, width, height| Item::Sized {
+        id,
+        size: iced::Size::new(width, height),
+    };
+    assert_eq!(
+        sized(1, 0.0, 0.0).distance_to(&sized(2, 2.0, 4.0)),
+        vec![-2.0, -4.0]
+    );
+
+    let mut value = sized(3, 0.0, 0.0);
+    value.lerp(&sized(1, 0.0, 0.0), &sized(2, 2.0, 4.0), 0.5);
+    assert_eq!(value, sized(3, 1.0, 2.0));
+}
+
+/// Generic fields should get an `Animate` bound so the struct can be animated.
+#[test]
+fn generic_struct_springs() {
+    use iced_anim::Spring;
+    use std::time::{Duration, Instant};
+
+    assert_eq!(Wrapper::<f32>::components(), 2);
+    assert_eq!(Wrapper::<iced::Color>::components(), 5);
+
+    let start = Wrapper {
+        inner: 0.0_f32,
+        opacity: 0.0,
+    };
+    let end = Wrapper {
+        inner: 10.0,
+        opacity: 1.0,
+    };
+
+    let mut spring = Spring::new(start).to(end.clone());
+    let mut now = Instant::now();
+    spring.resume_at(now);
+    while spring.has_energy() {
+        now += Duration::from_millis(16);
+        spring.tick(now);
+    }
+    assert_eq!(spring.value(), &end);
+}
+
+/// Existing where clauses are kept, and skipped generic fields don't need `Animate`.
+#[test]
+fn generic_where_clause() {
+    let start = Bounded {
+        values: [0.0_f32, 2.0],
+        label: "label",
+    };
+    let end = Bounded {
+        values: [4.0, 6.0],
+        label: "label",
+    };
+
+    let mut value = start.clone();
+    value.lerp(&start, &end, 0.5);
+    assert_eq!(value.values, [2.0, 4.0]);
+    assert_eq!(Bounded::<f32, &str>::components(), 2);
+}
+
+/// Animated struct fields get the range of their components in declaration order.
+#[test]
+fn field_ranges() {
+    let card = card(0, "card", 0.0);
+    assert_eq!(card.width_range(), 0..1);
+    assert_eq!(card.color_range(), 1..5);
+
+    let wrapper = Wrapper {
+        inner: iced::Color::BLACK,
+        opacity: 1.0,
+    };
+    assert_eq!(wrapper.inner_range(), 0..4);
+    assert_eq!(wrapper.opacity_range(), 4..5);
+}
+
+/// Fields after a vector start after the components of each of its elements.
+#[test]
+fn field_ranges_after_vec() {
+    let chart = Chart {
+        bars: vec![1.0, 2.0, 3.0],
+        opacity: 1.0,
+    };
+    assert_eq!(chart.bars_range(), 0..3);
+    assert_eq!(chart.opacity_range(), 3..4);
+    assert_eq!(chart.instance_components(), 4);
+}
+
+#[derive(Animate, Debug, Clone, PartialEq)]
+enum Plot {
+    Bars(Vec<f32>),
+    Level(f32, f32),
+}
+
+/// Variants holding vectors shouldn't lose any of their distance to padding.
+#[test]
+fn enum_with_vec() {
+    let start = Plot::Bars(vec![0.0, 0.0, 0.0]);
+    let end = Plot::Bars(vec![1.0, 2.0, 3.0]);
+    assert_eq!(start.distance_to(&end), [-1.0, -2.0, -3.0]);
+    assert_eq!(start.instance_components(), 3);
+    assert_eq!(Plot::Level(0.0, 0.0).instance_components(), 2);
+    assert_eq!(start.distance_to(&Plot::Level(1.0, 1.0)), [0.0, 0.0, 0.0]);
+
+    let mut value = start.clone();
+    value.update(&mut [1.0, 2.0, 3.0].into_iter());
+    assert_eq!(value, end);
+}
+
+/// Field ranges let a spring give each field its own motion.
+#[test]
+fn field_motions() {
+    use iced_anim::{spring::Motion, Spring};
+    use std::time::{Duration, Instant};
+
+    let slow = Motion::SMOOTH.with_duration(Duration::from_secs(2));
+    let start = Wrapper {
+        inner: 0.0_f32,
+        opacity: 0.0,
+    };
+    let opacity = start.opacity_range();
+    let mut spring = Spring::new(start)
+        .to(Wrapper {
+            inner: 1.0,
+            opacity: 1.0,
+        })
+        .with_motion(Motion::SNAPPY)
+        .with_component_motion(opacity, slow);
+
+    let mut now = Instant::now();
+    spring.resume_at(now);
+    for _ in 0..10 {
+        now += Duration::from_millis(16);
+        spring.tick(now);
+    }
+    assert!(spring.value().inner > spring.value().opacity);
+}
+
+#[derive(Animate, Debug, Clone, PartialEq)]
+struct SceneNode {
+    transform: Box<iced::Point>,
+    style: std::sync::Arc<iced::Color>,
+    opacity: std::rc::Rc<f32>,
+}
+
+/// Fields behind boxes and shared pointers should animate like the values they hold.
+#[test]
+fn pointer_fields_animate() {
+    assert_eq!(
+        SceneNode::components(),
+        iced::Point::<f32>::components() + iced::Color::components() + 1
+    );
+
+    let start = SceneNode {
+        transform: Box::new(iced::Point::ORIGIN),
+        style: std::sync::Arc::new(iced::Color::BLACK),
+        opacity: std::rc::Rc::new(0.0),
+    };
+    let end = SceneNode {
+        transform: Box::new(iced::Point::new(10.0, 20.0)),
+        style: std::sync::Arc::new(iced::Color::WHITE),
+        opacity: std::rc::Rc::new(1.0),
+    };
+
+    let mut value = start.clone();
+    value.lerp(&start, &end, 0.5);
+    assert_eq!(*value.transform, iced::Point::new(5.0, 10.0));
+    assert_eq!(*value.opacity, 0.5);
+    assert_eq!(*start.style, iced::Color::BLACK);
+}
+
+#[derive(Animate, Debug, Clone, PartialEq)]
+struct Panel {
+    width: iced::Length,
+    height: iced::Length,
+}
+
+/// Fixed lengths should animate within a derived struct, while flexible ones snap.
+#[test]
+fn length_fields_animate() {
+    assert_eq!(Panel::components(), 2);
+
+    let start = Panel {
+        width: iced::Length::Fixed(100.0),
+        height: iced::Length::Shrink,
+    };
+    let end = Panel {
+        width: iced::Length::Fixed(300.0),
+        height: iced::Length::Fill,
+    };
+
+    let mut value = start.clone();
+    value.lerp(&start, &end, 0.25);
+    assert_eq!(value.width, iced::Length::Fixed(150.0));
+    assert_eq!(value.height, iced::Length::Shrink);
+    value.lerp(&start, &end, 0.75);
+    assert_eq!(value.height, iced::Length::Fill);
+}
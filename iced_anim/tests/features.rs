@@ -0,0 +1,146 @@
+//! Smoke tests for the public API available under each combination of feature flags.
+//!
+//! Each module is only compiled when its features are enabled, so running the tests with
+//! `--no-default-features`, `--all-features`, and each feature on its own covers the matrix.
+use std::time::{Duration, Instant};
+
+use iced::Element;
+use iced_anim::{
+    spring::Motion, transition::Easing, Animated, AnimationBuilder, Event, Hub, RelativePoint,
+    SharedMotion,
+};
+
+/// The core API is available without any features.
+mod core {
+    use super::*;
+
+    #[test]
+    fn animated_values() {
+        let mut spring = Animated::spring(0.0_f32, Motion::SMOOTH);
+        spring.update(Event::Target(1.0));
+        assert!(spring.is_animating());
+        spring.update(Event::Settle);
+        assert_eq!(*spring.value(), 1.0);
+
+        let mut transition = Animated::transition(iced::Color::BLACK, Easing::LINEAR);
+        transition.set_target(iced::Color::WHITE);
+        transition.tick(Instant::now() + Duration::from_secs(1));
+        assert_eq!(*transition.value(), iced::Color::WHITE);
+    }
+
+    #[test]
+    fn shared_and_batched_animations() {
+        let motion = SharedMotion::new(0.0, Easing::LINEAR);
+        motion.set_target(1.0);
+        assert!(motion.is_animating());
+
+        let hub = Hub::new();
+        let _handle = hub.register();
+        assert_eq!(hub.registered(), 1);
+    }
+
+    #[test]
+    fn builder_elements() {
+        let _: Element<'_, ()> = AnimationBuilder::new(RelativePoint::CENTER, |point| {
+            iced::widget::text(point.x).into()
+        })
+        .animation(Motion::BOUNCY)
+        .into();
+    }
+}
+
+/// Deriving `Animate` is available with the `derive` feature.
+#[cfg(feature = "derive")]
+mod derive {
+    use iced_anim::{Animate, Animated};
+
+    #[derive(Animate, Debug, Clone, PartialEq)]
+    struct Style {
+        size: f32,
+        color: iced::Color,
+    }
+
+    #[test]
+    fn derived_values_animate() {
+        let style = Style {
+            size: 1.0,
+            color: iced::Color::BLACK,
+        };
+        let animated = Animated::new(style.clone(), iced_anim::animated::Mode::default());
+        assert_eq!(Style::components(), 5);
+        assert_eq!(animated.value(), &style);
+    }
+}
+
+/// Animation modes can be serialized with the `serde` feature.
+#[cfg(feature = "serde")]
+mod serde {
+    use super::*;
+    use iced_anim::animated::Mode;
+
+    #[test]
+    fn modes_round_trip() {
+        let mode = Mode::Spring(Motion::BOUNCY);
+        let json = serde_json::to_string(&mode).unwrap();
+        assert_eq!(serde_json::from_str::<Mode>(&json).unwrap(), mode);
+    }
+}
+
+/// The animated widgets are available with the `widgets` feature.
+#[cfg(feature = "widgets")]
+mod widgets {
+    use super::*;
+    use iced::widget::text;
+    use iced_anim::widget::{
+        button, container, freeze, indeterminate_bar, marquee, relative_layer, retain_exit,
+        spinner, stepper, svg, ExitPhase,
+    };
+
+    #[test]
+    fn widget_elements() {
+        let elements: Vec<Element<'_, ()>> = vec![
+            button(text("Button")).on_press(()).into(),
+            container(text("Container")).padding(8).into(),
+            svg(svg::Handle::from_memory(Vec::new())).into(),
+            marquee(text("Marquee")).into(),
+            freeze(true, text("Frozen")).into(),
+            relative_layer(RelativePoint::CENTER, text("Layer")).into(),
+            retain_exit(0, true, |phase| text(phase.visibility()).into()).into(),
+            spinner().into(),
+            indeterminate_bar().into(),
+            iced_anim::widget::text("Text").size(20).into(),
+            iced_anim::widget::text_input("Placeholder", "").into(),
+            stepper(1.0).range(0.0..=10.0).on_change(|_| ()).into(),
+        ];
+        assert_eq!(elements.len(), 12);
+    }
+
+    #[test]
+    fn exit_phase() {
+        assert_eq!(ExitPhase::Exiting(0.25).visibility(), 0.75);
+    }
+}
+
+/// Derived types can be animated by the widgets when both features are enabled.
+#[cfg(all(feature = "derive", feature = "widgets"))]
+mod derive_widgets {
+    use super::*;
+    use iced_anim::{widget::retain_exit, Animate};
+
+    #[derive(Animate, Debug, Clone, PartialEq)]
+    struct Offset {
+        x: f32,
+        y: f32,
+    }
+
+    #[test]
+    fn derived_values_in_widgets() {
+        let _: Element<'_, ()> = AnimationBuilder::new(Offset { x: 0.0, y: 0.0 }, |offset| {
+            retain_exit("offset", true, move |_| {
+                iced::widget::text(offset.x + offset.y).into()
+            })
+            .into()
+        })
+        .into();
+    }
+}
@@ -0,0 +1,72 @@
+//! Compile tests for the public API that's usable in const contexts.
+//!
+//! Run these with the `rust-version` declared in `Cargo.toml`, e.g. `cargo +1.80 test --test msrv`,
+//! so anything here that needs a newer compiler, like floating point arithmetic in a `const fn`,
+//! fails to build instead of silently raising the minimum supported Rust version.
+use std::time::Duration;
+
+use iced_anim::{
+    animated::DEFAULT_DURATION,
+    spring::Motion,
+    transition::{bezier, Curve, Easing},
+    RelativePoint, RelativeRect,
+};
+
+const EASE: Curve = Curve::Bezier(bezier::EASE);
+const EASE_IN: Curve = Curve::Bezier(bezier::EASE_IN);
+const EASE_OUT: Curve = Curve::Bezier(bezier::EASE_OUT);
+const EASE_IN_OUT: Curve = Curve::Bezier(bezier::EASE_IN_OUT);
+
+const SLOW_EASING: Easing = Easing {
+    curve: EASE_IN_OUT,
+    duration: Duration::from_secs(2),
+    reversible: true,
+    chain_smoothing: false,
+    velocity_matching: false,
+};
+
+const MOTIONS: [Motion; 4] = [
+    Motion::SMOOTH,
+    Motion::SNAPPY,
+    Motion::BOUNCY,
+    Motion::INSTANT,
+];
+const EASINGS: [Easing; 5] = [
+    Easing::LINEAR,
+    Easing::EASE,
+    Easing::EASE_IN,
+    Easing::EASE_OUT,
+    Easing::EASE_IN_OUT,
+];
+
+const POINT: RelativePoint = RelativePoint::new(0.25, 0.75);
+const RECT: RelativeRect = RelativeRect::new(0.0, 0.0, 0.5, 0.5);
+
+/// The bezier presets should behave the same as their named curves.
+#[test]
+fn const_curves_match_named_curves() {
+    let curves = [
+        (EASE, Curve::Ease),
+        (EASE_IN, Curve::EaseIn),
+        (EASE_OUT, Curve::EaseOut),
+        (EASE_IN_OUT, Curve::EaseInOut),
+    ];
+
+    for (bezier, named) in curves {
+        for progress in [0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0] {
+            assert_eq!(bezier.value(progress), named.value(progress));
+        }
+    }
+}
+
+/// Constants built from the public API keep the values they were given.
+#[test]
+fn const_items() {
+    assert_eq!(SLOW_EASING.duration, Duration::from_secs(2));
+    assert!(EASINGS
+        .iter()
+        .all(|easing| easing.duration == DEFAULT_DURATION));
+    assert_eq!(MOTIONS.len(), 4);
+    assert_eq!(POINT, RelativePoint::new(0.25, 0.75));
+    assert_eq!(RECT, RelativeRect::new(0.0, 0.0, 0.5, 0.5));
+}
@@ -0,0 +1,40 @@
+//! Tests for the crate-wide switch that disables animations.
+//!
+//! The switch is global, so these live in their own test binary where flipping it can't affect
+//! animations in other tests running at the same time.
+use std::time::{Duration, Instant};
+
+use iced_anim::{spring::Motion, transition::Easing, Animated, Event};
+
+/// Disabling animations should settle running animations on their next tick and make new targets
+/// settle immediately, until animations are enabled again.
+#[test]
+fn disabling_animations_settles_values() {
+    let mut spring = Animated::spring(0.0_f32, Motion::SMOOTH);
+    let mut transition = Animated::transition(0.0_f32, Easing::LINEAR);
+    spring.set_target(1.0);
+    transition.update(Event::Target(1.0));
+
+    let now = Instant::now() + Duration::from_millis(16);
+    spring.tick(now);
+    transition.tick(now);
+    assert!(spring.is_animating() && transition.is_animating());
+
+    iced_anim::set_animations_enabled(false);
+    assert!(!iced_anim::animations_enabled());
+
+    let now = now + Duration::from_millis(16);
+    spring.tick(now);
+    transition.tick(now);
+    assert!(!spring.is_animating() && !transition.is_animating());
+    assert_eq!((*spring.value(), *transition.value()), (1.0, 1.0));
+
+    spring.set_target(2.0);
+    transition.update(Event::Target(2.0));
+    assert!(!spring.is_animating() && !transition.is_animating());
+    assert_eq!((*spring.value(), *transition.value()), (2.0, 2.0));
+
+    iced_anim::set_animations_enabled(true);
+    spring.set_target(3.0);
+    assert!(spring.is_animating());
+}
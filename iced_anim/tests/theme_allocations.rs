@@ -0,0 +1,57 @@
+//! Tests that animating a theme doesn't allocate every frame.
+//!
+//! Allocations are counted by a global allocator, so these live in their own test binary where
+//! the allocator can't affect other tests.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use iced::Theme;
+use iced_anim::Animate;
+
+thread_local! {
+    /// The number of allocations made by the current thread.
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// The system allocator, counting the allocations of each thread.
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.set(ALLOCATIONS.get() + 1);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Once the first frames have allocated the in-between themes, later frames should reuse them
+/// without allocating, even while something else holds the previous frame's theme like the
+/// runtime does.
+#[test]
+fn theme_lerp_reuses_allocations() {
+    let (start, end) = (Theme::Light, Theme::Dark);
+    let mut theme = start.clone();
+    let mut held = theme.clone();
+
+    for step in 1..=2 {
+        theme.lerp(&start, &end, step as f32 / 20.0);
+        held = theme.clone();
+    }
+
+    let before = ALLOCATIONS.get();
+    for step in 3..=20 {
+        theme.lerp(&start, &end, step as f32 / 20.0);
+        held = theme.clone();
+    }
+    assert_eq!(ALLOCATIONS.get() - before, 0);
+
+    drop(held);
+}
@@ -1,7 +1,7 @@
 //! Derive macro for the `Animate` trait in `iced_anim`.
 //!
-//! This makes it easy to animate your own custom structs. Ensure each field in your struct
-//! already implements the `Animate` trait to derive it automatically. See the documentation
+//! This makes it easy to animate your own custom structs and enums. Ensure each field in your
+//! type already implements the `Animate` trait to derive it automatically. See the documentation
 //! for `iced_anim` for usage and more details.
 extern crate proc_macro;
 extern crate syn;
@@ -9,78 +9,433 @@ extern crate syn;
 extern crate quote;
 
 use proc_macro::TokenStream;
-use syn::{parse_macro_input, Data, DeriveInput, Fields};
+use proc_macro2::TokenTree;
+use quote::ToTokens;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Field, Fields, Ident};
 
 /// Derive macro generating an impl of the trait `Animate`.
-#[proc_macro_derive(Animate)]
+///
+/// Structs animate each of their named fields. Enums animate the fields of a variant only when
+/// the start, end, and current values are all that same variant, and otherwise switch between
+/// variants without animating, similar to `iced::Background`. Tuple structs, unit structs, and
+/// unions report a compile error instead and need to implement `Animate` manually.
+///
+/// Fields that don't implement `Animate` or shouldn't be animated, like identifiers or labels,
+/// can be excluded with `#[animate(skip)]`. Skipped fields keep their current value while the
+/// rest of the type animates.
+///
+/// Structs also get a `<field>_range()` method for each animated field, returning the range of
+/// components the field uses in that value. Passing it to `Spring::with_component_motion` or
+/// `Animated::with_field_motion` gives that field its own motion, e.g. a bouncy position with a
+/// smooth color.
+///
+/// Generic types get an `Animate` bound for each type parameter used by an animated field, in
+/// addition to any bounds and where clauses they already have.
+#[proc_macro_derive(Animate, attributes(animate))]
 pub fn animate_derive(input: TokenStream) -> TokenStream {
     // Parse the input tokens into a syntax tree
-    let input = parse_macro_input!(input as DeriveInput);
+    let mut input = parse_macro_input!(input as DeriveInput);
 
     // Extract the identifier and data from the input
     let name = input.ident;
     let data = input.data;
 
-    // TODO: Support other types of data structures
-    let Data::Struct(data_struct) = data else {
-        panic!("Animate can only be derived for structs");
+    // Report any invalid `#[animate(...)]` attributes before generating anything.
+    let fields: Vec<&Field> = match &data {
+        Data::Struct(data_struct) => data_struct.fields.iter().collect(),
+        Data::Enum(data_enum) => data_enum
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .collect(),
+        Data::Union(_) => Vec::new(),
     };
+    if let Some(error) = fields.iter().find_map(|f| is_skipped(f).err()) {
+        return error.to_compile_error().into();
+    }
 
-    let Fields::Named(fields) = data_struct.fields else {
-        panic!("Animate can only be derived for structs with named fields");
-    };
+    // Type parameters used by animated fields need to be animatable themselves.
+    let animated_params: Vec<Ident> = input
+        .generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .filter(|param| {
+            fields
+                .iter()
+                .filter(|f| !is_skipped(f).unwrap_or_default())
+                .any(|f| mentions(f.ty.to_token_stream(), param))
+        })
+        .collect();
+    for param in animated_params {
+        input
+            .generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#param: ::iced_anim::Animate));
+    }
 
-    let component_fields = fields.named.iter().map(|f| {
-        let ty = &f.ty;
-        quote! {
-            total += <#ty as ::iced_anim::Animate>::components();
-        }
-    });
+    // Parameters that aren't animated, like those of skipped fields, may not be `Clone` or
+    // `PartialEq`, so require that of the whole type as `Animate` does.
+    if input.generics.type_params().next().is_some() {
+        let (_, ty_generics, _) = input.generics.split_for_impl();
+        let predicate = parse_quote! {
+            #name #ty_generics: ::core::clone::Clone + ::core::cmp::PartialEq
+        };
+        input
+            .generics
+            .make_where_clause()
+            .predicates
+            .push(predicate);
+    }
 
-    let update_fields = fields.named.iter().map(|f| {
-        let name = &f.ident;
-        quote! {
-            ::iced_anim::Animate::update(&mut self.#name, components);
-        }
-    });
+    // Forward any generics, including const generics and where clauses, to the impl.
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let (components, instance_components, update, distance_to, lerp, reshape, ranges) = match data {
+        Data::Struct(data_struct) => {
+            let fields = match data_struct.fields {
+                Fields::Named(fields) => fields,
+                Fields::Unnamed(fields) => return unsupported(fields, "tuple structs"),
+                Fields::Unit => return unsupported(name, "unit structs"),
+            };
+            let fields: Vec<&Field> = fields
+                .named
+                .iter()
+                .filter(|f| !is_skipped(f).unwrap_or_default())
+                .collect();
+
+            let component_fields = fields.iter().map(|f| {
+                let ty = &f.ty;
+                quote! {
+                    total += <#ty as ::iced_anim::Animate>::components();
+                }
+            });
+
+            let instance_component_fields = fields.iter().map(|f| {
+                let name = &f.ident;
+                quote! {
+                    total += ::iced_anim::Animate::instance_components(&self.#name);
+                }
+            });
+
+            let update_fields = fields.iter().map(|f| {
+                let name = &f.ident;
+                quote! {
+                    ::iced_anim::Animate::update(&mut self.#name, components);
+                }
+            });
+
+            let distance_fields = fields.iter().map(|f| {
+                let name = &f.ident;
+                quote! {
+                    distances.push(::iced_anim::Animate::distance_to(&self.#name, &end.#name));
+                }
+            });
+
+            let lerp_fields = fields.iter().map(|f| {
+                let name = &f.ident;
+                quote! {
+                    ::iced_anim::Animate::lerp(&mut self.#name, &start.#name, &end.#name, progress);
+                }
+            });
 
-    let distance_fields = fields.named.iter().map(|f| {
-        let name = &f.ident;
-        quote! {
-            distances.push(::iced_anim::Animate::distance_to(&self.#name, &end.#name));
+            let reshape_fields = fields.iter().map(|f| {
+                let name = &f.ident;
+                quote! {
+                    ::iced_anim::Animate::reshape(&mut self.#name, &target.#name);
+                }
+            });
+
+            // Each field's components start after those of the animated fields before it.
+            let range_fields = fields.iter().enumerate().map(|(i, f)| {
+                let vis = &f.vis;
+                let name = f.ident.as_ref().expect("named fields have identifiers");
+                let range = format_ident!("{}_range", name);
+                let previous = fields[..i].iter().map(|f| &f.ident);
+                let doc = format!(
+                    "The range of components used by the `{name}` field when it's animated."
+                );
+                quote! {
+                    #[doc = #doc]
+                    #vis fn #range(&self) -> ::core::ops::Range<::core::primitive::usize> {
+                        let start = 0
+                            #(+ ::iced_anim::Animate::instance_components(&self.#previous))*;
+                        start..start + ::iced_anim::Animate::instance_components(&self.#name)
+                    }
+                }
+            });
+
+            (
+                quote! {
+                    let mut total = 0;
+                    #(#component_fields)*
+                    total
+                },
+                quote! {
+                    let mut total = 0;
+                    #(#instance_component_fields)*
+                    total
+                },
+                quote! {
+                    #(#update_fields)*
+                },
+                quote! {
+                    let mut distances: ::std::vec::Vec<::std::vec::Vec<::core::primitive::f32>> =
+                        ::std::vec::Vec::with_capacity(Self::components());
+                    #(#distance_fields)*
+                    distances.concat()
+                },
+                quote! {
+                    #(#lerp_fields)*
+                },
+                quote! {
+                    #(#reshape_fields)*
+                },
+                quote! {
+                    #(#range_fields)*
+                },
+            )
         }
-    });
+        Data::Enum(data_enum) => {
+            // Each variant binds its animated fields to numbered identifiers so named, tuple, and
+            // unit variants can all be matched the same way. Skipped fields are ignored.
+            let variants: Vec<_> = data_enum
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    let skipped: Vec<bool> = variant
+                        .fields
+                        .iter()
+                        .map(|f| is_skipped(f).unwrap_or_default())
+                        .collect();
+                    let types: Vec<_> = variant
+                        .fields
+                        .iter()
+                        .zip(&skipped)
+                        .filter(|(_, skipped)| !**skipped)
+                        .map(|(f, _)| &f.ty)
+                        .collect();
+                    let binding = |prefix: &str, i: usize| format_ident!("__{}_{}", prefix, i);
+                    let bindings = |prefix: &str| {
+                        (0..skipped.len())
+                            .filter(|i| !skipped[*i])
+                            .map(|i| binding(prefix, i))
+                            .collect::<Vec<_>>()
+                    };
+                    let pattern = |prefix: &str| {
+                        let bindings = skipped.iter().enumerate().map(|(i, skipped)| {
+                            if *skipped {
+                                quote! { _ }
+                            } else {
+                                let binding = binding(prefix, i);
+                                quote! { #binding }
+                            }
+                        });
+                        match &variant.fields {
+                            Fields::Named(fields) => {
+                                let names = fields.named.iter().map(|f| &f.ident);
+                                quote! { Self::#ident { #(#names: #bindings),* } }
+                            }
+                            Fields::Unnamed(_) => quote! { Self::#ident(#(#bindings),*) },
+                            Fields::Unit => quote! { Self::#ident },
+                        }
+                    };
+
+                    let variant_components = quote! {
+                        0 #(+ <#types as ::iced_anim::Animate>::components())*
+                    };
+                    let (value, start, end) =
+                        (bindings("value"), bindings("start"), bindings("end"));
+                    let (value_pattern, start_pattern, end_pattern) =
+                        (pattern("value"), pattern("start"), pattern("end"));
+                    let value_components = quote! {
+                        0 #(+ ::iced_anim::Animate::instance_components(&*#value))*
+                    };
+
+                    let component = quote! {
+                        total = total.max(#variant_components);
+                    };
+
+                    // Variants with fewer components than others are padded to the same size.
+                    let instance_component = quote! {
+                        #value_pattern => Self::components().max(#value_components),
+                    };
+
+                    // Skip the components that other variants use so the next value
+                    // starts at the right place.
+                    let update = quote! {
+                        #value_pattern => {
+                            let used = #value_components;
+                            #(::iced_anim::Animate::update(#value, components);)*
+                            for _ in used..Self::components() {
+                                components.next();
+                            }
+                        }
+                    };
+
+                    let distance = quote! {
+                        (#value_pattern, #end_pattern) => {
+                            let mut distances =
+                                ::std::vec::Vec::<::core::primitive::f32>::with_capacity(
+                                    Self::components(),
+                                );
+                            #(distances.extend(::iced_anim::Animate::distance_to(#value, #end));)*
+                            distances.resize(distances.len().max(Self::components()), 0.0);
+                            distances
+                        }
+                    };
+
+                    let lerp = quote! {
+                        (#value_pattern, #start_pattern, #end_pattern) => {
+                            #(::iced_anim::Animate::lerp(#value, #start, #end, progress);)*
+                        }
+                    };
+
+                    let reshape = quote! {
+                        (#value_pattern, #end_pattern) => {
+                            #(::iced_anim::Animate::reshape(#value, #end);)*
+                        }
+                    };
 
-    let lerp_fields = fields.named.iter().map(|f| {
-        let name = &f.ident;
-        quote! {
-            ::iced_anim::Animate::lerp(&mut self.#name, &start.#name, &end.#name, progress);
+                    (
+                        component,
+                        instance_component,
+                        update,
+                        distance,
+                        lerp,
+                        reshape,
+                    )
+                })
+                .collect();
+
+            let component_variants = variants.iter().map(|(component, ..)| component);
+            let instance_component_variants = variants.iter().map(|(_, instance, ..)| instance);
+            let update_variants = variants.iter().map(|(_, _, update, ..)| update);
+            let distance_variants = variants.iter().map(|(_, _, _, distance, ..)| distance);
+            let lerp_variants = variants.iter().map(|(.., lerp, _)| lerp);
+            let reshape_variants = variants.iter().map(|(.., reshape)| reshape);
+
+            (
+                quote! {
+                    let mut total = 0;
+                    #(#component_variants)*
+                    total
+                },
+                quote! {
+                    match self {
+                        #(#instance_component_variants)*
+                        _ => Self::components(),
+                    }
+                },
+                quote! {
+                    match self {
+                        #(#update_variants)*
+                        _ => {}
+                    }
+                },
+                // Different variants can't be animated between, so they have no distance.
+                quote! {
+                    match (self, end) {
+                        #(#distance_variants)*
+                        _ => ::std::vec![0.0; ::iced_anim::Animate::instance_components(self)],
+                    }
+                },
+                quote! {
+                    match (self, start, end) {
+                        #(#lerp_variants)*
+                        _ => {}
+                    }
+                },
+                quote! {
+                    match (self, target) {
+                        #(#reshape_variants)*
+                        _ => {}
+                    }
+                },
+                // Variants share the same components, so fields don't have their own ranges.
+                quote! {},
+            )
         }
-    });
+        Data::Union(data_union) => return unsupported(data_union.union_token, "unions"),
+    };
 
+    // Enums with a single variant make the fallback match arms unreachable.
     let impl_gen = quote! {
-        impl ::iced_anim::Animate for #name {
+        #[allow(unreachable_patterns)]
+        impl #impl_generics ::iced_anim::Animate for #name #ty_generics #where_clause {
             fn components() -> usize {
-                let mut total = 0;
-                #(#component_fields)*
-                total
+                #components
+            }
+
+            fn instance_components(&self) -> usize {
+                #instance_components
             }
 
             fn update(&mut self, components: &mut impl Iterator<Item = ::core::primitive::f32>) {
-                #(#update_fields)*
+                #update
             }
 
             fn distance_to(&self, end: &Self) -> ::std::vec::Vec<::core::primitive::f32> {
-                let mut distances = ::std::vec::Vec::with_capacity(Self::components());
-                #(#distance_fields)*
-                distances.concat()
+                #distance_to
             }
 
             fn lerp(&mut self, start: &Self, end: &Self, progress: ::core::primitive::f32) {
-                #(#lerp_fields)*
+                #lerp
             }
+
+            fn reshape(&mut self, target: &Self) {
+                #reshape
+            }
+        }
+
+        // The ranges of private fields may only be used by some of the crate.
+        #[allow(dead_code)]
+        impl #impl_generics #name #ty_generics #where_clause {
+            #ranges
         }
     };
 
     TokenStream::from(impl_gen)
 }
+
+/// A spanned compile error for a `kind` of type that `Animate` can't be derived for.
+fn unsupported(tokens: impl ToTokens, kind: &str) -> TokenStream {
+    let message = format!(
+        "`Animate` cannot be derived for {kind} yet; use a struct with named fields or an enum, \
+        or implement it manually along with `Clone` and `PartialEq`"
+    );
+    syn::Error::new_spanned(tokens, message)
+        .to_compile_error()
+        .into()
+}
+
+/// Whether the `field` is excluded from animating with `#[animate(skip)]`.
+fn is_skipped(field: &Field) -> syn::Result<bool> {
+    let mut skipped = false;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("animate"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skipped = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported animate attribute, expected `skip`"))
+            }
+        })?;
+    }
+
+    Ok(skipped)
+}
+
+/// Whether the `tokens` of a type mention the identifier `ident` anywhere, e.g. `T` in `Vec<T>`.
+fn mentions(tokens: proc_macro2::TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(token) => &token == ident,
+        TokenTree::Group(group) => mentions(group.stream(), ident),
+        _ => false,
+    })
+}
@@ -0,0 +1,6 @@
+//! Compile errors for types that `Animate` can't be derived for.
+#[test]
+fn ui() {
+    let tests = trybuild::TestCases::new();
+    tests.compile_fail("tests/ui/*.rs");
+}
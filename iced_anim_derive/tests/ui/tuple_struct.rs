@@ -0,0 +1,6 @@
+use iced_anim_derive::Animate;
+
+#[derive(Animate)]
+struct Offset(f32, f32);
+
+fn main() {}
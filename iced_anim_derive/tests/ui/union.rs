@@ -0,0 +1,9 @@
+use iced_anim_derive::Animate;
+
+#[derive(Animate)]
+union Value {
+    float: f32,
+    int: u32,
+}
+
+fn main() {}
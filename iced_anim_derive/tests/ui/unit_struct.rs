@@ -0,0 +1,6 @@
+use iced_anim_derive::Animate;
+
+#[derive(Animate)]
+struct Empty;
+
+fn main() {}
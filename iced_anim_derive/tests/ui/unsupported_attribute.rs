@@ -0,0 +1,9 @@
+use iced_anim_derive::Animate;
+
+#[derive(Animate)]
+struct Label {
+    #[animate(ignore)]
+    text: String,
+}
+
+fn main() {}